@@ -18,9 +18,9 @@ use super::*;
 use cumulus_primitives_core::ParaId;
 use rococo_parachain_runtime::{
 	AccountId, AuraId, Balance, BalancesConfig, BitacrossConfig, CouncilMembershipConfig,
-	GenesisConfig, ParachainInfoConfig, ParachainStakingConfig, PolkadotXcmConfig, SessionConfig,
-	SudoConfig, SystemConfig, TechnicalCommitteeMembershipConfig, TeebagConfig,
-	TeebagOperationalMode, VCManagementConfig, UNIT, WASM_BINARY,
+	EvmAssertionsConfig, GenesisConfig, ParachainInfoConfig, ParachainStakingConfig,
+	PolkadotXcmConfig, SessionConfig, SudoConfig, SystemConfig, TechnicalCommitteeMembershipConfig,
+	TeebagConfig, TeebagOperationalMode, VCManagementConfig, UNIT, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sc_telemetry::TelemetryEndpoints;
@@ -246,6 +246,7 @@ fn generate_genesis(
 			admin: Some(root_key.clone()),
 			mode: TeebagOperationalMode::Development,
 		},
-		bitacross: BitacrossConfig { admin: Some(root_key) },
+		bitacross: BitacrossConfig { admin: Some(root_key.clone()) },
+		evm_assertions: EvmAssertionsConfig { admin: Some(root_key) },
 	}
 }
@@ -48,6 +48,47 @@ use crate::tracing;
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
+/// The parachain staking RPC, only merged for the runtimes with parachain staking,
+/// litmus selects its collators with pallet-collator-selection instead
+pub trait ParachainStakingRpc<C> {
+	fn merge_parachain_staking_rpc(
+		module: &mut RpcExtension,
+		client: Arc<C>,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+macro_rules! impl_parachain_staking_rpc {
+	($runtime_api:ty) => {
+		impl<C> ParachainStakingRpc<C> for $runtime_api
+		where
+			C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+			C::Api:
+				pallet_parachain_staking_rpc::ParachainStakingRuntimeApi<Block, AccountId, Balance>,
+		{
+			fn merge_parachain_staking_rpc(
+				module: &mut RpcExtension,
+				client: Arc<C>,
+			) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+				use pallet_parachain_staking_rpc::{ParachainStaking, ParachainStakingApiServer};
+				module.merge(ParachainStaking::<_, _, Balance>::new(client).into_rpc())?;
+				Ok(())
+			}
+		}
+	};
+}
+
+impl_parachain_staking_rpc!(litentry_parachain_runtime::RuntimeApi);
+impl_parachain_staking_rpc!(rococo_parachain_runtime::RuntimeApi);
+
+impl<C> ParachainStakingRpc<C> for litmus_parachain_runtime::RuntimeApi {
+	fn merge_parachain_staking_rpc(
+		_module: &mut RpcExtension,
+		_client: Arc<C>,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+		Ok(())
+	}
+}
+
 #[derive(Clone)]
 pub struct EvmTracingConfig {
 	pub tracing_requesters: tracing::RpcRequesters,
@@ -296,8 +296,11 @@ async fn start_node_impl<RuntimeApi, Executor, RB, BIQ, BIC>(
 	hwbench: Option<sc_sysinfo::HwBench>,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient<RuntimeApi, Executor>>)>
 where
-	RuntimeApi:
-		ConstructRuntimeApi<Block, ParachainClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi: ConstructRuntimeApi<Block, ParachainClient<RuntimeApi, Executor>>
+		+ crate::rpc::ParachainStakingRpc<ParachainClient<RuntimeApi, Executor>>
+		+ Send
+		+ Sync
+		+ 'static,
 	RuntimeApi::RuntimeApi: sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
 		+ sp_api::Metadata<Block>
 		+ sp_session::SessionKeys<Block>
@@ -455,6 +458,10 @@ where
 				pubsub_notification_sinks.clone(),
 				rpc_config.clone(),
 			)
+			.and_then(|mut module| {
+				RuntimeApi::merge_parachain_staking_rpc(&mut module, client.clone())?;
+				Ok(module)
+			})
 			.map_err(Into::into)
 		};
 		Box::new(result)
@@ -563,8 +570,11 @@ pub async fn start_node<RuntimeApi, Executor>(
 	hwbench: Option<sc_sysinfo::HwBench>,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient<RuntimeApi, Executor>>)>
 where
-	RuntimeApi:
-		ConstructRuntimeApi<Block, ParachainClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi: ConstructRuntimeApi<Block, ParachainClient<RuntimeApi, Executor>>
+		+ crate::rpc::ParachainStakingRpc<ParachainClient<RuntimeApi, Executor>>
+		+ Send
+		+ Sync
+		+ 'static,
 	RuntimeApi::RuntimeApi: sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
 		+ sp_api::Metadata<Block>
 		+ sp_session::SessionKeys<Block>
@@ -783,8 +793,11 @@ pub async fn start_standalone_node<RuntimeApi, Executor>(
 	evm_tracing_config: crate::evm_tracing_types::EvmTracingConfig,
 ) -> Result<TaskManager, sc_service::Error>
 where
-	RuntimeApi:
-		ConstructRuntimeApi<Block, ParachainClient<RuntimeApi, Executor>> + Send + Sync + 'static,
+	RuntimeApi: ConstructRuntimeApi<Block, ParachainClient<RuntimeApi, Executor>>
+		+ crate::rpc::ParachainStakingRpc<ParachainClient<RuntimeApi, Executor>>
+		+ Send
+		+ Sync
+		+ 'static,
 	RuntimeApi::RuntimeApi: sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
 		+ sp_api::Metadata<Block>
 		+ sp_session::SessionKeys<Block>
@@ -988,6 +1001,10 @@ where
 				pubsub_notification_sinks.clone(),
 				rpc_config.clone(),
 			)
+			.and_then(|mut module| {
+				RuntimeApi::merge_parachain_staking_rpc(&mut module, client.clone())?;
+				Ok(module)
+			})
 			.map_err(Into::into)
 		})
 	};
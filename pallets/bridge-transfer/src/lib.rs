@@ -33,7 +33,7 @@ pub mod pallet {
 	use crate::weights::WeightInfo;
 	use frame_support::{
 		pallet_prelude::*,
-		traits::{fungible::Mutate, Currency, SortedMembers, StorageVersion},
+		traits::{fungible::Mutate, Currency, IsSubType, SortedMembers, StorageVersion},
 		transactional,
 	};
 	use frame_system::pallet_prelude::*;
@@ -215,6 +215,23 @@ pub mod pallet {
 
 	impl<T: Config> Pallet<T> {}
 
+	/// Extract the amount of the `transfer` proposals for the inflow limits of the bridge.
+	pub struct BridgeTransferProposalAmount<T>(PhantomData<T>);
+	impl<T: Config> bridge::ExtractProposalAmount<<T as bridge::Config>::Proposal, BalanceOf<T>>
+		for BridgeTransferProposalAmount<T>
+	where
+		<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+	{
+		fn proposal_amount(
+			proposal: &<T as bridge::Config>::Proposal,
+		) -> Option<(ResourceId, BalanceOf<T>)> {
+			match proposal.into_ref().is_sub_type()? {
+				Call::transfer { amount, rid, .. } => Some((*rid, *amount)),
+				_ => None,
+			}
+		}
+	}
+
 	impl<T: Config> IssuanceAdapter<BalanceOf<T>> for Pallet<T> {
 		fn adapted_total_issuance() -> BalanceOf<T> {
 			<ExternalBalances<T>>::get()
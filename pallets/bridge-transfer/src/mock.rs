@@ -25,7 +25,7 @@ use frame_system::{self as system, EnsureSignedBy};
 use hex_literal::hex;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
 };
 
@@ -115,7 +115,12 @@ impl bridge::Config for Test {
 	type BridgeChainId = TestChainId;
 	type Currency = Balances;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxPrunedProposals = ConstU32<100>;
+	type MaxRelayerSignatures = ConstU32<16>;
 	type TreasuryAccount = TreasuryAccount;
+	type ProposalAmount = bridge_transfer::BridgeTransferProposalAmount<Test>;
+	type RelayerSignature = TestSignature;
+	type RelayerPublic = UintAuthorityId;
 	type WeightInfo = ();
 }
 
@@ -372,3 +372,60 @@ fn set_maximum_issuance_fails_with_unprivileged_origin() {
 		assert_eq!(pallet::MaximumIssuance::<Test>::get(), mock::MaximumIssuance::get());
 	});
 }
+
+#[test]
+fn proposal_amount_works() {
+	use bridge::ExtractProposalAmount;
+
+	let proposal = make_transfer_proposal(RELAYER_A, 10);
+	assert_eq!(
+		BridgeTransferProposalAmount::<Test>::proposal_amount(&proposal),
+		Some((NativeTokenResourceId::get(), 10))
+	);
+
+	let proposal = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	assert_eq!(BridgeTransferProposalAmount::<Test>::proposal_amount(&proposal), None);
+}
+
+#[test]
+fn transfer_proposal_over_inflow_limit_is_queued() {
+	new_test_ext().execute_with(|| {
+		let prop_id = 1;
+		let src_id = 1;
+		let r_id = bridge::derive_resource_id(src_id, b"transfer");
+		let resource = b"BridgeTransfer.transfer".to_vec();
+		let proposal = make_transfer_proposal(RELAYER_A, 10);
+
+		assert_ok!(Bridge::set_threshold(RuntimeOrigin::root(), TEST_THRESHOLD,));
+		assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), RELAYER_A));
+		assert_ok!(Bridge::add_relayer(RuntimeOrigin::root(), RELAYER_B));
+		assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), src_id));
+		assert_ok!(Bridge::set_resource(RuntimeOrigin::root(), r_id, resource));
+		assert_ok!(Bridge::set_volume_limit(
+			RuntimeOrigin::root(),
+			bridge::TransferDirection::Inflow,
+			bridge::LimitScope::Resource(NativeTokenResourceId::get()),
+			Some(bridge::VolumeLimit { limit: 5, window: 10 })
+		));
+
+		for relayer in [RELAYER_A, RELAYER_B] {
+			assert_ok!(Bridge::acknowledge_proposal(
+				RuntimeOrigin::signed(relayer),
+				prop_id,
+				src_id,
+				r_id,
+				Box::new(proposal.clone())
+			));
+		}
+
+		// nothing is minted
+		assert_eq!(Balances::free_balance(RELAYER_A), ENDOWED_BALANCE);
+		assert_eq!(Bridge::queued_proposals(src_id, prop_id), Some(proposal));
+		assert!(Bridge::bridge_paused());
+		assert_events(vec![
+			RuntimeEvent::Bridge(bridge::Event::ProposalApproved(src_id, prop_id)),
+			RuntimeEvent::Bridge(bridge::Event::ProposalQueued { src_id, nonce: prop_id }),
+			RuntimeEvent::Bridge(bridge::Event::BridgePaused { by_limit: true }),
+		]);
+	})
+}
@@ -36,6 +36,8 @@ fn make_proposal<T: Config>(remark: Vec<u8>) -> T::Proposal {
 }
 
 benchmarks! {
+	where_clause { where T::RelayerSignature: BenchmarkSignature<T::AccountId> }
+
 	set_threshold{
 		let i = 100u32;
 	}:_(RawOrigin::Root,i)
@@ -206,6 +208,118 @@ benchmarks! {
 
 	}:_(RawOrigin::Signed(relayer_id_c),prop_id,src_id,Box::new(proposal))
 
+	set_volume_limit{
+		let scope = LimitScope::Chain(1);
+		let limit = VolumeLimit { limit: 100u32.into(), window: 10u32.into() };
+	}:_(RawOrigin::Root,TransferDirection::Outflow,scope,Some(limit.clone()))
+	verify{
+		assert_eq!(VolumeLimits::<T>::get(TransferDirection::Outflow, scope), Some(limit));
+	}
+
+	release_queued_proposal{
+		let src_id: BridgeChainId = 1;
+		let nonce: DepositNonce = 1;
+		QueuedProposals::<T>::insert(src_id, nonce, make_proposal::<T>(vec![]));
+	}:_(RawOrigin::Root,src_id,nonce,Weight::MAX)
+	verify{
+		assert_last_event::<T>(Event::ProposalSucceeded(src_id, nonce).into());
+	}
+
+	cancel_queued_proposal{
+		let src_id: BridgeChainId = 1;
+		let nonce: DepositNonce = 1;
+		QueuedProposals::<T>::insert(src_id, nonce, make_proposal::<T>(vec![]));
+	}:_(RawOrigin::Root,src_id,nonce)
+	verify{
+		assert!(!QueuedProposals::<T>::contains_key(src_id, nonce));
+	}
+
+	set_bridge_paused{
+	}:_(RawOrigin::Root,true)
+	verify{
+		assert!(Paused::<T>::get());
+	}
+
+	prune_expired_proposals{
+		let n in 1 .. T::MaxPrunedProposals::get();
+		let r in 0 .. 64;
+
+		let src_id: BridgeChainId = 1;
+		let now = frame_system::Pallet::<T>::block_number();
+		for i in 0 .. r {
+			let relayer: T::AccountId = account("RELAYER", i, USER_SEED);
+			RelayerStatistics::<T>::insert(relayer, RelayerStats::default());
+		}
+		RelayerCount::<T>::put(r);
+
+		// the last proposal expired without decision, the others are completed and removed
+		// from `CompletedProposals` right away
+		let mut proposals = vec![];
+		for nonce in 1 ..= n as DepositNonce {
+			let proposal = make_proposal::<T>(vec![0u8; 32]);
+			let status = if nonce == n as DepositNonce {
+				ProposalStatus::Initiated
+			} else {
+				ProposalStatus::Approved
+			};
+			let votes = ProposalVotes {
+				votes_for: vec![],
+				votes_against: vec![],
+				status,
+				expiry: now,
+			};
+			Votes::<T>::insert(src_id, (nonce, proposal.clone()), votes);
+			proposals.push((nonce, Box::new(proposal)));
+		}
+	}:_(RawOrigin::Signed(account("TEST_A", 0u32, USER_SEED)),src_id,proposals)
+	verify{
+		assert_last_event::<T>(Event::ExpiredProposalsPruned {
+			src_id,
+			nonces: (1 ..= n as DepositNonce).collect(),
+		}.into());
+		assert_eq!(CompletedNonce::<T>::get(src_id), n as DepositNonce - 1);
+	}
+
+	acknowledge_proposal_with_signatures{
+		let s in 1 .. T::MaxRelayerSignatures::get();
+
+		let relayer_id: T::AccountId = account("TEST_A", 0u32, USER_SEED);
+		let prop_id:DepositNonce = 1;
+		let src_id:BridgeChainId = T::BridgeChainId::get().saturating_add(1);
+		let r_id:ResourceId = derive_resource_id(src_id, b"remark");
+		let deadline = frame_system::Pallet::<T>::block_number();
+
+		let proposal = make_proposal::<T>(vec![]);
+		let method = vec![0u8];
+
+		bridge::<T>::add_relayer(
+			RawOrigin::Root.into(),
+			relayer_id.clone(),
+		)?;
+
+		bridge::<T>::whitelist_chain(
+			RawOrigin::Root.into(),
+			src_id,
+		)?;
+
+		bridge::<T>::set_resource(
+			RawOrigin::Root.into(),
+			r_id,method,
+		)?;
+
+		let payload = bridge::<T>::proposal_signing_payload(prop_id, src_id, r_id, deadline, &proposal);
+		let mut signatures = vec![];
+		for i in 0 .. s {
+			let (relayer, signature) = T::RelayerSignature::sign(i, &payload[..]);
+			bridge::<T>::add_relayer(RawOrigin::Root.into(), relayer.clone())?;
+			signatures.push((relayer, signature));
+		}
+		// every signature is verified and counted before the proposal is executed
+		bridge::<T>::set_threshold(RawOrigin::Root.into(), s + 1)?;
+	}:_(RawOrigin::Signed(relayer_id),prop_id,src_id,r_id,Box::new(proposal),deadline,signatures)
+	verify{
+		assert_last_event::<T>(Event::ProposalSucceeded(src_id, prop_id).into());
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -30,12 +30,40 @@ pub mod weights;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// Signs the proposal signing payload for the benchmarks, as a new relayer per index
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkSignature<AccountId>: Sized {
+	fn sign(index: u32, payload: &[u8]) -> (AccountId, Self);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkSignature<sp_runtime::AccountId32> for sp_runtime::MultiSignature {
+	fn sign(_index: u32, payload: &[u8]) -> (sp_runtime::AccountId32, Self) {
+		use sp_runtime::traits::IdentifyAccount;
+
+		let key_type = sp_core::crypto::KeyTypeId(*b"brdg");
+		let public = sp_io::crypto::sr25519_generate(key_type, None);
+		let signature = sp_io::crypto::sr25519_sign(key_type, &public, payload)
+			.expect("the key was just generated; qed");
+		(sp_runtime::MultiSigner::from(public).into_account(), signature.into())
+	}
+}
+
+#[cfg(all(feature = "runtime-benchmarks", feature = "std"))]
+impl BenchmarkSignature<u64> for sp_runtime::testing::TestSignature {
+	fn sign(index: u32, payload: &[u8]) -> (u64, Self) {
+		let relayer = 100 + index as u64;
+		(relayer, sp_runtime::testing::TestSignature(relayer, payload.to_vec()))
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::weights::WeightInfo;
 	use frame_support::{
 		dispatch::GetDispatchInfo,
 		traits::{fungible::Mutate, Currency, ExistenceRequirement::AllowDeath, WithdrawReasons},
+		transactional,
 	};
 	pub use frame_support::{pallet_prelude::*, traits::StorageVersion, PalletId, Parameter};
 	use frame_system::{
@@ -45,8 +73,11 @@ pub mod pallet {
 	use parity_scale_codec::{Decode, Encode, EncodeLike};
 	use scale_info::TypeInfo;
 	use sp_runtime::{
-		traits::{AccountIdConversion, Dispatchable},
-		RuntimeDebug, SaturatedConversion,
+		traits::{
+			AccountIdConversion, CheckedAdd, Dispatchable, IdentifyAccount, Saturating, Verify,
+			Zero,
+		},
+		Perbill, RuntimeDebug, SaturatedConversion,
 	};
 	use sp_std::prelude::*;
 
@@ -103,6 +134,66 @@ pub mod pallet {
 		GenericTransfer(BridgeChainId, DepositNonce, ResourceId, Vec<u8>),
 	}
 
+	/// The activity of a relayer, for the committee to evaluate the relayers
+	#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct RelayerStats<BlockNumber> {
+		/// Number of votes for or against proposals
+		pub votes_cast: u32,
+		/// Number of executed proposals the relayer voted for
+		pub proposals_executed: u32,
+		/// Number of proposals the relayer didn't vote on, which expired without decision
+		pub proposals_missed: u32,
+		/// Block number of the last vote
+		pub last_vote_at: Option<BlockNumber>,
+	}
+
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub enum TransferDirection {
+		/// Transfers from other chains into this chain, i.e. executed proposals
+		#[codec(index = 0)]
+		Inflow,
+		/// Transfers from this chain to other chains
+		#[codec(index = 1)]
+		Outflow,
+	}
+
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub enum LimitScope {
+		#[codec(index = 0)]
+		Chain(BridgeChainId),
+		#[codec(index = 1)]
+		Resource(ResourceId),
+	}
+
+	/// At most `limit` can be transferred within a rolling window of `window` blocks.
+	///
+	/// The used allowance is released linearly over the window, e.g. half of it is available
+	/// again after `window / 2` blocks.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct VolumeLimit<Balance, BlockNumber> {
+		pub limit: Balance,
+		pub window: BlockNumber,
+	}
+
+	#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct VolumeUsage<Balance, BlockNumber> {
+		/// The used allowance at `updated_at`
+		pub used: Balance,
+		pub updated_at: BlockNumber,
+	}
+
+	/// Extract the resource and the amount transferred by a proposal,
+	/// which are accounted in the inflow limits.
+	pub trait ExtractProposalAmount<Proposal, Balance> {
+		fn proposal_amount(proposal: &Proposal) -> Option<(ResourceId, Balance)>;
+	}
+
+	impl<Proposal, Balance> ExtractProposalAmount<Proposal, Balance> for () {
+		fn proposal_amount(_proposal: &Proposal) -> Option<(ResourceId, Balance)> {
+			None
+		}
+	}
+
 	impl<A: PartialEq, B: PartialOrd + Default> ProposalVotes<A, B> {
 		/// Attempts to mark the proposal as approve or rejected.
 		/// Returns new status.
@@ -177,9 +268,28 @@ pub mod pallet {
 		#[pallet::constant]
 		type ProposalLifetime: Get<Self::BlockNumber>;
 
+		/// The maximum number of proposals `prune_expired_proposals` takes at once
+		#[pallet::constant]
+		type MaxPrunedProposals: Get<u32>;
+
+		/// The maximum number of signatures `acknowledge_proposal_with_signatures` takes at once
+		#[pallet::constant]
+		type MaxRelayerSignatures: Get<u32>;
+
 		/// Treasury account to receive assets fee
 		type TreasuryAccount: Get<Self::AccountId>;
 
+		/// Extract the amount of proposals for the inflow limits,
+		/// proposals without amount are not limited
+		type ProposalAmount: ExtractProposalAmount<Self::Proposal, BalanceOf<Self>>;
+
+		/// Signature of relayers over the proposal signing payload,
+		/// used by `acknowledge_proposal_with_signatures`
+		type RelayerSignature: Parameter + Verify<Signer = Self::RelayerPublic>;
+
+		/// Public key of relayers that can be identified as their account
+		type RelayerPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -217,6 +327,32 @@ pub mod pallet {
 		ProposalFailed(BridgeChainId, DepositNonce),
 		/// Update bridge transfer fee
 		FeeUpdated { dest_id: BridgeChainId, fee: BalanceOf<T> },
+		/// The volume limit was set, None means the limit was removed
+		VolumeLimitSet {
+			direction: TransferDirection,
+			scope: LimitScope,
+			limit: Option<VolumeLimit<BalanceOf<T>, T::BlockNumber>>,
+		},
+		/// Some allowance of the volume limit was used
+		VolumeUsed {
+			direction: TransferDirection,
+			scope: LimitScope,
+			amount: BalanceOf<T>,
+			remaining: BalanceOf<T>,
+		},
+		/// An approved proposal was queued, because the bridge is paused or the volume limit is
+		/// reached
+		ProposalQueued { src_id: BridgeChainId, nonce: DepositNonce },
+		/// A queued proposal was released by the committee
+		QueuedProposalReleased { src_id: BridgeChainId, nonce: DepositNonce },
+		/// A queued proposal was cancelled by the committee
+		QueuedProposalCancelled { src_id: BridgeChainId, nonce: DepositNonce },
+		/// The bridge was paused, `by_limit` is true if it's caused by a volume limit
+		BridgePaused { by_limit: bool },
+		/// The bridge was unpaused
+		BridgeUnpaused,
+		/// Expired proposals were removed from `Votes`
+		ExpiredProposalsPruned { src_id: BridgeChainId, nonces: Vec<DepositNonce> },
 	}
 
 	#[pallet::error]
@@ -261,6 +397,24 @@ pub mod pallet {
 		CannotPayAsFee,
 
 		NonceOverFlow,
+		/// The window of a volume limit cannot be 0
+		InvalidWindow,
+		/// The transfer exceeds the volume limit
+		VolumeLimitExceeded,
+		/// The bridge is paused
+		BridgePaused,
+		/// No queued proposal with the ID was found
+		QueuedProposalDoesNotExist,
+		/// The given weight bound is lower than the weight of the queued proposal
+		WrongProposalWeight,
+		/// The signature doesn't match the relayer and the proposal
+		InvalidSignature,
+		/// More signatures than relayers or `MaxRelayerSignatures` were provided
+		TooManySignatures,
+		/// The deadline of the signatures has passed
+		SignaturesExpired,
+		/// More proposals than `MaxPrunedProposals` were provided
+		TooManyProposals,
 	}
 
 	#[pallet::storage]
@@ -295,6 +449,26 @@ pub mod pallet {
 		ProposalVotes<T::AccountId, T::BlockNumber>,
 	>;
 
+	/// The proposals which were pruned from `Votes`, either decided or expired without decision,
+	/// kept to prevent them from being voted again
+	#[pallet::storage]
+	#[pallet::getter(fn completed_proposals)]
+	pub type CompletedProposals<T> =
+		StorageDoubleMap<_, Blake2_256, BridgeChainId, Twox64Concat, DepositNonce, ProposalStatus>;
+
+	/// The nonce per source chain up to which every proposal is pruned, the entries of
+	/// `CompletedProposals` up to it are removed. Deposit nonces start at 1.
+	#[pallet::storage]
+	#[pallet::getter(fn completed_nonce)]
+	pub type CompletedNonce<T> = StorageMap<_, Blake2_256, BridgeChainId, DepositNonce, ValueQuery>;
+
+	/// The activity of the relayers, relayers registered before the accounting was introduced
+	/// get their entry upon their first vote
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_stats)]
+	pub type RelayerStatistics<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, RelayerStats<T::BlockNumber>>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn resources)]
 	pub type Resources<T> = StorageMap<_, Blake2_256, ResourceId, Vec<u8>>;
@@ -310,6 +484,46 @@ pub mod pallet {
 	#[pallet::getter(fn bridge_fee)]
 	pub type BridgeFee<T: Config> = StorageMap<_, Twox64Concat, BridgeChainId, BalanceOf<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn volume_limits)]
+	pub type VolumeLimits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TransferDirection,
+		Blake2_256,
+		LimitScope,
+		VolumeLimit<BalanceOf<T>, T::BlockNumber>,
+	>;
+
+	/// The used allowance of the volume limits, use `remaining_allowance` to get the allowance
+	/// available now
+	#[pallet::storage]
+	#[pallet::getter(fn volume_usages)]
+	pub type VolumeUsages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TransferDirection,
+		Blake2_256,
+		LimitScope,
+		VolumeUsage<BalanceOf<T>, T::BlockNumber>,
+	>;
+
+	/// The approved proposals waiting for the release by the committee
+	#[pallet::storage]
+	#[pallet::getter(fn queued_proposals)]
+	pub type QueuedProposals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_256,
+		BridgeChainId,
+		Twox64Concat,
+		DepositNonce,
+		<T as Config>::Proposal,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bridge_paused)]
+	pub type Paused<T> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(_n: T::BlockNumber) -> Weight {
@@ -451,6 +665,62 @@ pub mod pallet {
 			Self::vote_for(who, nonce, src_id, call)
 		}
 
+		/// Commits votes in favour of a provided proposal on behalf of the caller and every
+		/// relayer that signed it off-chain, so that it can be executed in a single extrinsic.
+		///
+		/// Each signature must be made over `proposal_signing_payload` by a registered relayer,
+		/// the signatures can't be used after the `deadline` block.
+		/// The caller's own vote is implied and must not be part of `signatures`.
+		///
+		/// # <weight>
+		/// - O(signatures) verifications + weight of proposed call, regardless of whether execution
+		///   is performed
+		/// # </weight>
+		#[pallet::call_index(15)]
+		#[pallet::weight({
+		let di = call.get_dispatch_info();
+		(< T as Config >::WeightInfo::acknowledge_proposal_with_signatures(signatures.len() as u32)
+		.saturating_add(di.weight),
+		di.class)
+		})]
+		#[transactional]
+		pub fn acknowledge_proposal_with_signatures(
+			origin: OriginFor<T>,
+			nonce: DepositNonce,
+			src_id: BridgeChainId,
+			r_id: ResourceId,
+			call: Box<<T as Config>::Proposal>,
+			deadline: T::BlockNumber,
+			signatures: Vec<(T::AccountId, T::RelayerSignature)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let count = signatures.len() as u32;
+			ensure!(
+				count <= T::MaxRelayerSignatures::get() && count <= Self::relayer_count(),
+				Error::<T>::TooManySignatures
+			);
+			ensure!(Self::is_relayer(&who), Error::<T>::MustBeRelayer);
+			ensure!(Self::chain_whitelisted(src_id), Error::<T>::ChainNotWhitelisted);
+			ensure!(Self::resource_exists(r_id), Error::<T>::ResourceDoesNotExist);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= deadline,
+				Error::<T>::SignaturesExpired
+			);
+
+			let payload = Self::proposal_signing_payload(nonce, src_id, r_id, deadline, &call);
+			for (relayer, signature) in signatures.iter() {
+				ensure!(Self::is_relayer(relayer), Error::<T>::MustBeRelayer);
+				ensure!(signature.verify(&payload[..], relayer), Error::<T>::InvalidSignature);
+			}
+
+			Self::commit_vote(who, nonce, src_id, call.clone(), true)?;
+			for (relayer, _) in signatures {
+				Self::commit_vote(relayer, nonce, src_id, call.clone(), true)?;
+			}
+
+			Self::try_resolve_proposal(nonce, src_id, call)
+		}
+
 		/// Commits a vote against a provided proposal.
 		///
 		/// # <weight>
@@ -498,11 +768,175 @@ pub mod pallet {
 
 			Self::try_resolve_proposal(nonce, src_id, prop)
 		}
+
+		/// Sets or removes the volume limit of a chain or a resource.
+		///
+		/// A transfer must be within the limits of both its chain and its resource.
+		/// Outflows beyond the limits are rejected, inflows beyond the limits are queued and pause
+		/// the bridge.
+		///
+		/// # <weight>
+		/// - O(1) write
+		/// # </weight>
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_volume_limit())]
+		pub fn set_volume_limit(
+			origin: OriginFor<T>,
+			direction: TransferDirection,
+			scope: LimitScope,
+			limit: Option<VolumeLimit<BalanceOf<T>, T::BlockNumber>>,
+		) -> DispatchResult {
+			T::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			match &limit {
+				Some(l) => {
+					ensure!(!l.window.is_zero(), Error::<T>::InvalidWindow);
+					VolumeLimits::<T>::insert(direction, scope, l);
+				},
+				None => VolumeLimits::<T>::remove(direction, scope),
+			}
+			Self::deposit_event(Event::VolumeLimitSet { direction, scope, limit });
+			Ok(())
+		}
+
+		/// Executes a queued proposal, regardless of the volume limits and the pause.
+		///
+		/// # <weight>
+		/// - weight of the queued proposal, bounded by `proposal_weight_bound`
+		/// # </weight>
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::release_queued_proposal()
+			.saturating_add(*proposal_weight_bound))]
+		pub fn release_queued_proposal(
+			origin: OriginFor<T>,
+			src_id: BridgeChainId,
+			nonce: DepositNonce,
+			proposal_weight_bound: Weight,
+		) -> DispatchResult {
+			T::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			let prop = QueuedProposals::<T>::take(src_id, nonce)
+				.ok_or(Error::<T>::QueuedProposalDoesNotExist)?;
+			ensure!(
+				prop.get_dispatch_info().weight.all_lte(proposal_weight_bound),
+				Error::<T>::WrongProposalWeight
+			);
+
+			Self::deposit_event(Event::QueuedProposalReleased { src_id, nonce });
+			Self::execute_proposal(src_id, nonce, Box::new(prop))
+		}
+
+		/// Removes a queued proposal without executing it.
+		///
+		/// # <weight>
+		/// - O(1) removal
+		/// # </weight>
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_queued_proposal())]
+		pub fn cancel_queued_proposal(
+			origin: OriginFor<T>,
+			src_id: BridgeChainId,
+			nonce: DepositNonce,
+		) -> DispatchResult {
+			T::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			ensure!(
+				QueuedProposals::<T>::take(src_id, nonce).is_some(),
+				Error::<T>::QueuedProposalDoesNotExist
+			);
+			Self::deposit_event(Event::QueuedProposalCancelled { src_id, nonce });
+			Ok(())
+		}
+
+		/// Pauses or unpauses the bridge.
+		///
+		/// While the bridge is paused, outflows are rejected and approved proposals are queued.
+		///
+		/// # <weight>
+		/// - O(1) write
+		/// # </weight>
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_bridge_paused())]
+		pub fn set_bridge_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			T::BridgeCommitteeOrigin::ensure_origin(origin)?;
+			Paused::<T>::put(paused);
+			if paused {
+				Self::deposit_event(Event::BridgePaused { by_limit: false });
+			} else {
+				Self::deposit_event(Event::BridgeUnpaused);
+			}
+			Ok(())
+		}
+
+		/// Removes the given expired proposals from `Votes`, anyone can call it.
+		///
+		/// Proposals which are not found or not expired are skipped. Relayers which didn't vote on
+		/// a pruned proposal that expired without decision are accounted as missed.
+		/// The pruned proposals can't be voted again. Those following the completed nonce of the
+		/// chain are removed from `CompletedProposals`. The fee is waived only if every proposal
+		/// is pruned.
+		///
+		/// # <weight>
+		/// - O(proposals + relayers)
+		/// # </weight>
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::prune_expired_proposals(
+			proposals.len() as u32,
+			RelayerCount::<T>::get(),
+		))]
+		pub fn prune_expired_proposals(
+			origin: OriginFor<T>,
+			src_id: BridgeChainId,
+			proposals: Vec<(DepositNonce, Box<<T as Config>::Proposal>)>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let count = proposals.len() as u32;
+			ensure!(count <= T::MaxPrunedProposals::get(), Error::<T>::TooManyProposals);
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			let mut nonces = Vec::new();
+			let mut missed = Vec::new();
+			for (nonce, prop) in proposals {
+				let key = (nonce, *prop);
+				let votes = match Votes::<T>::get(src_id, &key) {
+					Some(votes) if votes.is_expired(now) => votes,
+					_ => continue,
+				};
+				Votes::<T>::remove(src_id, &key);
+
+				CompletedProposals::<T>::insert(src_id, nonce, votes.status.clone());
+				if votes.status == ProposalStatus::Initiated {
+					missed.push(votes);
+				}
+				nonces.push(nonce);
+			}
+			Self::note_missed_votes(&missed);
+			Self::prune_completed_proposals(src_id, count);
+
+			let relayers = if missed.is_empty() { 0 } else { Self::relayer_count() };
+			let actual_weight = <T as Config>::WeightInfo::prune_expired_proposals(count, relayers);
+			if nonces.is_empty() {
+				return Ok((Some(actual_weight), Pays::Yes).into())
+			}
+			let pays_fee = if nonces.len() as u32 == count { Pays::No } else { Pays::Yes };
+			Self::deposit_event(Event::ExpiredProposalsPruned { src_id, nonces });
+			Ok((Some(actual_weight), pays_fee).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		// *** Utility methods ***
 
+		/// The payload relayers sign off-chain to acknowledge a proposal until the `deadline`
+		/// block, bound to this chain to prevent the signatures being replayed on other bridges
+		pub fn proposal_signing_payload(
+			nonce: DepositNonce,
+			src_id: BridgeChainId,
+			r_id: ResourceId,
+			deadline: T::BlockNumber,
+			prop: &T::Proposal,
+		) -> [u8; 32] {
+			(b"litentry/bridge", T::BridgeChainId::get(), src_id, nonce, r_id, deadline, prop)
+				.using_encoded(sp_io::hashing::blake2_256)
+		}
+
 		/// Checks if who is a relayer
 		pub fn is_relayer(who: &T::AccountId) -> bool {
 			Self::relayers(who)
@@ -572,6 +1006,7 @@ pub mod pallet {
 			ensure!(!Self::is_relayer(&relayer), Error::<T>::RelayerAlreadyExists);
 			Relayers::<T>::insert(&relayer, true);
 			RelayerCount::<T>::mutate(|i| *i += 1);
+			RelayerStatistics::<T>::insert(&relayer, RelayerStats::default());
 
 			Self::deposit_event(Event::RelayerAdded(relayer));
 			Ok(())
@@ -582,6 +1017,7 @@ pub mod pallet {
 			ensure!(Self::is_relayer(&relayer), Error::<T>::RelayerInvalid);
 			Relayers::<T>::remove(&relayer);
 			RelayerCount::<T>::mutate(|i| *i -= 1);
+			RelayerStatistics::<T>::remove(&relayer);
 			Self::deposit_event(Event::RelayerRemoved(relayer));
 			Ok(())
 		}
@@ -599,8 +1035,15 @@ pub mod pallet {
 			let now = <frame_system::Pallet<T>>::block_number();
 			let mut votes = match Votes::<T>::get(src_id, (nonce, prop.clone())) {
 				Some(v) => v,
-				None =>
-					ProposalVotes { expiry: now + T::ProposalLifetime::get(), ..Default::default() },
+				None => {
+					// the proposal was pruned
+					ensure!(
+						nonce > Self::completed_nonce(src_id) &&
+							!CompletedProposals::<T>::contains_key(src_id, nonce),
+						Error::<T>::ProposalAlreadyComplete
+					);
+					ProposalVotes { expiry: now + T::ProposalLifetime::get(), ..Default::default() }
+				},
 			};
 
 			// Ensure the proposal isn't complete and relayer hasn't already voted
@@ -608,6 +1051,12 @@ pub mod pallet {
 			ensure!(!votes.is_expired(now), Error::<T>::ProposalExpired);
 			ensure!(!votes.has_voted(&who), Error::<T>::RelayerAlreadyVoted);
 
+			RelayerStatistics::<T>::mutate(&who, |stats| {
+				let stats = stats.get_or_insert_with(Default::default);
+				stats.votes_cast = stats.votes_cast.saturating_add(1);
+				stats.last_vote_at = Some(now);
+			});
+
 			if in_favour {
 				votes.votes_for.push(who.clone());
 				Self::deposit_event(Event::VoteFor(src_id, nonce, who));
@@ -669,7 +1118,8 @@ pub mod pallet {
 			Self::try_resolve_proposal(nonce, src_id, prop)
 		}
 
-		/// Execute the proposal and signals the result as an event
+		/// Execute the proposal, or queue it if the bridge is paused or the inflow limit is
+		/// reached
 		#[allow(clippy::boxed_local)]
 		fn finalize_execution(
 			src_id: BridgeChainId,
@@ -677,6 +1127,42 @@ pub mod pallet {
 			call: Box<T::Proposal>,
 		) -> DispatchResult {
 			Self::deposit_event(Event::ProposalApproved(src_id, nonce));
+
+			if Self::bridge_paused() {
+				QueuedProposals::<T>::insert(src_id, nonce, *call);
+				Self::deposit_event(Event::ProposalQueued { src_id, nonce });
+				return Ok(())
+			}
+
+			if let Some((resource_id, amount)) = T::ProposalAmount::proposal_amount(&call) {
+				if !Self::try_use_volume(TransferDirection::Inflow, src_id, resource_id, amount) {
+					QueuedProposals::<T>::insert(src_id, nonce, *call);
+					Self::deposit_event(Event::ProposalQueued { src_id, nonce });
+					Paused::<T>::put(true);
+					Self::deposit_event(Event::BridgePaused { by_limit: true });
+					return Ok(())
+				}
+			}
+
+			Self::execute_proposal(src_id, nonce, call)
+		}
+
+		/// Execute the proposal and signals the result as an event
+		#[allow(clippy::boxed_local)]
+		fn execute_proposal(
+			src_id: BridgeChainId,
+			nonce: DepositNonce,
+			call: Box<T::Proposal>,
+		) -> DispatchResult {
+			if let Some(votes) = Votes::<T>::get(src_id, (nonce, call.clone())) {
+				for relayer in votes.votes_for {
+					RelayerStatistics::<T>::mutate_exists(&relayer, |stats| {
+						if let Some(stats) = stats {
+							stats.proposals_executed = stats.proposals_executed.saturating_add(1);
+						}
+					});
+				}
+			}
 			call.dispatch(frame_system::RawOrigin::Signed(Self::account_id()).into())
 				.map(|_| ())
 				.map_err(|e| e.error)?;
@@ -684,12 +1170,104 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Accounts the proposals as missed for the relayers which didn't vote on them,
+		/// iterating the relayers once for all proposals
+		fn note_missed_votes(missed: &[ProposalVotes<T::AccountId, T::BlockNumber>]) {
+			if missed.is_empty() {
+				return
+			}
+			for relayer in RelayerStatistics::<T>::iter_keys().collect::<Vec<_>>() {
+				let count = missed.iter().filter(|votes| !votes.has_voted(&relayer)).count() as u32;
+				if count > 0 {
+					RelayerStatistics::<T>::mutate_exists(&relayer, |stats| {
+						if let Some(stats) = stats {
+							stats.proposals_missed = stats.proposals_missed.saturating_add(count);
+						}
+					});
+				}
+			}
+		}
+
+		/// Advances the completed nonce of the chain over the following completed proposals,
+		/// removing at most `limit` of them from `CompletedProposals`
+		fn prune_completed_proposals(src_id: BridgeChainId, limit: u32) {
+			let start = Self::completed_nonce(src_id);
+			let mut completed = start;
+			for _ in 0..limit {
+				let next = completed.saturating_add(1);
+				if CompletedProposals::<T>::take(src_id, next).is_none() {
+					break
+				}
+				completed = next;
+			}
+			if completed != start {
+				CompletedNonce::<T>::insert(src_id, completed);
+			}
+		}
+
 		/// Cancels a proposal.
 		fn cancel_execution(src_id: BridgeChainId, nonce: DepositNonce) -> DispatchResult {
 			Self::deposit_event(Event::ProposalRejected(src_id, nonce));
 			Ok(())
 		}
 
+		/// The allowance of the volume limit available now,
+		/// None if there's no limit for the given scope
+		pub fn remaining_allowance(
+			direction: TransferDirection,
+			scope: LimitScope,
+		) -> Option<BalanceOf<T>> {
+			Self::volume_limits(direction, scope).map(|limit| {
+				let now = <frame_system::Pallet<T>>::block_number();
+				limit.limit.saturating_sub(Self::current_usage(direction, scope, &limit, now))
+			})
+		}
+
+		/// The used allowance at `now`, after releasing the allowance of the elapsed blocks
+		fn current_usage(
+			direction: TransferDirection,
+			scope: LimitScope,
+			limit: &VolumeLimit<BalanceOf<T>, T::BlockNumber>,
+			now: T::BlockNumber,
+		) -> BalanceOf<T> {
+			Self::volume_usages(direction, scope).map_or_else(Zero::zero, |usage| {
+				let elapsed = now.saturating_sub(usage.updated_at);
+				let released = Perbill::from_rational(
+					elapsed.saturated_into::<u64>(),
+					limit.window.saturated_into::<u64>(),
+				) * limit.limit;
+				usage.used.saturating_sub(released)
+			})
+		}
+
+		/// Uses `amount` of the allowance of both the chain and the resource,
+		/// returns false without using any allowance if any of the limits would be exceeded
+		fn try_use_volume(
+			direction: TransferDirection,
+			chain_id: BridgeChainId,
+			resource_id: ResourceId,
+			amount: BalanceOf<T>,
+		) -> bool {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut usages = Vec::new();
+
+			for scope in [LimitScope::Chain(chain_id), LimitScope::Resource(resource_id)] {
+				if let Some(limit) = Self::volume_limits(direction, scope) {
+					match Self::current_usage(direction, scope, &limit, now).checked_add(&amount) {
+						Some(used) if used <= limit.limit =>
+							usages.push((scope, used, limit.limit - used)),
+						_ => return false,
+					}
+				}
+			}
+
+			for (scope, used, remaining) in usages {
+				VolumeUsages::<T>::insert(direction, scope, VolumeUsage { used, updated_at: now });
+				Self::deposit_event(Event::VolumeUsed { direction, scope, amount, remaining });
+			}
+			true
+		}
+
 		/// Initiates a transfer of a fungible asset out of the chain. This should be called by
 		/// another pallet.
 		pub fn transfer_fungible(
@@ -700,12 +1278,22 @@ pub mod pallet {
 			amount: BalanceOf<T>,
 		) -> DispatchResult {
 			ensure!(Self::chain_whitelisted(dest_id), Error::<T>::ChainNotWhitelisted);
+			ensure!(!Self::bridge_paused(), Error::<T>::BridgePaused);
 			let fee: BalanceOf<T> =
 				BridgeFee::<T>::get(dest_id).ok_or(Error::<T>::CannotPayAsFee)?;
 			// No need to transfer to to dest chains if it's not enough to pay fee.
 			ensure!(amount > fee, Error::<T>::FeeTooExpensive);
 
 			let actual_amount = amount - fee;
+			ensure!(
+				Self::try_use_volume(
+					TransferDirection::Outflow,
+					dest_id,
+					resource_id,
+					actual_amount
+				),
+				Error::<T>::VolumeLimitExceeded
+			);
 			// Ensure we have sufficient free balance
 			let balance: BalanceOf<T> = T::Currency::free_balance(&sender);
 			ensure!(balance >= amount, Error::<T>::InsufficientBalance);
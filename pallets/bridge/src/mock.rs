@@ -25,7 +25,7 @@ use frame_support::{
 use frame_system::{self as system};
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
 };
 
@@ -105,6 +105,18 @@ parameter_types! {
 	pub const TreasuryAccount:u64 = 0x8;
 }
 
+// The balance transfers from the bridge account are used as the proposals with amount
+pub struct MockProposalAmount;
+impl ExtractProposalAmount<RuntimeCall, u64> for MockProposalAmount {
+	fn proposal_amount(proposal: &RuntimeCall) -> Option<(ResourceId, u64)> {
+		match proposal {
+			RuntimeCall::Balances(pallet_balances::Call::transfer { value, .. }) =>
+				Some((derive_resource_id(1, b"transfer"), *value)),
+			_ => None,
+		}
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type BridgeCommitteeOrigin = frame_system::EnsureRoot<Self::AccountId>;
@@ -112,7 +124,12 @@ impl Config for Test {
 	type BridgeChainId = TestChainId;
 	type Currency = Balances;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxPrunedProposals = ConstU32<4>;
+	type MaxRelayerSignatures = ConstU32<2>;
 	type TreasuryAccount = TreasuryAccount;
+	type ProposalAmount = MockProposalAmount;
+	type RelayerSignature = TestSignature;
+	type RelayerPublic = UintAuthorityId;
 	type WeightInfo = ();
 }
 
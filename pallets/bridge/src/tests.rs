@@ -27,6 +27,7 @@ use super::{
 };
 use frame_support::{assert_noop, assert_ok};
 use frame_system as system;
+use sp_runtime::testing::TestSignature;
 
 #[test]
 fn derive_ids() {
@@ -565,3 +566,516 @@ fn transfer_fungible_insufficient_free_balance() {
 		);
 	})
 }
+
+fn make_transfer_proposal(value: u64) -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer { dest: RELAYER_B, value })
+}
+
+// Approve a proposal with the votes of RELAYER_A and RELAYER_C
+fn approve_proposal(prop_id: DepositNonce, src_id: BridgeChainId, proposal: RuntimeCall) {
+	let r_id = derive_resource_id(1, b"transfer");
+	assert_ok!(Bridge::acknowledge_proposal(
+		RuntimeOrigin::signed(RELAYER_A),
+		prop_id,
+		src_id,
+		r_id,
+		Box::new(proposal.clone())
+	));
+	assert_ok!(Bridge::acknowledge_proposal(
+		RuntimeOrigin::signed(RELAYER_C),
+		prop_id,
+		src_id,
+		r_id,
+		Box::new(proposal)
+	));
+}
+
+#[test]
+fn set_volume_limit_works() {
+	new_test_ext().execute_with(|| {
+		let scope = LimitScope::Chain(1);
+		let limit = VolumeLimit { limit: 100, window: 10 };
+
+		assert_noop!(
+			Bridge::set_volume_limit(
+				RuntimeOrigin::signed(RELAYER_A),
+				TransferDirection::Outflow,
+				scope,
+				Some(limit.clone())
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Bridge::set_volume_limit(
+				RuntimeOrigin::root(),
+				TransferDirection::Outflow,
+				scope,
+				Some(VolumeLimit { limit: 100, window: 0 })
+			),
+			Error::<Test>::InvalidWindow
+		);
+
+		assert_ok!(Bridge::set_volume_limit(
+			RuntimeOrigin::root(),
+			TransferDirection::Outflow,
+			scope,
+			Some(limit.clone())
+		));
+		assert_eq!(Bridge::volume_limits(TransferDirection::Outflow, scope), Some(limit.clone()));
+		assert_eq!(Bridge::remaining_allowance(TransferDirection::Outflow, scope), Some(100));
+		assert_eq!(Bridge::remaining_allowance(TransferDirection::Inflow, scope), None);
+		assert_events(vec![RuntimeEvent::Bridge(PalletEvent::VolumeLimitSet {
+			direction: TransferDirection::Outflow,
+			scope,
+			limit: Some(limit),
+		})]);
+
+		assert_ok!(Bridge::set_volume_limit(
+			RuntimeOrigin::root(),
+			TransferDirection::Outflow,
+			scope,
+			None
+		));
+		assert_eq!(Bridge::volume_limits(TransferDirection::Outflow, scope), None);
+	})
+}
+
+#[test]
+fn outflow_volume_limit_works() {
+	new_test_ext().execute_with(|| {
+		let dest_id: BridgeChainId = 0;
+		let resource_id = derive_resource_id(dest_id, b"remark");
+		let scope = LimitScope::Chain(dest_id);
+		assert_ok!(Bridge::update_fee(RuntimeOrigin::root(), dest_id, 10));
+		assert_ok!(Bridge::whitelist_chain(RuntimeOrigin::root(), dest_id));
+		assert_ok!(Bridge::set_volume_limit(
+			RuntimeOrigin::root(),
+			TransferDirection::Outflow,
+			scope,
+			Some(VolumeLimit { limit: 100, window: 10 })
+		));
+
+		// the amount without the fee is accounted
+		assert_ok!(Bridge::transfer_fungible(RELAYER_A, dest_id, resource_id, vec![1], 60));
+		assert_eq!(Bridge::remaining_allowance(TransferDirection::Outflow, scope), Some(50));
+		assert_events(vec![
+			RuntimeEvent::Bridge(PalletEvent::VolumeUsed {
+				direction: TransferDirection::Outflow,
+				scope,
+				amount: 50,
+				remaining: 50,
+			}),
+			RuntimeEvent::Bridge(PalletEvent::FungibleTransfer(
+				dest_id,
+				1,
+				resource_id,
+				50,
+				vec![1],
+			)),
+		]);
+
+		assert_noop!(
+			Bridge::transfer_fungible(RELAYER_A, dest_id, resource_id, vec![1], 70),
+			Error::<Test>::VolumeLimitExceeded
+		);
+
+		// the used allowance is released linearly over the window
+		System::set_block_number(6);
+		assert_eq!(Bridge::remaining_allowance(TransferDirection::Outflow, scope), Some(100));
+		assert_ok!(Bridge::transfer_fungible(RELAYER_A, dest_id, resource_id, vec![1], 70));
+		assert_eq!(Bridge::remaining_allowance(TransferDirection::Outflow, scope), Some(40));
+
+		System::set_block_number(7);
+		assert_eq!(Bridge::remaining_allowance(TransferDirection::Outflow, scope), Some(50));
+	})
+}
+
+#[test]
+fn inflow_over_volume_limit_is_queued() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"transfer");
+
+	new_test_ext_initialized(src_id, r_id, b"Balances.transfer".to_vec()).execute_with(|| {
+		let scope = LimitScope::Resource(r_id);
+		assert_ok!(Bridge::set_volume_limit(
+			RuntimeOrigin::root(),
+			TransferDirection::Inflow,
+			scope,
+			Some(VolumeLimit { limit: 100, window: 10 })
+		));
+
+		// within the limit
+		approve_proposal(1, src_id, make_transfer_proposal(60));
+		assert_eq!(Balances::free_balance(RELAYER_B), 60);
+		assert_eq!(Bridge::remaining_allowance(TransferDirection::Inflow, scope), Some(40));
+
+		// beyond the limit, the proposal is queued and the bridge is paused
+		approve_proposal(2, src_id, make_transfer_proposal(50));
+		assert_eq!(Balances::free_balance(RELAYER_B), 60);
+		assert_eq!(Bridge::remaining_allowance(TransferDirection::Inflow, scope), Some(40));
+		assert_eq!(Bridge::queued_proposals(src_id, 2), Some(make_transfer_proposal(50)));
+		assert!(Bridge::bridge_paused());
+		assert_events(vec![
+			RuntimeEvent::Bridge(PalletEvent::ProposalApproved(src_id, 2)),
+			RuntimeEvent::Bridge(PalletEvent::ProposalQueued { src_id, nonce: 2 }),
+			RuntimeEvent::Bridge(PalletEvent::BridgePaused { by_limit: true }),
+		]);
+
+		// the paused bridge queues all proposals, and rejects outflows
+		approve_proposal(3, src_id, make_proposal(vec![10]));
+		assert_eq!(Bridge::queued_proposals(src_id, 3), Some(make_proposal(vec![10])));
+		assert_ok!(Bridge::update_fee(RuntimeOrigin::root(), src_id, 10));
+		assert_noop!(
+			Bridge::transfer_fungible(RELAYER_A, src_id, r_id, vec![1], 100),
+			Error::<Test>::BridgePaused
+		);
+
+		// the committee releases the queued proposal regardless of the limit
+		assert_noop!(
+			Bridge::release_queued_proposal(RuntimeOrigin::root(), src_id, 2, Weight::zero()),
+			Error::<Test>::WrongProposalWeight
+		);
+		assert_ok!(Bridge::release_queued_proposal(RuntimeOrigin::root(), src_id, 2, Weight::MAX));
+		assert_eq!(Balances::free_balance(RELAYER_B), 110);
+		assert_eq!(Bridge::queued_proposals(src_id, 2), None);
+		assert_events(vec![
+			RuntimeEvent::Bridge(PalletEvent::QueuedProposalReleased { src_id, nonce: 2 }),
+			RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+				from: Bridge::account_id(),
+				to: RELAYER_B,
+				amount: 50,
+			}),
+			RuntimeEvent::Bridge(PalletEvent::ProposalSucceeded(src_id, 2)),
+		]);
+
+		// ...or cancels it
+		assert_ok!(Bridge::cancel_queued_proposal(RuntimeOrigin::root(), src_id, 3));
+		assert_noop!(
+			Bridge::cancel_queued_proposal(RuntimeOrigin::root(), src_id, 3),
+			Error::<Test>::QueuedProposalDoesNotExist
+		);
+
+		assert_ok!(Bridge::set_bridge_paused(RuntimeOrigin::root(), false));
+		assert!(!Bridge::bridge_paused());
+		assert_events(vec![RuntimeEvent::Bridge(PalletEvent::BridgeUnpaused)]);
+	})
+}
+
+#[test]
+fn prune_expired_proposals_works() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"remark");
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		let approved = make_proposal(vec![10]);
+		let initiated = make_proposal(vec![11]);
+		let prune = |proposals: Vec<(DepositNonce, RuntimeCall)>| {
+			Bridge::prune_expired_proposals(
+				RuntimeOrigin::signed(9),
+				src_id,
+				proposals.into_iter().map(|(nonce, prop)| (nonce, Box::new(prop))).collect(),
+			)
+		};
+
+		for relayer in [RELAYER_A, RELAYER_C] {
+			assert_ok!(Bridge::acknowledge_proposal(
+				RuntimeOrigin::signed(relayer),
+				1,
+				src_id,
+				r_id,
+				Box::new(approved.clone())
+			));
+		}
+		assert_ok!(Bridge::acknowledge_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			2,
+			src_id,
+			r_id,
+			Box::new(initiated.clone())
+		));
+
+		// nothing is pruned before the expiry, and the fee is paid
+		let result = prune(vec![(1, approved.clone()), (2, initiated.clone())]);
+		assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+		assert!(Bridge::votes(src_id, (1, approved.clone())).is_some());
+
+		System::set_block_number(ProposalLifetime::get() + 1);
+		assert_noop!(prune(vec![(1, approved.clone()); 5]), Error::<Test>::TooManyProposals);
+
+		// a proposal is skipped, so the fee is paid
+		let result =
+			prune(vec![(1, approved.clone()), (2, initiated.clone()), (3, approved.clone())]);
+		assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+		assert_eq!(Bridge::votes(src_id, (1, approved.clone())), None);
+		assert_eq!(Bridge::votes(src_id, (2, initiated.clone())), None);
+		assert_events(vec![RuntimeEvent::Bridge(PalletEvent::ExpiredProposalsPruned {
+			src_id,
+			nonces: vec![1, 2],
+		})]);
+
+		// the pruned proposals can't be voted again
+		assert_eq!(Bridge::completed_nonce(src_id), 2);
+		assert_eq!(Bridge::completed_proposals(src_id, 1), None);
+		assert_eq!(Bridge::completed_proposals(src_id, 2), None);
+		assert_noop!(
+			Bridge::acknowledge_proposal(
+				RuntimeOrigin::signed(RELAYER_A),
+				1,
+				src_id,
+				r_id,
+				Box::new(approved)
+			),
+			Error::<Test>::ProposalAlreadyComplete
+		);
+		// ...neither the proposal expired without decision
+		assert_noop!(
+			Bridge::acknowledge_proposal(
+				RuntimeOrigin::signed(RELAYER_B),
+				2,
+				src_id,
+				r_id,
+				Box::new(initiated)
+			),
+			Error::<Test>::ProposalAlreadyComplete
+		);
+	})
+}
+
+#[test]
+fn prune_completed_proposals_works() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"remark");
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		let proposals: Vec<RuntimeCall> = (1..=3).map(|i| make_proposal(vec![i])).collect();
+		let prune = |nonces: Vec<DepositNonce>| {
+			Bridge::prune_expired_proposals(
+				RuntimeOrigin::signed(9),
+				src_id,
+				nonces
+					.into_iter()
+					.map(|nonce| (nonce, Box::new(proposals[nonce as usize - 1].clone())))
+					.collect(),
+			)
+		};
+
+		for (nonce, proposal) in (1..).zip(proposals.iter()) {
+			for relayer in [RELAYER_A, RELAYER_C] {
+				assert_ok!(Bridge::acknowledge_proposal(
+					RuntimeOrigin::signed(relayer),
+					nonce,
+					src_id,
+					r_id,
+					Box::new(proposal.clone())
+				));
+			}
+		}
+		System::set_block_number(ProposalLifetime::get() + 1);
+
+		// every proposal is pruned, so the fee is waived
+		assert_eq!(prune(vec![3]).unwrap().pays_fee, Pays::No);
+		// the completed nonce doesn't advance over the gap
+		assert_eq!(Bridge::completed_nonce(src_id), 0);
+		assert_eq!(Bridge::completed_proposals(src_id, 3), Some(ProposalStatus::Approved));
+
+		// it advances at most by the number of given proposals
+		assert_eq!(prune(vec![1, 2]).unwrap().pays_fee, Pays::No);
+		assert_eq!(Bridge::completed_nonce(src_id), 2);
+		assert_eq!(Bridge::completed_proposals(src_id, 1), None);
+		assert_eq!(Bridge::completed_proposals(src_id, 2), None);
+		assert_eq!(Bridge::completed_proposals(src_id, 3), Some(ProposalStatus::Approved));
+
+		assert_eq!(prune(vec![1]).unwrap().pays_fee, Pays::Yes);
+		assert_eq!(Bridge::completed_nonce(src_id), 3);
+		assert_eq!(Bridge::completed_proposals(src_id, 3), None);
+
+		for (nonce, proposal) in (1..).zip(proposals) {
+			assert_noop!(
+				Bridge::acknowledge_proposal(
+					RuntimeOrigin::signed(RELAYER_B),
+					nonce,
+					src_id,
+					r_id,
+					Box::new(proposal)
+				),
+				Error::<Test>::ProposalAlreadyComplete
+			);
+		}
+	})
+}
+
+#[test]
+fn relayer_stats_works() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"remark");
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		let approved = make_proposal(vec![10]);
+		let initiated = make_proposal(vec![11]);
+
+		for relayer in [RELAYER_A, RELAYER_C] {
+			assert_ok!(Bridge::acknowledge_proposal(
+				RuntimeOrigin::signed(relayer),
+				1,
+				src_id,
+				r_id,
+				Box::new(approved.clone())
+			));
+		}
+		assert_ok!(Bridge::reject_proposal(
+			RuntimeOrigin::signed(RELAYER_A),
+			2,
+			src_id,
+			r_id,
+			Box::new(initiated.clone())
+		));
+
+		System::set_block_number(ProposalLifetime::get() + 1);
+		assert_ok!(Bridge::prune_expired_proposals(
+			RuntimeOrigin::signed(RELAYER_A),
+			src_id,
+			vec![(2, Box::new(initiated))]
+		));
+
+		assert_eq!(
+			Bridge::relayer_stats(RELAYER_A),
+			Some(RelayerStats {
+				votes_cast: 2,
+				proposals_executed: 1,
+				proposals_missed: 0,
+				last_vote_at: Some(1),
+			})
+		);
+		assert_eq!(
+			Bridge::relayer_stats(RELAYER_B),
+			Some(RelayerStats {
+				votes_cast: 0,
+				proposals_executed: 0,
+				proposals_missed: 1,
+				last_vote_at: None,
+			})
+		);
+		assert_eq!(
+			Bridge::relayer_stats(RELAYER_C),
+			Some(RelayerStats {
+				votes_cast: 1,
+				proposals_executed: 1,
+				proposals_missed: 1,
+				last_vote_at: Some(1),
+			})
+		);
+
+		// the stats are removed with the relayer
+		assert_ok!(Bridge::remove_relayer(RuntimeOrigin::root(), RELAYER_B));
+		assert_eq!(Bridge::relayer_stats(RELAYER_B), None);
+	})
+}
+
+#[test]
+fn acknowledge_proposal_with_signatures_works() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"remark");
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		let prop_id = 1;
+		let proposal = make_proposal(vec![10]);
+		let deadline = 1;
+		let payload = Bridge::proposal_signing_payload(prop_id, src_id, r_id, deadline, &proposal);
+
+		assert_ok!(Bridge::acknowledge_proposal_with_signatures(
+			RuntimeOrigin::signed(RELAYER_A),
+			prop_id,
+			src_id,
+			r_id,
+			Box::new(proposal.clone()),
+			deadline,
+			vec![(RELAYER_C, TestSignature(RELAYER_C, payload.to_vec()))]
+		));
+
+		let prop = Bridge::votes(src_id, (prop_id, proposal)).unwrap();
+		let expected = ProposalVotes {
+			votes_for: vec![RELAYER_A, RELAYER_C],
+			votes_against: vec![],
+			status: ProposalStatus::Approved,
+			expiry: ProposalLifetime::get() + 1,
+		};
+		assert_eq!(prop, expected);
+
+		assert_events(vec![
+			RuntimeEvent::Bridge(PalletEvent::VoteFor(src_id, prop_id, RELAYER_A)),
+			RuntimeEvent::Bridge(PalletEvent::VoteFor(src_id, prop_id, RELAYER_C)),
+			RuntimeEvent::Bridge(PalletEvent::ProposalApproved(src_id, prop_id)),
+			RuntimeEvent::Bridge(PalletEvent::ProposalSucceeded(src_id, prop_id)),
+		]);
+	})
+}
+
+#[test]
+fn acknowledge_proposal_with_invalid_signatures_fails() {
+	let src_id = 1;
+	let r_id = derive_resource_id(src_id, b"remark");
+
+	new_test_ext_initialized(src_id, r_id, b"System.remark".to_vec()).execute_with(|| {
+		let prop_id = 1;
+		let proposal = make_proposal(vec![10]);
+		let deadline = 10;
+		let payload = Bridge::proposal_signing_payload(prop_id, src_id, r_id, deadline, &proposal);
+		let acknowledge = |signatures: Vec<(u64, TestSignature)>| {
+			Bridge::acknowledge_proposal_with_signatures(
+				RuntimeOrigin::signed(RELAYER_A),
+				prop_id,
+				src_id,
+				r_id,
+				Box::new(proposal.clone()),
+				deadline,
+				signatures,
+			)
+		};
+
+		// signed over another nonce
+		let other_payload =
+			Bridge::proposal_signing_payload(prop_id + 1, src_id, r_id, deadline, &proposal);
+		assert_noop!(
+			acknowledge(vec![(RELAYER_C, TestSignature(RELAYER_C, other_payload.to_vec()))]),
+			Error::<Test>::InvalidSignature
+		);
+		// signed until another deadline
+		let other_payload =
+			Bridge::proposal_signing_payload(prop_id, src_id, r_id, deadline + 1, &proposal);
+		assert_noop!(
+			acknowledge(vec![(RELAYER_C, TestSignature(RELAYER_C, other_payload.to_vec()))]),
+			Error::<Test>::InvalidSignature
+		);
+		// signed by someone else
+		assert_noop!(
+			acknowledge(vec![(RELAYER_C, TestSignature(RELAYER_B, payload.to_vec()))]),
+			Error::<Test>::InvalidSignature
+		);
+		// not a relayer
+		assert_noop!(
+			acknowledge(vec![(9, TestSignature(9, payload.to_vec()))]),
+			Error::<Test>::MustBeRelayer
+		);
+		// the caller already votes
+		assert_noop!(
+			acknowledge(vec![(RELAYER_A, TestSignature(RELAYER_A, payload.to_vec()))]),
+			Error::<Test>::RelayerAlreadyVoted
+		);
+		// more than relayers or `MaxRelayerSignatures`
+		assert_noop!(
+			acknowledge(vec![(RELAYER_B, TestSignature(RELAYER_B, payload.to_vec())); 4]),
+			Error::<Test>::TooManySignatures
+		);
+		assert_noop!(
+			acknowledge(vec![(RELAYER_B, TestSignature(RELAYER_B, payload.to_vec())); 3]),
+			Error::<Test>::TooManySignatures
+		);
+
+		// the deadline has passed
+		System::set_block_number(deadline + 1);
+		assert_noop!(
+			acknowledge(vec![(RELAYER_C, TestSignature(RELAYER_C, payload.to_vec()))]),
+			Error::<Test>::SignaturesExpired
+		);
+	})
+}
@@ -57,6 +57,12 @@ pub trait WeightInfo {
 	fn acknowledge_proposal() -> Weight;
 	fn reject_proposal() -> Weight;
 	fn eval_vote_state() -> Weight;
+	fn set_volume_limit() -> Weight;
+	fn release_queued_proposal() -> Weight;
+	fn cancel_queued_proposal() -> Weight;
+	fn set_bridge_paused() -> Weight;
+	fn prune_expired_proposals(n: u32, r: u32, ) -> Weight;
+	fn acknowledge_proposal_with_signatures(s: u32, ) -> Weight;
 }
 
 /// Weights for pallet_bridge using the Litentry node and recommended hardware.
@@ -132,6 +138,60 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: ChainBridge VolumeLimits (r:0 w:1)
+	fn set_volume_limit() -> Weight {
+		Weight::from_parts(12_000_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge QueuedProposals (r:1 w:1)
+	fn release_queued_proposal() -> Weight {
+		Weight::from_parts(20_000_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge QueuedProposals (r:1 w:1)
+	fn cancel_queued_proposal() -> Weight {
+		Weight::from_parts(15_000_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Paused (r:0 w:1)
+	fn set_bridge_paused() -> Weight {
+		Weight::from_parts(10_000_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge CompletedProposals (r:1 w:2)
+	// Storage: ChainBridge CompletedNonce (r:1 w:1)
+	// Storage: ChainBridge RelayerStatistics (r:1 w:1)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn prune_expired_proposals(n: u32, r: u32, ) -> Weight {
+		Weight::from_parts(12_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(14_000_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(Weight::from_parts(8_000_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge Resources (r:1 w:0)
+	// Storage: ChainBridge RelayerCount (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge RelayerStatistics (r:1 w:1)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn acknowledge_proposal_with_signatures(s: u32, ) -> Weight {
+		Weight::from_parts(52_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(58_000_000 as u64, 0).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(s as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(s as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -206,4 +266,58 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: ChainBridge VolumeLimits (r:0 w:1)
+	fn set_volume_limit() -> Weight {
+		Weight::from_parts(12_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge QueuedProposals (r:1 w:1)
+	fn release_queued_proposal() -> Weight {
+		Weight::from_parts(20_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge QueuedProposals (r:1 w:1)
+	fn cancel_queued_proposal() -> Weight {
+		Weight::from_parts(15_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Paused (r:0 w:1)
+	fn set_bridge_paused() -> Weight {
+		Weight::from_parts(10_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge CompletedProposals (r:1 w:2)
+	// Storage: ChainBridge CompletedNonce (r:1 w:1)
+	// Storage: ChainBridge RelayerStatistics (r:1 w:1)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn prune_expired_proposals(n: u32, r: u32, ) -> Weight {
+		Weight::from_parts(12_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(14_000_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(Weight::from_parts(8_000_000 as u64, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: ChainBridge Relayers (r:1 w:0)
+	// Storage: ChainBridge ChainNonces (r:1 w:0)
+	// Storage: ChainBridge Resources (r:1 w:0)
+	// Storage: ChainBridge RelayerCount (r:1 w:0)
+	// Storage: ChainBridge Votes (r:1 w:1)
+	// Storage: ChainBridge RelayerStatistics (r:1 w:1)
+	// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn acknowledge_proposal_with_signatures(s: u32, ) -> Weight {
+		Weight::from_parts(52_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(58_000_000 as u64, 0).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(s as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(s as u64)))
+	}
 }
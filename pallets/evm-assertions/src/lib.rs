@@ -20,18 +20,29 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-use frame_system::ensure_signed;
+pub mod migration;
+
+use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
+	ensure,
+	traits::{Currency, ReservableCurrency},
+};
+use frame_system::{ensure_root, ensure_signed, pallet_prelude::OriginFor};
 pub use pallet::*;
+use sp_runtime::{traits::Hash, DispatchError};
 use sp_std::vec::Vec;
 
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::OriginFor;
+	use frame_system::pallet_prelude::BlockNumberFor;
 	use scale_info::TypeInfo;
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -40,8 +51,34 @@ pub mod pallet {
 
 	#[derive(Encode, Decode, Clone, Default, Debug, PartialEq, Eq, TypeInfo)]
 	pub struct Assertion {
-		byte_code: Vec<u8>,
-		secrets: Vec<Vec<u8>>,
+		pub(crate) byte_code: Vec<u8>,
+		pub(crate) secrets: Vec<Vec<u8>>,
+	}
+
+	#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+	pub enum AssertionStatus {
+		/// Assertion can be used to request VCs
+		Active,
+		/// Assertion is paused, VC requests for it are rejected by the worker
+		Disabled,
+	}
+
+	/// Ownership and lifecycle information of an assertion
+	#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+	pub struct AssertionMetadata<AccountId, Balance> {
+		pub owner: AccountId,
+		/// The amount reserved from `owner` when the assertion was created
+		pub deposit: Balance,
+		/// The current version, starts from 1 and is bumped on every update
+		pub version: u32,
+		pub status: AssertionStatus,
+	}
+
+	/// A historical version of an assertion
+	#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+	pub struct AssertionVersion<Hash, BlockNumber> {
+		pub code_hash: Hash,
+		pub block_number: BlockNumber,
 	}
 
 	#[pallet::config]
@@ -56,8 +93,23 @@ pub mod pallet {
 			+ core::fmt::Debug
 			+ parity_scale_codec::FullCodec
 			+ TypeInfo;
+
+		/// The currency used to reserve the assertion deposit
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount reserved from the creator of an assertion, returned upon removal
+		#[pallet::constant]
+		type AssertionDeposit: Get<BalanceOf<Self>>;
+
+		/// Origin to manage the admin account
+		type SetAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
+	/// The admin account that can manage any assertion, alongside with root
+	#[pallet::storage]
+	#[pallet::getter(fn admin)]
+	pub type Admin<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
 	/// Map for storing assertion smart contract bytecode alongside with additional secrets
 	/// Secrets are encrypted with worker's shielding key
 	#[pallet::storage]
@@ -65,35 +117,252 @@ pub mod pallet {
 	pub type Assertions<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssertionId, Assertion, OptionQuery>;
 
+	/// Owner, deposit, current version and status of each assertion
+	#[pallet::storage]
+	#[pallet::getter(fn assertion_metadata)]
+	pub type AssertionsMetadata<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssertionId,
+		AssertionMetadata<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Version history of each assertion: (assertion id, version) => (code hash, block number)
+	#[pallet::storage]
+	#[pallet::getter(fn assertion_history)]
+	pub type AssertionHistory<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssertionId,
+		Twox64Concat,
+		u32,
+		AssertionVersion<T::Hash, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		AssertionCreated { id: T::AssertionId, byte_code: Vec<u8>, secrets: Vec<Vec<u8>> },
+		AssertionCreated {
+			id: T::AssertionId,
+			byte_code: Vec<u8>,
+			secrets: Vec<Vec<u8>>,
+		},
+		AssertionUpdated {
+			id: T::AssertionId,
+			version: u32,
+			byte_code: Vec<u8>,
+			secrets: Vec<Vec<u8>>,
+		},
+		AssertionDisabled {
+			id: T::AssertionId,
+		},
+		AssertionEnabled {
+			id: T::AssertionId,
+			byte_code: Vec<u8>,
+			secrets: Vec<Vec<u8>>,
+		},
+		AssertionRemoved {
+			id: T::AssertionId,
+		},
+		AdminSet {
+			new_admin: Option<T::AccountId>,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		AssertionExists,
+		AssertionNotExist,
+		/// Only the assertion owner, the admin or root can manage an assertion
+		RequireOwnerOrAdminOrRoot,
+		AssertionAlreadyDisabled,
+		AssertionAlreadyEnabled,
+		/// Updating a disabled assertion is not allowed, it needs to be enabled first
+		AssertionDisabled,
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub admin: Option<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { admin: None }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			if let Some(ref admin) = self.admin {
+				Admin::<T>::put(admin);
+			}
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Create a new assertion, `AssertionDeposit` is reserved from the caller
+		/// who becomes the owner of the assertion
 		#[pallet::call_index(0)]
-		#[pallet::weight((T::DbWeight::get().write, DispatchClass::Normal, Pays::No))]
+		#[pallet::weight((T::DbWeight::get().reads_writes(1, 4), DispatchClass::Normal))]
 		pub fn create_assertion(
 			origin: OriginFor<T>,
 			id: T::AssertionId,
 			byte_code: Vec<u8>,
 			secrets: Vec<Vec<u8>>,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
+			let who = ensure_signed(origin)?;
 			ensure!(!Assertions::<T>::contains_key(id), Error::<T>::AssertionExists);
+			let deposit = T::AssertionDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			Self::record_version(id, 1, &byte_code);
+			AssertionsMetadata::<T>::insert(
+				id,
+				AssertionMetadata {
+					owner: who,
+					deposit,
+					version: 1,
+					status: AssertionStatus::Active,
+				},
+			);
 			Assertions::<T>::insert(
 				id,
 				Assertion { byte_code: byte_code.clone(), secrets: secrets.clone() },
 			);
 			Self::deposit_event(Event::AssertionCreated { id, byte_code, secrets });
+			Ok(().into())
+		}
+
+		/// Replace the byte code and secrets of an active assertion, bumping its version
+		#[pallet::call_index(1)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(3, 3), DispatchClass::Normal))]
+		pub fn update_assertion(
+			origin: OriginFor<T>,
+			id: T::AssertionId,
+			byte_code: Vec<u8>,
+			secrets: Vec<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			let mut metadata = Self::ensure_owner_or_admin_or_root(origin, id)?;
+			ensure!(metadata.status == AssertionStatus::Active, Error::<T>::AssertionDisabled);
+
+			metadata.version = metadata.version.saturating_add(1);
+			let version = metadata.version;
+			Self::record_version(id, version, &byte_code);
+			AssertionsMetadata::<T>::insert(id, metadata);
+			Assertions::<T>::insert(
+				id,
+				Assertion { byte_code: byte_code.clone(), secrets: secrets.clone() },
+			);
+			Self::deposit_event(Event::AssertionUpdated { id, version, byte_code, secrets });
+			Ok(().into())
+		}
+
+		/// Pause an assertion, the worker drops it and rejects VC requests for it
+		#[pallet::call_index(2)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(3, 1), DispatchClass::Normal))]
+		pub fn disable_assertion(
+			origin: OriginFor<T>,
+			id: T::AssertionId,
+		) -> DispatchResultWithPostInfo {
+			let mut metadata = Self::ensure_owner_or_admin_or_root(origin, id)?;
+			ensure!(
+				metadata.status == AssertionStatus::Active,
+				Error::<T>::AssertionAlreadyDisabled
+			);
+			metadata.status = AssertionStatus::Disabled;
+			AssertionsMetadata::<T>::insert(id, metadata);
+			Self::deposit_event(Event::AssertionDisabled { id });
+			Ok(().into())
+		}
+
+		/// Resume a disabled assertion, its latest version is published to the worker again
+		#[pallet::call_index(3)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(3, 1), DispatchClass::Normal))]
+		pub fn enable_assertion(
+			origin: OriginFor<T>,
+			id: T::AssertionId,
+		) -> DispatchResultWithPostInfo {
+			let mut metadata = Self::ensure_owner_or_admin_or_root(origin, id)?;
+			ensure!(
+				metadata.status == AssertionStatus::Disabled,
+				Error::<T>::AssertionAlreadyEnabled
+			);
+			let assertion = Assertions::<T>::get(id).ok_or(Error::<T>::AssertionNotExist)?;
+			metadata.status = AssertionStatus::Active;
+			AssertionsMetadata::<T>::insert(id, metadata);
+			Self::deposit_event(Event::AssertionEnabled {
+				id,
+				byte_code: assertion.byte_code,
+				secrets: assertion.secrets,
+			});
+			Ok(().into())
+		}
+
+		/// Remove an assertion together with its history and return the deposit to the owner
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(3, 3), DispatchClass::Normal))]
+		pub fn remove_assertion(
+			origin: OriginFor<T>,
+			id: T::AssertionId,
+		) -> DispatchResultWithPostInfo {
+			let metadata = Self::ensure_owner_or_admin_or_root(origin, id)?;
+			T::Currency::unreserve(&metadata.owner, metadata.deposit);
+			Assertions::<T>::remove(id);
+			AssertionsMetadata::<T>::remove(id);
+			let _ = AssertionHistory::<T>::clear_prefix(id, u32::MAX, None);
+			Self::deposit_event(Event::AssertionRemoved { id });
+			Ok(().into())
+		}
+
+		/// Set the admin account
+		///
+		/// Weights should be 2 DB writes: 1 for admin and 1 for event
+		#[pallet::call_index(5)]
+		#[pallet::weight((2 * T::DbWeight::get().write, DispatchClass::Normal, Pays::No))]
+		pub fn set_admin(
+			origin: OriginFor<T>,
+			new_admin: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::SetAdminOrigin::ensure_origin(origin)?;
+			Admin::<T>::put(new_admin.clone());
+			Self::deposit_event(Event::AdminSet { new_admin: Some(new_admin) });
 			Ok(Pays::No.into())
 		}
 	}
 }
+
+impl<T: Config> Pallet<T> {
+	/// Returns the metadata of the assertion if the origin is allowed to manage it
+	fn ensure_owner_or_admin_or_root(
+		origin: OriginFor<T>,
+		id: T::AssertionId,
+	) -> Result<AssertionMetadata<T::AccountId, BalanceOf<T>>, DispatchError> {
+		let metadata = AssertionsMetadata::<T>::get(id).ok_or(Error::<T>::AssertionNotExist)?;
+		if ensure_root(origin.clone()).is_ok() {
+			return Ok(metadata)
+		}
+		let who = ensure_signed(origin)?;
+		ensure!(
+			who == metadata.owner || Some(who) == Self::admin(),
+			Error::<T>::RequireOwnerOrAdminOrRoot
+		);
+		Ok(metadata)
+	}
+
+	fn record_version(id: T::AssertionId, version: u32, byte_code: &[u8]) {
+		AssertionHistory::<T>::insert(
+			id,
+			version,
+			AssertionVersion {
+				code_hash: T::Hashing::hash(byte_code),
+				block_number: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+	}
+}
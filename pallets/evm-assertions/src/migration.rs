@@ -0,0 +1,96 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of pallet-evm-assertions
+
+use super::*;
+use frame_support::{
+	pallet_prelude::Weight,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::traits::Zero;
+use sp_std::marker::PhantomData;
+
+/// Backfill the `AssertionsMetadata` of the assertions created before it was introduced,
+/// without it they can't be managed at all, not even by root.
+///
+/// The legacy assertions are owned by the admin, or by `LegacyOwner` if no admin is set,
+/// no deposit was reserved for them. The migration is skipped if there is no owner at all.
+pub struct MigrateToV1<T, LegacyOwner>(PhantomData<(T, LegacyOwner)>);
+impl<T: Config, LegacyOwner: Get<Option<T::AccountId>>> OnRuntimeUpgrade
+	for MigrateToV1<T, LegacyOwner>
+{
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 0 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let owner = match Admin::<T>::get().or_else(LegacyOwner::get) {
+			Some(owner) => owner,
+			None => {
+				frame_support::log::warn!(
+					"No owner for the legacy assertions, skipping the migration of pallet-evm-assertions"
+				);
+				return T::DbWeight::get().reads(2)
+			},
+		};
+
+		let mut count = 0u64;
+		let mut migrated = 0u64;
+		for (id, assertion) in Assertions::<T>::iter() {
+			count += 1;
+			if AssertionsMetadata::<T>::contains_key(id) {
+				continue
+			}
+			AssertionsMetadata::<T>::insert(
+				id,
+				AssertionMetadata {
+					owner: owner.clone(),
+					deposit: Zero::zero(),
+					version: 1,
+					status: AssertionStatus::Active,
+				},
+			);
+			Pallet::<T>::record_version(id, 1, &assertion.byte_code);
+			migrated += 1;
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2 + 2 * count, 1 + 2 * migrated)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok((Assertions::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count: u32 = Decode::decode(&mut &state[..]).map_err(|_| "cannot decode count")?;
+		if Pallet::<T>::on_chain_storage_version() == 0 {
+			return Ok(())
+		}
+		ensure!(Pallet::<T>::on_chain_storage_version() == 1, "unexpected storage version");
+		ensure!(
+			Assertions::<T>::iter_keys().all(AssertionsMetadata::<T>::contains_key),
+			"assertion without metadata"
+		);
+		ensure!(Assertions::<T>::iter_keys().count() == count as usize, "unexpected assertions");
+		Ok(())
+	}
+}
@@ -16,7 +16,12 @@
 
 #![cfg(test)]
 use crate as pallet_evm_assertions;
-use frame_support::{parameter_types, weights::Weight};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU128, ConstU32, GenesisBuild},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
 use sp_core::{H160, U256};
 use sp_runtime::{
 	generic,
@@ -39,7 +44,8 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		EvmAssertions: pallet_evm_assertions::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		EvmAssertions: pallet_evm_assertions::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -96,6 +102,22 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
 parameter_types! {
 	pub const MinimumPeriod: u64 = 6000 / 2;
 }
@@ -103,8 +125,16 @@ parameter_types! {
 impl pallet_evm_assertions::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AssertionId = H160;
+	type Currency = Balances;
+	type AssertionDeposit = ConstU128<ASSERTION_DEPOSIT>;
+	type SetAdminOrigin = EnsureRoot<Self::AccountId>;
 }
 
+pub const ASSERTION_DEPOSIT: u128 = 10;
+pub const ALICE: [u8; 32] = [1u8; 32];
+pub const BOB: [u8; 32] = [2u8; 32];
+pub const ADMIN: [u8; 32] = [3u8; 32];
+
 parameter_types! {
 	pub WeightPerGas: Weight = Weight::from_parts(20_000, 0);
 	// It will be the best if we can implement this in a more professional way
@@ -115,8 +145,20 @@ parameter_types! {
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let test_storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
-	let mut ext = sp_io::TestExternalities::new(test_storage);
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(AccountId::from(ALICE), 100),
+			(AccountId::from(BOB), 100),
+			(AccountId::from(ADMIN), 100),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	pallet_evm_assertions::GenesisConfig::<Test> { admin: Some(AccountId::from(ADMIN)) }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| {
 		System::set_block_number(1);
 	});
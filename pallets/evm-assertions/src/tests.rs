@@ -13,27 +13,28 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migration::MigrateToV1, mock::*, Admin, Assertion, AssertionStatus, Assertions, Error, Pallet,
+};
+use frame_support::{
+	assert_noop, assert_ok, parameter_types,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use sp_core::H160;
+use sp_runtime::DispatchError;
 
 type SystemAccountId = <Test as frame_system::Config>::AccountId;
 
 #[test]
 fn should_create_new_assertion() {
 	new_test_ext().execute_with(|| {
-		let pubkey: [u8; 32] = [
-			65, 89, 193, 118, 86, 172, 17, 149, 206, 160, 174, 75, 219, 151, 51, 235, 110, 135, 20,
-			55, 147, 162, 106, 110, 143, 207, 57, 64, 67, 63, 203, 95,
-		];
-
-		let alice: SystemAccountId = get_signer(&pubkey);
+		let alice: SystemAccountId = get_signer(&ALICE);
 		let assertion_id: H160 = H160::from_slice(&[1u8; 20]);
 		let byte_code = [0u8; 256].to_vec();
 		let secrets = vec![[2u8; 13].to_vec(), [3u8; 32].to_vec()];
 
 		assert_ok!(EvmAssertions::create_assertion(
-			RuntimeOrigin::signed(alice),
+			RuntimeOrigin::signed(alice.clone()),
 			assertion_id,
 			byte_code.clone(),
 			secrets.clone()
@@ -43,18 +44,20 @@ fn should_create_new_assertion() {
 			byte_code,
 			secrets,
 		}));
+
+		let metadata = EvmAssertions::assertion_metadata(assertion_id).unwrap();
+		assert_eq!(metadata.owner, alice);
+		assert_eq!(metadata.version, 1);
+		assert_eq!(metadata.status, AssertionStatus::Active);
+		assert_eq!(Balances::reserved_balance(&alice), ASSERTION_DEPOSIT);
+		assert!(EvmAssertions::assertion_history(assertion_id, 1).is_some());
 	});
 }
 
 #[test]
 fn should_not_create_new_assertion_if_exists() {
 	new_test_ext().execute_with(|| {
-		let pubkey: [u8; 32] = [
-			65, 89, 193, 118, 86, 172, 17, 149, 206, 160, 174, 75, 219, 151, 51, 235, 110, 135, 20,
-			55, 147, 162, 106, 110, 143, 207, 57, 64, 67, 63, 203, 95,
-		];
-
-		let alice: SystemAccountId = get_signer(&pubkey);
+		let alice: SystemAccountId = get_signer(&ALICE);
 		let assertion_id: H160 = H160::from_slice(&[1u8; 20]);
 		let byte_code = [0u8; 256].to_vec();
 		let secrets = vec![[2u8; 13].to_vec(), [3u8; 32].to_vec()];
@@ -78,6 +81,242 @@ fn should_not_create_new_assertion_if_exists() {
 	});
 }
 
+#[test]
+fn should_not_create_new_assertion_without_deposit() {
+	new_test_ext().execute_with(|| {
+		let poor: SystemAccountId = get_signer(&[9u8; 32]);
+		assert_noop!(
+			EvmAssertions::create_assertion(
+				RuntimeOrigin::signed(poor),
+				H160::from_slice(&[1u8; 20]),
+				[0u8; 256].to_vec(),
+				vec![]
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn should_update_assertion_and_record_history() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(&ALICE);
+		let assertion_id: H160 = H160::from_slice(&[1u8; 20]);
+		assert_ok!(EvmAssertions::create_assertion(
+			RuntimeOrigin::signed(alice.clone()),
+			assertion_id,
+			[0u8; 256].to_vec(),
+			vec![]
+		));
+
+		System::set_block_number(2);
+		let byte_code = [1u8; 128].to_vec();
+		let secrets = vec![[4u8; 16].to_vec()];
+		assert_ok!(EvmAssertions::update_assertion(
+			RuntimeOrigin::signed(alice),
+			assertion_id,
+			byte_code.clone(),
+			secrets.clone()
+		));
+		System::assert_last_event(RuntimeEvent::EvmAssertions(crate::Event::AssertionUpdated {
+			id: assertion_id,
+			version: 2,
+			byte_code,
+			secrets,
+		}));
+
+		assert_eq!(EvmAssertions::assertion_metadata(assertion_id).unwrap().version, 2);
+		let v1 = EvmAssertions::assertion_history(assertion_id, 1).unwrap();
+		let v2 = EvmAssertions::assertion_history(assertion_id, 2).unwrap();
+		assert_eq!(v1.block_number, 1);
+		assert_eq!(v2.block_number, 2);
+		assert_ne!(v1.code_hash, v2.code_hash);
+	});
+}
+
+#[test]
+fn only_owner_admin_or_root_can_manage_assertion() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(&ALICE);
+		let bob: SystemAccountId = get_signer(&BOB);
+		let admin: SystemAccountId = get_signer(&ADMIN);
+		let assertion_id: H160 = H160::from_slice(&[1u8; 20]);
+		assert_ok!(EvmAssertions::create_assertion(
+			RuntimeOrigin::signed(alice),
+			assertion_id,
+			[0u8; 256].to_vec(),
+			vec![]
+		));
+
+		assert_noop!(
+			EvmAssertions::update_assertion(
+				RuntimeOrigin::signed(bob.clone()),
+				assertion_id,
+				[1u8; 256].to_vec(),
+				vec![]
+			),
+			Error::<Test>::RequireOwnerOrAdminOrRoot
+		);
+		assert_noop!(
+			EvmAssertions::disable_assertion(RuntimeOrigin::signed(bob.clone()), assertion_id),
+			Error::<Test>::RequireOwnerOrAdminOrRoot
+		);
+		assert_noop!(
+			EvmAssertions::remove_assertion(RuntimeOrigin::signed(bob), assertion_id),
+			Error::<Test>::RequireOwnerOrAdminOrRoot
+		);
+
+		assert_ok!(EvmAssertions::disable_assertion(RuntimeOrigin::signed(admin), assertion_id));
+		assert_ok!(EvmAssertions::enable_assertion(RuntimeOrigin::root(), assertion_id));
+	});
+}
+
+#[test]
+fn should_disable_and_enable_assertion() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(&ALICE);
+		let assertion_id: H160 = H160::from_slice(&[1u8; 20]);
+		let byte_code = [0u8; 256].to_vec();
+		let secrets = vec![[2u8; 13].to_vec()];
+		assert_ok!(EvmAssertions::create_assertion(
+			RuntimeOrigin::signed(alice.clone()),
+			assertion_id,
+			byte_code.clone(),
+			secrets.clone()
+		));
+
+		assert_ok!(EvmAssertions::disable_assertion(
+			RuntimeOrigin::signed(alice.clone()),
+			assertion_id
+		));
+		System::assert_last_event(RuntimeEvent::EvmAssertions(crate::Event::AssertionDisabled {
+			id: assertion_id,
+		}));
+		assert_eq!(
+			EvmAssertions::assertion_metadata(assertion_id).unwrap().status,
+			AssertionStatus::Disabled
+		);
+		assert_noop!(
+			EvmAssertions::disable_assertion(RuntimeOrigin::signed(alice.clone()), assertion_id),
+			Error::<Test>::AssertionAlreadyDisabled
+		);
+		assert_noop!(
+			EvmAssertions::update_assertion(
+				RuntimeOrigin::signed(alice.clone()),
+				assertion_id,
+				byte_code.clone(),
+				vec![]
+			),
+			Error::<Test>::AssertionDisabled
+		);
+
+		assert_ok!(EvmAssertions::enable_assertion(RuntimeOrigin::signed(alice), assertion_id));
+		System::assert_last_event(RuntimeEvent::EvmAssertions(crate::Event::AssertionEnabled {
+			id: assertion_id,
+			byte_code,
+			secrets,
+		}));
+		assert_eq!(
+			EvmAssertions::assertion_metadata(assertion_id).unwrap().status,
+			AssertionStatus::Active
+		);
+	});
+}
+
+#[test]
+fn should_remove_assertion_and_return_deposit() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(&ALICE);
+		let assertion_id: H160 = H160::from_slice(&[1u8; 20]);
+		assert_ok!(EvmAssertions::create_assertion(
+			RuntimeOrigin::signed(alice.clone()),
+			assertion_id,
+			[0u8; 256].to_vec(),
+			vec![]
+		));
+		assert_ok!(EvmAssertions::update_assertion(
+			RuntimeOrigin::signed(alice.clone()),
+			assertion_id,
+			[1u8; 256].to_vec(),
+			vec![]
+		));
+
+		// removed by root, the deposit still goes back to the owner
+		assert_ok!(EvmAssertions::remove_assertion(RuntimeOrigin::root(), assertion_id));
+		System::assert_last_event(RuntimeEvent::EvmAssertions(crate::Event::AssertionRemoved {
+			id: assertion_id,
+		}));
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		assert!(EvmAssertions::assertions(assertion_id).is_none());
+		assert!(EvmAssertions::assertion_metadata(assertion_id).is_none());
+		assert!(EvmAssertions::assertion_history(assertion_id, 1).is_none());
+		assert!(EvmAssertions::assertion_history(assertion_id, 2).is_none());
+
+		assert_noop!(
+			EvmAssertions::remove_assertion(RuntimeOrigin::root(), assertion_id),
+			Error::<Test>::AssertionNotExist
+		);
+	});
+}
+
+#[test]
+fn set_admin_works() {
+	new_test_ext().execute_with(|| {
+		let bob: SystemAccountId = get_signer(&BOB);
+		assert_noop!(
+			EvmAssertions::set_admin(RuntimeOrigin::signed(bob.clone()), bob.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(EvmAssertions::set_admin(RuntimeOrigin::root(), bob.clone()));
+		assert_eq!(EvmAssertions::admin(), Some(bob.clone()));
+		System::assert_last_event(RuntimeEvent::EvmAssertions(crate::Event::AdminSet {
+			new_admin: Some(bob),
+		}));
+	});
+}
+
+parameter_types! {
+	pub LegacyOwner: Option<SystemAccountId> = Some(get_signer(&BOB));
+}
+
+#[test]
+fn migration_backfills_metadata_of_legacy_assertions() {
+	new_test_ext().execute_with(|| {
+		let admin: SystemAccountId = get_signer(&ADMIN);
+		let bob: SystemAccountId = get_signer(&BOB);
+		let first_id: H160 = H160::from_slice(&[1u8; 20]);
+		let second_id: H160 = H160::from_slice(&[2u8; 20]);
+		let assertion = Assertion { byte_code: [0u8; 256].to_vec(), secrets: vec![] };
+
+		StorageVersion::new(0).put::<Pallet<Test>>();
+		Assertions::<Test>::insert(first_id, assertion.clone());
+		assert_noop!(
+			EvmAssertions::disable_assertion(RuntimeOrigin::root(), first_id),
+			Error::<Test>::AssertionNotExist
+		);
+
+		MigrateToV1::<Test, LegacyOwner>::on_runtime_upgrade();
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		let metadata = EvmAssertions::assertion_metadata(first_id).unwrap();
+		assert_eq!(metadata.owner, admin);
+		assert_eq!(metadata.deposit, 0);
+		assert_eq!(metadata.version, 1);
+		assert_eq!(metadata.status, AssertionStatus::Active);
+		assert!(EvmAssertions::assertion_history(first_id, 1).is_some());
+		assert_ok!(EvmAssertions::disable_assertion(RuntimeOrigin::root(), first_id));
+
+		// without admin the legacy owner is used
+		StorageVersion::new(0).put::<Pallet<Test>>();
+		Admin::<Test>::kill();
+		Assertions::<Test>::insert(second_id, assertion);
+		MigrateToV1::<Test, LegacyOwner>::on_runtime_upgrade();
+		assert_eq!(EvmAssertions::assertion_metadata(second_id).unwrap().owner, bob.clone());
+		// the metadata of migrated assertions is kept
+		assert_eq!(EvmAssertions::assertion_metadata(first_id).unwrap().owner, admin);
+		assert_ok!(EvmAssertions::remove_assertion(RuntimeOrigin::signed(bob), second_id));
+	});
+}
+
 pub fn get_signer<AccountId: From<[u8; 32]>>(pubkey: &[u8; 32]) -> AccountId {
 	AccountId::from(*pubkey)
 }
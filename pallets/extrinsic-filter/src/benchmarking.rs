@@ -41,7 +41,7 @@ benchmarks! {
 	verify {
 		assert_eq!(
 			ExtrinsicFilter::<T>::blocked_extrinsics((pallet_name_bytes.clone(), function_name_bytes.clone())),
-			Some(())
+			Some(None)
 		);
 		assert_last_event::<T>(Event::ExtrinsicsBlocked {
 			pallet_name_bytes,
@@ -56,6 +56,18 @@
 //! - simplicity
 //! - whitelisting is not supported
 //!
+//! A block can be given a duration with `block_extrinsics_for`, it's then lifted automatically
+//! in `on_initialize` of the block where it expires.
+//!
+//! Besides, certain accounts can be blocked from all extrinsics or from all extrinsics in
+//! certain pallet, e.g. in case of a compromised key. The transactions of blocked accounts are
+//! rejected by the signed extension `CheckAccountBlocklist`, which must be included in the
+//! `SignedExtra` of the runtime. Runtimes with self-contained transactions, e.g. ethereum ones,
+//! must check their signers with `ensure_account_not_blocked` as well.
+//! The calls nested in the call of a transaction are checked too, against the accounts they are
+//! dispatched from, e.g. the call of `proxy.proxy` against its `real` account. The runtime
+//! resolves them with `Config::NestedCalls`.
+//!
 //! All dispatchables in this pallet must come from a priviledged origin.
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -70,15 +82,23 @@ mod tests;
 mod benchmarking;
 pub mod weights;
 
+pub mod migration;
+
 use frame_support::{
-	dispatch::{CallMetadata, GetCallMetadata},
+	dispatch::{CallMetadata, DispatchInfo, GetCallMetadata},
 	pallet_prelude::*,
 	traits::{Contains, StorageVersion},
 	transactional,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
-use sp_std::prelude::*;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, SignedExtension, Zero},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
 
 use scale_info::TypeInfo;
 pub use weights::WeightInfo;
@@ -97,12 +117,28 @@ pub enum OperationalMode {
 	Test,
 }
 
+/// The maximum depth of the nested calls checked against the account blocklist, a transaction
+/// nesting deeper is rejected.
+pub const MAX_NESTED_CALL_DEPTH: u32 = 16;
+
+/// Resolves the calls nested in a call dispatched from `who`, with the accounts they are
+/// dispatched from.
+pub trait NestedCalls<AccountId, Call> {
+	fn nested_calls<'a>(who: &AccountId, call: &'a Call) -> Vec<(AccountId, &'a Call)>;
+}
+
+impl<AccountId, Call> NestedCalls<AccountId, Call> for () {
+	fn nested_calls<'a>(_who: &AccountId, _call: &'a Call) -> Vec<(AccountId, &'a Call)> {
+		Vec::new()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -122,6 +158,9 @@ pub mod pallet {
 		type SafeModeFilter: Contains<Self::RuntimeCall>;
 		type TestModeFilter: Contains<Self::RuntimeCall>;
 
+		/// The calls nested in a call, which are checked against the account blocklist as well
+		type NestedCalls: NestedCalls<Self::AccountId, Self::RuntimeCall>;
+
 		/// Weights
 		type WeightInfo: WeightInfo;
 	}
@@ -134,10 +173,24 @@ pub mod pallet {
 	/// a tuple (pallet_name_bytes, Option<function_name_bytes>) to represent blocked extrinsics
 	/// if `Option<function_name_bytes>` is None, then all extrinsics in `pallet_name_bytes` are
 	/// blocked
+	/// the value is the block number when the block expires, None means it never expires
 	#[pallet::storage]
 	#[pallet::getter(fn blocked_extrinsics)]
 	pub type BlockedExtrinsics<T: Config> =
-		StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
+		StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), Option<BlockNumberFor<T>>, OptionQuery>;
+
+	/// the blocked extrinsics that expire at a given block number
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_extrinsics)]
+	pub type ExpiringExtrinsics<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<(Vec<u8>, Vec<u8>)>, ValueQuery>;
+
+	/// a tuple (account, pallet_name_bytes) to represent blocked accounts
+	/// if `pallet_name_bytes` is empty, then the account is blocked from all extrinsics
+	#[pallet::storage]
+	#[pallet::getter(fn blocked_accounts)]
+	pub type BlockedAccounts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, Vec<u8>, (), OptionQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -148,6 +201,18 @@ pub mod pallet {
 		ExtrinsicsBlocked { pallet_name_bytes: Vec<u8>, function_name_bytes: Option<Vec<u8>> },
 		/// some extrinsics are unblocked
 		ExtrinsicsUnblocked { pallet_name_bytes: Vec<u8>, function_name_bytes: Option<Vec<u8>> },
+		/// some extrinsics are blocked until the given block number
+		ExtrinsicsBlockedUntil {
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Option<Vec<u8>>,
+			expiry: BlockNumberFor<T>,
+		},
+		/// the block of some extrinsics expired
+		ExtrinsicsBlockExpired { pallet_name_bytes: Vec<u8>, function_name_bytes: Option<Vec<u8>> },
+		/// an account is blocked
+		AccountBlocked { account: T::AccountId, pallet_name_bytes: Option<Vec<u8>> },
+		/// an account is unblocked
+		AccountUnblocked { account: T::AccountId, pallet_name_bytes: Option<Vec<u8>> },
 	}
 
 	#[pallet::error]
@@ -160,6 +225,38 @@ pub mod pallet {
 		ExtrinsicAlreadyBlocked,
 		/// Error when trying to unblock a non-existent extrinsic
 		ExtrinsicNotBlocked,
+		/// Error when the duration of a block is zero
+		ZeroDuration,
+		/// Error when trying to block account more than once
+		AccountAlreadyBlocked,
+		/// Error when trying to unblock a non-blocked account
+		AccountNotBlocked,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+			for (pallet_name_bytes, function_name_bytes) in ExpiringExtrinsics::<T>::take(now) {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+				let key = (pallet_name_bytes, function_name_bytes);
+				// the extrinsics might have been unblocked or blocked again in the meantime
+				if BlockedExtrinsics::<T>::get(&key) != Some(Some(now)) {
+					continue
+				}
+				BlockedExtrinsics::<T>::remove(&key);
+				weight.saturating_accrue(T::DbWeight::get().writes(2));
+
+				let (pallet_name_bytes, function_name_bytes) = key;
+				Self::deposit_event(Event::ExtrinsicsBlockExpired {
+					pallet_name_bytes,
+					function_name_bytes: Some(function_name_bytes).filter(|f| !f.is_empty()),
+				});
+			}
+
+			weight
+		}
 	}
 
 	#[pallet::call]
@@ -198,30 +295,17 @@ pub mod pallet {
 			function_name_bytes: Option<Vec<u8>>,
 		) -> DispatchResultWithPostInfo {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			let pallet_name_string = sp_std::str::from_utf8(&pallet_name_bytes)
-				.map_err(|_| Error::<T>::CannotConvertToString)?;
-			// we disallow blocking this pallet itself
-			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotBlock
-			);
-
-			BlockedExtrinsics::<T>::mutate(
-				(pallet_name_bytes.clone(), function_name_bytes.clone().unwrap_or_default()),
-				|v| {
-					if v.is_none() {
-						*v = Some(());
-						Self::deposit_event(Event::ExtrinsicsBlocked {
-							pallet_name_bytes,
-							function_name_bytes,
-						});
-						// do not pay the fee upon successful block
-						Ok(Pays::No.into())
-					} else {
-						Err(Error::<T>::ExtrinsicAlreadyBlocked.into())
-					}
-				},
-			)
+			Self::do_block_extrinsics(
+				pallet_name_bytes.clone(),
+				function_name_bytes.clone(),
+				None,
+			)?;
+			Self::deposit_event(Event::ExtrinsicsBlocked {
+				pallet_name_bytes,
+				function_name_bytes,
+			});
+			// do not pay the fee upon successful block
+			Ok(Pays::No.into())
 		}
 
 		/// unblock the given extrinsics
@@ -252,6 +336,157 @@ pub mod pallet {
 			// do not pay the fee upon successful unblock
 			Ok(Pays::No.into())
 		}
+
+		/// block the given extrinsics for `duration` blocks,
+		/// the block is lifted automatically when it expires
+		/// it can still be lifted earlier by `unblock_extrinsics`
+		#[pallet::call_index(3)]
+		#[pallet::weight({10_000})]
+		#[transactional]
+		pub fn block_extrinsics_for(
+			origin: OriginFor<T>,
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Option<Vec<u8>>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(!duration.is_zero(), Error::<T>::ZeroDuration);
+
+			let expiry = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			Self::do_block_extrinsics(
+				pallet_name_bytes.clone(),
+				function_name_bytes.clone(),
+				Some(expiry),
+			)?;
+			ExpiringExtrinsics::<T>::append(
+				expiry,
+				(pallet_name_bytes.clone(), function_name_bytes.clone().unwrap_or_default()),
+			);
+			Self::deposit_event(Event::ExtrinsicsBlockedUntil {
+				pallet_name_bytes,
+				function_name_bytes,
+				expiry,
+			});
+			// do not pay the fee upon successful block
+			Ok(Pays::No.into())
+		}
+
+		/// block the given account
+		/// if pallet_name_bytes is None, the account is blocked from all extrinsics,
+		/// otherwise only from the extrinsics in `pallet_name_bytes`
+		#[pallet::call_index(4)]
+		#[pallet::weight({10_000})]
+		#[transactional]
+		pub fn block_account(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			pallet_name_bytes: Option<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			BlockedAccounts::<T>::mutate(
+				&account,
+				pallet_name_bytes.clone().unwrap_or_default(),
+				|v| {
+					if v.is_none() {
+						*v = Some(());
+						Ok(())
+					} else {
+						Err(Error::<T>::AccountAlreadyBlocked)
+					}
+				},
+			)?;
+			Self::deposit_event(Event::AccountBlocked { account, pallet_name_bytes });
+			// do not pay the fee upon successful block
+			Ok(Pays::No.into())
+		}
+
+		/// unblock the given account
+		/// it must be called with the same pallet_name_bytes used in `block_account`
+		#[pallet::call_index(5)]
+		#[pallet::weight({10_000})]
+		#[transactional]
+		pub fn unblock_account(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			pallet_name_bytes: Option<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				BlockedAccounts::<T>::take(&account, pallet_name_bytes.clone().unwrap_or_default())
+					.is_some(),
+				Error::<T>::AccountNotBlocked
+			);
+			Self::deposit_event(Event::AccountUnblocked { account, pallet_name_bytes });
+			// do not pay the fee upon successful unblock
+			Ok(Pays::No.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn do_block_extrinsics(
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Option<Vec<u8>>,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let pallet_name_string = sp_std::str::from_utf8(&pallet_name_bytes)
+				.map_err(|_| Error::<T>::CannotConvertToString)?;
+			// we disallow blocking this pallet itself
+			ensure!(
+				pallet_name_string != <Self as PalletInfoAccess>::name(),
+				Error::<T>::CannotBlock
+			);
+
+			BlockedExtrinsics::<T>::mutate(
+				(pallet_name_bytes, function_name_bytes.unwrap_or_default()),
+				|v| {
+					if v.is_none() {
+						*v = Some(expiry);
+						Ok(())
+					} else {
+						Err(Error::<T>::ExtrinsicAlreadyBlocked.into())
+					}
+				},
+			)
+		}
+
+		/// whether `account` is blocked from the extrinsics in `pallet_name_bytes`
+		pub fn is_account_blocked(account: &T::AccountId, pallet_name_bytes: &[u8]) -> bool {
+			BlockedAccounts::<T>::contains_key(account, Vec::<u8>::default()) ||
+				BlockedAccounts::<T>::contains_key(account, pallet_name_bytes)
+		}
+
+		/// reject the transaction if `account` is blocked from `call`, or the account of a call
+		/// nested in it is blocked from that call
+		pub fn ensure_account_not_blocked(
+			account: &T::AccountId,
+			call: &T::RuntimeCall,
+		) -> Result<(), TransactionValidityError>
+		where
+			<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+		{
+			Self::do_ensure_account_not_blocked(account, call, 0)
+		}
+
+		fn do_ensure_account_not_blocked(
+			account: &T::AccountId,
+			call: &T::RuntimeCall,
+			depth: u32,
+		) -> Result<(), TransactionValidityError>
+		where
+			<T as frame_system::Config>::RuntimeCall: GetCallMetadata,
+		{
+			if depth > MAX_NESTED_CALL_DEPTH {
+				return Err(InvalidTransaction::Call.into())
+			}
+			let CallMetadata { pallet_name, .. } = call.get_call_metadata();
+			if Self::is_account_blocked(account, pallet_name.as_bytes()) {
+				return Err(InvalidTransaction::Call.into())
+			}
+			for (account, call) in T::NestedCalls::nested_calls(account, call) {
+				Self::do_ensure_account_not_blocked(&account, call, depth + 1)?;
+			}
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Contains<T::RuntimeCall> for Pallet<T>
@@ -281,3 +516,62 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Reject the extrinsics from blocked accounts, regardless of the mode.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckAccountBlocklist<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckAccountBlocklist<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckAccountBlocklist<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> Debug for CheckAccountBlocklist<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckAccountBlocklist")
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckAccountBlocklist<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo> + GetCallMetadata,
+{
+	const IDENTIFIER: &'static str = "CheckAccountBlocklist";
+	type AccountId = T::AccountId;
+	type Call = T::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		Pallet::<T>::ensure_account_not_blocked(who, call)?;
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Pallet::<T>::ensure_account_not_blocked(who, call)
+	}
+}
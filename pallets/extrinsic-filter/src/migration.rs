@@ -0,0 +1,56 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of pallet-extrinsic-filter
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+/// Migrate the values of `BlockedExtrinsics` from `()` to `Option<BlockNumber>`,
+/// the existing blocks never expire.
+pub struct MigrateToV1<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 0 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut count = 0u64;
+		BlockedExtrinsics::<T>::translate::<(), _>(|_, _| {
+			count += 1;
+			Some(None)
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(count + 1, count + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok((BlockedExtrinsics::<T>::iter_keys().count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count: u32 = Decode::decode(&mut &state[..]).map_err(|_| "cannot decode count")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() == 1, "unexpected storage version");
+		ensure!(
+			BlockedExtrinsics::<T>::iter_values().filter(|v| v.is_none()).count() == count as usize,
+			"unexpected number of blocked extrinsics"
+		);
+		Ok(())
+	}
+}
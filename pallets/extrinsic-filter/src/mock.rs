@@ -123,6 +123,7 @@ impl pallet_extrinsic_filter::Config for Test {
 	type SafeModeFilter = SafeModeFilter;
 	type NormalModeFilter = NormalModeFilter;
 	type TestModeFilter = Everything;
+	type NestedCalls = ();
 	type WeightInfo = ();
 }
 
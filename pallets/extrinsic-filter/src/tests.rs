@@ -14,9 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::traits::Dispatchable;
+use crate::{mock::*, CheckAccountBlocklist, Error};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchInfo, traits::Hooks};
+use sp_runtime::{
+	traits::{Dispatchable, SignedExtension},
+	transaction_validity::InvalidTransaction,
+};
 
 #[test]
 fn set_mode_works() {
@@ -55,7 +58,7 @@ fn set_mode_should_not_clear_blocked_extrinsics() {
 		));
 		assert_eq!(
 			ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), b"transfer".to_vec())),
-			Some(())
+			Some(None)
 		);
 
 		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Test));
@@ -63,7 +66,7 @@ fn set_mode_should_not_clear_blocked_extrinsics() {
 		// previously blocked extrinsics are still there
 		assert_eq!(
 			ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), b"transfer".to_vec())),
-			Some(())
+			Some(None)
 		);
 	});
 }
@@ -153,7 +156,7 @@ fn block_single_extrinsic_works() {
 		}));
 		assert_eq!(
 			ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), b"transfer".to_vec())),
-			Some(())
+			Some(None)
 		);
 		// try to dispatch Balances.transfer should fail
 		let call: RuntimeCall = pallet_balances::Call::transfer { dest: 2, value: 10 }.into();
@@ -188,7 +191,7 @@ fn block_whole_pallet_works() {
 		}));
 		assert_eq!(
 			ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), Vec::<u8>::default())),
-			Some(())
+			Some(None)
 		);
 		// try to dispatch Balances.transfer should fail
 		let call: RuntimeCall = pallet_balances::Call::transfer { dest: 2, value: 10 }.into();
@@ -226,7 +229,7 @@ fn unblock_single_extrinsic_works() {
 		}));
 		assert_eq!(
 			ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), b"transfer".to_vec())),
-			Some(())
+			Some(None)
 		);
 		// try to dispatch Balances.transfer should fail
 		let call: RuntimeCall = pallet_balances::Call::transfer { dest: 2, value: 10 }.into();
@@ -276,7 +279,7 @@ fn unblock_whole_pallet_works() {
 		}));
 		assert_eq!(
 			ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), Vec::<u8>::default())),
-			Some(())
+			Some(None)
 		);
 		// try to dispatch Balances.transfer should fail
 		let call: RuntimeCall = pallet_balances::Call::transfer { dest: 2, value: 10 }.into();
@@ -498,3 +501,190 @@ fn blocked_extrinsic_is_retained_upon_mode_switch() {
 		assert_ok!(call.dispatch(RuntimeOrigin::none()));
 	});
 }
+
+#[test]
+fn block_extrinsics_for_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Test));
+
+		// block Balances.transfer for 2 blocks
+		assert_ok!(ExtrinsicFilter::block_extrinsics_for(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			Some(b"transfer".to_vec()),
+			2
+		));
+		assert_eq!(
+			ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), b"transfer".to_vec())),
+			Some(Some(3))
+		);
+		assert_eq!(
+			ExtrinsicFilter::expiring_extrinsics(3),
+			vec![(b"Balances".to_vec(), b"transfer".to_vec())]
+		);
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(
+			crate::Event::ExtrinsicsBlockedUntil {
+				pallet_name_bytes: b"Balances".to_vec(),
+				function_name_bytes: Some(b"transfer".to_vec()),
+				expiry: 3,
+			},
+		));
+
+		let call: RuntimeCall = pallet_balances::Call::transfer { dest: 2, value: 10 }.into();
+		assert_noop!(
+			call.clone().dispatch(RuntimeOrigin::signed(1)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+
+		// still blocked in block 2
+		System::set_block_number(2);
+		ExtrinsicFilter::on_initialize(2);
+		assert_noop!(
+			call.clone().dispatch(RuntimeOrigin::signed(1)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+
+		// the block expires in block 3
+		System::set_block_number(3);
+		ExtrinsicFilter::on_initialize(3);
+		assert_eq!(
+			ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), b"transfer".to_vec())),
+			None
+		);
+		assert!(ExtrinsicFilter::expiring_extrinsics(3).is_empty());
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(
+			crate::Event::ExtrinsicsBlockExpired {
+				pallet_name_bytes: b"Balances".to_vec(),
+				function_name_bytes: Some(b"transfer".to_vec()),
+			},
+		));
+		assert_ok!(call.dispatch(RuntimeOrigin::signed(1)));
+	});
+}
+
+#[test]
+fn block_extrinsics_for_fails_with_zero_duration() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ExtrinsicFilter::block_extrinsics_for(
+				RuntimeOrigin::root(),
+				b"Balances".to_vec(),
+				None,
+				0
+			),
+			Error::<Test>::ZeroDuration
+		);
+	});
+}
+
+#[test]
+fn expiry_does_not_unblock_extrinsics_blocked_again() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::block_extrinsics_for(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			None,
+			2
+		));
+		// the block can be lifted before it expires
+		assert_ok!(ExtrinsicFilter::unblock_extrinsics(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			None
+		));
+		// block it again without expiry
+		assert_ok!(ExtrinsicFilter::block_extrinsics(
+			RuntimeOrigin::root(),
+			b"Balances".to_vec(),
+			None
+		));
+
+		System::set_block_number(3);
+		ExtrinsicFilter::on_initialize(3);
+		assert_eq!(ExtrinsicFilter::blocked_extrinsics((b"Balances".to_vec(), vec![])), Some(None));
+	});
+}
+
+#[test]
+fn block_account_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Test));
+
+		// block account 1 from pallet_balances
+		assert_ok!(ExtrinsicFilter::block_account(
+			RuntimeOrigin::root(),
+			1,
+			Some(b"Balances".to_vec())
+		));
+		assert_eq!(ExtrinsicFilter::blocked_accounts(1, b"Balances".to_vec()), Some(()));
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(crate::Event::AccountBlocked {
+			account: 1,
+			pallet_name_bytes: Some(b"Balances".to_vec()),
+		}));
+		assert_noop!(
+			ExtrinsicFilter::block_account(RuntimeOrigin::root(), 1, Some(b"Balances".to_vec())),
+			Error::<Test>::AccountAlreadyBlocked
+		);
+
+		let info = DispatchInfo::default();
+		let transfer: RuntimeCall = pallet_balances::Call::transfer { dest: 2, value: 10 }.into();
+		let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+		// the signed extension rejects account 1 calling pallet_balances
+		assert_eq!(
+			CheckAccountBlocklist::<Test>::new().validate(&1, &transfer, &info, 0),
+			Err(InvalidTransaction::Call.into())
+		);
+		assert_ok!(CheckAccountBlocklist::<Test>::new().validate(&1, &remark, &info, 0));
+		assert_ok!(CheckAccountBlocklist::<Test>::new().validate(&2, &transfer, &info, 0));
+
+		assert_noop!(
+			CheckAccountBlocklist::<Test>::new().pre_dispatch(&1, &transfer, &info, 0),
+			InvalidTransaction::Call
+		);
+		assert_ok!(CheckAccountBlocklist::<Test>::new().pre_dispatch(&1, &remark, &info, 0));
+		assert_eq!(
+			ExtrinsicFilter::ensure_account_not_blocked(&1, &transfer),
+			Err(InvalidTransaction::Call.into())
+		);
+
+		// unblock account 1
+		assert_ok!(ExtrinsicFilter::unblock_account(
+			RuntimeOrigin::root(),
+			1,
+			Some(b"Balances".to_vec())
+		));
+		System::assert_last_event(RuntimeEvent::ExtrinsicFilter(crate::Event::AccountUnblocked {
+			account: 1,
+			pallet_name_bytes: Some(b"Balances".to_vec()),
+		}));
+		assert_ok!(CheckAccountBlocklist::<Test>::new().validate(&1, &transfer, &info, 0));
+	});
+}
+
+#[test]
+fn block_account_from_all_extrinsics_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ExtrinsicFilter::set_mode(RuntimeOrigin::root(), crate::OperationalMode::Test));
+		assert_ok!(ExtrinsicFilter::block_account(RuntimeOrigin::root(), 1, None));
+
+		let info = DispatchInfo::default();
+		let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		assert_eq!(
+			CheckAccountBlocklist::<Test>::new().validate(&1, &remark, &info, 0),
+			Err(InvalidTransaction::Call.into())
+		);
+		assert_noop!(
+			CheckAccountBlocklist::<Test>::new().pre_dispatch(&1, &remark, &info, 0),
+			InvalidTransaction::Call
+		);
+
+		// unblocking the account from a single pallet doesn't work
+		assert_noop!(
+			ExtrinsicFilter::unblock_account(RuntimeOrigin::root(), 1, Some(b"System".to_vec())),
+			Error::<Test>::AccountNotBlocked
+		);
+		assert_ok!(ExtrinsicFilter::unblock_account(RuntimeOrigin::root(), 1, None));
+		assert_ok!(CheckAccountBlocklist::<Test>::new().validate(&1, &remark, &info, 0));
+	});
+}
@@ -0,0 +1,207 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the parachain staking pallet
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+pub use pallet_parachain_staking_runtime_api::ParachainStakingApi as ParachainStakingRuntimeApi;
+use pallet_parachain_staking_runtime_api::{
+	DelegationAction, DelegationRequest, DelegationStatus, PendingReward,
+};
+use parity_scale_codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{traits::Block as BlockT, Perbill};
+
+#[rpc(server)]
+pub trait ParachainStakingApi<BlockHash, AccountId> {
+	#[method(name = "parachainStaking_pendingRewards")]
+	fn pending_rewards(
+		&self,
+		delegator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PendingReward<AccountId, NumberOrHex>>>;
+
+	#[method(name = "parachainStaking_claimableRewards")]
+	fn claimable_rewards(
+		&self,
+		delegator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	#[method(name = "parachainStaking_collatorApr")]
+	fn collator_apr(
+		&self,
+		collator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Perbill>>;
+
+	#[method(name = "parachainStaking_delegationRequests")]
+	fn delegation_requests(
+		&self,
+		delegator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DelegationRequest<AccountId, NumberOrHex>>>;
+
+	#[method(name = "parachainStaking_delegationStatus")]
+	fn delegation_status(
+		&self,
+		delegator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DelegationStatus<AccountId, NumberOrHex>>>;
+}
+
+/// Provides RPC methods to query rewards and delegations of the parachain staking pallet.
+/// Balances are returned as `NumberOrHex`, as json numbers can not hold all `u128` values.
+pub struct ParachainStaking<C, Block, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(Block, Balance)>,
+}
+
+impl<C, Block, Balance> ParachainStaking<C, Block, Balance> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query parachain staking.",
+		Some(format!("{:?}", e)),
+	))
+	.into()
+}
+
+impl<C, Block, AccountId, Balance> ParachainStakingApiServer<<Block as BlockT>::Hash, AccountId>
+	for ParachainStaking<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ParachainStakingRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + Send + Sync + 'static,
+	Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+{
+	fn pending_rewards(
+		&self,
+		delegator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PendingReward<AccountId, NumberOrHex>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let rewards = self
+			.client
+			.runtime_api()
+			.pending_rewards(at, delegator)
+			.map_err(runtime_error)?;
+		Ok(rewards
+			.into_iter()
+			.map(|r| PendingReward {
+				round: r.round,
+				collator: r.collator,
+				amount: r.amount.into(),
+			})
+			.collect())
+	}
+
+	fn claimable_rewards(
+		&self,
+		delegator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let amount = self
+			.client
+			.runtime_api()
+			.claimable_rewards(at, delegator)
+			.map_err(runtime_error)?;
+		Ok(amount.into())
+	}
+
+	fn collator_apr(
+		&self,
+		collator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Perbill>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().collator_apr(at, collator).map_err(runtime_error)
+	}
+
+	fn delegation_requests(
+		&self,
+		delegator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DelegationRequest<AccountId, NumberOrHex>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let requests = self
+			.client
+			.runtime_api()
+			.delegation_requests(at, delegator)
+			.map_err(runtime_error)?;
+		Ok(requests
+			.into_iter()
+			.map(|r| DelegationRequest {
+				collator: r.collator,
+				action: match r.action {
+					DelegationAction::Revoke(amount) => DelegationAction::Revoke(amount.into()),
+					DelegationAction::Decrease(amount) => DelegationAction::Decrease(amount.into()),
+				},
+				when_executable: r.when_executable,
+			})
+			.collect())
+	}
+
+	fn delegation_status(
+		&self,
+		delegator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DelegationStatus<AccountId, NumberOrHex>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let status = self
+			.client
+			.runtime_api()
+			.delegation_status(at, delegator)
+			.map_err(runtime_error)?;
+		Ok(status
+			.into_iter()
+			.map(|s| DelegationStatus {
+				collator: s.collator,
+				amount: s.amount.into(),
+				counted: s.counted,
+			})
+			.collect())
+	}
+}
@@ -0,0 +1,51 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime api to query rewards and delegations of `pallet-parachain-staking`
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_parachain_staking::{
+	DelegationAction, DelegationRequest, DelegationStatus, PendingReward,
+};
+use parity_scale_codec::Codec;
+use sp_runtime::Perbill;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ParachainStakingApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Rewards of the delegator which are not paid out yet, per round and collator
+		fn pending_rewards(delegator: AccountId) -> Vec<PendingReward<AccountId, Balance>>;
+
+		/// Rewards paid out to the delegator in the `Claim` payout mode and not claimed yet,
+		/// they are not part of the pending rewards
+		fn claimable_rewards(delegator: AccountId) -> Balance;
+
+		/// Estimated annual percentage rate of the stake delegated to the collator,
+		/// `None` if the collator is not selected in the current round
+		fn collator_apr(collator: AccountId) -> Option<Perbill>;
+
+		/// Scheduled requests of the delegator
+		fn delegation_requests(delegator: AccountId) -> Vec<DelegationRequest<AccountId, Balance>>;
+
+		/// Delegations of the delegator and whether they are counted by the collator
+		fn delegation_status(delegator: AccountId) -> Vec<DelegationStatus<AccountId, Balance>>;
+	}
+}
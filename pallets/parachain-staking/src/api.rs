@@ -0,0 +1,178 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Read-only queries backing the `ParachainStakingApi` runtime api
+
+use crate::{
+	inflation::rounds_per_year,
+	pallet::{
+		AtStake, AwardedPts, BalanceOf, Config, DelayedPayouts, DelegationScheduledRequests,
+		DelegatorState, Pallet, Points, Round, RoundIndex, Staked, TopDelegations,
+	},
+	types::DelayedPayout,
+	DelegationAction,
+};
+use frame_support::{traits::Get, RuntimeDebug};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill,
+};
+use sp_std::prelude::*;
+
+/// Reward earned by a delegator through a collator in a round, which is not paid out yet
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingReward<AccountId, Balance> {
+	pub round: RoundIndex,
+	pub collator: AccountId,
+	/// Estimated for the rounds whose payout is not prepared yet
+	pub amount: Balance,
+}
+
+/// A scheduled request of a delegator towards a collator
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DelegationRequest<AccountId, Balance> {
+	pub collator: AccountId,
+	pub action: DelegationAction<Balance>,
+	/// The round from which on the request can be executed
+	pub when_executable: RoundIndex,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DelegationStatus<AccountId, Balance> {
+	pub collator: AccountId,
+	pub amount: Balance,
+	/// Whether the delegation is among the top delegations of the collator, only those are
+	/// counted towards the collator stake and rewarded
+	pub counted: bool,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Rewards of the delegator which are not paid out yet. Rewards are paid out
+	/// `RewardPaymentDelay` rounds after the round they are earned in, the rewards of the rounds
+	/// in between, including the current one, are estimated from the points awarded so far.
+	/// The rewards paid out in the `Claim` payout mode are in `ClaimableRewards` instead.
+	pub fn pending_rewards(
+		delegator: &T::AccountId,
+	) -> Vec<PendingReward<T::AccountId, BalanceOf<T>>> {
+		let now = <Round<T>>::get().current;
+		let mut rewards = Vec::new();
+		for round in now.saturating_sub(T::RewardPaymentDelay::get())..=now {
+			let total_points = <Points<T>>::get(round);
+			if total_points.is_zero() {
+				continue
+			}
+			let payout =
+				<DelayedPayouts<T>>::get(round).unwrap_or_else(|| Self::estimate_payout(round));
+			let collator_issuance = payout.collator_commission * payout.round_issuance;
+
+			// collators are removed from `AwardedPts` once they are paid
+			for (collator, pts) in <AwardedPts<T>>::iter_prefix(round) {
+				let state = <AtStake<T>>::get(round, &collator);
+				let Some(bond) = state.delegations.iter().find(|bond| &bond.owner == delegator)
+				else {
+					continue
+				};
+				// same split as in `pay_one_collator_reward`
+				let pct_due = Perbill::from_rational(pts, total_points);
+				let commission = pct_due * collator_issuance;
+				let amt_due = (pct_due * payout.total_staking_reward).saturating_sub(commission);
+				let amount = Perbill::from_rational(bond.amount, state.total) * amt_due;
+				if !amount.is_zero() {
+					rewards.push(PendingReward { round, collator, amount });
+				}
+			}
+		}
+		rewards
+	}
+
+	/// Estimated annual percentage rate of the stake delegated to the collator in the current
+	/// round, assuming that blocks are authored evenly by the selected collators and rewards are
+	/// not compounded. `None` if the collator is not selected in the current round.
+	pub fn collator_apr(collator: &T::AccountId) -> Option<Perbill> {
+		let round = <Round<T>>::get().current;
+		let state = <AtStake<T>>::get(round, collator);
+		if state.total.is_zero() {
+			return None
+		}
+		let selected = Self::selected_candidates().len() as u32;
+		let payout = Self::estimate_payout(round);
+
+		let pct_due = Perbill::from_rational(1, selected.max(1));
+		let commission = pct_due * (payout.collator_commission * payout.round_issuance);
+		// the commission goes to the collator, the rest is shared pro rata to the counted stake
+		let round_reward = (pct_due * payout.total_staking_reward).saturating_sub(commission);
+		let annual_reward = round_reward.saturating_mul(rounds_per_year::<T>().into());
+		// saturates at 100%
+		Some(Perbill::from_rational(annual_reward, state.total))
+	}
+
+	/// Scheduled requests of the delegator, one at most per collator
+	pub fn delegation_requests(
+		delegator: &T::AccountId,
+	) -> Vec<DelegationRequest<T::AccountId, BalanceOf<T>>> {
+		let Some(state) = <DelegatorState<T>>::get(delegator) else { return Vec::new() };
+		state
+			.delegations
+			.0
+			.into_iter()
+			.filter_map(|bond| {
+				<DelegationScheduledRequests<T>>::get(&bond.owner)
+					.into_iter()
+					.find(|request| &request.delegator == delegator)
+					.map(|request| DelegationRequest {
+						collator: bond.owner,
+						action: request.action,
+						when_executable: request.when_executable,
+					})
+			})
+			.collect()
+	}
+
+	/// Delegations of the delegator and whether they are counted by the collator
+	pub fn delegation_status(
+		delegator: &T::AccountId,
+	) -> Vec<DelegationStatus<T::AccountId, BalanceOf<T>>> {
+		let Some(state) = <DelegatorState<T>>::get(delegator) else { return Vec::new() };
+		state
+			.delegations
+			.0
+			.into_iter()
+			.map(|bond| {
+				let counted = <TopDelegations<T>>::get(&bond.owner)
+					.map_or(false, |top| top.delegations.iter().any(|d| &d.owner == delegator));
+				DelegationStatus { collator: bond.owner, amount: bond.amount, counted }
+			})
+			.collect()
+	}
+
+	/// The payout `prepare_staking_payouts` would compute for the round with the current config
+	fn estimate_payout(round: RoundIndex) -> DelayedPayout<BalanceOf<T>> {
+		let round_issuance = Self::compute_issuance(<Staked<T>>::get(round));
+		let parachain_bond_reserve = Self::parachain_bond_info().percent * round_issuance;
+		DelayedPayout {
+			round_issuance,
+			total_staking_reward: round_issuance.saturating_sub(parachain_bond_reserve),
+			collator_commission: Self::collator_commission(),
+		}
+	}
+}
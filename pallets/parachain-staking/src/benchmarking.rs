@@ -23,14 +23,15 @@
 
 //! Benchmarking
 use crate::{
-	BalanceOf, Call, CandidateBondLessRequest, Config, DelegationAction, Event, Pallet, Range,
-	Round, ScheduledRequest,
+	BalanceOf, Call, CandidateBondLessRequest, ClaimableRewards, Config, DelegationAction, Event,
+	Liveness, LivenessConfig, OfflineRounds, Pallet, Points, Range, RewardPayoutMode, Round,
+	ScheduledRequest, SelectedCandidates,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::{Currency, Get, OnFinalize, OnInitialize, ReservableCurrency};
 use frame_system::RawOrigin;
 use pallet_authorship::EventHandler;
-use sp_runtime::{Perbill, Percent, Saturating};
+use sp_runtime::{traits::Zero, Perbill, Percent, Saturating};
 use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 
 /// Minimum collator candidate stake
@@ -233,6 +234,13 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::parachain_bond_info().percent, Percent::from_percent(33));
 	}
 
+	set_liveness_config {
+		let config = LivenessConfig { max_offline_rounds: 3, slash: Perbill::from_percent(1) };
+	}: _(RawOrigin::Root, config)
+	verify {
+		assert_eq!(Pallet::<T>::liveness_config(), config);
+	}
+
 	// ROOT DISPATCHABLES
 
 	set_total_selected {
@@ -1039,6 +1047,38 @@ benchmarks! {
 		assert_eq!(start + 1u32.into(), end);
 	}
 
+	offline_collators_on_initialize {
+		// TOTAL SELECTED COLLATORS PER ROUND
+		let s in 1..100;
+		let mut seed = Seed::new();
+		let mut collators: Vec<T::AccountId> = Vec::new();
+		for _ in 0..s {
+			collators.push(create_funded_collator::<T>(
+				"collator",
+				seed.take(),
+				0u32.into(),
+				true,
+			)?);
+		}
+		// none of the selected collators authored a block in its last allowed round, they are
+		// all moved to idle and slashed below the minimum self bond, which schedules them to leave
+		let round = Pallet::<T>::round().current;
+		<Liveness<T>>::put(LivenessConfig {
+			max_offline_rounds: 2,
+			slash: Perbill::from_percent(50),
+		});
+		<SelectedCandidates<T>>::put(collators.clone());
+		<Points<T>>::insert(round, 20);
+		for collator in &collators {
+			<OfflineRounds<T>>::insert(collator, 1);
+		}
+	}: { Pallet::<T>::handle_offline_collators(round); }
+	verify {
+		for collator in &collators {
+			assert!(!Pallet::<T>::candidate_info(collator).unwrap().is_active());
+		}
+	}
+
 	set_auto_compound {
 		// x controls number of distinct auto-compounding delegations the prime collator will have
 		// y controls number of distinct delegations the prime delegator will have
@@ -1203,6 +1243,28 @@ benchmarks! {
 			"delegation must have an auto-compound entry",
 		);
 	}
+
+	set_reward_payout_mode {
+		let collator = create_funded_collator::<T>("collator", USER_SEED, 0u32.into(), true)?;
+		let delegator = create_funded_delegator::<T>(
+			"delegator",
+			USER_SEED,
+			0u32.into(),
+			collator,
+			true,
+		)?;
+	}: _(RawOrigin::Signed(delegator.clone()), RewardPayoutMode::Claim)
+	verify {
+		assert_eq!(Pallet::<T>::reward_payout_mode(&delegator), RewardPayoutMode::Claim);
+	}
+
+	claim_rewards {
+		let (delegator, _) = create_funded_user::<T>("delegator", USER_SEED, 0u32.into());
+		<ClaimableRewards<T>>::insert(&delegator, min_delegator_stk::<T>());
+	}: _(RawOrigin::Signed(delegator.clone()))
+	verify {
+		assert!(Pallet::<T>::claimable_rewards(&delegator).is_zero());
+	}
 }
 
 #[cfg(test)]
@@ -1244,6 +1306,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_set_liveness_config() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_liveness_config());
+		});
+	}
+
 	#[test]
 	fn bench_set_total_selected() {
 		new_test_ext().execute_with(|| {
@@ -1412,12 +1481,33 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn bench_offline_collators_on_initialize() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_offline_collators_on_initialize());
+		});
+	}
+
 	#[test]
 	fn bench_base_on_initialize() {
 		new_test_ext().execute_with(|| {
 			assert_ok!(Pallet::<Test>::test_benchmark_base_on_initialize());
 		});
 	}
+
+	#[test]
+	fn bench_set_reward_payout_mode() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_reward_payout_mode());
+		});
+	}
+
+	#[test]
+	fn bench_claim_rewards() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_claim_rewards());
+		});
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
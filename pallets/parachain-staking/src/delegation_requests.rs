@@ -36,10 +36,13 @@ use frame_support::{
 };
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::traits::Saturating;
 use sp_std::{vec, vec::Vec};
 
 /// An action that can be performed upon a delegation
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
 pub enum DelegationAction<Balance> {
 	#[codec(index = 0)]
@@ -248,6 +251,7 @@ impl<T: Config> Pallet<T> {
 				<DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
 				if leaving {
 					<DelegatorState<T>>::remove(&delegator);
+					Self::clear_delegator_rewards(&delegator);
 					Self::deposit_event(Event::DelegatorLeft {
 						delegator,
 						unstaked_amount: amount,
@@ -473,6 +477,7 @@ impl<T: Config> Pallet<T> {
 			unstaked_amount: state.total,
 		});
 		<DelegatorState<T>>::remove(&delegator);
+		Self::clear_delegator_rewards(&delegator);
 		let actual_weight =
 			Some(T::WeightInfo::execute_leave_delegators(state.delegations.0.len() as u32));
 		Ok(actual_weight.into())
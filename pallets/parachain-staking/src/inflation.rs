@@ -34,7 +34,7 @@ use substrate_fixed::{transcendental::pow as floatpow, types::I64F64};
 use core_primitives::*;
 pub const BLOCKS_PER_YEAR: u32 = YEARS;
 
-fn rounds_per_year<T: Config>() -> u32 {
+pub(crate) fn rounds_per_year<T: Config>() -> u32 {
 	let blocks_per_round = <Pallet<T>>::round().length;
 	BLOCKS_PER_YEAR / blocks_per_round
 }
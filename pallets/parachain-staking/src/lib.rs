@@ -57,6 +57,7 @@
 #![allow(clippy::useless_conversion)]
 #![allow(clippy::needless_borrow)]
 
+mod api;
 mod auto_compound;
 mod delegation_requests;
 pub mod inflation;
@@ -72,6 +73,7 @@ mod set;
 #[cfg(test)]
 mod tests;
 
+pub use api::{DelegationRequest, DelegationStatus, PendingReward};
 pub use inflation::{InflationInfo, Range};
 pub use weights::WeightInfo;
 
@@ -166,6 +168,9 @@ pub mod pallet {
 		/// Maximum delegations per delegator
 		#[pallet::constant]
 		type MaxDelegationsPerDelegator: Get<u32>;
+		/// Number of rounds the rewards of a delegator are kept in the reward ledger
+		#[pallet::constant]
+		type RewardLedgerRounds: Get<u32>;
 		/// Default commission due to collators, is `CollatorCommission` storage value in genesis
 		#[pallet::constant]
 		type DefaultCollatorCommission: Get<Perbill>;
@@ -236,6 +241,7 @@ pub mod pallet {
 		CannotDelegateLessThanOrEqualToLowestBottomWhenFull,
 		PendingDelegationRevoke,
 		CandidateUnauthorized,
+		NoRewardsToClaim,
 	}
 
 	#[pallet::event]
@@ -457,6 +463,39 @@ pub mod pallet {
 			delegator: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Set liveness requirements of the selected collators.
+		LivenessConfigSet {
+			old: LivenessConfig,
+			new: LivenessConfig,
+		},
+		/// Collator was moved to idle for not authoring any block in `offline_rounds`
+		/// consecutive rounds it was selected for.
+		CollatorMarkedOffline {
+			candidate: T::AccountId,
+			offline_rounds: u32,
+		},
+		/// Collator self bond was slashed for being offline.
+		CollatorSlashed {
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			new_bond: BalanceOf<T>,
+		},
+		/// Delegator changed how its rewards are paid out.
+		RewardPayoutModeSet {
+			delegator: T::AccountId,
+			mode: RewardPayoutMode,
+		},
+		/// Reward accrued for a delegator in the claim payout mode.
+		RewardAccrued {
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Delegator claimed its accrued rewards.
+		RewardsClaimed {
+			delegator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -472,6 +511,10 @@ pub mod pallet {
 				weight = weight.saturating_add(T::OnNewRound::on_new_round(round.current));
 				// pay all stakers for T::RewardPaymentDelay rounds ago
 				Self::prepare_staking_payouts(round.current);
+				// move collators which stopped authoring blocks to idle before the selection
+				weight = weight.saturating_add(Self::handle_offline_collators(
+					round.current.saturating_sub(1),
+				));
 				// select top collator candidates for next round
 				let (collator_count, delegation_count, total_staked) =
 					Self::select_top_candidates(round.current);
@@ -582,7 +625,7 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn selected_candidates)]
 	/// The collator candidates selected for the current round
-	type SelectedCandidates<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+	pub(crate) type SelectedCandidates<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn total)]
@@ -642,6 +685,42 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn liveness_config)]
+	/// Liveness requirements of the selected collators, offline detection is disabled by default
+	pub type Liveness<T: Config> = StorageValue<_, LivenessConfig, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn offline_rounds)]
+	/// Consecutive rounds in which the selected collator did not author any block
+	pub type OfflineRounds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reward_payout_mode)]
+	/// Reward payout mode of the delegators, `Eager` if not set
+	pub type RewardPayoutModes<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RewardPayoutMode, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn claimable_rewards)]
+	/// Rewards accrued by the delegators in the `Claim` payout mode
+	pub type ClaimableRewards<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Rewards of the delegators, kept in a ring of `RewardLedgerRounds` slots per delegator.
+	/// The rewards of a round are stored in the slot `round % RewardLedgerRounds` and replace
+	/// the ones of the round which used the slot before.
+	pub type RewardLedger<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		BoundedVec<RewardRecord<T::AccountId, BalanceOf<T>>, T::MaxDelegationsPerDelegator>,
+		ValueQuery,
+	>;
+
 	/// The whitelist of collation candidates.
 	/// This storage should be safe to delete after
 	/// we release the restriction
@@ -1021,6 +1100,7 @@ pub mod pallet {
 						// since it is assumed that they were removed incrementally before only the
 						// last delegation was left.
 						<DelegatorState<T>>::remove(&bond.owner);
+						Self::clear_delegator_rewards(&bond.owner);
 					} else {
 						<DelegatorState<T>>::insert(&bond.owner, delegator);
 					}
@@ -1070,6 +1150,7 @@ pub mod pallet {
 			let collator = ensure_signed(origin)?;
 			let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
 			ensure!(state.is_leaving(), Error::<T>::CandidateNotLeaving);
+			ensure!(state.bond >= T::MinCandidateStk::get(), Error::<T>::CandidateBondBelowMin);
 			state.go_online();
 			let mut candidates = <CandidatePool<T>>::get();
 			ensure!(
@@ -1343,6 +1424,59 @@ pub mod pallet {
 			let delegator = ensure_signed(origin)?;
 			<AutoCompoundDelegations<T>>::set_auto_compound(candidate, delegator, value)
 		}
+
+		/// Set the liveness requirements of the selected collators. A collator which doesn't
+		/// author any block in `max_offline_rounds` consecutive rounds it's selected for is
+		/// moved to idle and `slash` of its self bond is slashed.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_liveness_config())]
+		pub fn set_liveness_config(
+			origin: OriginFor<T>,
+			new: LivenessConfig,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			let old = <Liveness<T>>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			<Liveness<T>>::put(new);
+			Self::deposit_event(Event::LivenessConfigSet { old, new });
+			Ok(().into())
+		}
+
+		/// Set how the rewards of the caller are paid out. Rewards accrued in the `Claim` mode
+		/// stay claimable after switching back to `Eager`.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_reward_payout_mode())]
+		pub fn set_reward_payout_mode(
+			origin: OriginFor<T>,
+			mode: RewardPayoutMode,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			ensure!(Self::is_delegator(&delegator), Error::<T>::DelegatorDNE);
+			ensure!(
+				<RewardPayoutModes<T>>::get(&delegator) != mode,
+				Error::<T>::NoWritingSameValue
+			);
+			if mode == RewardPayoutMode::default() {
+				<RewardPayoutModes<T>>::remove(&delegator);
+			} else {
+				<RewardPayoutModes<T>>::insert(&delegator, mode);
+			}
+			Self::deposit_event(Event::RewardPayoutModeSet { delegator, mode });
+			Ok(().into())
+		}
+
+		/// Withdraw all rewards accrued in the `Claim` payout mode
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
+		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			let amount = <ClaimableRewards<T>>::get(&delegator);
+			ensure!(!amount.is_zero(), Error::<T>::NoRewardsToClaim);
+			let minted = T::Currency::deposit_into_existing(&delegator, amount)?;
+			<ClaimableRewards<T>>::remove(&delegator);
+			Self::deposit_event(Event::RewardsClaimed { delegator, amount: minted.peek() });
+			Ok(().into())
+		}
 	}
 	impl<T: Config> Pallet<T> {
 		pub fn is_delegator(acc: &T::AccountId) -> bool {
@@ -1385,7 +1519,7 @@ pub mod pallet {
 			<CandidatePool<T>>::put(candidates);
 		}
 		/// Compute round issuance based on total staked for the given round
-		fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
+		pub(crate) fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
 			let config = <InflationConfig<T>>::get();
 			let round_issuance = crate::inflation::round_issuance_range::<T>(config.round);
 			// TODO: consider interpolation instead of bounded range
@@ -1456,6 +1590,68 @@ pub mod pallet {
 			<DelayedPayouts<T>>::insert(round_to_payout, payout);
 		}
 
+		/// Updates the offline rounds of the collators selected in `round` and moves the ones
+		/// exceeding `max_offline_rounds` to idle, slashing their self bond if configured.
+		/// Only the collators of the last selection are tracked, so `OfflineRounds` is bounded
+		/// by `TotalSelected`.
+		pub(crate) fn handle_offline_collators(round: RoundIndex) -> Weight {
+			let config = <Liveness<T>>::get();
+			let previous: BTreeMap<T::AccountId, u32> = <OfflineRounds<T>>::drain().collect();
+			let tracked = previous.len() as u32;
+			if !config.is_enabled() {
+				return T::WeightInfo::offline_collators_on_initialize(tracked)
+			}
+
+			// without any points awarded in the round there is nothing to tell the offline
+			// collators apart from the online ones
+			if <Points<T>>::get(round).is_zero() {
+				return T::WeightInfo::offline_collators_on_initialize(tracked)
+			}
+
+			let selected = <SelectedCandidates<T>>::get();
+			let weight =
+				T::WeightInfo::offline_collators_on_initialize(tracked.max(selected.len() as u32));
+			for collator in selected {
+				let Some(mut state) = <CandidateInfo<T>>::get(&collator) else { continue };
+				// collators which went offline or are leaving by themselves are not tracked
+				if !state.is_active() || !<AwardedPts<T>>::get(round, &collator).is_zero() {
+					continue
+				}
+				let offline_rounds = previous.get(&collator).copied().unwrap_or_default() + 1;
+				if offline_rounds < config.max_offline_rounds {
+					<OfflineRounds<T>>::insert(&collator, offline_rounds);
+					continue
+				}
+
+				state.go_offline();
+				let mut candidates = <CandidatePool<T>>::get();
+				if candidates.remove(&Bond::from_owner(collator.clone())) {
+					<CandidatePool<T>>::put(candidates);
+				}
+				Self::deposit_event(Event::CollatorMarkedOffline {
+					candidate: collator.clone(),
+					offline_rounds,
+				});
+				let slash = config.slash * state.bond;
+				if !slash.is_zero() {
+					state.slash_bond::<T>(collator.clone(), slash);
+				}
+				// a candidate slashed below the minimum self bond can't stay a candidate,
+				// it is scheduled to leave and has to bond more to cancel the exit
+				if state.bond < T::MinCandidateStk::get() {
+					if let Ok((now, when)) = state.schedule_leave::<T>() {
+						Self::deposit_event(Event::CandidateScheduledExit {
+							exit_allowed_round: now,
+							candidate: collator.clone(),
+							scheduled_exit: when,
+						});
+					}
+				}
+				<CandidateInfo<T>>::insert(&collator, state);
+			}
+			weight
+		}
+
 		/// Wrapper around pay_one_collator_reward which handles the following logic:
 		/// * whether or not a payout needs to be made
 		/// * cleaning up when payouts are done
@@ -1550,12 +1746,15 @@ pub mod pallet {
 						let percent = Perbill::from_rational(amount, state.total);
 						let due = percent * amt_due;
 						if !due.is_zero() {
-							Self::mint_and_compound(
+							Self::pay_delegator_reward(
+								paid_for_round,
 								due,
 								auto_compound,
 								collator.clone(),
 								owner.clone(),
 							);
+							extra_weight =
+								extra_weight.saturating_add(Self::pay_delegator_reward_weight());
 						}
 					}
 				}
@@ -1737,47 +1936,125 @@ pub mod pallet {
 			}
 		}
 
+		/// Pay out the delegation reward according to the payout mode of the delegator and
+		/// record it in the reward ledger.
+		fn pay_delegator_reward(
+			round: RoundIndex,
+			amt: BalanceOf<T>,
+			compound_percent: Percent,
+			candidate: T::AccountId,
+			delegator: T::AccountId,
+		) {
+			let (paid, compounded) = match <RewardPayoutModes<T>>::get(&delegator) {
+				RewardPayoutMode::Eager => Self::mint_and_compound(
+					amt,
+					compound_percent,
+					candidate.clone(),
+					delegator.clone(),
+				),
+				RewardPayoutMode::Claim => {
+					<ClaimableRewards<T>>::mutate(&delegator, |claimable| {
+						*claimable = claimable.saturating_add(amt)
+					});
+					Self::deposit_event(Event::RewardAccrued {
+						delegator: delegator.clone(),
+						candidate: candidate.clone(),
+						amount: amt,
+					});
+					(amt, Zero::zero())
+				},
+			};
+			if paid.is_zero() && compounded.is_zero() {
+				return
+			}
+			let record = RewardRecord { round, collator: candidate, compounded, paid };
+			let slot = round % T::RewardLedgerRounds::get().max(1);
+			<RewardLedger<T>>::mutate(&delegator, slot, |records| {
+				// the slot still holds the rewards of an older round
+				if records.first().map_or(false, |r| r.round != round) {
+					records.clear();
+				}
+				// at most one record per delegation, can't exceed `MaxDelegationsPerDelegator`
+				let _ = records.try_push(record);
+			});
+		}
+
+		/// Weight of `pay_delegator_reward`, on top of `pay_one_collator_reward` which covers
+		/// minting and compounding
+		fn pay_delegator_reward_weight() -> Weight {
+			// RewardPayoutModes: Twox64Concat(8) + AccountId(32) + RewardPayoutMode(1)
+			// ClaimableRewards: Twox64Concat(8) + AccountId(32) + Balance(16)
+			// RewardLedger: Twox64Concat(8) + AccountId(32) + Twox64Concat(8) + u32(4)
+			// + BoundedVec(RewardRecord(4 + 32 + 16 + 16) * MaxDelegationsPerDelegator)
+			let proof_size = 41 + 56 + 52 + 68 * T::MaxDelegationsPerDelegator::get() as u64;
+			T::DbWeight::get()
+				.reads_writes(3, 2)
+				.saturating_add(Weight::from_parts(0, proof_size))
+		}
+
+		/// Rewards of the delegator in the last `RewardLedgerRounds` rounds, oldest first
+		pub fn reward_ledger(
+			delegator: &T::AccountId,
+		) -> Vec<RewardRecord<T::AccountId, BalanceOf<T>>> {
+			let rounds = T::RewardLedgerRounds::get();
+			let now = <Round<T>>::get().current;
+			let mut records: Vec<_> = <RewardLedger<T>>::iter_prefix_values(delegator)
+				.flatten()
+				.filter(|r| r.round.saturating_add(rounds) > now)
+				.collect();
+			records.sort_by_key(|r| r.round);
+			records
+		}
+
+		/// Remove the reward settings and history of a delegator which left, accrued rewards
+		/// stay claimable
+		pub(crate) fn clear_delegator_rewards(delegator: &T::AccountId) {
+			<RewardPayoutModes<T>>::remove(delegator);
+			let _ = <RewardLedger<T>>::clear_prefix(delegator, T::RewardLedgerRounds::get(), None);
+		}
+
 		/// Mint and compound delegation rewards. The function mints the amount towards the
 		/// delegator and tries to compound a specified percent of it back towards the delegation.
 		/// If a scheduled delegation revoke exists, then the amount is only minted, and nothing is
 		/// compounded. Emits the [Compounded] event.
+		/// Returns the paid out and the compounded amount.
 		fn mint_and_compound(
 			amt: BalanceOf<T>,
 			compound_percent: Percent,
 			candidate: T::AccountId,
 			delegator: T::AccountId,
-		) {
-			if let Ok(amount_transferred) = T::Currency::deposit_into_existing(&delegator, amt) {
-				Self::deposit_event(Event::Rewarded {
-					account: delegator.clone(),
-					rewards: amount_transferred.peek(),
-				});
-
-				let compound_amount = compound_percent.mul_ceil(amount_transferred.peek());
-				if compound_amount.is_zero() {
-					return
-				}
+		) -> (BalanceOf<T>, BalanceOf<T>) {
+			let Ok(amount_transferred) = T::Currency::deposit_into_existing(&delegator, amt) else {
+				return (Zero::zero(), Zero::zero())
+			};
+			let minted = amount_transferred.peek();
+			Self::deposit_event(Event::Rewarded { account: delegator.clone(), rewards: minted });
 
-				if let Err(err) = Self::delegation_bond_more_without_event(
-					delegator.clone(),
-					candidate.clone(),
-					compound_amount,
-				) {
-					log::error!(
-								"Error compounding staking reward towards candidate '{:?}' for delegator '{:?}': {:?}",
-								candidate,
-								delegator,
-								err
-							);
-					return
-				};
+			let compound_amount = compound_percent.mul_ceil(minted);
+			if compound_amount.is_zero() {
+				return (minted, Zero::zero())
+			}
 
-				Pallet::<T>::deposit_event(Event::Compounded {
-					delegator,
+			if let Err(err) = Self::delegation_bond_more_without_event(
+				delegator.clone(),
+				candidate.clone(),
+				compound_amount,
+			) {
+				log::error!(
+					"Error compounding staking reward towards candidate '{:?}' for delegator '{:?}': {:?}",
 					candidate,
-					amount: compound_amount,
-				});
+					delegator,
+					err
+				);
+				return (minted, Zero::zero())
 			};
+
+			Pallet::<T>::deposit_event(Event::Compounded {
+				delegator,
+				candidate,
+				amount: compound_amount,
+			});
+			(minted.saturating_sub(compound_amount), compound_amount)
 		}
 	}
 
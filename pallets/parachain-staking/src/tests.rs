@@ -34,11 +34,12 @@ use crate::{
 	auto_compound::{AutoCompoundConfig, AutoCompoundDelegations},
 	delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest},
 	mock::{
-		roll_one_block, roll_to, roll_to_round_begin, roll_to_round_end, set_author, Balances,
-		ExtBuilder, ParachainStaking, RuntimeEvent as MetaEvent, RuntimeOrigin, Test,
+		events, roll_one_block, roll_to, roll_to_round_begin, roll_to_round_end, set_author,
+		Balances, ExtBuilder, ParachainStaking, RuntimeEvent as MetaEvent, RuntimeOrigin, Test,
 	},
-	AtStake, Bond, CollatorStatus, DelegationScheduledRequests, DelegatorAdded, Error, Event,
-	Range,
+	AtStake, Bond, CollatorStatus, DelegationRequest, DelegationScheduledRequests,
+	DelegationStatus, DelegatorAdded, Error, Event, InflationInfo, LivenessConfig, PendingReward,
+	Range, RewardLedger, RewardPayoutMode, RewardRecord,
 };
 use frame_support::{
 	assert_noop, assert_ok,
@@ -566,6 +567,42 @@ fn cannot_set_same_parachain_bond_reserve_percent() {
 	});
 }
 
+#[test]
+fn set_liveness_config_event_emits_correctly() {
+	ExtBuilder::default().build().execute_with(|| {
+		let new = LivenessConfig { max_offline_rounds: 3, slash: Perbill::from_percent(1) };
+		assert_ok!(ParachainStaking::set_liveness_config(RuntimeOrigin::root(), new));
+		assert_last_event!(MetaEvent::ParachainStaking(Event::LivenessConfigSet {
+			old: LivenessConfig::default(),
+			new,
+		}));
+		assert_eq!(ParachainStaking::liveness_config(), new);
+	});
+}
+
+#[test]
+fn cannot_set_same_liveness_config() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_liveness_config(RuntimeOrigin::root(), LivenessConfig::default()),
+			Error::<Test>::NoWritingSameValue
+		);
+	});
+}
+
+#[test]
+fn invalid_origin_cannot_set_liveness_config() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ParachainStaking::set_liveness_config(
+				RuntimeOrigin::signed(45),
+				LivenessConfig { max_offline_rounds: 3, slash: Perbill::zero() }
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
 // ~~ PUBLIC ~~
 
 // JOIN CANDIDATES
@@ -6721,3 +6758,445 @@ fn test_delegate_skips_auto_compound_storage_but_emits_event_for_zero_auto_compo
 			}));
 		});
 }
+
+// ~~ RUNTIME API ~~
+
+#[test]
+fn pending_rewards_match_paid_rewards() {
+	ExtBuilder::default()
+		// the parachain bond account must exist to receive its reserve
+		.with_balances(vec![(0, 100), (1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10), (3, 1, 30)])
+		.build()
+		.execute_with(|| {
+			roll_to_round_begin(3);
+			set_author(3, 1, 100);
+			roll_to_round_begin(4);
+			let pending = ParachainStaking::pending_rewards(&2);
+			assert_eq!(pending.len(), 1);
+			assert_eq!((pending[0].round, pending[0].collator), (3, 1));
+			assert!(ParachainStaking::pending_rewards(&1).is_empty());
+
+			// round 3 is paid out in round 5
+			roll_to_round_begin(5);
+			roll_one_block();
+			let paid: Vec<_> = events()
+				.into_iter()
+				.filter_map(|e| match e {
+					Event::Rewarded { account: 2, rewards } => Some(rewards),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(pending, vec![PendingReward { round: 3, collator: 1, amount: paid[0] }]);
+			assert!(ParachainStaking::pending_rewards(&2).is_empty());
+		});
+}
+
+#[test]
+fn collator_apr_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1_000_000_000_000), (2, 1_000_000_000_000), (3, 1_000_000_000_000)])
+		.with_candidates(vec![(1, 500_000_000_000), (2, 500_000_000_000)])
+		.with_delegations(vec![(3, 1, 500_000_000_000)])
+		.with_inflation(InflationInfo {
+			expect: Range { min: 700, ideal: 700, max: 700 },
+			annual: Range {
+				min: Perbill::from_percent(50),
+				ideal: Perbill::from_percent(50),
+				max: Perbill::from_percent(50),
+			},
+			round: Range {
+				min: Perbill::from_parts(10),
+				ideal: Perbill::from_parts(10),
+				max: Perbill::from_parts(10),
+			},
+		})
+		.build()
+		.execute_with(|| {
+			roll_to_round_begin(2);
+			// round issuance of 30_000 less 30% parachain bond reserve, shared by 2 collators,
+			// less the 20% commission of the round issuance for each collator
+			let round_reward = 21_000 / 2 - 3_000;
+			// 525_960 rounds of 5 blocks per year
+			let annual_reward = round_reward * 525_960u128;
+			assert_eq!(
+				ParachainStaking::collator_apr(&1),
+				Some(Perbill::from_rational(annual_reward, 1_000_000_000_000))
+			);
+			assert_eq!(
+				ParachainStaking::collator_apr(&2),
+				Some(Perbill::from_rational(annual_reward, 500_000_000_000))
+			);
+			assert_eq!(ParachainStaking::collator_apr(&3), None);
+		});
+}
+
+#[test]
+fn delegation_requests_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.with_delegations(vec![(3, 1, 20), (3, 2, 20)])
+		.build()
+		.execute_with(|| {
+			assert!(ParachainStaking::delegation_requests(&3).is_empty());
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(3), 1));
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(3),
+				2,
+				5
+			));
+			assert_eq!(
+				ParachainStaking::delegation_requests(&3),
+				vec![
+					DelegationRequest {
+						collator: 1,
+						action: DelegationAction::Revoke(20),
+						when_executable: 3,
+					},
+					DelegationRequest {
+						collator: 2,
+						action: DelegationAction::Decrease(5),
+						when_executable: 3,
+					},
+				]
+			);
+			assert!(ParachainStaking::delegation_requests(&4).is_empty());
+		});
+}
+
+#[test]
+fn delegation_status_reports_bottom_delegations_as_uncounted() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100), (6, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10), (3, 1, 20), (4, 1, 20), (5, 1, 20), (6, 1, 20)])
+		.build()
+		.execute_with(|| {
+			// top delegations are limited to 4
+			assert_eq!(
+				ParachainStaking::delegation_status(&2),
+				vec![DelegationStatus { collator: 1, amount: 10, counted: false }]
+			);
+			assert_eq!(
+				ParachainStaking::delegation_status(&3),
+				vec![DelegationStatus { collator: 1, amount: 20, counted: true }]
+			);
+			assert!(ParachainStaking::delegation_status(&7).is_empty());
+		});
+}
+
+// ~~ LIVENESS ~~
+
+#[test]
+fn offline_collator_is_moved_to_idle_and_slashed() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.with_delegations(vec![(3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_liveness_config(
+				RuntimeOrigin::root(),
+				LivenessConfig { max_offline_rounds: 2, slash: Perbill::from_percent(10) }
+			));
+			roll_to_round_begin(2);
+			set_author(2, 2, 20);
+			roll_to_round_begin(3);
+			assert_eq!(ParachainStaking::offline_rounds(1), 1);
+			assert_eq!(ParachainStaking::offline_rounds(2), 0);
+			assert!(ParachainStaking::candidate_info(1).unwrap().is_active());
+
+			set_author(3, 2, 20);
+			roll_to_round_begin(4);
+			assert_event_emitted!(Event::CollatorMarkedOffline { candidate: 1, offline_rounds: 2 });
+			assert_event_emitted!(Event::CollatorSlashed { candidate: 1, amount: 2, new_bond: 18 });
+			let state = ParachainStaking::candidate_info(1).unwrap();
+			assert_eq!(state.status, CollatorStatus::Idle);
+			assert_eq!((state.bond, state.total_counted), (18, 28));
+			assert_eq!(ParachainStaking::offline_rounds(1), 0);
+			assert_eq!(ParachainStaking::total(), 48);
+			assert_eq!(Balances::reserved_balance(&1), 18);
+			assert_eq!(Balances::total_balance(&1), 98);
+			assert!(!ParachainStaking::candidate_pool().0.iter().any(|c| c.owner == 1));
+			assert_eq!(ParachainStaking::selected_candidates(), vec![2]);
+		});
+}
+
+#[test]
+fn collator_slashed_below_min_bond_is_scheduled_to_leave() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 10), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_liveness_config(
+				RuntimeOrigin::root(),
+				LivenessConfig { max_offline_rounds: 1, slash: Perbill::from_percent(10) }
+			));
+			roll_to_round_begin(2);
+			set_author(2, 2, 20);
+			roll_to_round_begin(3);
+			assert_event_emitted!(Event::CollatorSlashed { candidate: 1, amount: 1, new_bond: 9 });
+			assert_event_emitted!(Event::CandidateScheduledExit {
+				exit_allowed_round: 3,
+				candidate: 1,
+				scheduled_exit: 5,
+			});
+			assert!(ParachainStaking::candidate_info(1).unwrap().is_leaving());
+			assert_noop!(
+				ParachainStaking::go_online(RuntimeOrigin::signed(1)),
+				Error::<Test>::CannotGoOnlineIfLeaving
+			);
+			assert_noop!(
+				ParachainStaking::cancel_leave_candidates(RuntimeOrigin::signed(1)),
+				Error::<Test>::CandidateBondBelowMin
+			);
+			assert_ok!(ParachainStaking::candidate_bond_more(RuntimeOrigin::signed(1), 1));
+			assert_ok!(ParachainStaking::cancel_leave_candidates(RuntimeOrigin::signed(1)));
+			assert!(ParachainStaking::candidate_info(1).unwrap().is_active());
+		});
+}
+
+#[test]
+fn authoring_resets_offline_rounds() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_liveness_config(
+				RuntimeOrigin::root(),
+				LivenessConfig { max_offline_rounds: 2, slash: Perbill::from_percent(10) }
+			));
+			roll_to_round_begin(2);
+			set_author(2, 2, 20);
+			roll_to_round_begin(3);
+			assert_eq!(ParachainStaking::offline_rounds(1), 1);
+			set_author(3, 1, 20);
+			roll_to_round_begin(4);
+			assert_eq!(ParachainStaking::offline_rounds(1), 0);
+			set_author(4, 2, 20);
+			roll_to_round_begin(5);
+			assert_eq!(ParachainStaking::offline_rounds(1), 1);
+			assert!(ParachainStaking::candidate_info(1).unwrap().is_active());
+		});
+}
+
+#[test]
+fn offline_collator_is_not_slashed_without_slash_configured() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_liveness_config(
+				RuntimeOrigin::root(),
+				LivenessConfig { max_offline_rounds: 1, slash: Perbill::zero() }
+			));
+			roll_to_round_begin(2);
+			set_author(2, 2, 20);
+			roll_to_round_begin(3);
+			assert_event_emitted!(Event::CollatorMarkedOffline { candidate: 1, offline_rounds: 1 });
+			assert!(!events().iter().any(|e| matches!(e, Event::CollatorSlashed { .. })));
+			let state = ParachainStaking::candidate_info(1).unwrap();
+			assert_eq!((state.status, state.bond), (CollatorStatus::Idle, 20));
+			// the collator can rejoin the candidates
+			assert_ok!(ParachainStaking::go_online(RuntimeOrigin::signed(1)));
+		});
+}
+
+#[test]
+fn offline_detection_is_disabled_by_default() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			for round in 1..5 {
+				set_author(round, 2, 20);
+			}
+			roll_to_round_begin(5);
+			assert_eq!(ParachainStaking::offline_rounds(1), 0);
+			assert!(ParachainStaking::candidate_info(1).unwrap().is_active());
+		});
+}
+
+// ~~ REWARD LEDGER ~~
+
+#[test]
+fn reward_ledger_records_paid_and_compounded_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(0, 100), (1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.with_auto_compounding_delegations(vec![(3, 1, 10, Percent::from_percent(50))])
+		.build()
+		.execute_with(|| {
+			roll_to_round_begin(3);
+			set_author(3, 1, 100);
+			roll_to_round_begin(5);
+			roll_one_block();
+
+			let rewarded = |who| {
+				events()
+					.into_iter()
+					.find_map(|e| match e {
+						Event::Rewarded { account, rewards } if account == who => Some(rewards),
+						_ => None,
+					})
+					.unwrap()
+			};
+			let compounded = events()
+				.into_iter()
+				.find_map(|e| match e {
+					Event::Compounded { delegator: 3, amount, .. } => Some(amount),
+					_ => None,
+				})
+				.unwrap();
+			assert_eq!(
+				ParachainStaking::reward_ledger(2).into_inner(),
+				vec![RewardRecord { round: 3, collator: 1, compounded: 0, paid: rewarded(2) }]
+			);
+			assert_eq!(
+				ParachainStaking::reward_ledger(3).into_inner(),
+				vec![RewardRecord {
+					round: 3,
+					collator: 1,
+					compounded,
+					paid: rewarded(3) - compounded
+				}]
+			);
+			// collators are not delegators
+			assert!(ParachainStaking::reward_ledger(&1).is_empty());
+		});
+}
+
+#[test]
+fn reward_ledger_keeps_the_last_rounds() {
+	ExtBuilder::default()
+		.with_balances(vec![(0, 100), (1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			for round in 3..=8 {
+				roll_to_round_begin(round);
+				set_author(round as u32, 1, 100);
+			}
+			roll_to_round_begin(10);
+			roll_one_block();
+			let rounds: Vec<_> =
+				ParachainStaking::reward_ledger(&2).into_iter().map(|r| r.round).collect();
+			// RewardLedgerRounds is 4
+			assert_eq!(rounds, vec![7, 8]);
+			// the slots of the rounds 3 and 4 were reused by the rounds 7 and 8
+			assert_eq!(RewardLedger::<Test>::iter_prefix(2).count(), 4);
+		});
+}
+
+#[test]
+fn leaving_delegator_reward_state_is_cleared() {
+	ExtBuilder::default()
+		.with_balances(vec![(0, 100), (1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_payout_mode(
+				RuntimeOrigin::signed(2),
+				RewardPayoutMode::Claim
+			));
+			roll_to_round_begin(3);
+			set_author(3, 1, 100);
+			roll_to_round_begin(5);
+			roll_one_block();
+			let claimable = ParachainStaking::claimable_rewards(2);
+			assert!(!ParachainStaking::reward_ledger(&2).is_empty());
+
+			assert_ok!(ParachainStaking::schedule_leave_delegators(RuntimeOrigin::signed(2)));
+			roll_to_round_begin(7);
+			assert_ok!(ParachainStaking::execute_leave_delegators(RuntimeOrigin::signed(2), 2));
+			assert_eq!(ParachainStaking::reward_payout_mode(2), RewardPayoutMode::Eager);
+			assert_eq!(RewardLedger::<Test>::iter_prefix(2).count(), 0);
+			// accrued rewards can still be claimed
+			assert_eq!(ParachainStaking::claimable_rewards(2), claimable);
+			assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+		});
+}
+
+#[test]
+fn claim_mode_accrues_rewards_until_claimed() {
+	ExtBuilder::default()
+		.with_balances(vec![(0, 100), (1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_auto_compounding_delegations(vec![(2, 1, 10, Percent::from_percent(50))])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_reward_payout_mode(
+				RuntimeOrigin::signed(2),
+				RewardPayoutMode::Claim
+			));
+			assert_last_event!(MetaEvent::ParachainStaking(Event::RewardPayoutModeSet {
+				delegator: 2,
+				mode: RewardPayoutMode::Claim,
+			}));
+			roll_to_round_begin(3);
+			set_author(3, 1, 100);
+			roll_to_round_begin(5);
+			roll_one_block();
+
+			let claimable = ParachainStaking::claimable_rewards(2);
+			assert!(!claimable.is_zero());
+			assert_event_emitted!(Event::RewardAccrued {
+				delegator: 2,
+				candidate: 1,
+				amount: claimable
+			});
+			// nothing is minted nor compounded before the claim
+			assert_eq!(Balances::free_balance(&2), 90);
+			assert_eq!(ParachainStaking::delegator_state(2).unwrap().total, 10);
+			assert_eq!(
+				ParachainStaking::reward_ledger(2).into_inner(),
+				vec![RewardRecord { round: 3, collator: 1, compounded: 0, paid: claimable }]
+			);
+
+			assert_ok!(ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)));
+			assert_last_event!(MetaEvent::ParachainStaking(Event::RewardsClaimed {
+				delegator: 2,
+				amount: claimable,
+			}));
+			assert_eq!(Balances::free_balance(&2), 90 + claimable);
+			assert!(ParachainStaking::claimable_rewards(2).is_zero());
+			assert_noop!(
+				ParachainStaking::claim_rewards(RuntimeOrigin::signed(2)),
+				Error::<Test>::NoRewardsToClaim
+			);
+		});
+}
+
+#[test]
+fn set_reward_payout_mode_fails_for_non_delegator_or_same_mode() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::set_reward_payout_mode(
+					RuntimeOrigin::signed(1),
+					RewardPayoutMode::Claim
+				),
+				Error::<Test>::DelegatorDNE
+			);
+			assert_noop!(
+				ParachainStaking::set_reward_payout_mode(
+					RuntimeOrigin::signed(2),
+					RewardPayoutMode::Eager
+				),
+				Error::<Test>::NoWritingSameValue
+			);
+		});
+}
@@ -25,7 +25,10 @@ use crate::{
 	CandidateInfo, Config, DelegatorState, Error, Event, Pallet, Round, RoundIndex, TopDelegations,
 	Total,
 };
-use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Imbalance, ReservableCurrency},
+};
 use parity_scale_codec::{Decode, Encode};
 use sp_runtime::{
 	traits::{Saturating, Zero},
@@ -362,6 +365,27 @@ impl<
 		});
 		Ok(())
 	}
+	/// Slash the collator candidate self bond, the slashed amount is burned
+	pub fn slash_bond<T: Config>(&mut self, who: T::AccountId, amount: Balance)
+	where
+		BalanceOf<T>: From<Balance> + Into<Balance>,
+	{
+		let (imbalance, _) = T::Currency::slash_reserved(&who, amount.into());
+		let slashed: Balance = imbalance.peek().into();
+		let new_total = <Total<T>>::get().saturating_sub(slashed.into());
+		<Total<T>>::put(new_total);
+		self.bond = self.bond.saturating_sub(slashed);
+		self.total_counted = self.total_counted.saturating_sub(slashed);
+		// the pending decrease can't be executed with the remaining bond anymore
+		if matches!(self.request, Some(request) if request.amount >= self.bond) {
+			self.request = None;
+		}
+		<Pallet<T>>::deposit_event(Event::CollatorSlashed {
+			candidate: who,
+			amount: slashed.into(),
+			new_bond: self.bond.into(),
+		});
+	}
 	/// Schedule executable decrease of collator candidate self bond
 	/// Returns the round at which the collator can execute the pending request
 	pub fn schedule_bond_less<T: Config>(
@@ -598,6 +622,7 @@ impl<
 			});
 			if leaving {
 				<DelegatorState<T>>::remove(&lowest_bottom_to_be_kicked.owner);
+				Pallet::<T>::clear_delegator_rewards(&lowest_bottom_to_be_kicked.owner);
 				Pallet::<T>::deposit_event(Event::DelegatorLeft {
 					delegator: lowest_bottom_to_be_kicked.owner,
 					unstaked_amount: lowest_bottom_to_be_kicked.amount,
@@ -1241,3 +1266,40 @@ impl<A: Decode> Default for ParachainBondConfig<A> {
 		}
 	}
 }
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Liveness requirements of the selected collators
+pub struct LivenessConfig {
+	/// Number of consecutive rounds a selected collator can go without authoring a block
+	/// before it's moved to idle, 0 disables the offline detection
+	pub max_offline_rounds: u32,
+	/// Portion of the self bond slashed when the collator is moved to idle
+	pub slash: Perbill,
+}
+impl LivenessConfig {
+	pub fn is_enabled(&self) -> bool {
+		self.max_offline_rounds > 0
+	}
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// How the rewards of a delegator are paid out
+pub enum RewardPayoutMode {
+	/// Rewards are paid out, and compounded if configured, as soon as they are due
+	#[default]
+	Eager,
+	/// Rewards accrue and are withdrawn with `claim_rewards`, nothing is compounded
+	Claim,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Reward earned by a delegator through a collator in a round
+pub struct RewardRecord<AccountId, Balance> {
+	pub round: RoundIndex,
+	pub collator: AccountId,
+	/// Portion of the reward compounded towards the delegation
+	pub compounded: Balance,
+	/// Portion of the reward paid out to the delegator, or accrued to be claimed in the
+	/// `Claim` payout mode
+	pub paid: Balance,
+}
@@ -79,8 +79,12 @@ pub trait WeightInfo {
 	fn round_transition_on_initialize(x: u32, y: u32, ) -> Weight;
 	fn pay_one_collator_reward(y: u32, ) -> Weight;
 	fn base_on_initialize() -> Weight;
+	fn offline_collators_on_initialize(s: u32, ) -> Weight;
 	fn set_auto_compound(x: u32, y: u32, ) -> Weight;
 	fn delegate_with_auto_compound(x: u32, y: u32, z: u32, ) -> Weight;
+	fn set_liveness_config() -> Weight;
+	fn set_reward_payout_mode() -> Weight;
+	fn claim_rewards() -> Weight;
 }
 
 /// Weights for pallet_parachain_staking using the Litentry node and recommended hardware.
@@ -396,6 +400,23 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 		Weight::from_parts(7_173_000 as u64, 0)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 	}
+	// Storage: ParachainStaking Liveness (r:1 w:0)
+	// Storage: ParachainStaking OfflineRounds (r:100 w:100)
+	// Storage: ParachainStaking Points (r:1 w:0)
+	// Storage: ParachainStaking SelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking CandidateInfo (r:100 w:100)
+	// Storage: ParachainStaking AwardedPts (r:100 w:0)
+	// Storage: ParachainStaking CandidatePool (r:100 w:100)
+	// Storage: System Account (r:100 w:100)
+	// Storage: ParachainStaking Total (r:100 w:100)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn offline_collators_on_initialize(s: u32, ) -> Weight {
+		Weight::from_parts(12_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(45_000_000 as u64, 0).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((6 as u64).saturating_mul(s as u64)))
+			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(s as u64)))
+	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:0)
 	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
 	fn set_auto_compound(x: u32, y: u32, ) -> Weight {
@@ -426,6 +447,29 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
+	// Storage: ParachainStaking Liveness (r:1 w:1)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn set_liveness_config() -> Weight {
+		Weight::from_parts(19_827_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking RewardPayoutModes (r:1 w:1)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn set_reward_payout_mode() -> Weight {
+		Weight::from_parts(21_341_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking ClaimableRewards (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn claim_rewards() -> Weight {
+		Weight::from_parts(32_509_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -740,6 +784,23 @@ impl WeightInfo for () {
 		Weight::from_parts(7_173_000 as u64, 0)
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 	}
+	// Storage: ParachainStaking Liveness (r:1 w:0)
+	// Storage: ParachainStaking OfflineRounds (r:100 w:100)
+	// Storage: ParachainStaking Points (r:1 w:0)
+	// Storage: ParachainStaking SelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking CandidateInfo (r:100 w:100)
+	// Storage: ParachainStaking AwardedPts (r:100 w:0)
+	// Storage: ParachainStaking CandidatePool (r:100 w:100)
+	// Storage: System Account (r:100 w:100)
+	// Storage: ParachainStaking Total (r:100 w:100)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn offline_collators_on_initialize(s: u32, ) -> Weight {
+		Weight::from_parts(12_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(45_000_000 as u64, 0).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((6 as u64).saturating_mul(s as u64)))
+			.saturating_add(RocksDbWeight::get().writes((5 as u64).saturating_mul(s as u64)))
+	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:0)
 	// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
 	fn set_auto_compound(x: u32, y: u32, ) -> Weight {
@@ -770,5 +831,28 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
+	// Storage: ParachainStaking Liveness (r:1 w:1)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn set_liveness_config() -> Weight {
+		Weight::from_parts(19_827_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking RewardPayoutModes (r:1 w:1)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn set_reward_payout_mode() -> Weight {
+		Weight::from_parts(21_341_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking ClaimableRewards (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// PLACEHOLDER: hand-written estimate, not benchmarked yet
+	fn claim_rewards() -> Weight {
+		Weight::from_parts(32_509_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
 }
 
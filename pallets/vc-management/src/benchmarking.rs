@@ -69,11 +69,12 @@ benchmarks! {
 		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_A", 0u32, USER_SEED).into();
 		let assertion = Assertion::A1;
+		let index = H256::default();
 		let id_graph_hash = H256::default();
 		let req_ext_hash = H256::default();
-	}: _<T::RuntimeOrigin>(call_origin, identity.clone(), assertion.clone(), id_graph_hash, req_ext_hash)
+	}: _<T::RuntimeOrigin>(call_origin, identity.clone(), assertion.clone(), index, id_graph_hash, req_ext_hash)
 	verify{
-		assert_last_event::<T>(Event::VCIssued{ identity, assertion, id_graph_hash, req_ext_hash }.into());
+		assert_last_event::<T>(Event::VCIssued{ identity, assertion, index, id_graph_hash, req_ext_hash }.into());
 	}
 
 	// Benchmark `some_error`. There are no worst conditions. The benchmark showed that
@@ -157,6 +158,20 @@ benchmarks! {
 		assert_last_event::<T>(Event::SchemaRevoked { account, shard, index: 0 }.into())
 	}
 
+	// Benchmark `revoke_vc`. There are no worst conditions. The benchmark showed that
+	// execution time is constant irrespective of encrypted_data size.
+	revoke_vc {
+		let account: T::AccountId =  frame_benchmarking::account("TEST_A", 0u32, USER_SEED);
+		VCManagement::<T>::set_admin(RawOrigin::Root.into(), account.clone())?;
+		let call_origin = T::TEECallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let identity: Identity =  frame_benchmarking::account::<AccountId>("TEST_B", 0u32, USER_SEED).into();
+		let index = H256::default();
+		VCManagement::<T>::vc_issued(call_origin, identity, Assertion::A1, index, H256::default(), H256::default())?;
+	}: _(RawOrigin::Signed(account.clone()), index)
+	verify {
+		assert_last_event::<T>(Event::VCRevoked { account, index }.into())
+	}
+
 }
 
 #[cfg(test)]
@@ -43,6 +43,9 @@ use sp_std::vec::Vec;
 mod schema;
 pub use schema::*;
 
+mod vc_context;
+pub use vc_context::*;
+
 pub type VCIndex = H256;
 
 #[frame_support::pallet]
@@ -94,6 +97,11 @@ pub mod pallet {
 	#[pallet::getter(fn schema_registry)]
 	pub type SchemaRegistry<T: Config> = StorageMap<_, Blake2_128Concat, SchemaIndex, VCSchema<T>>;
 
+	// the issued VCs, keyed by the VC id
+	#[pallet::storage]
+	#[pallet::getter(fn vc_registry)]
+	pub type VCRegistry<T: Config> = StorageMap<_, Blake2_128Concat, VCIndex, VCContext<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -115,9 +123,15 @@ pub mod pallet {
 		VCIssued {
 			identity: Identity,
 			assertion: Assertion,
+			index: VCIndex,
 			id_graph_hash: H256,
 			req_ext_hash: H256,
 		},
+		// a VC is revoked by its subject or the admin
+		VCRevoked {
+			account: T::AccountId,
+			index: VCIndex,
+		},
 		// Admin account was changed
 		AdminChanged {
 			old_admin: Option<T::AccountId>,
@@ -187,6 +201,8 @@ pub mod pallet {
 		SchemaAlreadyActivated,
 		SchemaIndexOverFlow,
 		LengthMismatch,
+		/// Error when the caller account is neither the subject of the VC nor the admin
+		RequireSubjectOrAdmin,
 	}
 
 	#[pallet::genesis_config]
@@ -341,6 +357,24 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// revoke an issued VC, only the subject of the VC or the admin can revoke it
+		///
+		/// the VC is removed from the registry, so a VC not found in it is to be seen as revoked
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_vc())]
+		pub fn revoke_vc(origin: OriginFor<T>, index: VCIndex) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let context = VCRegistry::<T>::get(index).ok_or(Error::<T>::VCNotExist)?;
+			ensure!(
+				Some(sender.clone()) == Self::admin() ||
+					Self::is_subject_account(&context.subject, &sender),
+				Error::<T>::RequireSubjectOrAdmin
+			);
+			VCRegistry::<T>::remove(index);
+			Self::deposit_event(Event::VCRevoked { account: sender, index });
+			Ok(().into())
+		}
+
 		/// ---------------------------------------------------
 		/// The following extrinsics are supposed to be called by TEE only
 		/// ---------------------------------------------------
@@ -350,13 +384,24 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			identity: Identity,
 			assertion: Assertion,
+			index: VCIndex,
 			id_graph_hash: H256,
 			req_ext_hash: H256,
 		) -> DispatchResultWithPostInfo {
 			let _ = T::TEECallOrigin::ensure_origin(origin)?;
+			ensure!(!VCRegistry::<T>::contains_key(index), Error::<T>::VCAlreadyExists);
+			VCRegistry::<T>::insert(
+				index,
+				VCContext::<T>::new(
+					identity.clone(),
+					assertion.clone(),
+					<frame_system::Pallet<T>>::block_number(),
+				),
+			);
 			Self::deposit_event(Event::VCIssued {
 				identity,
 				assertion,
+				index,
 				id_graph_hash,
 				req_ext_hash,
 			});
@@ -386,4 +431,14 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 	}
+
+	impl<T: Config> Pallet<T> {
+		// whether `account` is the substrate account of the VC subject
+		fn is_subject_account(subject: &Identity, account: &T::AccountId) -> bool {
+			subject
+				.to_account_id()
+				.and_then(|a| T::AccountId::decode(&mut &a.encode()[..]).ok())
+				.map_or(false, |a| &a == account)
+		}
+	}
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{mock::*, Error, ShardIdentifier, Status};
+use crate::{mock::*, Error, ShardIdentifier, Status, VCIndex};
 use core_primitives::{Assertion, Identity};
 use frame_support::{assert_noop, assert_ok};
 use sp_core::H256;
@@ -26,6 +26,19 @@ type SystemAccountId = <Test as frame_system::Config>::AccountId;
 const ALICE_PUBKEY: &[u8; 32] = &[1u8; 32];
 const BOB_PUBKEY: &[u8; 32] = &[2u8; 32];
 const EDDIE_PUBKEY: &[u8; 32] = &[5u8; 32];
+const VC_INDEX: VCIndex = H256::repeat_byte(1);
+
+fn issue_vc(subject: &[u8; 32]) {
+	let signer: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+	assert_ok!(VCManagement::vc_issued(
+		RuntimeOrigin::signed(signer),
+		get_signer(subject),
+		Assertion::A1,
+		VC_INDEX,
+		H256::default(),
+		H256::default(),
+	));
+}
 
 #[test]
 fn request_vc_without_delegatee_works() {
@@ -88,11 +101,43 @@ fn vc_issued_works() {
 		let alice: Identity = get_signer(ALICE_PUBKEY);
 		assert_ok!(VCManagement::vc_issued(
 			RuntimeOrigin::signed(signer),
-			alice,
+			alice.clone(),
 			Assertion::A1,
+			VC_INDEX,
 			H256::default(),
 			H256::default(),
 		));
+		let context = VCManagement::vc_registry(VC_INDEX).unwrap();
+		assert_eq!(context.subject, alice);
+		assert_eq!(context.assertion, Assertion::A1);
+		assert_eq!(context.issued_at, 1);
+	});
+}
+
+#[test]
+fn vc_issued_with_duplicated_index_fails() {
+	new_test_ext().execute_with(|| {
+		let signer: SystemAccountId = get_signer(TEST8_SIGNER_PUB);
+		let alice: Identity = get_signer(ALICE_PUBKEY);
+		assert_ok!(VCManagement::vc_issued(
+			RuntimeOrigin::signed(signer.clone()),
+			alice.clone(),
+			Assertion::A1,
+			VC_INDEX,
+			H256::default(),
+			H256::default(),
+		));
+		assert_noop!(
+			VCManagement::vc_issued(
+				RuntimeOrigin::signed(signer),
+				alice,
+				Assertion::A1,
+				VC_INDEX,
+				H256::default(),
+				H256::default(),
+			),
+			Error::<Test>::VCAlreadyExists
+		);
 	});
 }
 
@@ -106,6 +151,7 @@ fn vc_issued_with_unpriviledged_origin_fails() {
 				RuntimeOrigin::signed(bob),
 				alice.into(),
 				Assertion::A1,
+				VC_INDEX,
 				H256::default(),
 				H256::default(),
 			),
@@ -349,3 +395,63 @@ fn revoke_schema_with_unprivileged_origin_fails() {
 		);
 	});
 }
+
+#[test]
+fn revoke_vc_by_subject_works() {
+	new_test_ext().execute_with(|| {
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		issue_vc(BOB_PUBKEY);
+		assert_ok!(VCManagement::revoke_vc(RuntimeOrigin::signed(bob.clone()), VC_INDEX));
+		assert!(VCManagement::vc_registry(VC_INDEX).is_none());
+		System::assert_last_event(RuntimeEvent::VCManagement(crate::Event::VCRevoked {
+			account: bob,
+			index: VC_INDEX,
+		}));
+	});
+}
+
+#[test]
+fn revoke_vc_by_admin_works() {
+	new_test_ext().execute_with(|| {
+		let alice: SystemAccountId = get_signer(ALICE_PUBKEY);
+		issue_vc(BOB_PUBKEY);
+		assert_ok!(VCManagement::revoke_vc(RuntimeOrigin::signed(alice), VC_INDEX));
+		assert!(VCManagement::vc_registry(VC_INDEX).is_none());
+	});
+}
+
+#[test]
+fn revoke_vc_with_unpriviledged_origin_fails() {
+	new_test_ext().execute_with(|| {
+		let eddie: SystemAccountId = get_signer(EDDIE_PUBKEY);
+		issue_vc(BOB_PUBKEY);
+		assert_noop!(
+			VCManagement::revoke_vc(RuntimeOrigin::signed(eddie), VC_INDEX),
+			Error::<Test>::RequireSubjectOrAdmin
+		);
+	});
+}
+
+#[test]
+fn revoke_vc_with_non_existent_fails() {
+	new_test_ext().execute_with(|| {
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		assert_noop!(
+			VCManagement::revoke_vc(RuntimeOrigin::signed(bob), VC_INDEX),
+			Error::<Test>::VCNotExist
+		);
+	});
+}
+
+#[test]
+fn revoke_already_revoked_vc_fails() {
+	new_test_ext().execute_with(|| {
+		let bob: SystemAccountId = get_signer(BOB_PUBKEY);
+		issue_vc(BOB_PUBKEY);
+		assert_ok!(VCManagement::revoke_vc(RuntimeOrigin::signed(bob.clone()), VC_INDEX));
+		assert_noop!(
+			VCManagement::revoke_vc(RuntimeOrigin::signed(bob), VC_INDEX),
+			Error::<Test>::VCNotExist
+		);
+	});
+}
@@ -0,0 +1,44 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+// VC Registry entry
+// Every VC issued by the TEE is registered on chain under its id, which is embedded in the
+// `credentialStatus` of the VC, so that verifiers can check whether it has been revoked.
+// Revoked VCs are removed from the registry.
+
+use crate::Config;
+use core_primitives::{Assertion, Identity};
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct VCContext<T: Config> {
+	// the subject of the VC
+	pub subject: Identity,
+	// the assertion of the VC
+	pub assertion: Assertion,
+	// the block number at which the VC was issued
+	pub issued_at: BlockNumberFor<T>,
+}
+
+impl<T: Config> VCContext<T> {
+	pub fn new(subject: Identity, assertion: Assertion, issued_at: BlockNumberFor<T>) -> Self {
+		Self { subject, assertion, issued_at }
+	}
+}
@@ -57,6 +57,7 @@ pub trait WeightInfo {
 	fn disable_schema() -> Weight;
 	fn activate_schema() -> Weight;
 	fn revoke_schema() -> Weight;
+	fn revoke_vc() -> Weight;
 }
 
 /// Weights for pallet_vc_management using the Litentry node and recommended hardware.
@@ -141,6 +142,15 @@ impl<T: frame_system::Config> WeightInfo for LitentryWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: VCManagement VCRegistry (r:1 w:1)
+	// Proof: VCManagement VCRegistry (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	// Storage: VCManagement Admin (r:1 w:0)
+	// Proof: VCManagement Admin (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn revoke_vc() -> Weight {
+		Weight::from_parts(23_754_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -224,5 +234,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: VCManagement VCRegistry (r:1 w:1)
+	// Proof: VCManagement VCRegistry (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	// Storage: VCManagement Admin (r:1 w:0)
+	// Proof: VCManagement Admin (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn revoke_vc() -> Weight {
+		Weight::from_parts(23_754_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
 
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @author The Litentry Team
+/// @title ERC20 interface of the foreign assets
+/// @dev Every foreign asset registered in pallet-asset-manager, whose balances are held in
+/// orml-tokens, is exposed at the address 0xFFFFFFFF followed by the 16 bytes big-endian
+/// encoded asset id, e.g. asset 1 lives at 0xFFFFFFFF00000000000000000000000000000001.
+/// See https://github.com/ethereum/EIPs/blob/master/EIPS/eip-20.md
+interface IERC20 {
+    /// @dev Returns the name of the token.
+    /// @custom:selector 06fdde03
+    function name() external view returns (string memory);
+
+    /// @dev Returns the symbol of the token.
+    /// @custom:selector 95d89b41
+    function symbol() external view returns (string memory);
+
+    /// @dev Returns the decimals places of the token.
+    /// @custom:selector 313ce567
+    function decimals() external view returns (uint8);
+
+    /// @dev Total number of tokens in existence
+    /// @custom:selector 18160ddd
+    function totalSupply() external view returns (uint256);
+
+    /// @dev Gets the balance of the specified address.
+    /// @custom:selector 70a08231
+    /// @param owner The address to query the balance of.
+    /// @return An uint256 representing the amount owned by the passed address.
+    function balanceOf(address owner) external view returns (uint256);
+
+    /// @dev Function to check the amount of tokens that an owner allowed to a spender.
+    /// @custom:selector dd62ed3e
+    /// @param owner address The address which owns the funds.
+    /// @param spender address The address which will spend the funds.
+    /// @return A uint256 specifying the amount of tokens still available for the spender.
+    function allowance(address owner, address spender)
+        external
+        view
+        returns (uint256);
+
+    /// @dev Transfer token for a specified address
+    /// @custom:selector a9059cbb
+    /// @param to The address to transfer to.
+    /// @param value The amount to be transferred.
+    /// @return true if the transfer was succesful, revert otherwise.
+    function transfer(address to, uint256 value) external returns (bool);
+
+    /// @dev Approve the passed address to spend the specified amount of tokens on behalf
+    /// of msg.sender.
+    /// @custom:selector 095ea7b3
+    /// @param spender The address which will spend the funds.
+    /// @param value The amount of tokens to be spent.
+    /// @return true, this cannot fail
+    function approve(address spender, uint256 value) external returns (bool);
+
+    /// @dev Transfer tokens from one address to another
+    /// @custom:selector 23b872dd
+    /// @param from address The address which you want to send tokens from
+    /// @param to address The address which you want to transfer to
+    /// @param value uint256 the amount of tokens to be transferred
+    /// @return true if the transfer was succesful, revert otherwise.
+    function transferFrom(
+        address from,
+        address to,
+        uint256 value
+    ) external returns (bool);
+
+    /// @dev Event emited when a transfer has been performed.
+    /// @custom:selector ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef
+    /// @param from address The address sending the tokens
+    /// @param to address The address receiving the tokens.
+    /// @param value uint256 The amount of tokens transfered.
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    /// @dev Event emited when an approval has been registered.
+    /// @custom:selector 8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925
+    /// @param owner address Owner of the tokens.
+    /// @param spender address Allowed spender.
+    /// @param value uint256 Amount of tokens approved.
+    event Approval(
+        address indexed owner,
+        address indexed spender,
+        uint256 value
+    );
+}
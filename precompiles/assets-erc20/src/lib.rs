@@ -0,0 +1,399 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use fp_evm::{IsPrecompileResult, PrecompileHandle, PrecompileOutput};
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	storage::types::{Key as NMapKey, StorageNMap, ValueQuery},
+	traits::StorageInstance,
+	Blake2_128Concat,
+};
+use pallet_evm::{AddressMapping, PrecompileResult, PrecompileSet};
+use precompile_utils::{
+	keccak256, revert, succeed, Address, Bytes, EvmData, EvmDataWriter, EvmResult,
+	FunctionModifier, LogExt, LogsBuilder, PrecompileHandleExt, RuntimeHelper,
+};
+use sp_core::H160;
+use sp_runtime::traits::{Dispatchable, StaticLookup, Zero};
+use sp_std::marker::PhantomData;
+
+/// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the Approval log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+/// The first 4 bytes of the address of every foreign asset precompile, the remaining 16 bytes
+/// are the big-endian encoded `AssetId`.
+pub const FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX: [u8; 4] = [255u8; 4];
+
+/// Proof size of an `AssetIdMetadata` read: Twox64Concat(8) + AssetId(16)
+/// + AssetMetadata(name(1 + 32) + symbol(1 + 32) + decimals(1) + minimal_balance(16) +
+/// is_frozen(1)), assuming names and symbols of at most 32 bytes.
+const ASSET_METADATA_SIZE: usize = 108;
+
+type AssetIdOf<Runtime> = <Runtime as orml_tokens::Config>::CurrencyId;
+type BalanceOf<Runtime> = <Runtime as orml_tokens::Config>::Balance;
+
+/// Storage prefix of the ERC-20 allowances, orml-tokens has no notion of approvals.
+pub struct ApprovesPrefix;
+
+impl StorageInstance for ApprovesPrefix {
+	const STORAGE_PREFIX: &'static str = "Approves";
+
+	fn pallet_prefix() -> &'static str {
+		"Erc20Assets"
+	}
+}
+
+/// Allowances: (asset_id, owner, spender) => amount
+pub type ApprovesStorage<Runtime> = StorageNMap<
+	ApprovesPrefix,
+	(
+		NMapKey<Blake2_128Concat, AssetIdOf<Runtime>>,
+		NMapKey<Blake2_128Concat, <Runtime as frame_system::Config>::AccountId>,
+		NMapKey<Blake2_128Concat, <Runtime as frame_system::Config>::AccountId>,
+	),
+	BalanceOf<Runtime>,
+	ValueQuery,
+>;
+
+/// Derive the precompile address of a foreign asset.
+pub fn asset_id_to_address(asset_id: u128) -> H160 {
+	let mut data = [0u8; 20];
+	data[0..4].copy_from_slice(&FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX);
+	data[4..20].copy_from_slice(&asset_id.to_be_bytes());
+	H160::from(data)
+}
+
+/// Extract the foreign asset id from a precompile address, if the address has the right prefix.
+pub fn address_to_asset_id(address: H160) -> Option<u128> {
+	if address[0..4] != FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX {
+		return None
+	}
+	let mut data = [0u8; 16];
+	data.copy_from_slice(&address[4..20]);
+	Some(u128::from_be_bytes(data))
+}
+
+/// A set of ERC-20 compatible precompiles, one per foreign asset registered in
+/// pallet_asset_manager, whose balances are held in orml_tokens.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Erc20AssetsPrecompileSet<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Erc20AssetsPrecompileSet<Runtime> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<Runtime> Erc20AssetsPrecompileSet<Runtime>
+where
+	Runtime: orml_tokens::Config
+		+ pallet_asset_manager::Config<AssetId = AssetIdOf<Runtime>>
+		+ pallet_evm::Config,
+	AssetIdOf<Runtime>: From<u128>,
+{
+	/// The asset id behind `address`, if it belongs to a registered foreign asset.
+	fn registered_asset_id(address: H160) -> Option<AssetIdOf<Runtime>> {
+		let asset_id: AssetIdOf<Runtime> = address_to_asset_id(address)?.into();
+		pallet_asset_manager::AssetIdType::<Runtime>::contains_key(asset_id).then_some(asset_id)
+	}
+}
+
+impl<Runtime> Erc20AssetsPrecompileSet<Runtime>
+where
+	Runtime: orml_tokens::Config
+		+ pallet_asset_manager::Config<AssetId = AssetIdOf<Runtime>>
+		+ pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<orml_tokens::Call<Runtime>>,
+	BalanceOf<Runtime>: EvmData,
+{
+	fn name(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		handle.record_db_read::<Runtime>(ASSET_METADATA_SIZE)?;
+		let metadata =
+			pallet_asset_manager::Pallet::<Runtime>::asset_metadatas(asset_id).unwrap_or_default();
+
+		Ok(succeed(EvmDataWriter::new().write(Bytes(metadata.name)).build()))
+	}
+
+	fn symbol(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		handle.record_db_read::<Runtime>(ASSET_METADATA_SIZE)?;
+		let metadata =
+			pallet_asset_manager::Pallet::<Runtime>::asset_metadatas(asset_id).unwrap_or_default();
+
+		Ok(succeed(EvmDataWriter::new().write(Bytes(metadata.symbol)).build()))
+	}
+
+	fn decimals(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		handle.record_db_read::<Runtime>(ASSET_METADATA_SIZE)?;
+		let metadata =
+			pallet_asset_manager::Pallet::<Runtime>::asset_metadatas(asset_id).unwrap_or_default();
+
+		Ok(succeed(EvmDataWriter::new().write(metadata.decimals).build()))
+	}
+
+	fn total_supply(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		// TotalIssuance: Twox64Concat(8) + CurrencyId(16) + Balance(16)
+		handle.record_db_read::<Runtime>(40)?;
+		let total_issuance = orml_tokens::TotalIssuance::<Runtime>::get(asset_id);
+
+		Ok(succeed(EvmDataWriter::new().write(total_issuance).build()))
+	}
+
+	fn balance_of(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let owner: H160 = input.read::<Address>()?.into();
+		let owner = Runtime::AddressMapping::into_account_id(owner);
+
+		// Accounts: Blake2_128Concat(16) + AccountId(32) + Twox64Concat(8) + CurrencyId(16)
+		// + AccountData(16 * 3)
+		handle.record_db_read::<Runtime>(120)?;
+		let balance = orml_tokens::Accounts::<Runtime>::get(owner, asset_id).free;
+
+		Ok(succeed(EvmDataWriter::new().write(balance).build()))
+	}
+
+	fn allowance(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let owner: H160 = input.read::<Address>()?.into();
+		let spender: H160 = input.read::<Address>()?.into();
+		let owner = Runtime::AddressMapping::into_account_id(owner);
+		let spender = Runtime::AddressMapping::into_account_id(spender);
+
+		// Approves: Blake2_128Concat(16) + AssetId(16) + 2 * (Blake2_128Concat(16) + AccountId(32))
+		// + Balance(16)
+		handle.record_db_read::<Runtime>(144)?;
+		let amount = ApprovesStorage::<Runtime>::get((asset_id, owner, spender));
+
+		Ok(succeed(EvmDataWriter::new().write(amount).build()))
+	}
+
+	fn approve(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(3, 32)?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let spender: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<BalanceOf<Runtime>>()?;
+
+		let caller = handle.context().caller;
+		let owner = Runtime::AddressMapping::into_account_id(caller);
+		let spender_account = Runtime::AddressMapping::into_account_id(spender);
+
+		// Like most ERC-20 implementations the allowance is overwritten, not increased.
+		ApprovesStorage::<Runtime>::insert((asset_id, owner, spender_account), amount);
+
+		LogsBuilder::new(handle.context().address)
+			.log3(
+				SELECTOR_LOG_APPROVAL,
+				caller,
+				spender,
+				EvmDataWriter::new().write(amount).build(),
+			)
+			.record(handle)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn transfer(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let to: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<BalanceOf<Runtime>>()?;
+
+		let caller = handle.context().caller;
+		let origin = Runtime::AddressMapping::into_account_id(caller);
+		let dest = Runtime::AddressMapping::into_account_id(to);
+
+		// Dispatch call (if enough gas).
+		let call = orml_tokens::Call::<Runtime>::transfer {
+			dest: Runtime::Lookup::unlookup(dest),
+			currency_id: asset_id,
+			amount,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		LogsBuilder::new(handle.context().address)
+			.log3(SELECTOR_LOG_TRANSFER, caller, to, EvmDataWriter::new().write(amount).build())
+			.record(handle)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn transfer_from(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+		let from: H160 = input.read::<Address>()?.into();
+		let to: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<BalanceOf<Runtime>>()?;
+
+		let caller = handle.context().caller;
+		let owner = Runtime::AddressMapping::into_account_id(from);
+		let dest = Runtime::AddressMapping::into_account_id(to);
+
+		// The owner doesn't need an allowance to move its own funds.
+		if caller != from {
+			handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+			let spender = Runtime::AddressMapping::into_account_id(caller);
+			let key = (asset_id, owner.clone(), spender);
+			// Approves: Blake2_128Concat(16) + AssetId(16) + 2 * (Blake2_128Concat(16) +
+			// AccountId(32)) + Balance(16)
+			handle.record_db_read::<Runtime>(144)?;
+			let allowed = ApprovesStorage::<Runtime>::get(key.clone());
+			if allowed < amount {
+				return Err(revert("Trying to transfer more than allowed"))
+			}
+
+			let remaining = allowed - amount;
+			if remaining.is_zero() {
+				ApprovesStorage::<Runtime>::remove(key);
+			} else {
+				ApprovesStorage::<Runtime>::insert(key, remaining);
+			}
+		}
+
+		// Dispatch call (if enough gas).
+		let call = orml_tokens::Call::<Runtime>::transfer {
+			dest: Runtime::Lookup::unlookup(dest),
+			currency_id: asset_id,
+			amount,
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(owner).into(), call)?;
+
+		LogsBuilder::new(handle.context().address)
+			.log3(SELECTOR_LOG_TRANSFER, from, to, EvmDataWriter::new().write(amount).build())
+			.record(handle)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn execute_selector(
+		asset_id: AssetIdOf<Runtime>,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Action::Transfer | Action::Approve | Action::TransferFrom =>
+				FunctionModifier::NonPayable,
+			_ => FunctionModifier::View,
+		})?;
+
+		match selector {
+			// read storage
+			Action::Name => Self::name(asset_id, handle),
+			Action::Symbol => Self::symbol(asset_id, handle),
+			Action::Decimals => Self::decimals(asset_id, handle),
+			Action::TotalSupply => Self::total_supply(asset_id, handle),
+			Action::BalanceOf => Self::balance_of(asset_id, handle),
+			Action::Allowance => Self::allowance(asset_id, handle),
+			// Dispatchables
+			Action::Transfer => Self::transfer(asset_id, handle),
+			Action::Approve => Self::approve(asset_id, handle),
+			Action::TransferFrom => Self::transfer_from(asset_id, handle),
+		}
+	}
+}
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+	Name = "name()",
+	Symbol = "symbol()",
+	Decimals = "decimals()",
+	TotalSupply = "totalSupply()",
+	BalanceOf = "balanceOf(address)",
+	Allowance = "allowance(address,address)",
+	Transfer = "transfer(address,uint256)",
+	Approve = "approve(address,uint256)",
+	TransferFrom = "transferFrom(address,address,uint256)",
+}
+
+impl<R> PrecompileSet for Erc20AssetsPrecompileSet<R>
+where
+	R: orml_tokens::Config
+		+ pallet_asset_manager::Config<AssetId = AssetIdOf<R>>
+		+ pallet_evm::Config,
+	R::RuntimeCall:
+		From<orml_tokens::Call<R>> + Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
+	AssetIdOf<R>: From<u128>,
+	BalanceOf<R>: EvmData,
+{
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		let asset_id = Self::registered_asset_id(handle.code_address())?;
+
+		log::trace!(
+			target: "assets-erc20-precompile",
+			"Execute input = {:?}",
+			handle.input()
+		);
+
+		Some(Self::execute_selector(asset_id, handle))
+	}
+
+	fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+		// Checking the registration of the asset costs a storage read.
+		IsPrecompileResult::Answer {
+			is_precompile: Self::registered_asset_id(address).is_some(),
+			extra_cost: RuntimeHelper::<R>::db_read_gas_cost(),
+		}
+	}
+}
@@ -0,0 +1,240 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64, Nothing},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, RuntimeDebug,
+};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+pub type AssetId = u128;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Evm: pallet_evm::{Pallet, Config, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
+		AssetManager: pallet_asset_manager::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type PalletInfo = PalletInfo;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<100>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type ExistentialDeposits = AssetManager;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type DustRemovalWhitelist = Nothing;
+	type WeightInfo = ();
+	type CurrencyHooks = ();
+}
+
+#[derive(
+	Clone,
+	Default,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub struct MockAssetType(pub u32);
+
+impl pallet_asset_manager::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = AssetId;
+	type ForeignAssetType = MockAssetType;
+	type ForeignAssetModifierOrigin = EnsureRoot<AccountId>;
+	type Currency = Balances;
+	type WeightInfo = ();
+}
+
+pub struct TruncatedAddressMapping;
+impl AddressMapping<AccountId> for TruncatedAddressMapping {
+	fn into_account_id(address: H160) -> AccountId {
+		let mut data = [0u8; 32];
+		data[0..20].copy_from_slice(&address[..]);
+		AccountId::from(Into::<[u8; 32]>::into(data))
+	}
+}
+
+// silly for test purpose only
+pub struct U8Wrapper(pub u8);
+impl From<U8Wrapper> for H160 {
+	fn from(x: U8Wrapper) -> H160 {
+		H160::repeat_byte(x.0)
+	}
+}
+impl From<U8Wrapper> for H256 {
+	fn from(x: U8Wrapper) -> H256 {
+		let h160 = H160::repeat_byte(x.0);
+		let mut data = [0u8; 32];
+		data[0..20].copy_from_slice(&h160[..]);
+		data.into()
+	}
+}
+impl From<U8Wrapper> for AccountId {
+	fn from(x: U8Wrapper) -> AccountId {
+		TruncatedAddressMapping::into_account_id(x.into())
+	}
+}
+
+parameter_types! {
+	pub PrecompilesValue: Erc20AssetsPrecompileSet<Test> = Erc20AssetsPrecompileSet::new();
+	pub WeightPerGas: Weight = Weight::from_parts(1, 0);
+}
+
+impl pallet_evm::Config for Test {
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type CallOrigin = EnsureAddressRoot<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type AddressMapping = TruncatedAddressMapping;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type PrecompilesType = Erc20AssetsPrecompileSet<Test>;
+	type PrecompilesValue = PrecompilesValue;
+	type Timestamp = Timestamp;
+	type ChainId = ();
+	type OnChargeTransaction = ();
+	type BlockGasLimit = ();
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type FindAuthor = ();
+	type OnCreate = ();
+	type WeightInfo = ();
+	type GasLimitPovSizeRatio = ConstU64<4>;
+}
+
+/// Build the externalities with the given balances of the foreign asset `0`, which gets
+/// registered in the asset manager.
+pub fn new_test_ext(balances: Vec<(AccountId, Balance)>) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	orml_tokens::GenesisConfig::<Test> {
+		balances: balances.into_iter().map(|(who, amount)| (who, 0, amount)).collect(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		assert!(AssetManager::register_foreign_asset_type(
+			RuntimeOrigin::root(),
+			MockAssetType(1),
+			pallet_asset_manager::AssetMetadata {
+				name: b"Tether USD".to_vec(),
+				symbol: b"USDT".to_vec(),
+				decimals: 6,
+				minimal_balance: 1,
+				is_frozen: false,
+			},
+		)
+		.is_ok());
+	});
+	ext
+}
+
+/// The precompile address of the registered foreign asset `0`.
+pub fn precompile_address() -> H160 {
+	asset_id_to_address(0)
+}
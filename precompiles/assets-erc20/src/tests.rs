@@ -0,0 +1,250 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, *};
+use precompile_utils::testing::*;
+use sp_core::U256;
+
+fn precompiles() -> Erc20AssetsPrecompileSet<Test> {
+	PrecompilesValue::get()
+}
+
+fn free_balance(who: u8) -> Balance {
+	orml_tokens::Accounts::<Test>::get(AccountId::from(U8Wrapper(who)), 0).free
+}
+
+#[test]
+fn address_asset_id_conversion_works() {
+	let address = asset_id_to_address(0x0102);
+	assert_eq!(
+		address,
+		H160::from_slice(&[255u8, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2])
+	);
+	assert_eq!(address_to_asset_id(address), Some(0x0102));
+	assert_eq!(address_to_asset_id(H160::from_low_u64_be(20480 + 45)), None);
+}
+
+#[test]
+fn unregistered_asset_is_not_precompile() {
+	new_test_ext(vec![]).execute_with(|| {
+		assert!(matches!(
+			precompiles().is_precompile(precompile_address(), 0),
+			IsPrecompileResult::Answer { is_precompile: true, .. }
+		));
+		assert!(matches!(
+			precompiles().is_precompile(asset_id_to_address(1), 0),
+			IsPrecompileResult::Answer { is_precompile: false, .. }
+		));
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				asset_id_to_address(1),
+				EvmDataWriter::new_with_selector(Action::TotalSupply).build(),
+			)
+			.execute_none();
+	})
+}
+
+#[test]
+fn metadata_works() {
+	new_test_ext(vec![(U8Wrapper(1u8).into(), 1000), (U8Wrapper(2u8).into(), 500)]).execute_with(
+		|| {
+			precompiles()
+				.prepare_test(
+					U8Wrapper(1u8),
+					precompile_address(),
+					EvmDataWriter::new_with_selector(Action::Name).build(),
+				)
+				.expect_no_logs()
+				.execute_returns(EvmDataWriter::new().write(Bytes(b"Tether USD".to_vec())).build());
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(1u8),
+					precompile_address(),
+					EvmDataWriter::new_with_selector(Action::Symbol).build(),
+				)
+				.expect_no_logs()
+				.execute_returns(EvmDataWriter::new().write(Bytes(b"USDT".to_vec())).build());
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(1u8),
+					precompile_address(),
+					EvmDataWriter::new_with_selector(Action::Decimals).build(),
+				)
+				.expect_no_logs()
+				.execute_returns(EvmDataWriter::new().write(6u8).build());
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(1u8),
+					precompile_address(),
+					EvmDataWriter::new_with_selector(Action::TotalSupply).build(),
+				)
+				.expect_no_logs()
+				.execute_returns(EvmDataWriter::new().write(U256::from(1500)).build());
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(1u8),
+					precompile_address(),
+					EvmDataWriter::new_with_selector(Action::BalanceOf)
+						.write(Address(U8Wrapper(2u8).into()))
+						.build(),
+				)
+				.expect_no_logs()
+				.execute_returns(EvmDataWriter::new().write(U256::from(500)).build());
+		},
+	)
+}
+
+#[test]
+fn transfer_works() {
+	new_test_ext(vec![(U8Wrapper(1u8).into(), 1000)]).execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::Transfer)
+					.write(Address(U8Wrapper(2u8).into()))
+					.write(U256::from(400))
+					.build(),
+			)
+			.expect_log(LogsBuilder::new(precompile_address()).log3(
+				SELECTOR_LOG_TRANSFER,
+				H160::from(U8Wrapper(1u8)),
+				H160::from(U8Wrapper(2u8)),
+				EvmDataWriter::new().write(U256::from(400)).build(),
+			))
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		assert_eq!(free_balance(1), 600);
+		assert_eq!(free_balance(2), 400);
+	})
+}
+
+#[test]
+fn transfer_more_than_balance_fails() {
+	new_test_ext(vec![(U8Wrapper(1u8).into(), 1000)]).execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::Transfer)
+					.write(Address(U8Wrapper(2u8).into()))
+					.write(U256::from(1001))
+					.build(),
+			)
+			.execute_reverts(|output| output.starts_with(b"Dispatched call failed with error:"));
+
+		assert_eq!(free_balance(1), 1000);
+	})
+}
+
+#[test]
+fn approve_and_transfer_from_works() {
+	new_test_ext(vec![(U8Wrapper(1u8).into(), 1000)]).execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::Approve)
+					.write(Address(U8Wrapper(2u8).into()))
+					.write(U256::from(500))
+					.build(),
+			)
+			.expect_log(LogsBuilder::new(precompile_address()).log3(
+				SELECTOR_LOG_APPROVAL,
+				H160::from(U8Wrapper(1u8)),
+				H160::from(U8Wrapper(2u8)),
+				EvmDataWriter::new().write(U256::from(500)).build(),
+			))
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(2u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::TransferFrom)
+					.write(Address(U8Wrapper(1u8).into()))
+					.write(Address(U8Wrapper(3u8).into()))
+					.write(U256::from(300))
+					.build(),
+			)
+			.expect_log(LogsBuilder::new(precompile_address()).log3(
+				SELECTOR_LOG_TRANSFER,
+				H160::from(U8Wrapper(1u8)),
+				H160::from(U8Wrapper(3u8)),
+				EvmDataWriter::new().write(U256::from(300)).build(),
+			))
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		assert_eq!(free_balance(1), 700);
+		assert_eq!(free_balance(3), 300);
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(2u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::Allowance)
+					.write(Address(U8Wrapper(1u8).into()))
+					.write(Address(U8Wrapper(2u8).into()))
+					.build(),
+			)
+			.expect_no_logs()
+			.execute_returns(EvmDataWriter::new().write(U256::from(200)).build());
+	})
+}
+
+#[test]
+fn transfer_from_more_than_allowed_fails() {
+	new_test_ext(vec![(U8Wrapper(1u8).into(), 1000)]).execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::Approve)
+					.write(Address(U8Wrapper(2u8).into()))
+					.write(U256::from(100))
+					.build(),
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(2u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::TransferFrom)
+					.write(Address(U8Wrapper(1u8).into()))
+					.write(Address(U8Wrapper(2u8).into()))
+					.write(U256::from(101))
+					.build(),
+			)
+			.execute_reverts(|output| output == b"Trying to transfer more than allowed");
+
+		assert_eq!(free_balance(1), 1000);
+		assert_eq!(
+			ApprovesStorage::<Test>::get((
+				0,
+				AccountId::from(U8Wrapper(1u8)),
+				AccountId::from(U8Wrapper(2u8))
+			)),
+			100
+		);
+	})
+}
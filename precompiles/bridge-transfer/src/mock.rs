@@ -29,7 +29,7 @@ use sp_core::{H160, H256};
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
-	AccountId32,
+	AccountId32, MultiSignature, MultiSigner,
 };
 
 pub type AccountId = AccountId32;
@@ -119,7 +119,12 @@ impl pallet_bridge::Config for Test {
 	type BridgeChainId = TestChainId;
 	type Currency = Balances;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxPrunedProposals = ConstU32<100>;
+	type MaxRelayerSignatures = ConstU32<16>;
 	type TreasuryAccount = TreasuryAccount;
+	type ProposalAmount = pallet_bridge_transfer::BridgeTransferProposalAmount<Test>;
+	type RelayerSignature = MultiSignature;
+	type RelayerPublic = MultiSigner;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @author The Litentry Team
+/// @title Pallet Identity Management and VC Management Interface
+/// @dev The interface through which solidity contracts will interact with Identity Management
+/// and VC Management. The encrypted payloads are the SCALE encoded `AesOutput` of the payload
+/// encrypted with the user shielding key, exactly like they are passed to the extrinsics.
+/// We follow this same interface including four-byte function selectors, in the precompile that
+/// wraps the pallets
+interface IdentityManagement {
+    /// @dev Request to link an identity to the IDGraph of the user
+    /// @custom:selector b492fa85
+    /// @param shard The shard of the enclave handling the request
+    /// @param user The account owning the IDGraph, must be the caller unless the caller is a delegatee
+    /// @param encryptedIdentity The encrypted identity to link
+    /// @param encryptedValidationData The encrypted validation data proving the ownership
+    /// @param encryptedWeb3networks The encrypted web3 networks of the identity
+    function linkIdentity(
+        bytes32 shard,
+        bytes32 user,
+        bytes calldata encryptedIdentity,
+        bytes calldata encryptedValidationData,
+        bytes calldata encryptedWeb3networks
+    ) external;
+
+    /// @dev Request to deactivate an identity in the IDGraph of the caller
+    /// @custom:selector 705d7fc2
+    /// @param shard The shard of the enclave handling the request
+    /// @param encryptedIdentity The encrypted identity to deactivate
+    function deactivateIdentity(
+        bytes32 shard,
+        bytes calldata encryptedIdentity
+    ) external;
+
+    /// @dev Request to activate an identity in the IDGraph of the caller
+    /// @custom:selector 121e97c8
+    /// @param shard The shard of the enclave handling the request
+    /// @param encryptedIdentity The encrypted identity to activate
+    function activateIdentity(
+        bytes32 shard,
+        bytes calldata encryptedIdentity
+    ) external;
+
+    /// @dev Request a VC for the caller
+    /// @custom:selector 1777a244
+    /// @param shard The shard of the enclave handling the request
+    /// @param assertion The SCALE encoded assertion to request the VC for
+    function requestVc(bytes32 shard, bytes calldata assertion) external;
+}
@@ -0,0 +1,179 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+use core_primitives::Assertion;
+use fp_evm::{PrecompileHandle, PrecompileOutput};
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use pallet_evm::{AddressMapping, Precompile};
+use parity_scale_codec::DecodeAll;
+use precompile_utils::{
+	error, succeed, Bytes, EvmDataWriter, EvmResult, FunctionModifier, PrecompileHandleExt,
+	RuntimeHelper,
+};
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// A precompile to wrap the requests of identity_management and vc_management.
+///
+/// The requests are only forwarded to the enclave, the payloads are encrypted with the user
+/// shielding key and are therefore passed as they are.
+pub struct IdentityManagementPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> IdentityManagementPrecompile<Runtime>
+where
+	Runtime: pallet_identity_management::Config + pallet_vc_management::Config + pallet_evm::Config,
+	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall:
+		From<pallet_identity_management::Call<Runtime>> + From<pallet_vc_management::Call<Runtime>>,
+{
+	fn link_identity(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(5)?;
+		let shard = input.read::<H256>()?;
+		let user: [u8; 32] = input.read::<H256>()?.into();
+		let user = Runtime::AccountId::from(user);
+		let encrypted_identity: Vec<u8> = input.read::<Bytes>()?.into();
+		let encrypted_validation_data: Vec<u8> = input.read::<Bytes>()?.into();
+		let encrypted_web3networks: Vec<u8> = input.read::<Bytes>()?.into();
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_identity_management::Call::<Runtime>::link_identity {
+			shard,
+			user,
+			encrypted_identity,
+			encrypted_validation_data,
+			encrypted_web3networks,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn deactivate_identity(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let shard = input.read::<H256>()?;
+		let encrypted_identity: Vec<u8> = input.read::<Bytes>()?.into();
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_identity_management::Call::<Runtime>::deactivate_identity {
+			shard,
+			encrypted_identity,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn activate_identity(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let shard = input.read::<H256>()?;
+		let encrypted_identity: Vec<u8> = input.read::<Bytes>()?.into();
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_identity_management::Call::<Runtime>::activate_identity {
+			shard,
+			encrypted_identity,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn request_vc(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let shard = input.read::<H256>()?;
+		let assertion: Vec<u8> = input.read::<Bytes>()?.into();
+		let assertion = Assertion::decode_all(&mut assertion.as_slice())
+			.map_err(|_| error("Invalid SCALE encoded assertion"))?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_vc_management::Call::<Runtime>::request_vc { shard, assertion };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+}
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+	LinkIdentity = "linkIdentity(bytes32,bytes32,bytes,bytes,bytes)",
+	DeactivateIdentity = "deactivateIdentity(bytes32,bytes)",
+	ActivateIdentity = "activateIdentity(bytes32,bytes)",
+	RequestVc = "requestVc(bytes32,bytes)",
+}
+
+impl<R> Precompile for IdentityManagementPrecompile<R>
+where
+	R: pallet_identity_management::Config + pallet_vc_management::Config + pallet_evm::Config,
+	R::RuntimeCall: From<pallet_identity_management::Call<R>>
+		+ From<pallet_vc_management::Call<R>>
+		+ Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo,
+	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
+	R::AccountId: From<[u8; 32]>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		log::trace!(
+			target: "identity-management-precompile",
+			"Execute input = {:?}",
+			handle.input()
+		);
+
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Action::LinkIdentity |
+			Action::DeactivateIdentity |
+			Action::ActivateIdentity |
+			Action::RequestVc => FunctionModifier::NonPayable,
+		})?;
+
+		match selector {
+			// read storage
+			// None
+			// Dispatchables
+			Action::LinkIdentity => Self::link_identity(handle),
+			Action::DeactivateIdentity => Self::deactivate_identity(handle),
+			Action::ActivateIdentity => Self::activate_identity(handle),
+			Action::RequestVc => Self::request_vc(handle),
+		}
+	}
+}
@@ -0,0 +1,196 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+extern crate alloc;
+use crate::{mock::*, *};
+use core_primitives::Assertion;
+use fp_evm::ExitError;
+use parity_scale_codec::Encode;
+use precompile_utils::testing::*;
+
+fn precompiles() -> IdentityManagementMockPrecompile<Test> {
+	PrecompilesValue::get()
+}
+
+fn last_event() -> RuntimeEvent {
+	frame_system::Pallet::<Test>::events().pop().expect("event expected").event
+}
+
+#[test]
+fn link_identity_is_ok() {
+	new_test_ext().execute_with(|| {
+		let shard = H256::repeat_byte(7);
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::LinkIdentity)
+					.write(shard)
+					.write(H256::from(U8Wrapper(1u8)))
+					.write(Bytes(vec![1u8, 2]))
+					.write(Bytes(vec![3u8]))
+					.write(Bytes(vec![4u8]))
+					.build(),
+			)
+			.expect_no_logs()
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::IdentityManagement(
+				pallet_identity_management::Event::LinkIdentityRequested {
+					shard,
+					account: U8Wrapper(1u8).into(),
+					encrypted_identity: vec![1u8, 2],
+					encrypted_validation_data: vec![3u8],
+					encrypted_web3networks: vec![4u8],
+				}
+			)
+		);
+	})
+}
+
+#[test]
+fn link_identity_for_other_user_fails() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::LinkIdentity)
+					.write(H256::repeat_byte(7))
+					.write(H256::from(U8Wrapper(2u8)))
+					.write(Bytes(vec![1u8, 2]))
+					.write(Bytes(vec![3u8]))
+					.write(Bytes(vec![4u8]))
+					.build(),
+			)
+			.execute_reverts(|output| {
+				alloc::str::from_utf8(output).unwrap().contains("UnauthorizedUser")
+			});
+	})
+}
+
+#[test]
+fn deactivate_and_activate_identity_are_ok() {
+	new_test_ext().execute_with(|| {
+		let shard = H256::repeat_byte(7);
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::DeactivateIdentity)
+					.write(shard)
+					.write(Bytes(vec![1u8, 2]))
+					.build(),
+			)
+			.expect_no_logs()
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::IdentityManagement(
+				pallet_identity_management::Event::DeactivateIdentityRequested {
+					shard,
+					account: U8Wrapper(1u8).into(),
+					encrypted_identity: vec![1u8, 2],
+				}
+			)
+		);
+
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::ActivateIdentity)
+					.write(shard)
+					.write(Bytes(vec![1u8, 2]))
+					.build(),
+			)
+			.expect_no_logs()
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::IdentityManagement(
+				pallet_identity_management::Event::ActivateIdentityRequested {
+					shard,
+					account: U8Wrapper(1u8).into(),
+					encrypted_identity: vec![1u8, 2],
+				}
+			)
+		);
+	})
+}
+
+#[test]
+fn request_vc_is_ok() {
+	new_test_ext().execute_with(|| {
+		let shard = H256::repeat_byte(7);
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::RequestVc)
+					.write(shard)
+					.write(Bytes(Assertion::A1.encode()))
+					.build(),
+			)
+			.expect_no_logs()
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::VCManagement(pallet_vc_management::Event::VCRequested {
+				account: U8Wrapper(1u8).into(),
+				shard,
+				assertion: Assertion::A1,
+			})
+		);
+	})
+}
+
+#[test]
+fn request_vc_with_invalid_assertion_fails() {
+	new_test_ext().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::RequestVc)
+					.write(H256::repeat_byte(7))
+					.write(Bytes(vec![u8::MAX]))
+					.build(),
+			)
+			.execute_error(ExitError::Other("Invalid SCALE encoded assertion".into()));
+	})
+}
+
+#[test]
+fn request_vc_with_trailing_bytes_fails() {
+	new_test_ext().execute_with(|| {
+		let mut assertion = Assertion::A1.encode();
+		assertion.push(0);
+		precompiles()
+			.prepare_test(
+				U8Wrapper(1u8),
+				precompile_address(),
+				EvmDataWriter::new_with_selector(Action::RequestVc)
+					.write(H256::repeat_byte(7))
+					.write(Bytes(assertion))
+					.build(),
+			)
+			.execute_error(ExitError::Other("Invalid SCALE encoded assertion".into()));
+	})
+}
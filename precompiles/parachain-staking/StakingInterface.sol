@@ -259,6 +259,41 @@ TODO: Only part for delagator is implemented for minimal task purpose */
         uint8 value
     ) external;
 
+    /// @dev Reward earned by a delegator through a collator in a round
+    struct RewardRecord {
+        uint32 round;
+        bytes32 collator;
+        /// The portion compounded towards the delegation
+        uint256 compounded;
+        /// The portion paid out, or accrued to be claimed in the claim payout mode
+        uint256 paid;
+    }
+
+    /// @dev Sets how the rewards of the caller are paid out
+    /// @custom:selector 9b7f6123
+    /// @param mode 0 to pay out (and compound) rewards when due, 1 to accrue them until claimed
+    function setRewardPayoutMode(uint8 mode) external;
+
+    /// @dev Withdraw all rewards accrued in the claim payout mode
+    /// @custom:selector 372500ab
+    function claimRewards() external;
+
+    /// @dev Rewards accrued by the delegator in the claim payout mode
+    /// @custom:selector 05927006
+    /// @param delegator The address of the delegator
+    /// @return The amount that can be claimed
+    function claimableRewards(
+        bytes32 delegator
+    ) external view returns (uint256);
+
+    /// @dev Rewards of the delegator in the rounds kept by the reward ledger, oldest first
+    /// @custom:selector 3450d2ef
+    /// @param delegator The address of the delegator
+    /// @return The reward records of the delegator
+    function rewardHistory(
+        bytes32 delegator
+    ) external view returns (RewardRecord[] memory);
+
 /* TODO: Only part for delagator is implemented for minimal task purpose 
     /// @dev Fetch the total staked amount of a delegator, regardless of the
     /// candidate.
@@ -27,13 +27,14 @@ use frame_support::{
 	traits::{Currency, Get},
 };
 use pallet_evm::{AddressMapping, Precompile};
+use pallet_parachain_staking::RewardPayoutMode;
 use precompile_utils::{
 	error, succeed, EvmData, EvmDataWriter, EvmResult, FunctionModifier, PrecompileHandleExt,
 	RuntimeHelper,
 };
 use sp_core::H256;
 use sp_runtime::traits::Dispatchable;
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 type BalanceOf<Runtime> = <<Runtime as pallet_parachain_staking::Config>::Currency as Currency<
 	<Runtime as frame_system::Config>::AccountId,
@@ -51,7 +52,7 @@ pub struct ParachainStakingPrecompile<Runtime>(PhantomData<Runtime>);
 impl<Runtime> ParachainStakingPrecompile<Runtime>
 where
 	Runtime: pallet_parachain_staking::Config + pallet_evm::Config,
-	Runtime::AccountId: From<[u8; 32]>,
+	Runtime::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
 	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
 	Runtime::RuntimeCall: From<pallet_parachain_staking::Call<Runtime>>,
@@ -380,6 +381,46 @@ where
 
 		Ok(succeed(EvmDataWriter::new().write(pending).build()))
 	}
+
+	fn claimable_rewards(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let delegator: [u8; 32] = input.read::<H256>()?.into();
+		let delegator = Runtime::AccountId::from(delegator);
+
+		// ClaimableRewards: Twox64Concat(8) + AccountId(32) + Balance(16)
+		handle.record_db_read::<Runtime>(56)?;
+		let amount = <pallet_parachain_staking::Pallet<Runtime>>::claimable_rewards(&delegator);
+
+		Ok(succeed(EvmDataWriter::new().write(amount).build()))
+	}
+
+	fn reward_history(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let delegator: [u8; 32] = input.read::<H256>()?.into();
+		let delegator = Runtime::AccountId::from(delegator);
+
+		let slots = <Runtime as pallet_parachain_staking::Config>::RewardLedgerRounds::get();
+		let max_records =
+			<Runtime as pallet_parachain_staking::Config>::MaxDelegationsPerDelegator::get();
+		// Round: RoundInfo(RoundIndex(4) + BlockNumber(4) + 4)
+		// RewardLedger, per slot:
+		// Twox64Concat(8) + AccountId(32) + Twox64Concat(8) + u32(4)
+		// + BoundedVec(RewardRecord(4 + 32 + 16 + 16) * MaxDelegationsPerDelegator)
+		handle.record_db_read::<Runtime>(12 + slots as usize * (52 + 68 * max_records as usize))?;
+
+		let records: Vec<(u32, H256, BalanceOf<Runtime>, BalanceOf<Runtime>)> =
+			<pallet_parachain_staking::Pallet<Runtime>>::reward_ledger(&delegator)
+				.into_iter()
+				.map(|record| {
+					let collator: [u8; 32] = record.collator.into();
+					(record.round, collator.into(), record.compounded, record.paid)
+				})
+				.collect();
+
+		Ok(succeed(EvmDataWriter::new().write(records).build()))
+	}
 	/*TODO: Only part for delagator is implemented for minimal task purpose
 
 	fn candidate_exit_is_pending(
@@ -779,6 +820,36 @@ where
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
+	fn set_reward_payout_mode(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let mode = match input.read::<u8>()? {
+			0 => RewardPayoutMode::Eager,
+			1 => RewardPayoutMode::Claim,
+			_ => return Err(error("Must be 0 (eager) or 1 (claim)")),
+		};
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::set_reward_payout_mode { mode };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn claim_rewards(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::claim_rewards {};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
 	/* TODO: Only part for delagator is implemented for minimal task purpose
 	fn get_delegator_total_staked(
 		handle: &mut impl PrecompileHandle
@@ -834,6 +905,10 @@ pub enum Action {
 	ExecuteDelegationRequest = "executeDelegationRequest(bytes32,bytes32)",
 	CancelDelegationRequest = "cancelDelegationRequest(bytes32)",
 	SetAutoCompound = "setAutoCompound(bytes32,uint8)",
+	SetRewardPayoutMode = "setRewardPayoutMode(uint8)",
+	ClaimRewards = "claimRewards()",
+	ClaimableRewards = "claimableRewards(bytes32)",
+	RewardHistory = "rewardHistory(bytes32)",
 }
 
 impl<R> Precompile for ParachainStakingPrecompile<R>
@@ -844,7 +919,7 @@ where
 		+ GetDispatchInfo,
 	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
 	BalanceOf<R>: EvmData,
-	R::AccountId: From<[u8; 32]>,
+	R::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
 {
 	fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
 		log::trace!(target: "parachain-staking-precompile", "Execute input = {:?}", handle.input());
@@ -859,13 +934,17 @@ where
 			Action::ScheduleDelegatorBondLess |
 			Action::ExecuteDelegationRequest |
 			Action::CancelDelegationRequest |
-			Action::SetAutoCompound => FunctionModifier::NonPayable,
+			Action::SetAutoCompound |
+			Action::SetRewardPayoutMode |
+			Action::ClaimRewards => FunctionModifier::NonPayable,
 			_ => FunctionModifier::View,
 		})?;
 
 		match selector {
 			// read storage
 			Action::DelegationRequestIsPending => Self::delegation_request_is_pending(handle),
+			Action::ClaimableRewards => Self::claimable_rewards(handle),
+			Action::RewardHistory => Self::reward_history(handle),
 			// Dispatchables
 			Action::Delegate => Self::delegate(handle),
 			Action::DelegateWithAutoCompound => Self::delegate_with_auto_compound(handle),
@@ -875,6 +954,8 @@ where
 			Action::ExecuteDelegationRequest => Self::execute_delegation_request(handle),
 			Action::CancelDelegationRequest => Self::cancel_delegation_request(handle),
 			Action::SetAutoCompound => Self::set_auto_compound(handle),
+			Action::SetRewardPayoutMode => Self::set_reward_payout_mode(handle),
+			Action::ClaimRewards => Self::claim_rewards(handle),
 		}
 	}
 }
@@ -119,3 +119,90 @@ fn delegation_request_is_pending_works() {
 				.execute_returns(EvmDataWriter::new().write(true).build());
 		})
 }
+
+#[test]
+fn claim_rewards_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(U8Wrapper(1).into(), 10_000), (U8Wrapper(2).into(), 500)])
+		.with_candidates(vec![(U8Wrapper(1).into(), 1_000)])
+		.with_delegations(vec![(U8Wrapper(2).into(), U8Wrapper(1).into(), 50)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					U8Wrapper(2u8),
+					precompile_address(),
+					EvmDataWriter::new_with_selector(Action::SetRewardPayoutMode)
+						.write(1u8)
+						.build(),
+				)
+				.expect_no_logs()
+				.execute_returns(EvmDataWriter::new().write(true).build());
+			assert_eq!(
+				pallet_parachain_staking::Pallet::<Test>::reward_payout_mode(AccountId::from(
+					U8Wrapper(2u8)
+				)),
+				pallet_parachain_staking::RewardPayoutMode::Claim
+			);
+
+			// accrue a reward
+			pallet_parachain_staking::ClaimableRewards::<Test>::insert(
+				AccountId::from(U8Wrapper(2u8)),
+				20,
+			);
+			pallet_parachain_staking::RewardLedger::<Test>::mutate(
+				AccountId::from(U8Wrapper(2u8)),
+				3,
+				|ledger| {
+					ledger
+						.try_push(pallet_parachain_staking::RewardRecord {
+							round: 3,
+							collator: U8Wrapper(1u8).into(),
+							compounded: 0,
+							paid: 20,
+						})
+						.unwrap()
+				},
+			);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(1u8),
+					precompile_address(),
+					EvmDataWriter::new_with_selector(Action::ClaimableRewards)
+						.write(H256::from(U8Wrapper(2u8)))
+						.build(),
+				)
+				.expect_no_logs()
+				.execute_returns(EvmDataWriter::new().write(20u128).build());
+			precompiles()
+				.prepare_test(
+					U8Wrapper(1u8),
+					precompile_address(),
+					EvmDataWriter::new_with_selector(Action::RewardHistory)
+						.write(H256::from(U8Wrapper(2u8)))
+						.build(),
+				)
+				.expect_no_logs()
+				.execute_returns(
+					EvmDataWriter::new()
+						.write(vec![(3u32, H256::from(U8Wrapper(1u8)), 0u128, 20u128)])
+						.build(),
+				);
+
+			precompiles()
+				.prepare_test(
+					U8Wrapper(2u8),
+					precompile_address(),
+					EvmDataWriter::new_with_selector(Action::ClaimRewards).build(),
+				)
+				.expect_no_logs()
+				.execute_returns(EvmDataWriter::new().write(true).build());
+			assert_last_event!(MetaEvent::ParachainStaking(
+				pallet_parachain_staking::Event::RewardsClaimed {
+					delegator: U8Wrapper(2u8).into(),
+					amount: 20,
+				}
+			));
+		})
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @author The Litentry Team
+/// @title Xtokens Interface
+/// @dev The interface through which solidity contracts will interact with orml-xtokens.
+/// We follow this same interface including four-byte function selectors, in the precompile that
+/// wraps the pallet
+interface XTokens {
+    /// A multilocation is defined by its number of parents and the encoded junctions (interior).
+    /// The first byte of a junction selects its kind:
+    /// 0x00 Parachain: 4 bytes big-endian para id
+    /// 0x01 AccountId32: 32 bytes id + network byte
+    /// 0x02 AccountIndex64: 8 bytes big-endian index + network byte
+    /// 0x03 AccountKey20: 20 bytes key + network byte
+    /// 0x04 PalletInstance: 1 byte pallet index
+    /// 0x05 GeneralIndex: 16 bytes big-endian index
+    /// 0x06 GeneralKey: up to 32 bytes key
+    /// 0xff any other junction, SCALE encoded
+    /// The network byte is 0x00 for none, 0x01 Polkadot, 0x02 Kusama, 0x03 Westend, 0x04 Rococo
+    struct Multilocation {
+        uint8 parents;
+        bytes[] interior;
+    }
+
+    /// @dev Transfer a token through XCM based on its currencyId
+    /// @custom:selector b9f813ff
+    /// @param currencyAddress The ERC20 address of the currency we want to transfer, the
+    /// foreign assets use their precompile address
+    /// @param amount The amount of tokens we want to transfer
+    /// @param destination The Multilocation to which we want to send the tokens
+    /// @param weight The weight (ref time) we want to buy in the destination chain, uint64::MAX
+    /// means Unlimited. A limited weight comes with a proof size of 256 KiB.
+    function transfer(
+        address currencyAddress,
+        uint256 amount,
+        Multilocation memory destination,
+        uint64 weight
+    ) external;
+
+    /// @dev Transfer a token through XCM based on its MultiLocation
+    /// @custom:selector b4f76f96
+    /// @param asset The asset we want to transfer, defined by its multilocation
+    /// @param amount The amount of tokens we want to transfer
+    /// @param destination The Multilocation to which we want to send the tokens
+    /// @param weight The weight (ref time) we want to buy in the destination chain, uint64::MAX
+    /// means Unlimited. A limited weight comes with a proof size of 256 KiB.
+    function transferMultiasset(
+        Multilocation memory asset,
+        uint256 amount,
+        Multilocation memory destination,
+        uint64 weight
+    ) external;
+}
@@ -0,0 +1,160 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+mod multilocation;
+pub use multilocation::EvmMultiLocation;
+
+use fp_evm::{PrecompileHandle, PrecompileOutput};
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	weights::Weight,
+};
+use pallet_evm::{AddressMapping, Precompile};
+use precompile_utils::{
+	revert, succeed, Address, EvmData, EvmDataWriter, EvmResult, FunctionModifier,
+	PrecompileHandleExt, RuntimeHelper,
+};
+use sp_core::H160;
+use sp_runtime::traits::Dispatchable;
+use sp_std::{boxed::Box, marker::PhantomData};
+use xcm::latest::{AssetId, Fungibility, MultiAsset, MultiLocation, WeightLimit};
+
+pub type CurrencyIdOf<Runtime> = <Runtime as orml_xtokens::Config>::CurrencyId;
+type BalanceOf<Runtime> = <Runtime as orml_xtokens::Config>::Balance;
+
+/// Resolve the currency of orml_xtokens from the address of its ERC-20 representation.
+pub trait AddressToCurrencyId<CurrencyId> {
+	fn address_to_currency_id(address: H160) -> Option<CurrencyId>;
+}
+
+/// A precompile to wrap the transfers of orml_xtokens.
+pub struct XTokensPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> XTokensPrecompile<Runtime>
+where
+	Runtime: orml_xtokens::Config + pallet_evm::Config + AddressToCurrencyId<CurrencyIdOf<Runtime>>,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<orml_xtokens::Call<Runtime>>,
+	BalanceOf<Runtime>: EvmData,
+{
+	fn transfer(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+		let currency_address: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<BalanceOf<Runtime>>()?;
+		let destination: MultiLocation = input.read::<EvmMultiLocation>()?.into();
+		let weight = input.read::<u64>()?;
+
+		let currency_id = Runtime::address_to_currency_id(currency_address)
+			.ok_or_else(|| revert("Cannot convert into currency id"))?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = orml_xtokens::Call::<Runtime>::transfer {
+			currency_id,
+			amount,
+			dest: Box::new(destination.into()),
+			dest_weight_limit: weight_limit(weight),
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn transfer_multiasset(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+		let asset: MultiLocation = input.read::<EvmMultiLocation>()?.into();
+		let amount = input.read::<u128>()?;
+		let destination: MultiLocation = input.read::<EvmMultiLocation>()?.into();
+		let weight = input.read::<u64>()?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = orml_xtokens::Call::<Runtime>::transfer_multiasset {
+			asset: Box::new(
+				MultiAsset { id: AssetId::Concrete(asset), fun: Fungibility::Fungible(amount) }
+					.into(),
+			),
+			dest: Box::new(destination.into()),
+			dest_weight_limit: weight_limit(weight),
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+}
+
+/// The proof size bought on the destination with a limited weight, the EVM interface only
+/// carries the ref time. The barrier of a destination metering the proof size rejects a
+/// message whose limit is below its weight, so it can't be 0.
+pub const DEFAULT_PROOF_SIZE: u64 = 256 * 1024;
+
+/// `u64::MAX` means no limit, like the weight of XCM v2.
+fn weight_limit(weight: u64) -> WeightLimit {
+	if weight == u64::MAX {
+		WeightLimit::Unlimited
+	} else {
+		WeightLimit::Limited(Weight::from_parts(weight, DEFAULT_PROOF_SIZE))
+	}
+}
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+	Transfer = "transfer(address,uint256,(uint8,bytes[]),uint64)",
+	TransferMultiasset = "transferMultiasset((uint8,bytes[]),uint256,(uint8,bytes[]),uint64)",
+}
+
+impl<R> Precompile for XTokensPrecompile<R>
+where
+	R: orml_xtokens::Config + pallet_evm::Config + AddressToCurrencyId<CurrencyIdOf<R>>,
+	R::RuntimeCall:
+		From<orml_xtokens::Call<R>> + Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
+	BalanceOf<R>: EvmData,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		log::trace!(
+			target: "xtokens-precompile",
+			"Execute input = {:?}",
+			handle.input()
+		);
+
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Action::Transfer | Action::TransferMultiasset => FunctionModifier::NonPayable,
+		})?;
+
+		match selector {
+			// read storage
+			// None
+			// Dispatchables
+			Action::Transfer => Self::transfer(handle),
+			Action::TransferMultiasset => Self::transfer_multiasset(handle),
+		}
+	}
+}
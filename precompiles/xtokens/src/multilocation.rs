@@ -0,0 +1,214 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Solidity encoding of XCM multilocations.
+//!
+//! A multilocation is the struct `(uint8 parents, bytes[] interior)`, every junction of the
+//! interior is a byte array whose first byte selects the kind of junction:
+//!
+//! | selector | junction       | data                                     |
+//! |----------|----------------|------------------------------------------|
+//! | 0x00     | Parachain      | u32, 4 bytes big-endian                  |
+//! | 0x01     | AccountId32    | 32 bytes id + 1 byte network             |
+//! | 0x02     | AccountIndex64 | u64, 8 bytes big-endian + 1 byte network |
+//! | 0x03     | AccountKey20   | 20 bytes key + 1 byte network            |
+//! | 0x04     | PalletInstance | 1 byte                                   |
+//! | 0x05     | GeneralIndex   | u128, 16 bytes big-endian                |
+//! | 0x06     | GeneralKey     | up to 32 bytes                           |
+//! | 0xff     | any other      | the SCALE encoded `Junction`             |
+//!
+//! The network byte is 0x00 for `None`, 0x01 for Polkadot, 0x02 for Kusama, 0x03 for Westend
+//! and 0x04 for Rococo. Junctions with any other network use the 0xff selector.
+
+use parity_scale_codec::{Decode, Encode};
+use precompile_utils::{revert, Bytes, EvmData, EvmDataReader, EvmDataWriter, EvmResult};
+use sp_std::vec::Vec;
+use xcm::latest::{Junction, Junctions, MultiLocation, NetworkId};
+
+/// The Solidity representation of a `MultiLocation`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvmMultiLocation(pub MultiLocation);
+
+impl From<MultiLocation> for EvmMultiLocation {
+	fn from(location: MultiLocation) -> Self {
+		Self(location)
+	}
+}
+
+impl From<EvmMultiLocation> for MultiLocation {
+	fn from(location: EvmMultiLocation) -> Self {
+		location.0
+	}
+}
+
+impl EvmData for EvmMultiLocation {
+	fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+		let (parents, interior) = reader.read::<(u8, Vec<Bytes>)>()?;
+
+		let mut junctions = Junctions::Here;
+		for junction in interior {
+			junctions
+				.push(decode_junction(junction.as_bytes())?)
+				.map_err(|_| revert("too many junctions"))?;
+		}
+
+		Ok(Self(MultiLocation { parents, interior: junctions }))
+	}
+
+	fn write(writer: &mut EvmDataWriter, value: Self) {
+		let interior: Vec<Bytes> = value
+			.0
+			.interior
+			.iter()
+			.map(|junction| Bytes(encode_junction(junction)))
+			.collect();
+		EvmData::write(writer, (value.0.parents, interior));
+	}
+
+	fn has_static_size() -> bool {
+		false
+	}
+}
+
+fn decode_network(data: &[u8]) -> EvmResult<Option<NetworkId>> {
+	match data {
+		[0] => Ok(None),
+		[1] => Ok(Some(NetworkId::Polkadot)),
+		[2] => Ok(Some(NetworkId::Kusama)),
+		[3] => Ok(Some(NetworkId::Westend)),
+		[4] => Ok(Some(NetworkId::Rococo)),
+		_ => Err(revert("invalid network")),
+	}
+}
+
+fn encode_network(network: &Option<NetworkId>) -> Option<u8> {
+	match network {
+		None => Some(0),
+		Some(NetworkId::Polkadot) => Some(1),
+		Some(NetworkId::Kusama) => Some(2),
+		Some(NetworkId::Westend) => Some(3),
+		Some(NetworkId::Rococo) => Some(4),
+		_ => None,
+	}
+}
+
+/// Split `data` into a fixed size array and the remaining bytes.
+fn split_array<const N: usize>(data: &[u8]) -> EvmResult<([u8; N], &[u8])> {
+	if data.len() < N {
+		return Err(revert("junction is too short"))
+	}
+	let (head, tail) = data.split_at(N);
+	let mut array = [0u8; N];
+	array.copy_from_slice(head);
+	Ok((array, tail))
+}
+
+fn decode_junction(data: &[u8]) -> EvmResult<Junction> {
+	let (selector, data) = data.split_first().ok_or_else(|| revert("empty junction"))?;
+
+	let junction = match selector {
+		0x00 => {
+			let (id, rest) = split_array::<4>(data)?;
+			if !rest.is_empty() {
+				return Err(revert("invalid junction length"))
+			}
+			Junction::Parachain(u32::from_be_bytes(id))
+		},
+		0x01 => {
+			let (id, network) = split_array::<32>(data)?;
+			Junction::AccountId32 { network: decode_network(network)?, id }
+		},
+		0x02 => {
+			let (index, network) = split_array::<8>(data)?;
+			Junction::AccountIndex64 {
+				network: decode_network(network)?,
+				index: u64::from_be_bytes(index),
+			}
+		},
+		0x03 => {
+			let (key, network) = split_array::<20>(data)?;
+			Junction::AccountKey20 { network: decode_network(network)?, key }
+		},
+		0x04 => match data {
+			[instance] => Junction::PalletInstance(*instance),
+			_ => return Err(revert("invalid junction length")),
+		},
+		0x05 => {
+			let (index, rest) = split_array::<16>(data)?;
+			if !rest.is_empty() {
+				return Err(revert("invalid junction length"))
+			}
+			Junction::GeneralIndex(u128::from_be_bytes(index))
+		},
+		0x06 => {
+			if data.len() > 32 {
+				return Err(revert("general key is too long"))
+			}
+			let mut key = [0u8; 32];
+			key[..data.len()].copy_from_slice(data);
+			Junction::GeneralKey { length: data.len() as u8, data: key }
+		},
+		0xff => Junction::decode(&mut &data[..])
+			.map_err(|_| revert("invalid SCALE encoded junction"))?,
+		_ => return Err(revert("unknown junction selector")),
+	};
+
+	Ok(junction)
+}
+
+fn encode_junction(junction: &Junction) -> Vec<u8> {
+	let mut encoded = Vec::new();
+
+	match junction {
+		Junction::Parachain(id) => {
+			encoded.push(0x00);
+			encoded.extend_from_slice(&id.to_be_bytes());
+		},
+		Junction::AccountId32 { network, id } if encode_network(network).is_some() => {
+			encoded.push(0x01);
+			encoded.extend_from_slice(id);
+			encoded.extend(encode_network(network));
+		},
+		Junction::AccountIndex64 { network, index } if encode_network(network).is_some() => {
+			encoded.push(0x02);
+			encoded.extend_from_slice(&index.to_be_bytes());
+			encoded.extend(encode_network(network));
+		},
+		Junction::AccountKey20 { network, key } if encode_network(network).is_some() => {
+			encoded.push(0x03);
+			encoded.extend_from_slice(key);
+			encoded.extend(encode_network(network));
+		},
+		Junction::PalletInstance(instance) => {
+			encoded.push(0x04);
+			encoded.push(*instance);
+		},
+		Junction::GeneralIndex(index) => {
+			encoded.push(0x05);
+			encoded.extend_from_slice(&index.to_be_bytes());
+		},
+		Junction::GeneralKey { length, data } if *length <= 32 => {
+			encoded.push(0x06);
+			encoded.extend_from_slice(&data[..*length as usize]);
+		},
+		other => {
+			encoded.push(0xff);
+			encoded.extend(other.encode());
+		},
+	}
+
+	encoded
+}
@@ -0,0 +1,106 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+// The transfers themselves are tested with the XCM simulator in the rococo runtime.
+
+use crate::*;
+use precompile_utils::{Bytes, EvmDataReader};
+use xcm::latest::{BodyId, BodyPart, Junction, Junctions, NetworkId};
+
+fn roundtrip(location: MultiLocation) -> MultiLocation {
+	let encoded = EvmDataWriter::new().write(EvmMultiLocation::from(location)).build();
+	EvmDataReader::new(&encoded).read::<EvmMultiLocation>().unwrap().into()
+}
+
+#[test]
+fn multilocation_roundtrip_works() {
+	// Only the first `length` bytes of a general key are encoded.
+	let mut general_key = [0u8; 32];
+	general_key[..3].copy_from_slice(b"LIT");
+
+	let locations = vec![
+		MultiLocation::here(),
+		MultiLocation::parent(),
+		MultiLocation::new(
+			1,
+			Junctions::X2(
+				Junction::Parachain(2013),
+				Junction::AccountId32 { network: None, id: [2u8; 32] },
+			),
+		),
+		MultiLocation::new(
+			0,
+			Junctions::X4(
+				Junction::PalletInstance(10),
+				Junction::GeneralIndex(u128::MAX),
+				Junction::AccountKey20 { network: Some(NetworkId::Kusama), key: [3u8; 20] },
+				Junction::AccountIndex64 { network: Some(NetworkId::Rococo), index: 42 },
+			),
+		),
+		MultiLocation::new(
+			1,
+			Junctions::X2(
+				Junction::GeneralKey { length: 3, data: general_key },
+				Junction::Plurality { id: BodyId::Technical, part: BodyPart::Voice },
+			),
+		),
+	];
+
+	for location in locations {
+		assert_eq!(roundtrip(location), location);
+	}
+}
+
+#[test]
+fn multilocation_encoding_works() {
+	let location = MultiLocation::new(
+		1,
+		Junctions::X2(
+			Junction::Parachain(2013),
+			Junction::AccountId32 { network: None, id: [2u8; 32] },
+		),
+	);
+
+	let mut parachain = vec![0u8];
+	parachain.extend_from_slice(&2013u32.to_be_bytes());
+	let mut account = vec![1u8];
+	account.extend_from_slice(&[2u8; 32]);
+	account.push(0);
+
+	assert_eq!(
+		EvmDataWriter::new().write(EvmMultiLocation::from(location)).build(),
+		EvmDataWriter::new()
+			.write((1u8, vec![Bytes(parachain), Bytes(account)]))
+			.build()
+	);
+}
+
+#[test]
+fn invalid_junction_fails() {
+	for junction in [vec![], vec![0u8, 1, 2], vec![1u8; 32], vec![0x04, 1, 2], vec![0x42]] {
+		let encoded = EvmDataWriter::new().write((1u8, vec![Bytes(junction)])).build();
+		assert!(EvmDataReader::new(&encoded).read::<EvmMultiLocation>().is_err());
+	}
+}
+
+#[test]
+fn weight_limit_works() {
+	assert_eq!(weight_limit(u64::MAX), WeightLimit::Unlimited);
+	assert_eq!(
+		weight_limit(1_000),
+		WeightLimit::Limited(Weight::from_parts(1_000, DEFAULT_PROOF_SIZE))
+	);
+}
@@ -189,4 +189,92 @@ impl Assertion {
 			Self::Dynamic(_) => all_web3networks(),
 		}
 	}
+	/// The names of all the variants, see `variant_name`.
+	pub const VARIANT_NAMES: &'static [&'static str] = &[
+		"A1",
+		"A2",
+		"A3",
+		"A4",
+		"A6",
+		"A7",
+		"A8",
+		"A10",
+		"A11",
+		"A13",
+		"A14",
+		"Achainable",
+		"A20",
+		"OneBlock",
+		"GenericDiscordRole",
+		"BnbDomainHolding",
+		"BnbDigitDomainClub",
+		"VIP3MembershipCard",
+		"WeirdoGhostGangHolder",
+		"LITStaking",
+		"EVMAmountHolding",
+		"BRC20AmountHolder",
+		"CryptoSummary",
+		"TokenHoldingAmount",
+		"PlatformUser",
+		"NftHolder",
+		"Dynamic",
+	];
+
+	// The name of the variant, regardless of its parameters, e.g. `A4` for `A4(..)`.
+	// It's used as a stable key in configs, so it must not change when a variant is renamed.
+	pub fn variant_name(&self) -> &'static str {
+		match self {
+			Self::A1 => "A1",
+			Self::A2(..) => "A2",
+			Self::A3(..) => "A3",
+			Self::A4(..) => "A4",
+			Self::A6 => "A6",
+			Self::A7(..) => "A7",
+			Self::A8(..) => "A8",
+			Self::A10(..) => "A10",
+			Self::A11(..) => "A11",
+			Self::A13(..) => "A13",
+			Self::A14 => "A14",
+			Self::Achainable(..) => "Achainable",
+			Self::A20 => "A20",
+			Self::OneBlock(..) => "OneBlock",
+			Self::GenericDiscordRole(..) => "GenericDiscordRole",
+			Self::BnbDomainHolding => "BnbDomainHolding",
+			Self::BnbDigitDomainClub(..) => "BnbDigitDomainClub",
+			Self::VIP3MembershipCard(..) => "VIP3MembershipCard",
+			Self::WeirdoGhostGangHolder => "WeirdoGhostGangHolder",
+			Self::LITStaking => "LITStaking",
+			Self::EVMAmountHolding(..) => "EVMAmountHolding",
+			Self::BRC20AmountHolder => "BRC20AmountHolder",
+			Self::CryptoSummary => "CryptoSummary",
+			Self::TokenHoldingAmount(..) => "TokenHoldingAmount",
+			Self::PlatformUser(..) => "PlatformUser",
+			Self::NftHolder(..) => "NftHolder",
+			Self::Dynamic(..) => "Dynamic",
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn variant_name_works() {
+		for assertion in [
+			Assertion::A1,
+			Assertion::A4(Default::default()),
+			Assertion::A13(AccountId::new([0; 32])),
+			Assertion::BnbDomainHolding,
+			Assertion::Dynamic(H160::zero()),
+		] {
+			assert!(Assertion::VARIANT_NAMES.contains(&assertion.variant_name()));
+		}
+		assert_eq!(Assertion::A4(Default::default()).variant_name(), "A4");
+
+		let mut names = Assertion::VARIANT_NAMES.to_vec();
+		names.sort();
+		names.dedup();
+		assert_eq!(names.len(), Assertion::VARIANT_NAMES.len());
+	}
 }
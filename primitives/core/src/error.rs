@@ -69,6 +69,10 @@ pub enum ErrorDetail {
 	// error when trying to build vc but no eligible identity is found
 	#[codec(index = 11)]
 	NoEligibleIdentity,
+	// error when the data provider is temporarily unavailable, e.g. it's rate-limiting us
+	// and the circuit breaker is open, the request can be retried later
+	#[codec(index = 12)]
+	DataProviderUnavailable(ErrorString),
 }
 
 impl fmt::Debug for ErrorDetail {
@@ -91,6 +95,10 @@ impl fmt::Debug for ErrorDetail {
 			ErrorDetail::UnexpectedMessage => write!(f, "UnexpectedMessage"),
 			ErrorDetail::VerifyWeb3SignatureFailed => write!(f, "VerifyWeb3SignatureFailed"),
 			ErrorDetail::NoEligibleIdentity => write!(f, "NoEligibleIdentity"),
+			ErrorDetail::DataProviderUnavailable(error_string) => {
+				let text = String::from_utf8(error_string.to_vec()).map_err(|_| fmt::Error)?;
+				write!(f, "DataProviderUnavailable({})", text)
+			},
 		}
 	}
 }
@@ -32,7 +32,7 @@ use frame_support::assert_ok;
 use frame_support::{
 	pallet_prelude::DispatchClass,
 	parameter_types, sp_runtime,
-	traits::{Currency, EitherOfDiverse, EnsureOrigin, OnUnbalanced, OriginTrait},
+	traits::{Currency, EitherOfDiverse, EnsureOrigin, IsSubType, OnUnbalanced, OriginTrait},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_SECOND},
 		Weight,
@@ -40,7 +40,11 @@ use frame_support::{
 };
 use frame_system::{limits, EnsureRoot};
 use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
-use sp_runtime::{traits::Bounded, FixedPointNumber, Perbill, Perquintill};
+use sp_runtime::{
+	traits::{Bounded, StaticLookup},
+	FixedPointNumber, Perbill, Perquintill,
+};
+use sp_std::{vec, vec::Vec};
 
 use xcm::latest::prelude::*;
 
@@ -363,3 +367,68 @@ where
 		Ok(frame_system::RawOrigin::Signed(signer).into())
 	}
 }
+
+/// The calls nested in the proxy, multisig and utility calls, with the accounts they are
+/// dispatched from, for the account blocklist of the extrinsic filter
+pub struct NestedCallsOf<T>(PhantomData<T>);
+impl<T> pallet_extrinsic_filter::NestedCalls<T::AccountId, <T as frame_system::Config>::RuntimeCall>
+	for NestedCallsOf<T>
+where
+	T: pallet_proxy::Config<RuntimeCall = <T as frame_system::Config>::RuntimeCall>
+		+ pallet_multisig::Config<RuntimeCall = <T as frame_system::Config>::RuntimeCall>
+		+ pallet_utility::Config<RuntimeCall = <T as frame_system::Config>::RuntimeCall>,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<pallet_proxy::Call<T>>
+		+ IsSubType<pallet_multisig::Call<T>>
+		+ IsSubType<pallet_utility::Call<T>>,
+{
+	fn nested_calls<'a>(
+		who: &T::AccountId,
+		call: &'a <T as frame_system::Config>::RuntimeCall,
+	) -> Vec<(T::AccountId, &'a <T as frame_system::Config>::RuntimeCall)> {
+		if let Some(call) = IsSubType::<pallet_proxy::Call<T>>::is_sub_type(call) {
+			return match call {
+				pallet_proxy::Call::proxy { real, call, .. } |
+				pallet_proxy::Call::proxy_announced { real, call, .. } => match T::Lookup::lookup(real.clone())
+				{
+					Ok(real) => vec![(real, call.as_ref())],
+					// the call fails anyway
+					Err(_) => Vec::new(),
+				},
+				_ => Vec::new(),
+			}
+		}
+
+		if let Some(call) = IsSubType::<pallet_multisig::Call<T>>::is_sub_type(call) {
+			// the signatories are sorted when the multisig account is derived
+			let multi_account_id = |other_signatories: &Vec<T::AccountId>, threshold| {
+				let mut signatories = other_signatories.clone();
+				signatories.push(who.clone());
+				signatories.sort();
+				pallet_multisig::Pallet::<T>::multi_account_id(&signatories, threshold)
+			};
+			return match call {
+				pallet_multisig::Call::as_multi_threshold_1 { other_signatories, call } =>
+					vec![(multi_account_id(other_signatories, 1), call.as_ref())],
+				pallet_multisig::Call::as_multi { threshold, other_signatories, call, .. } =>
+					vec![(multi_account_id(other_signatories, *threshold), call.as_ref())],
+				_ => Vec::new(),
+			}
+		}
+
+		if let Some(call) = IsSubType::<pallet_utility::Call<T>>::is_sub_type(call) {
+			return match call {
+				pallet_utility::Call::batch { calls } |
+				pallet_utility::Call::batch_all { calls } |
+				pallet_utility::Call::force_batch { calls } =>
+					calls.iter().map(|call| (who.clone(), call)).collect(),
+				pallet_utility::Call::as_derivative { index, call } => vec![(
+					pallet_utility::Pallet::<T>::derivative_account_id(who.clone(), *index),
+					call.as_ref(),
+				)],
+				_ => Vec::new(),
+			}
+		}
+
+		Vec::new()
+	}
+}
@@ -14,10 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::{assert_noop, assert_ok, pallet_prelude::Weight, traits::VestingSchedule};
+use frame_support::{
+	assert_noop, assert_ok, dispatch::GetCallMetadata, pallet_prelude::Weight,
+	traits::VestingSchedule,
+};
 use frame_system::RawOrigin;
 use parity_scale_codec::{Decode, Encode};
-use sp_runtime::traits::Dispatchable;
+use sp_runtime::{traits::Dispatchable, transaction_validity::InvalidTransaction};
 
 use core_primitives::AccountId;
 
@@ -172,7 +175,7 @@ where
 					b"System".to_vec(),
 					Vec::<u8>::default()
 				)),
-				Some(())
+				Some(None)
 			);
 			// ...however, no effect in the actual call dispatching
 			assert_ok!(call.dispatch(Origin::signed(alice())));
@@ -220,7 +223,7 @@ where
 					b"Vesting".to_vec(),
 					Vec::<u8>::default()
 				)),
-				Some(())
+				Some(None)
 			);
 			// ...and it will take effect
 			assert_noop!(
@@ -232,6 +235,127 @@ where
 		})
 }
 
+pub fn block_account_blocks_core_call<
+	R: BaseRuntimeRequirements + frame_system::Config<RuntimeOrigin = Origin, RuntimeCall = Call>,
+	Origin: frame_support::traits::OriginTrait<AccountId = AccountId> + From<RawOrigin<AccountId>>,
+	Call: Clone + Dispatchable<RuntimeOrigin = Origin> + From<frame_system::Call<R>> + GetCallMetadata,
+>()
+where
+	<<R as frame_system::Config>::Lookup as sp_runtime::traits::StaticLookup>::Source:
+		From<sp_runtime::AccountId32>,
+{
+	ExtBuilder::<R>::default()
+		.balances(vec![(alice(), 10 * UNIT), (bob(), 10 * UNIT)])
+		.build()
+		.execute_with(|| {
+			let call: Call = frame_system::Call::remark { remark: vec![] }.into();
+
+			// block alice from all calls
+			assert_ok!(ExtrinsicFilter::<R>::block_account(Origin::root(), alice(), None));
+
+			// even the core calls are rejected, which is what `CheckAccountBlocklist` checks
+			assert_eq!(
+				ExtrinsicFilter::<R>::ensure_account_not_blocked(&alice(), &call),
+				Err(InvalidTransaction::Call.into())
+			);
+
+			// other accounts are unaffected
+			assert_ok!(ExtrinsicFilter::<R>::ensure_account_not_blocked(&bob(), &call));
+		})
+}
+
+pub fn block_account_blocks_nested_call<
+	R: BaseRuntimeRequirements
+		+ frame_system::Config<RuntimeOrigin = Origin, RuntimeCall = Call>
+		+ pallet_proxy::Config<RuntimeCall = Call>
+		+ pallet_multisig::Config<RuntimeCall = Call>
+		+ pallet_utility::Config<RuntimeCall = Call>,
+	Origin: frame_support::traits::OriginTrait<AccountId = AccountId> + From<RawOrigin<AccountId>>,
+	Call: Clone
+		+ Dispatchable<RuntimeOrigin = Origin>
+		+ From<frame_system::Call<R>>
+		+ From<pallet_proxy::Call<R>>
+		+ From<pallet_multisig::Call<R>>
+		+ From<pallet_utility::Call<R>>
+		+ GetCallMetadata,
+>()
+where
+	<<R as frame_system::Config>::Lookup as sp_runtime::traits::StaticLookup>::Source:
+		From<sp_runtime::AccountId32>,
+{
+	ExtBuilder::<R>::default()
+		.balances(vec![(alice(), 10 * UNIT), (bob(), 10 * UNIT)])
+		.build()
+		.execute_with(|| {
+			let remark: Call = frame_system::Call::remark { remark: vec![] }.into();
+			let mut signatories = vec![bob(), charlie()];
+			signatories.sort();
+			let multisig = Multisig::<R>::multi_account_id(&signatories, 1);
+
+			// block alice and the multisig account of bob and charlie from the system calls
+			assert_ok!(ExtrinsicFilter::<R>::block_account(
+				Origin::root(),
+				alice(),
+				Some(b"System".to_vec())
+			));
+			assert_ok!(ExtrinsicFilter::<R>::block_account(
+				Origin::root(),
+				multisig,
+				Some(b"System".to_vec())
+			));
+
+			// the system call in a batch
+			let call: Call =
+				pallet_utility::Call::<R>::batch { calls: vec![remark.clone()] }.into();
+			assert_eq!(
+				ExtrinsicFilter::<R>::ensure_account_not_blocked(&alice(), &call),
+				Err(InvalidTransaction::Call.into())
+			);
+			assert_ok!(ExtrinsicFilter::<R>::ensure_account_not_blocked(&bob(), &call));
+
+			// the system call of alice through a proxy
+			let call: Call = pallet_proxy::Call::<R>::proxy {
+				real: alice().into(),
+				force_proxy_type: None,
+				call: Box::new(remark.clone()),
+			}
+			.into();
+			assert_eq!(
+				ExtrinsicFilter::<R>::ensure_account_not_blocked(&bob(), &call),
+				Err(InvalidTransaction::Call.into())
+			);
+
+			// the system call of the multisig account, nested in the batch of a proxy
+			let call: Call = pallet_multisig::Call::<R>::as_multi_threshold_1 {
+				other_signatories: vec![charlie()],
+				call: Box::new(remark.clone()),
+			}
+			.into();
+			assert_eq!(
+				ExtrinsicFilter::<R>::ensure_account_not_blocked(&bob(), &call),
+				Err(InvalidTransaction::Call.into())
+			);
+			let call: Call = pallet_proxy::Call::<R>::proxy {
+				real: bob().into(),
+				force_proxy_type: None,
+				call: Box::new(pallet_utility::Call::<R>::batch { calls: vec![call] }.into()),
+			}
+			.into();
+			assert_eq!(
+				ExtrinsicFilter::<R>::ensure_account_not_blocked(&charlie(), &call),
+				Err(InvalidTransaction::Call.into())
+			);
+
+			// the multisig account of other signatories is unaffected
+			let call: Call = pallet_multisig::Call::<R>::as_multi_threshold_1 {
+				other_signatories: vec![alice()],
+				call: Box::new(remark),
+			}
+			.into();
+			assert_ok!(ExtrinsicFilter::<R>::ensure_account_not_blocked(&charlie(), &call));
+		})
+}
+
 #[macro_export]
 macro_rules! run_call_filter_tests {
 	() => {
@@ -263,5 +387,17 @@ macro_rules! run_call_filter_tests {
 		fn block_non_core_call_works() {
 			base_call_filter::block_non_core_call_works::<Runtime, RuntimeOrigin, RuntimeCall>();
 		}
+
+		#[test]
+		fn block_account_blocks_core_call() {
+			base_call_filter::block_account_blocks_core_call::<Runtime, RuntimeOrigin, RuntimeCall>(
+			);
+		}
+
+		#[test]
+		fn block_account_blocks_nested_call() {
+			base_call_filter::block_account_blocks_nested_call::<Runtime, RuntimeOrigin, RuntimeCall>(
+			);
+		}
 	};
 }
@@ -1297,7 +1297,7 @@ fn register_channel_info<R: ParaRuntimeRequirements + cumulus_pallet_parachain_s
 
 pub const RELAY_SOVEREIGN_ACCOUNT_INITIAL_BALANCE: u128 = 100_000_000_000_000 * RELAY_UNIT;
 
-fn relaychain_parachains_set_up<R: TestXCMRequirements>() {
+pub fn relaychain_parachains_set_up<R: TestXCMRequirements>() {
 	R::reset();
 	R::Relay::execute_with(|| {
 		let _ = Balances::<R::RelayRuntime>::deposit_creating(
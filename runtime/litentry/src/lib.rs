@@ -105,6 +105,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_extrinsic_filter::CheckAccountBlocklist<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -127,6 +128,7 @@ pub type Executive = frame_executive::Executive<
 	// It was reverse order before.
 	// See the comment before collation related pallets too.
 	AllPalletsWithSystem,
+	pallet_extrinsic_filter::migration::MigrateToV1<Runtime>,
 >;
 
 impl_opaque_keys! {
@@ -746,6 +748,8 @@ impl pallet_parachain_staking::Config for Runtime {
 	type MaxBottomDelegationsPerCandidate = ConstU32<200>;
 	/// Maximum delegations per delegator
 	type MaxDelegationsPerDelegator = ConstU32<100>;
+	/// Rounds the rewards of a delegator are kept in the reward ledger
+	type RewardLedgerRounds = ConstU32<24>;
 	type DefaultCollatorCommission = DefaultCollatorCommission;
 	type DefaultParachainBondReservePercent = DefaultParachainBondReservePercent;
 	/// Minimum stake required to become a collator
@@ -793,7 +797,12 @@ impl pallet_bridge::Config for Runtime {
 	type BridgeChainId = BridgeChainId;
 	type Currency = Balances;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxPrunedProposals = ConstU32<100>;
+	type MaxRelayerSignatures = ConstU32<16>;
 	type TreasuryAccount = TreasuryAccount;
+	type ProposalAmount = pallet_bridge_transfer::BridgeTransferProposalAmount<Runtime>;
+	type RelayerSignature = Signature;
+	type RelayerPublic = sp_runtime::MultiSigner;
 	type WeightInfo = weights::pallet_bridge::WeightInfo<Runtime>;
 }
 
@@ -839,6 +848,7 @@ impl pallet_extrinsic_filter::Config for Runtime {
 	type NormalModeFilter = NormalModeFilter;
 	type SafeModeFilter = SafeModeFilter;
 	type TestModeFilter = Everything;
+	type NestedCalls = runtime_common::NestedCallsOf<Runtime>;
 	type WeightInfo = weights::pallet_extrinsic_filter::WeightInfo<Runtime>;
 }
 
@@ -1121,6 +1131,30 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_parachain_staking_runtime_api::ParachainStakingApi<Block, AccountId, Balance> for Runtime {
+		fn pending_rewards(
+			delegator: AccountId,
+		) -> Vec<pallet_parachain_staking_runtime_api::PendingReward<AccountId, Balance>> {
+			ParachainStaking::pending_rewards(&delegator)
+		}
+		fn claimable_rewards(delegator: AccountId) -> Balance {
+			ParachainStaking::claimable_rewards(&delegator)
+		}
+		fn collator_apr(collator: AccountId) -> Option<Perbill> {
+			ParachainStaking::collator_apr(&collator)
+		}
+		fn delegation_requests(
+			delegator: AccountId,
+		) -> Vec<pallet_parachain_staking_runtime_api::DelegationRequest<AccountId, Balance>> {
+			ParachainStaking::delegation_requests(&delegator)
+		}
+		fn delegation_status(
+			delegator: AccountId,
+		) -> Vec<pallet_parachain_staking_runtime_api::DelegationStatus<AccountId, Balance>> {
+			ParachainStaking::delegation_status(&delegator)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
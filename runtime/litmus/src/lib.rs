@@ -111,6 +111,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_extrinsic_filter::CheckAccountBlocklist<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -133,6 +134,7 @@ pub type Executive = frame_executive::Executive<
 	// it was reverse order before.
 	// See the comment before collation related pallets too.
 	AllPalletsWithSystem,
+	pallet_extrinsic_filter::migration::MigrateToV1<Runtime>,
 >;
 
 impl_opaque_keys! {
@@ -736,7 +738,12 @@ impl pallet_bridge::Config for Runtime {
 	type BridgeChainId = BridgeChainId;
 	type Currency = Balances;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxPrunedProposals = ConstU32<100>;
+	type MaxRelayerSignatures = ConstU32<16>;
 	type TreasuryAccount = TreasuryAccount;
+	type ProposalAmount = pallet_bridge_transfer::BridgeTransferProposalAmount<Runtime>;
+	type RelayerSignature = Signature;
+	type RelayerPublic = sp_runtime::MultiSigner;
 	type WeightInfo = weights::pallet_bridge::WeightInfo<Runtime>;
 }
 
@@ -782,6 +789,7 @@ impl pallet_extrinsic_filter::Config for Runtime {
 	type NormalModeFilter = NormalModeFilter;
 	type SafeModeFilter = SafeModeFilter;
 	type TestModeFilter = Everything;
+	type NestedCalls = runtime_common::NestedCallsOf<Runtime>;
 	type WeightInfo = weights::pallet_extrinsic_filter::WeightInfo<Runtime>;
 }
 
@@ -28,7 +28,7 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
 		ConstU128, ConstU32, ConstU64, ConstU8, Contains, ContainsLengthBound, EnsureOrigin,
-		Everything, FindAuthor, InstanceFilter, OnFinalize, SortedMembers, WithdrawReasons,
+		Everything, FindAuthor, Get, InstanceFilter, OnFinalize, SortedMembers, WithdrawReasons,
 	},
 	weights::{constants::RocksDbWeight, ConstantMultiplier, IdentityFee, Weight},
 	ConsensusEngineId, PalletId, RuntimeDebug,
@@ -83,7 +83,7 @@ use xcm_config::{XcmConfig, XcmOriginToTransactDispatchOrigin};
 
 use pallet_ethereum::{Call::transact, PostLogContent, TransactionStatus};
 use pallet_evm::{
-	EVMCurrencyAdapter, FeeCalculator, GasWeightMapping,
+	AddressMapping, EVMCurrencyAdapter, FeeCalculator, GasWeightMapping,
 	OnChargeEVMTransaction as OnChargeEVMTransactionT, Runner,
 };
 // Make the WASM binary available.
@@ -148,6 +148,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_extrinsic_filter::CheckAccountBlocklist<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -170,8 +171,22 @@ pub type Executive = frame_executive::Executive<
 	// it was reverse order before.
 	// See the comment before collation related pallets too.
 	AllPalletsWithSystem,
+	(
+		pallet_extrinsic_filter::migration::MigrateToV1<Runtime>,
+		pallet_evm_assertions::migration::MigrateToV1<Runtime, EvmAssertionsLegacyOwner>,
+	),
 >;
 
+/// Self-contained ethereum transactions bypass the signed extensions, the account blocklist of
+/// pallet-extrinsic-filter is checked against the substrate account of their signer instead.
+fn ensure_evm_signer_not_blocked(
+	signer: &H160,
+	call: &RuntimeCall,
+) -> Result<(), TransactionValidityError> {
+	let account = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(*signer);
+	ExtrinsicFilter::ensure_account_not_blocked(&account, call)
+}
+
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
 	type SignedInfo = H160;
 
@@ -196,7 +211,12 @@ impl fp_self_contained::SelfContainedCall for RuntimeCall {
 		len: usize,
 	) -> Option<TransactionValidity> {
 		match self {
-			RuntimeCall::Ethereum(call) => call.validate_self_contained(info, dispatch_info, len),
+			RuntimeCall::Ethereum(call) => {
+				if let Err(e) = ensure_evm_signer_not_blocked(info, self) {
+					return Some(Err(e))
+				}
+				call.validate_self_contained(info, dispatch_info, len)
+			},
 			_ => None,
 		}
 	}
@@ -208,8 +228,12 @@ impl fp_self_contained::SelfContainedCall for RuntimeCall {
 		len: usize,
 	) -> Option<Result<(), TransactionValidityError>> {
 		match self {
-			RuntimeCall::Ethereum(call) =>
-				call.pre_dispatch_self_contained(info, dispatch_info, len),
+			RuntimeCall::Ethereum(call) => {
+				if let Err(e) = ensure_evm_signer_not_blocked(info, self) {
+					return Some(Err(e))
+				}
+				call.pre_dispatch_self_contained(info, dispatch_info, len)
+			},
 			_ => None,
 		}
 	}
@@ -880,6 +904,8 @@ impl pallet_parachain_staking::Config for Runtime {
 	type MaxBottomDelegationsPerCandidate = ConstU32<200>;
 	/// Maximum delegations per delegator
 	type MaxDelegationsPerDelegator = ConstU32<100>;
+	/// Rounds the rewards of a delegator are kept in the reward ledger
+	type RewardLedgerRounds = ConstU32<24>;
 	type DefaultCollatorCommission = DefaultCollatorCommission;
 	type DefaultParachainBondReservePercent = DefaultParachainBondReservePercent;
 	/// Minimum stake required to become a collator
@@ -927,7 +953,12 @@ impl pallet_bridge::Config for Runtime {
 	type BridgeChainId = BridgeChainId;
 	type Currency = Balances;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxPrunedProposals = ConstU32<100>;
+	type MaxRelayerSignatures = ConstU32<16>;
 	type TreasuryAccount = TreasuryAccount;
+	type ProposalAmount = pallet_bridge_transfer::BridgeTransferProposalAmount<Runtime>;
+	type RelayerSignature = Signature;
+	type RelayerPublic = sp_runtime::MultiSigner;
 	type WeightInfo = weights::pallet_bridge::WeightInfo<Runtime>;
 }
 
@@ -972,6 +1003,7 @@ impl pallet_extrinsic_filter::Config for Runtime {
 	type NormalModeFilter = NormalModeFilter;
 	type SafeModeFilter = SafeModeFilter;
 	type TestModeFilter = Everything;
+	type NestedCalls = runtime_common::NestedCallsOf<Runtime>;
 	type WeightInfo = weights::pallet_extrinsic_filter::WeightInfo<Runtime>;
 }
 
@@ -1000,9 +1032,25 @@ impl pallet_bitacross::Config for Runtime {
 	type SetAdminOrigin = EnsureRootOrAllCouncil;
 }
 
+parameter_types! {
+	pub const AssertionDeposit: Balance = 10 * DOLLARS;
+}
+
 impl pallet_evm_assertions::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssertionId = H160;
+	type Currency = Balances;
+	type AssertionDeposit = AssertionDeposit;
+	type SetAdminOrigin = EnsureRootOrAllCouncil;
+}
+
+/// Owner of the assertions created before their ownership was recorded, used when no
+/// admin of pallet-evm-assertions is set at the time of the migration
+pub struct EvmAssertionsLegacyOwner;
+impl Get<Option<AccountId>> for EvmAssertionsLegacyOwner {
+	fn get() -> Option<AccountId> {
+		Sudo::key()
+	}
 }
 
 // Temporary for bitacross team to test
@@ -1287,8 +1335,9 @@ impl Contains<RuntimeCall> for NormalModeFilter {
 			RuntimeCall::Bounties(_) |
 			// BridgeTransfer
 			RuntimeCall::BridgeTransfer(_) |
-			// XTokens::transfer for normal users
+			// XTokens::transfer and XTokens::transfer_multiasset for normal users
 			RuntimeCall::XTokens(orml_xtokens::Call::transfer { .. }) |
+			RuntimeCall::XTokens(orml_xtokens::Call::transfer_multiasset { .. }) |
 			// memberships
 			RuntimeCall::CouncilMembership(_) |
 			RuntimeCall::TechnicalCommitteeMembership(_) |
@@ -1478,6 +1527,30 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_parachain_staking_runtime_api::ParachainStakingApi<Block, AccountId, Balance> for Runtime {
+		fn pending_rewards(
+			delegator: AccountId,
+		) -> Vec<pallet_parachain_staking_runtime_api::PendingReward<AccountId, Balance>> {
+			ParachainStaking::pending_rewards(&delegator)
+		}
+		fn claimable_rewards(delegator: AccountId) -> Balance {
+			ParachainStaking::claimable_rewards(&delegator)
+		}
+		fn collator_apr(collator: AccountId) -> Option<Perbill> {
+			ParachainStaking::collator_apr(&collator)
+		}
+		fn delegation_requests(
+			delegator: AccountId,
+		) -> Vec<pallet_parachain_staking_runtime_api::DelegationRequest<AccountId, Balance>> {
+			ParachainStaking::delegation_requests(&delegator)
+		}
+		fn delegation_status(
+			delegator: AccountId,
+		) -> Vec<pallet_parachain_staking_runtime_api::DelegationStatus<AccountId, Balance>> {
+			ParachainStaking::delegation_status(&delegator)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
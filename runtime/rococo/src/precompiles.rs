@@ -14,19 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{AssetManager, Runtime};
 use pallet_evm::{
 	ExitRevert, IsPrecompileResult, Precompile, PrecompileFailure, PrecompileHandle,
 	PrecompileResult, PrecompileSet,
 };
+use pallet_evm_precompile_assets_erc20::{address_to_asset_id, Erc20AssetsPrecompileSet};
 use pallet_evm_precompile_blake2::Blake2F;
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
 use pallet_evm_precompile_bridge_transfer::BridgeTransferPrecompile;
 use pallet_evm_precompile_dispatch::Dispatch;
 use pallet_evm_precompile_ed25519::Ed25519Verify;
+use pallet_evm_precompile_identity_management::IdentityManagementPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_parachain_staking::ParachainStakingPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_xtokens::{AddressToCurrencyId, XTokensPrecompile};
+use runtime_common::xcm_impl::CurrencyId;
 use sp_core::H160;
 use sp_std::{fmt::Debug, marker::PhantomData};
 
@@ -42,9 +47,26 @@ impl<R> RococoNetworkPrecompiles<R> {
 	/// Return all addresses that contain precompiles. This can be used to populate dummy code
 	/// under the precompile.
 	pub fn used_addresses() -> impl Iterator<Item = H160> {
-		sp_std::vec![1, 2, 3, 4, 5, 6, 7, 8, 1024, 1025, 1026, 1027, 20480 + 45, 20480 + 61]
-			.into_iter()
-			.map(hash)
+		sp_std::vec![
+			1,
+			2,
+			3,
+			4,
+			5,
+			6,
+			7,
+			8,
+			1024,
+			1025,
+			1026,
+			1027,
+			20480 + 45,
+			20480 + 54,
+			20480 + 61,
+			20480 + 64,
+		]
+		.into_iter()
+		.map(hash)
 	}
 }
 
@@ -54,7 +76,10 @@ impl<R> RococoNetworkPrecompiles<R> {
 impl<R> PrecompileSet for RococoNetworkPrecompiles<R>
 where
 	ParachainStakingPrecompile<R>: Precompile,
+	XTokensPrecompile<R>: Precompile,
 	BridgeTransferPrecompile<R>: Precompile,
+	IdentityManagementPrecompile<R>: Precompile,
+	Erc20AssetsPrecompileSet<R>: PrecompileSet,
 	Dispatch<R>: Precompile,
 	R: pallet_evm::Config,
 {
@@ -89,18 +114,33 @@ where
 			// Litentry precompiles (starts from 0x5000):
 			// ParachainStaking: pallet_parachain_staking = 45
 			a if a == hash(20480 + 45) => Some(ParachainStakingPrecompile::<R>::execute(handle)),
+			// XTokens: orml_xtokens = 54
+			a if a == hash(20480 + 54) => Some(XTokensPrecompile::<R>::execute(handle)),
 			// BridgeTransfer: pallet_bridge_transfer = 61
 			a if a == hash(20480 + 61) => Some(BridgeTransferPrecompile::<R>::execute(handle)),
-			// Default
-			_ => None,
+			// IdentityManagement: pallet_identity_management = 64
+			a if a == hash(20480 + 64) => Some(IdentityManagementPrecompile::<R>::execute(handle)),
+			// Foreign assets (0xFFFFFFFF + asset id): orml_tokens = 55, pallet_asset_manager = 65
+			_ => Erc20AssetsPrecompileSet::<R>::new().execute(handle),
 		}
 	}
 
-	fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
-		IsPrecompileResult::Answer {
-			is_precompile: Self::used_addresses().any(|x| x == address),
-			extra_cost: 0,
+	fn is_precompile(&self, address: H160, gas: u64) -> IsPrecompileResult {
+		if Self::used_addresses().any(|x| x == address) {
+			return IsPrecompileResult::Answer { is_precompile: true, extra_cost: 0 }
 		}
+		Erc20AssetsPrecompileSet::<R>::new().is_precompile(address, gas)
+	}
+}
+
+/// The native token has no ERC-20 precompile yet and is identified by the address reserved for
+/// pallet_balances = 10, the foreign assets by the address of their ERC-20 precompile.
+impl AddressToCurrencyId<CurrencyId<Runtime>> for Runtime {
+	fn address_to_currency_id(address: H160) -> Option<CurrencyId<Runtime>> {
+		if address == hash(20480 + 10) {
+			return Some(CurrencyId::SelfReserve(PhantomData))
+		}
+		AssetManager::asset_id_type(address_to_asset_id(address)?)
 	}
 }
 
@@ -40,4 +40,6 @@ mod xcm_parachain {
 	};
 
 	runtime_common::run_xcm_tests!();
+
+	mod xtokens_precompile;
 }
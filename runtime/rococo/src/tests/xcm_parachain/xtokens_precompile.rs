@@ -0,0 +1,148 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::{ParaA, ParaB, XCMRequirements};
+use crate::{xcm_config::UnitWeightCost, Balances, RococoNetworkPrecompiles, Runtime};
+use core_primitives::AccountId;
+use frame_support::traits::{Currency, Get};
+use pallet_evm::AddressMapping;
+use pallet_evm_precompile_assets_erc20::asset_id_to_address;
+use pallet_evm_precompile_xtokens::{Action, EvmMultiLocation};
+use precompile_utils::{testing::*, Address, EvmDataWriter};
+use runtime_common::{
+	currency::UNIT,
+	tests::{
+		setup::{bob, BOB},
+		xcm_parachain::relaychain_parachains_set_up,
+	},
+};
+use sp_core::{H160, U256};
+use xcm::latest::prelude::*;
+use xcm_simulator::TestExt;
+
+fn precompiles() -> RococoNetworkPrecompiles<Runtime> {
+	RococoNetworkPrecompiles::new()
+}
+
+fn xtokens_address() -> H160 {
+	H160::from_low_u64_be(20480 + 54)
+}
+
+fn native_token_address() -> H160 {
+	H160::from_low_u64_be(20480 + 10)
+}
+
+fn evm_caller() -> H160 {
+	H160::repeat_byte(0xaa)
+}
+
+fn evm_account() -> AccountId {
+	<Runtime as pallet_evm::Config>::AddressMapping::into_account_id(evm_caller())
+}
+
+fn bob_on_para_b() -> EvmMultiLocation {
+	MultiLocation::new(1, X2(Parachain(2), AccountId32 { network: None, id: BOB })).into()
+}
+
+fn para_b_foreign_balance(who: AccountId) -> u128 {
+	// Asset_id=0. The first registered Token in Para B
+	ParaB::execute_with(|| orml_tokens::Accounts::<Runtime>::get(who, 0).free)
+}
+
+#[test]
+fn xtokens_precompile_transfer_works() {
+	relaychain_parachains_set_up::<XCMRequirements>();
+	let weight = UnitWeightCost::get().ref_time() * 4;
+
+	ParaA::execute_with(|| {
+		let _ = Balances::deposit_creating(&evm_account(), 10 * UNIT);
+
+		precompiles()
+			.prepare_test(
+				evm_caller(),
+				xtokens_address(),
+				EvmDataWriter::new_with_selector(Action::Transfer)
+					.write(Address(native_token_address()))
+					.write(U256::from(UNIT))
+					.write(bob_on_para_b())
+					.write(weight)
+					.build(),
+			)
+			.expect_no_logs()
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		assert_eq!(Balances::free_balance(&evm_account()), 9 * UNIT);
+	});
+
+	assert_eq!(para_b_foreign_balance(bob()), UNIT - u128::from(weight));
+}
+
+#[test]
+fn xtokens_precompile_transfer_multiasset_works() {
+	relaychain_parachains_set_up::<XCMRequirements>();
+	let weight = UnitWeightCost::get().ref_time() * 4;
+
+	ParaA::execute_with(|| {
+		let _ = Balances::deposit_creating(&evm_account(), 10 * UNIT);
+
+		// The native token of ParaA, seen from ParaB
+		let asset: EvmMultiLocation =
+			MultiLocation::new(1, X2(Parachain(1), PalletInstance(10))).into();
+
+		precompiles()
+			.prepare_test(
+				evm_caller(),
+				xtokens_address(),
+				EvmDataWriter::new_with_selector(Action::TransferMultiasset)
+					.write(asset)
+					.write(U256::from(2 * UNIT))
+					.write(bob_on_para_b())
+					.write(weight)
+					.build(),
+			)
+			.expect_no_logs()
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		assert_eq!(Balances::free_balance(&evm_account()), 8 * UNIT);
+	});
+
+	assert_eq!(para_b_foreign_balance(bob()), 2 * UNIT - u128::from(weight));
+}
+
+#[test]
+fn xtokens_precompile_unknown_currency_fails() {
+	relaychain_parachains_set_up::<XCMRequirements>();
+
+	ParaA::execute_with(|| {
+		let _ = Balances::deposit_creating(&evm_account(), 10 * UNIT);
+
+		// Only the asset 0 is registered on ParaA
+		precompiles()
+			.prepare_test(
+				evm_caller(),
+				xtokens_address(),
+				EvmDataWriter::new_with_selector(Action::Transfer)
+					.write(Address(asset_id_to_address(1)))
+					.write(U256::from(UNIT))
+					.write(bob_on_para_b())
+					.write(UnitWeightCost::get().ref_time() * 4)
+					.build(),
+			)
+			.execute_reverts(|output| output == b"Cannot convert into currency id");
+
+		assert_eq!(Balances::free_balance(&evm_account()), 10 * UNIT);
+	});
+}
@@ -193,4 +193,105 @@ impl<T: frame_system::Config> pallet_bridge::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: ChainBridge VolumeLimits (r:0 w:1)
+	/// Proof Skipped: ChainBridge VolumeLimits (max_values: None, max_size: None, mode: Measured)
+	fn set_volume_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ChainBridge QueuedProposals (r:1 w:1)
+	/// Proof Skipped: ChainBridge QueuedProposals (max_values: None, max_size: None, mode: Measured)
+	fn release_queued_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `3611`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3611))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ChainBridge QueuedProposals (r:1 w:1)
+	/// Proof Skipped: ChainBridge QueuedProposals (max_values: None, max_size: None, mode: Measured)
+	fn cancel_queued_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `3611`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3611))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ChainBridge Paused (r:0 w:1)
+	/// Proof Skipped: ChainBridge Paused (max_values: Some(1), max_size: None, mode: Measured)
+	fn set_bridge_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ChainBridge Votes (r:1 w:1)
+	/// Proof Skipped: ChainBridge Votes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ChainBridge CompletedProposals (r:1 w:2)
+	/// Proof Skipped: ChainBridge CompletedProposals (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ChainBridge CompletedNonce (r:1 w:1)
+	/// Proof Skipped: ChainBridge CompletedNonce (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ChainBridge RelayerStatistics (r:1 w:1)
+	/// Proof Skipped: ChainBridge RelayerStatistics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 100]`.
+	/// The range of component `r` is `[0, 64]`.
+	/// PLACEHOLDER: hand-written estimate, not produced by the benchmark cli. Re-run `benchmark pallet` for `prune_expired_proposals` to replace it.
+	fn prune_expired_proposals(n: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3513 + n * (7290 ±0) + r * (2531 ±0)`
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 7290).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(r.into()))
+	}
+	/// Storage: ChainBridge Relayers (r:1 w:0)
+	/// Proof Skipped: ChainBridge Relayers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ChainBridge ChainNonces (r:1 w:0)
+	/// Proof Skipped: ChainBridge ChainNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ChainBridge Resources (r:1 w:0)
+	/// Proof Skipped: ChainBridge Resources (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ChainBridge RelayerCount (r:1 w:0)
+	/// Proof Skipped: ChainBridge RelayerCount (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ChainBridge Votes (r:1 w:1)
+	/// Proof Skipped: ChainBridge Votes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ChainBridge RelayerStatistics (r:1 w:1)
+	/// Proof Skipped: ChainBridge RelayerStatistics (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ChainBridge RelayerThreshold (r:1 w:0)
+	/// Proof Skipped: ChainBridge RelayerThreshold (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `s` is `[1, 16]`.
+	/// PLACEHOLDER: hand-written estimate, not produced by the benchmark cli. Re-run `benchmark pallet` for `acknowledge_proposal_with_signatures` to replace it.
+	fn acknowledge_proposal_with_signatures(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3805 + s * (2539 ±0)`
+		Weight::from_parts(53_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3805))
+			.saturating_add(Weight::from_parts(58_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(s.into()))
+	}
 }
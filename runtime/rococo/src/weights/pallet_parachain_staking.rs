@@ -659,6 +659,38 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(Weight::from_parts(0, 1932))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: ParachainStaking Liveness (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Liveness (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking OfflineRounds (r:100 w:100)
+	/// Proof Skipped: ParachainStaking OfflineRounds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking Points (r:1 w:0)
+	/// Proof Skipped: ParachainStaking Points (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking SelectedCandidates (r:1 w:0)
+	/// Proof Skipped: ParachainStaking SelectedCandidates (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidateInfo (r:100 w:100)
+	/// Proof Skipped: ParachainStaking CandidateInfo (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking AwardedPts (r:100 w:0)
+	/// Proof Skipped: ParachainStaking AwardedPts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking CandidatePool (r:100 w:100)
+	/// Proof Skipped: ParachainStaking CandidatePool (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: System Account (r:100 w:100)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Total (r:100 w:100)
+	/// Proof Skipped: ParachainStaking Total (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `s` is `[1, 100]`.
+	/// PLACEHOLDER: hand-written estimate, not produced by the benchmark cli.
+	/// Re-run `benchmark pallet` for `offline_collators_on_initialize` to replace it.
+	fn offline_collators_on_initialize(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `9000 + s * (5200 ±0)`
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9000))
+			.saturating_add(Weight::from_parts(45_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5200).saturating_mul(s.into()))
+	}
 	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
 	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
 	/// Storage: ParachainStaking AutoCompoundingDelegations (r:1 w:1)
@@ -715,4 +747,44 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(Weight::from_parts(0, 31).saturating_mul(y.into()))
 			.saturating_add(Weight::from_parts(0, 29).saturating_mul(z.into()))
 	}
+	/// Storage: ParachainStaking Liveness (r:1 w:1)
+	/// Proof Skipped: ParachainStaking Liveness (max_values: Some(1), max_size: None, mode: Measured)
+	/// PLACEHOLDER: hand-written estimate, not produced by the benchmark cli.
+	/// Re-run `benchmark pallet` for `set_liveness_config` to replace it.
+	fn set_liveness_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `1890`
+		Weight::from_parts(18_022_000, 0)
+			.saturating_add(Weight::from_parts(0, 1890))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof Skipped: ParachainStaking DelegatorState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainStaking RewardPayoutModes (r:1 w:1)
+	/// Proof Skipped: ParachainStaking RewardPayoutModes (max_values: None, max_size: None, mode: Measured)
+	/// PLACEHOLDER: hand-written estimate, not produced by the benchmark cli.
+	/// Re-run `benchmark pallet` for `set_reward_payout_mode` to replace it.
+	fn set_reward_payout_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `4056`
+		Weight::from_parts(20_806_000, 0)
+			.saturating_add(Weight::from_parts(0, 4056))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ParachainStaking ClaimableRewards (r:1 w:1)
+	/// Proof Skipped: ParachainStaking ClaimableRewards (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// PLACEHOLDER: hand-written estimate, not produced by the benchmark cli.
+	/// Re-run `benchmark pallet` for `claim_rewards` to replace it.
+	fn claim_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Estimated: `3801`
+		Weight::from_parts(31_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 3801))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -85,14 +85,17 @@ impl<T: frame_system::Config> pallet_vc_management::WeightInfo for WeightInfo<T>
 	}
 	/// Storage: Teerex EnclaveIndex (r:1 w:0)
 	/// Proof Skipped: Teerex EnclaveIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VCManagement VCRegistry (r:1 w:1)
+	/// Proof: VCManagement VCRegistry (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
 	fn vc_issued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `255`
-		//  Estimated: `3720`
-		// Minimum execution time: 19_878_000 picoseconds.
-		Weight::from_parts(20_219_000, 0)
-			.saturating_add(Weight::from_parts(0, 3720))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Estimated: `3777`
+		// Minimum execution time: 24_613_000 picoseconds.
+		Weight::from_parts(25_146_000, 0)
+			.saturating_add(Weight::from_parts(0, 3777))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Teerex EnclaveIndex (r:1 w:0)
 	/// Proof Skipped: Teerex EnclaveIndex (max_values: None, max_size: None, mode: Measured)
@@ -175,4 +178,18 @@ impl<T: frame_system::Config> pallet_vc_management::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: VCManagement VCRegistry (r:1 w:1)
+	/// Proof: VCManagement VCRegistry (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+	/// Storage: VCManagement Admin (r:1 w:0)
+	/// Proof: VCManagement Admin (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn revoke_vc() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3777`
+		// Minimum execution time: 22_467_000 picoseconds.
+		Weight::from_parts(22_905_000, 0)
+			.saturating_add(Weight::from_parts(0, 3777))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
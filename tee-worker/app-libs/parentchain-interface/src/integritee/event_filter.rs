@@ -23,7 +23,8 @@ use itp_node_api::api_client::StaticEvent;
 use itp_types::{
 	parentchain::{
 		events::{
-			ActivateIdentityRequested, AssertionCreated, DeactivateIdentityRequested,
+			ActivateIdentityRequested, AssertionCreated, AssertionDisabled, AssertionEnabled,
+			AssertionEvent, AssertionRemoved, AssertionUpdated, DeactivateIdentityRequested,
 			LinkIdentityRequested, OpaqueTaskPosted, ScheduledEnclaveRemoved, ScheduledEnclaveSet,
 			VCRequested,
 		},
@@ -51,6 +52,38 @@ impl FilterableEvents {
 			})
 			.collect())
 	}
+
+	// the assertion events are kept in the order they were emitted, so that e.g. an assertion
+	// removed and re-created in the same block ends up stored
+	fn filter_assertion_events<E>(&self) -> Result<Vec<AssertionEvent>, E> {
+		Ok(self
+			.to_events()
+			.iter()
+			.flatten()
+			.filter_map(|ev| {
+				let event = match ev.variant_name() {
+					AssertionCreated::EVENT =>
+						ev.as_event::<AssertionCreated>().map(|e| e.map(AssertionEvent::Created)),
+					AssertionUpdated::EVENT =>
+						ev.as_event::<AssertionUpdated>().map(|e| e.map(AssertionEvent::Updated)),
+					AssertionDisabled::EVENT =>
+						ev.as_event::<AssertionDisabled>().map(|e| e.map(AssertionEvent::Disabled)),
+					AssertionEnabled::EVENT =>
+						ev.as_event::<AssertionEnabled>().map(|e| e.map(AssertionEvent::Enabled)),
+					AssertionRemoved::EVENT =>
+						ev.as_event::<AssertionRemoved>().map(|e| e.map(AssertionEvent::Removed)),
+					_ => Ok(None),
+				};
+				match event {
+					Ok(maybe_event) => maybe_event,
+					Err(e) => {
+						log::error!("Could not decode event: {:?}", e);
+						None
+					},
+				}
+			})
+			.collect())
+	}
 }
 
 // todo: improve: https://github.com/integritee-network/worker/pull/1378#discussion_r1393933766
@@ -101,7 +134,7 @@ impl FilterEvents for FilterableEvents {
 		self.filter()
 	}
 
-	fn get_assertion_created_events(&self) -> Result<Vec<AssertionCreated>, Self::Error> {
-		self.filter()
+	fn get_assertion_events(&self) -> Result<Vec<AssertionEvent>, Self::Error> {
+		self.filter_assertion_events()
 	}
 }
@@ -22,7 +22,8 @@ use itc_parentchain_indirect_calls_executor::error::Error;
 use itp_stf_primitives::{traits::IndirectExecutor, types::TrustedOperation};
 use itp_types::{
 	parentchain::{
-		AccountId, FilterEvents, HandleParentchainEvents, ParentchainEventProcessingError,
+		events::AssertionEvent, AccountId, FilterEvents, HandleParentchainEvents,
+		ParentchainEventProcessingError,
 	},
 	RsaRequest, H256,
 };
@@ -230,6 +231,11 @@ impl ParentchainEventHandler {
 			.map_err(Error::AssertionCreatedHandling)?;
 		Ok(())
 	}
+
+	fn remove_assertion(&self, id: H160) -> Result<(), Error> {
+		debug!("remove assertion: {:?}", id);
+		self.assertion_repository.remove(&id).map_err(Error::AssertionRemovedHandling)
+	}
 }
 
 impl<Executor> HandleParentchainEvents<Executor, TrustedCallSigned, Error>
@@ -363,19 +369,55 @@ where
 				.map_err(|_| ParentchainEventProcessingError::OpaqueTaskPostedFailure)?;
 		}
 
-		if let Ok(events) = events.get_assertion_created_events() {
-			debug!("Handling AssertionCreated events");
-			events
-				.into_iter()
-				.try_for_each(|event| {
-					debug!("found AssertionCreated event: {:?}", event);
-					let event_hash = hash_of(&event);
-					let result =
-						self.store_assertion(executor, event.id, event.byte_code, event.secrets);
-					handled_events.push(event_hash);
-					result
-				})
-				.map_err(|_| ParentchainEventProcessingError::AssertionCreatedFailure)?;
+		if let Ok(events) = events.get_assertion_events() {
+			debug!("Handling EvmAssertions events");
+			events.into_iter().try_for_each(|event| {
+				debug!("found EvmAssertions event: {:?}", event);
+				match event {
+					AssertionEvent::Created(event) => {
+						let event_hash = hash_of(&event);
+						let result = self
+							.store_assertion(executor, event.id, event.byte_code, event.secrets)
+							.map_err(|_| ParentchainEventProcessingError::AssertionCreatedFailure);
+						handled_events.push(event_hash);
+						result
+					},
+					AssertionEvent::Updated(event) => {
+						let event_hash = hash_of(&event);
+						let result = self
+							.store_assertion(executor, event.id, event.byte_code, event.secrets)
+							.map_err(|_| ParentchainEventProcessingError::AssertionUpdatedFailure);
+						handled_events.push(event_hash);
+						result
+					},
+					AssertionEvent::Disabled(event) => {
+						let event_hash = hash_of(&event);
+						// disabled assertions are dropped from the repository so that VC requests
+						// for them fail, they are stored again upon `AssertionEnabled`
+						let result = self
+							.remove_assertion(event.id)
+							.map_err(|_| ParentchainEventProcessingError::AssertionDisabledFailure);
+						handled_events.push(event_hash);
+						result
+					},
+					AssertionEvent::Enabled(event) => {
+						let event_hash = hash_of(&event);
+						let result = self
+							.store_assertion(executor, event.id, event.byte_code, event.secrets)
+							.map_err(|_| ParentchainEventProcessingError::AssertionEnabledFailure);
+						handled_events.push(event_hash);
+						result
+					},
+					AssertionEvent::Removed(event) => {
+						let event_hash = hash_of(&event);
+						let result = self
+							.remove_assertion(event.id)
+							.map_err(|_| ParentchainEventProcessingError::AssertionRemovedFailure);
+						handled_events.push(event_hash);
+						result
+					},
+				}
+			})?;
 		}
 
 		Ok(handled_events)
@@ -20,7 +20,7 @@ use itc_parentchain_indirect_calls_executor::event_filter::ToEvents;
 use itp_api_client_types::Events;
 
 use itp_types::{
-	parentchain::{events::AssertionCreated, FilterEvents},
+	parentchain::{events::AssertionEvent, FilterEvents},
 	H256,
 };
 use std::vec::Vec;
@@ -85,7 +85,7 @@ impl FilterEvents for FilterableEvents {
 		Ok(Vec::new())
 	}
 
-	fn get_assertion_created_events(&self) -> Result<Vec<AssertionCreated>, Self::Error> {
+	fn get_assertion_events(&self) -> Result<Vec<AssertionEvent>, Self::Error> {
 		Ok(Vec::new())
 	}
 }
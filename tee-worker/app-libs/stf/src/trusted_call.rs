@@ -131,7 +131,16 @@ pub enum TrustedCall {
 		H256,
 	),
 	#[codec(index = 21)]
-	request_vc_callback(Identity, Identity, Assertion, Vec<u8>, Option<RequestAesKey>, bool, H256),
+	request_vc_callback(
+		Identity,
+		Identity,
+		Assertion,
+		H256,
+		Vec<u8>,
+		Option<RequestAesKey>,
+		bool,
+		H256,
+	),
 	#[codec(index = 22)]
 	handle_imp_error(Identity, Option<Identity>, IMPError, H256),
 	#[codec(index = 23)]
@@ -818,6 +827,7 @@ where
 				signer,
 				who,
 				assertion,
+				vc_index,
 				vc_payload,
 				maybe_key,
 				should_create_id_graph,
@@ -861,6 +871,7 @@ where
 					call_index,
 					who,
 					assertion,
+					vc_index,
 					id_graph_hash,
 					req_ext_hash,
 				))));
@@ -18,5 +18,7 @@ pub mod activate_identity;
 pub mod deactivate_identity;
 pub mod id_graph_hash;
 pub mod link_identity;
+pub mod run_assertion;
+pub mod verify_vc;
 
 pub const IMP: &str = "IdentityManagement";
@@ -0,0 +1,157 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CliResult, CliResultOk};
+use itc_rest_client::rest_client::Url;
+use lc_dynamic_assertion::AssertionLogicRepository;
+use lc_evm_dynamic_assertions::{
+	AssertionId, AssertionRepositoryItem, EvmAssertionExecutor, ExecutionLimits,
+};
+use litentry_primitives::{Identity, IdentityNetworkTuple, Web3Network};
+use std::{fs, path::Path, sync::Arc};
+
+// usage: ./bin/litentry-cli run-assertion <bytecode or path to .bin file> \
+//            -i did:litentry:twitter:alice \
+//            -i did:litentry:evm:0x...@ethereum,bsc \
+//            -s <secret> --mock-server-url http://localhost:19527
+//
+// this command executes a compiled dynamic assertion contract locally, outside of the enclave,
+// and prints the assertion result, the gas used and the http calls made by precompiles.
+// No worker or parachain is required.
+
+#[derive(Parser)]
+pub struct RunAssertionCommand {
+	/// compiled contract bytecode in hex, or path to a file containing it (solc --bin output)
+	bytecode: String,
+	/// identity in did form, optionally followed by `@` and comma separated web3 networks
+	#[clap(short = 'i', long = "identity")]
+	identities: Vec<String>,
+	/// secret passed to the contract, can be repeated
+	#[clap(short = 's', long = "secret")]
+	secrets: Vec<String>,
+	/// send all precompile http calls to this base url instead, e.g. the mock-server
+	#[clap(long)]
+	mock_server_url: Option<String>,
+	/// gas available for contract deployment and execution
+	#[clap(long)]
+	gas_limit: Option<u64>,
+}
+
+impl RunAssertionCommand {
+	pub(crate) fn run(&self) -> CliResult {
+		let byte_code = self.read_byte_code();
+		let identities: Vec<IdentityNetworkTuple> =
+			self.identities.iter().map(|i| parse_identity_with_networks(i)).collect();
+
+		let repository =
+			Arc::new(CommandLineAssertionRepository { item: (byte_code, self.secrets.clone()) });
+		let mut executor = EvmAssertionExecutor::new(repository);
+		if let Some(gas_limit) = self.gas_limit {
+			executor.limits = ExecutionLimits { gas_limit, ..Default::default() };
+		}
+		if let Some(url) = &self.mock_server_url {
+			executor =
+				executor.with_http_base_url(Url::parse(url).expect("invalid mock server url"));
+		}
+
+		let (result, trace) = executor.execute_with_trace(ASSERTION_ID, &identities);
+
+		println!("http calls:");
+		for (index, call) in trace.http_calls.iter().enumerate() {
+			let result = match &call.result {
+				Ok(status) => format!("{}", status),
+				Err(e) => format!("error: {}", e),
+			};
+			println!(
+				"  #{} [precompile {:?}] {} {} -> {}",
+				index, call.precompile, call.method, call.url, result
+			);
+			if let Some(body) = &call.body {
+				println!("      body: {}", body);
+			}
+		}
+		println!("gas used: {}", trace.gas_used);
+
+		match result {
+			Ok(result) => {
+				println!("description: {}", result.description);
+				println!("assertion type: {}", result.assertion_type);
+				println!("assertions:");
+				for assertion in result.assertions.iter() {
+					println!("  {}", assertion);
+				}
+				println!("schema url: {}", result.schema_url);
+				println!("meet: {}", result.meet);
+				match result.validity_period {
+					Some(u64::MAX) => println!("validity period: never expires"),
+					Some(validity_period) => println!("validity period: {}s", validity_period),
+					None => {},
+				}
+			},
+			Err(e) => println!("execution failed: {}", e),
+		}
+
+		Ok(CliResultOk::None)
+	}
+
+	fn read_byte_code(&self) -> Vec<u8> {
+		let encoded = if Path::new(&self.bytecode).is_file() {
+			fs::read_to_string(&self.bytecode).expect("cannot read bytecode file")
+		} else {
+			self.bytecode.clone()
+		};
+		let encoded = encoded.trim();
+		hex::decode(encoded.strip_prefix("0x").unwrap_or(encoded)).expect("invalid hex bytecode")
+	}
+}
+
+// the command line assertion is served under this id
+const ASSERTION_ID: AssertionId = AssertionId::zero();
+
+struct CommandLineAssertionRepository {
+	item: AssertionRepositoryItem,
+}
+
+impl AssertionLogicRepository for CommandLineAssertionRepository {
+	type Id = AssertionId;
+	type Item = AssertionRepositoryItem;
+
+	fn get(&self, id: &Self::Id) -> Result<Option<Self::Item>, String> {
+		Ok((id == &ASSERTION_ID).then(|| self.item.clone()))
+	}
+
+	fn save(&self, _id: Self::Id, _item: Self::Item) -> Result<(), String> {
+		Err("Command line assertion repository is read-only".to_string())
+	}
+
+	fn remove(&self, _id: &Self::Id) -> Result<(), String> {
+		Err("Command line assertion repository is read-only".to_string())
+	}
+}
+
+// e.g. `did:litentry:evm:0x...@ethereum,bsc`
+fn parse_identity_with_networks(value: &str) -> IdentityNetworkTuple {
+	let (did, networks): (&str, Vec<&str>) = match value.split_once('@') {
+		Some((did, networks)) => (did, networks.split(',').collect()),
+		None => (value, Vec::new()),
+	};
+	let identity = Identity::from_did(did).expect("invalid identity did");
+	let networks: Vec<Web3Network> = networks
+		.into_iter()
+		.map(|n| n.try_into().expect("cannot convert to Web3Network"))
+		.collect();
+	(identity, networks)
+}
@@ -0,0 +1,100 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{command_utils::get_chain_api, Cli, CliResult, CliResultOk};
+use base58::ToBase58;
+use itp_node_api::api_client::PalletTeebagApi;
+use itp_types::WorkerType;
+use lc_vc_verifier::{verify, CheckResult, RegisteredEnclave};
+use std::{
+	fs,
+	path::Path,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+// usage: ./bin/litentry-cli verify-vc <credential json or path to .json file> \
+//            [--enclaves <path to enclave snapshot .json file>]
+//
+// this command verifies an issued credential and prints the result of each check.
+// The enclave snapshot is a json array of `{ "mrenclave": <base58>, "vcPubkey": <hex> }`,
+// if it's not given, the enclaves currently registered on the parachain are used.
+// Pass `--save-enclaves` to store them as a snapshot for later offline verification.
+
+#[derive(Parser)]
+pub struct VerifyVcCommand {
+	/// credential json, or path to a file containing it
+	credential: String,
+	/// path to the snapshot of the registered enclaves
+	#[clap(long)]
+	enclaves: Option<String>,
+	/// store the enclaves queried from the parachain as a snapshot to this path
+	#[clap(long, conflicts_with = "enclaves")]
+	save_enclaves: Option<String>,
+}
+
+impl VerifyVcCommand {
+	pub(crate) fn run(&self, cli: &Cli) -> CliResult {
+		let credential = if Path::new(&self.credential).is_file() {
+			fs::read_to_string(&self.credential).expect("cannot read credential file")
+		} else {
+			self.credential.clone()
+		};
+
+		let enclaves: Vec<RegisteredEnclave> = match &self.enclaves {
+			Some(path) => serde_json::from_str(
+				&fs::read_to_string(path).expect("cannot read enclave snapshot file"),
+			)
+			.expect("invalid enclave snapshot"),
+			None => {
+				let enclaves = registered_enclaves(cli);
+				if let Some(path) = &self.save_enclaves {
+					let snapshot = serde_json::to_string_pretty(&enclaves).unwrap();
+					fs::write(path, snapshot).expect("cannot write enclave snapshot file");
+					println!("enclave snapshot saved to {}", path);
+				}
+				enclaves
+			},
+		};
+
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+		let report = verify(&credential, &enclaves, now).expect("invalid credential json");
+
+		for (check, result) in report.checks() {
+			match result {
+				CheckResult::Passed => println!("{:<20} passed", check),
+				CheckResult::Failed(reason) => println!("{:<20} FAILED: {}", check, reason),
+				CheckResult::Skipped(reason) => println!("{:<20} skipped: {}", check, reason),
+			}
+		}
+		println!("credential is {}", if report.is_valid() { "valid" } else { "invalid" });
+
+		Ok(CliResultOk::None)
+	}
+}
+
+fn registered_enclaves(cli: &Cli) -> Vec<RegisteredEnclave> {
+	let api = get_chain_api(cli);
+	api.all_enclaves(WorkerType::Identity, None)
+		.unwrap()
+		.into_iter()
+		.filter_map(|enclave| {
+			enclave.vc_pubkey.map(|vc_pubkey| RegisteredEnclave {
+				mrenclave: enclave.mrenclave.to_base58(),
+				vc_pubkey: format!("0x{}", hex::encode(vc_pubkey.0)),
+			})
+		})
+		.collect()
+}
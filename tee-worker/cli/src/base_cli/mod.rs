@@ -23,7 +23,8 @@ use crate::{
 		litentry::{
 			activate_identity::ActivateIdentityCommand,
 			deactivate_identity::DeactivateIdentityCommand, id_graph_hash::IDGraphHashCommand,
-			link_identity::LinkIdentityCommand,
+			link_identity::LinkIdentityCommand, run_assertion::RunAssertionCommand,
+			verify_vc::VerifyVcCommand,
 		},
 		register_tcb_info::RegisterTcbInfoCommand,
 		transfer::TransferCommand,
@@ -96,6 +97,12 @@ pub enum BaseCommand {
 
 	/// Activate identity
 	ActivateIdentity(ActivateIdentityCommand),
+
+	/// run a compiled dynamic assertion contract locally, for assertion development
+	RunAssertion(RunAssertionCommand),
+
+	/// verify an issued credential against the registered enclaves
+	VerifyVc(VerifyVcCommand),
 }
 
 impl BaseCommand {
@@ -117,6 +124,8 @@ impl BaseCommand {
 			BaseCommand::IDGraphHash(cmd) => cmd.run(cli),
 			BaseCommand::DeactivateIdentity(cmd) => cmd.run(cli),
 			BaseCommand::ActivateIdentity(cmd) => cmd.run(cli),
+			BaseCommand::RunAssertion(cmd) => cmd.run(),
+			BaseCommand::VerifyVc(cmd) => cmd.run(cli),
 		}
 	}
 }
@@ -183,6 +183,10 @@ fn send_get_storage_request(
 						warn!("request status is: {:?}, top_hash: {:?}", status, top_hash);
 						None
 					},
+					DirectRequestStatus::RequestProgress(hash) => {
+						warn!("request progress of: {:?}", hash);
+						None
+					},
 				}
 			} else {
 				None
@@ -343,6 +343,9 @@ fn send_direct_request<T: Decode + Debug>(
 								return Ok(value)
 							}
 						},
+						DirectRequestStatus::RequestProgress(hash) => {
+							debug!("request progress of {:?} is ignored", hash);
+						},
 						DirectRequestStatus::Ok => {
 							debug!("request status is ignored");
 							direct_api.close().unwrap();
@@ -525,6 +528,9 @@ pub(crate) fn wait_until(
 									}
 								}
 							},
+							DirectRequestStatus::RequestProgress(hash) => {
+								debug!("request progress of {:?} is ignored", hash);
+							},
 							DirectRequestStatus::Ok => {
 								debug!("request status is ignored");
 								return None
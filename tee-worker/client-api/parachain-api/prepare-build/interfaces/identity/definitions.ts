@@ -65,6 +65,7 @@ export default {
             _enum: {
                 Twitter: "TwitterValidationData",
                 Discord: "DiscordValidationData",
+                Github: "GithubValidationData",
             },
         },
         TwitterValidationData: {
@@ -82,10 +83,21 @@ export default {
             redirect_uri: "Vec<u8>",
         },
         DiscordValidationData: {
+            _enum: {
+                PublicMessage: "PublicMessage",
+                OAuth2: "OAuth2",
+            },
+        },
+        PublicMessage: {
             channel_id: "Vec<u8>",
             message_id: "Vec<u8>",
             guild_id: "Vec<u8>",
         },
+        GithubValidationData: {
+            code: "Vec<u8>",
+            state: "Vec<u8>",
+            redirect_uri: "Vec<u8>",
+        },
         Web3ValidationData: {
             _enum: {
                 Substrate: "Web3CommonValidationData",
@@ -10,8 +10,24 @@ export default {
                 Ok: null,
                 TrustedOperationStatus: "(TrustedOperationStatus, H256)",
                 Error: null,
+                RequestProgress: "H256",
             },
         },
+        RequestProgress: {
+            _enum: {
+                Queued: null,
+                FetchingData: null,
+                Building: null,
+                Signed: null,
+                SubmittedToParentchain: null,
+                Completed: "Vec<u8>",
+            },
+        },
+        RequestProgressEvent: {
+            idx: "u8",
+            len: "u8",
+            progress: "RequestProgress",
+        },
         AesOutput: {
             ciphertext: "Vec<u8>",
             aad: "Vec<u8>",
@@ -87,6 +103,7 @@ export default {
                 __Unused_WrongSignatureType: "Null",
                 VerifyWeb3SignatureFailed: "Null",
                 NoEligibleIdentity: "Null",
+                DataProviderUnavailable: "(Bytes)",
             },
         },
         ShardIdentifier: "H256",
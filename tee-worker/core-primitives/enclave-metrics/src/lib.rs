@@ -47,4 +47,7 @@ pub enum EnclaveMetric {
 	VCBuildTime(String, Duration),
 	SuccessfullVCIssuance,
 	FailedVCIssuance,
+	// data provider response cache hits and misses since the last update, per provider
+	DataProviderCacheHit(String, u64),
+	DataProviderCacheMiss(String, u64),
 }
@@ -48,6 +48,8 @@ pub mod files {
 	// litentry
 	pub const SCHEDULED_ENCLAVE_FILE: &str = "scheduled_enclave_sealed.bin";
 	pub const ASSERTIONS_FILE: &str = "assertions_sealed.bin";
+	pub const DATA_PROVIDER_CONFIG_FILE: &str = "data_provider_config_sealed.bin";
+	pub const VC_JOBS_PATH: &str = "vc_jobs";
 
 	pub const RA_DUMP_CERT_DER_FILE: &str = "ra_dump_cert.der";
 
@@ -41,7 +41,9 @@ use itp_top_pool::{
 		TrustedOperationSource, TxHash,
 	},
 };
-use itp_types::{BlockHash as SidechainBlockHash, DecryptableRequest, ShardIdentifier};
+use itp_types::{
+	BlockHash as SidechainBlockHash, DecryptableRequest, RequestProgressEvent, ShardIdentifier,
+};
 use itp_utils::hex::ToHexPrefixed;
 use jsonrpc_core::{
 	futures::future::{ready, TryFutureExt},
@@ -540,6 +542,10 @@ impl<
 		self.top_pool.send_rpc_response(hash, encoded_value, do_watch)
 	}
 
+	fn send_request_progress(&self, hash: TxHash, event: RequestProgressEvent) {
+		self.top_pool.send_request_progress(hash, event)
+	}
+
 	fn swap_rpc_connection_hash(&self, old_hash: TxHash, new_hash: TxHash) {
 		self.top_pool.swap_rpc_connection_hash(old_hash, new_hash)
 	}
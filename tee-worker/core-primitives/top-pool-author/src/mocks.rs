@@ -35,7 +35,7 @@ use itp_stf_primitives::{
 	types::{AccountId, TrustedOperation as StfTrustedOperation, TrustedOperationOrHash},
 };
 use itp_top_pool::primitives::{PoolFuture, PoolStatus};
-use itp_types::{DecryptableRequest, ShardIdentifier};
+use itp_types::{DecryptableRequest, RequestProgressEvent, ShardIdentifier};
 use jsonrpc_core::{futures::future::ready, Error as RpcError};
 use lazy_static::lazy_static;
 use sp_core::{blake2_256, H256};
@@ -266,6 +266,8 @@ where
 
 	fn send_rpc_response(&self, _hash: H256, _encoded_value: Vec<u8>, _do_watch: bool) {}
 
+	fn send_request_progress(&self, _hash: H256, _event: RequestProgressEvent) {}
+
 	fn swap_rpc_connection_hash(&self, _old_hash: H256, _new_hash: H256) {}
 }
 
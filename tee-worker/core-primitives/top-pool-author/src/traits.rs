@@ -25,7 +25,10 @@ use itp_stf_primitives::types::{
 	AccountId, TrustedOperation as StfTrustedOperation, TrustedOperationOrHash,
 };
 use itp_top_pool::primitives::{PoolFuture, PoolStatus};
-use itp_types::{BlockHash as SidechainBlockHash, DecryptableRequest, ShardIdentifier, H256};
+use itp_types::{
+	BlockHash as SidechainBlockHash, DecryptableRequest, RequestProgressEvent, ShardIdentifier,
+	H256,
+};
 use jsonrpc_core::Error as RpcError;
 use std::{string::String, vec::Vec};
 
@@ -99,6 +102,9 @@ where
 
 	fn send_rpc_response(&self, hash: Hash, encoded_value: Vec<u8>, do_watch: bool);
 
+	/// Litentry: send the progress of the request to the subscribed connections
+	fn send_request_progress(&self, hash: Hash, event: RequestProgressEvent);
+
 	/// Litentry: swap the old hash with the new one in rpc connection registry
 	fn swap_rpc_connection_hash(&self, old_hash: Hash, new_hash: Hash);
 }
@@ -40,6 +40,7 @@ use codec::Encode;
 use core::{marker::PhantomData, pin::Pin};
 use itc_direct_rpc_server::SendRpcResponse;
 use itp_stf_primitives::{traits::PoolTransactionValidation, types::ShardIdentifier};
+use itp_types::RequestProgressEvent;
 use its_primitives::types::BlockHash as SidechainBlockHash;
 use jsonrpc_core::futures::{
 	channel::oneshot,
@@ -256,6 +257,10 @@ where
 		self.pool.validated_pool().send_rpc_response(hash, encoded_value, do_watch);
 	}
 
+	fn send_request_progress(&self, hash: TxHash, event: RequestProgressEvent) {
+		self.pool.validated_pool().send_request_progress(hash, event);
+	}
+
 	fn swap_rpc_connection_hash(&self, old_hash: TxHash, new_hash: TxHash) {
 		self.pool.validated_pool().swap_rpc_connection_hash(old_hash, new_hash);
 	}
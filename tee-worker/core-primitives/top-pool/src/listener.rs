@@ -22,7 +22,7 @@ use crate::sgx_reexport_prelude::*;
 use crate::{primitives::TxHash, watcher::Watcher};
 
 use itc_direct_rpc_server::SendRpcResponse;
-use itp_types::BlockHash as SidechainBlockHash;
+use itp_types::{BlockHash as SidechainBlockHash, RequestProgressEvent};
 use linked_hash_map::LinkedHashMap;
 use log::*;
 
@@ -177,6 +177,13 @@ where
 		}
 	}
 
+	/// Litentry: send the progress of the request to the subscribed connections
+	pub fn send_request_progress(&mut self, hash: TxHash, event: RequestProgressEvent) {
+		if let Err(e) = self.rpc_response_sender.send_request_progress(hash, event) {
+			warn!("failed to send request progress: {:?}", e);
+		}
+	}
+
 	/// Litentry: swap the old hash with the new one in rpc connection registry
 	pub fn swap_rpc_connection_hash(&mut self, old_hash: TxHash, new_hash: TxHash) {
 		info!("Swapping connection {:?} to {:?}", &old_hash, &new_hash);
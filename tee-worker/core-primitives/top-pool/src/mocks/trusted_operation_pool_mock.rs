@@ -36,7 +36,9 @@ use crate::{
 use codec::Encode;
 use core::{future::Future, pin::Pin};
 
-use itp_types::{Block, BlockHash as SidechainBlockHash, ShardIdentifier, H256};
+use itp_types::{
+	Block, BlockHash as SidechainBlockHash, RequestProgressEvent, ShardIdentifier, H256,
+};
 use jsonrpc_core::futures::future::ready;
 use sp_runtime::{
 	generic::BlockId,
@@ -217,6 +219,8 @@ where
 
 	fn send_rpc_response(&self, _hash: TxHash, _encoded_value: Vec<u8>, _do_watch: bool) {}
 
+	fn send_request_progress(&self, _hash: TxHash, _event: RequestProgressEvent) {}
+
 	fn swap_rpc_connection_hash(&self, _old_hash: TxHash, _new_hash: TxHash) {}
 }
 
@@ -10,7 +10,7 @@ use byteorder::{BigEndian, ByteOrder};
 use codec::{Decode, Encode};
 use core::pin::Pin;
 use itp_stf_primitives::types::ShardIdentifier;
-use itp_types::BlockHash as SidechainBlockHash;
+use itp_types::{BlockHash as SidechainBlockHash, RequestProgressEvent};
 use jsonrpc_core::futures::{channel::mpsc::Receiver, Future, Stream};
 use sp_core::H256;
 use sp_runtime::{
@@ -259,6 +259,9 @@ pub trait TrustedOperationPool<TOP>: Send + Sync {
 
 	fn send_rpc_response(&self, hash: TxHash, encoded_value: Vec<u8>, do_watch: bool);
 
+	/// Litentry: send the progress of the request to the subscribed connections
+	fn send_request_progress(&self, hash: TxHash, event: RequestProgressEvent);
+
 	/// Litentry: swap the old hash with the new one in rpc connection registry
 	fn swap_rpc_connection_hash(&self, old_hash: TxHash, new_hash: TxHash);
 }
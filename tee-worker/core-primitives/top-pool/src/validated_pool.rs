@@ -41,7 +41,7 @@ use crate::{
 use core::{marker::PhantomData, result::Result};
 use itc_direct_rpc_server::SendRpcResponse;
 use itp_stf_primitives::types::ShardIdentifier;
-use itp_types::BlockHash as SidechainBlockHash;
+use itp_types::{BlockHash as SidechainBlockHash, RequestProgressEvent};
 use jsonrpc_core::futures::channel::mpsc::{channel, Sender};
 use sp_runtime::{
 	generic::BlockId,
@@ -715,6 +715,10 @@ where
 		self.listener.write().unwrap().send_rpc_response(hash, encoded_value, do_watch);
 	}
 
+	pub fn send_request_progress(&self, hash: TxHash, event: RequestProgressEvent) {
+		self.listener.write().unwrap().send_request_progress(hash, event);
+	}
+
 	pub fn swap_rpc_connection_hash(&self, old_hash: TxHash, new_hash: TxHash) {
 		self.listener.write().unwrap().swap_rpc_connection_hash(old_hash, new_hash);
 	}
@@ -90,6 +90,37 @@ pub enum DirectRequestStatus {
 	/// Direct request could not be executed
 	#[codec(index = 2)]
 	Error,
+	/// Progress of the request with the given hash, the value is a list of `RequestProgressEvent`
+	#[codec(index = 3)]
+	RequestProgress(H256),
+}
+
+/// Progress of a VC request of the vc-task, streamed to the connections subscribed to the
+/// request hash. The identity requests don't report any progress.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Eq)]
+pub enum RequestProgress {
+	#[codec(index = 0)]
+	Queued,
+	#[codec(index = 1)]
+	FetchingData,
+	#[codec(index = 2)]
+	Building,
+	#[codec(index = 3)]
+	Signed,
+	#[codec(index = 4)]
+	SubmittedToParentchain,
+	/// The request is done, with the same encoded response as sent to the requesting connection.
+	#[codec(index = 5)]
+	Completed(Vec<u8>),
+}
+
+/// Progress of the `idx`-th of the `len` items of a request, e.g. the assertions of a
+/// `request_batch_vc`.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Eq)]
+pub struct RequestProgressEvent {
+	pub idx: u8,
+	pub len: u8,
+	pub progress: RequestProgress,
 }
 
 #[derive(Debug, Clone, PartialEq, Encode, Decode, Eq)]
@@ -269,3 +269,101 @@ impl StaticEvent for AssertionCreated {
 	const PALLET: &'static str = "EvmAssertions";
 	const EVENT: &'static str = "AssertionCreated";
 }
+
+#[derive(Encode, Decode, Debug)]
+pub struct AssertionUpdated {
+	pub id: H160,
+	pub version: u32,
+	pub byte_code: Vec<u8>,
+	pub secrets: Vec<Vec<u8>>,
+}
+
+impl core::fmt::Display for AssertionUpdated {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let message = format!(
+			"{:?} :: id: {:?}, version: {:?}, byte_code: {:?}, secrets: {:?}",
+			AssertionUpdated::EVENT,
+			self.id,
+			self.version,
+			self.byte_code,
+			self.secrets
+		);
+		write!(f, "{}", message)
+	}
+}
+
+impl StaticEvent for AssertionUpdated {
+	const PALLET: &'static str = "EvmAssertions";
+	const EVENT: &'static str = "AssertionUpdated";
+}
+
+#[derive(Encode, Decode, Debug)]
+pub struct AssertionDisabled {
+	pub id: H160,
+}
+
+impl core::fmt::Display for AssertionDisabled {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let message = format!("{:?} :: id: {:?}", AssertionDisabled::EVENT, self.id);
+		write!(f, "{}", message)
+	}
+}
+
+impl StaticEvent for AssertionDisabled {
+	const PALLET: &'static str = "EvmAssertions";
+	const EVENT: &'static str = "AssertionDisabled";
+}
+
+#[derive(Encode, Decode, Debug)]
+pub struct AssertionEnabled {
+	pub id: H160,
+	pub byte_code: Vec<u8>,
+	pub secrets: Vec<Vec<u8>>,
+}
+
+impl core::fmt::Display for AssertionEnabled {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let message = format!(
+			"{:?} :: id: {:?}, byte_code: {:?}, secrets: {:?}",
+			AssertionEnabled::EVENT,
+			self.id,
+			self.byte_code,
+			self.secrets
+		);
+		write!(f, "{}", message)
+	}
+}
+
+impl StaticEvent for AssertionEnabled {
+	const PALLET: &'static str = "EvmAssertions";
+	const EVENT: &'static str = "AssertionEnabled";
+}
+
+#[derive(Encode, Decode, Debug)]
+pub struct AssertionRemoved {
+	pub id: H160,
+}
+
+impl core::fmt::Display for AssertionRemoved {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let message = format!("{:?} :: id: {:?}", AssertionRemoved::EVENT, self.id);
+		write!(f, "{}", message)
+	}
+}
+
+impl StaticEvent for AssertionRemoved {
+	const PALLET: &'static str = "EvmAssertions";
+	const EVENT: &'static str = "AssertionRemoved";
+}
+
+/// An event of the `EvmAssertions` pallet, they are handled in the order they were emitted
+/// as e.g. an assertion can be disabled and enabled again, or removed and re-created, in the
+/// same block.
+#[derive(Encode, Decode, Debug)]
+pub enum AssertionEvent {
+	Created(AssertionCreated),
+	Updated(AssertionUpdated),
+	Disabled(AssertionDisabled),
+	Enabled(AssertionEnabled),
+	Removed(AssertionRemoved),
+}
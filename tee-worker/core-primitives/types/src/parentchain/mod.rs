@@ -17,7 +17,7 @@
 
 pub mod events;
 
-use crate::{parentchain::events::AssertionCreated, OpaqueCall};
+use crate::{parentchain::events::AssertionEvent, OpaqueCall};
 use alloc::vec::Vec;
 use codec::{Decode, Encode};
 use core::fmt::Debug;
@@ -113,7 +113,7 @@ pub trait FilterEvents {
 
 	fn get_opaque_task_posted_events(&self) -> Result<Vec<OpaqueTaskPosted>, Self::Error>;
 
-	fn get_assertion_created_events(&self) -> Result<Vec<AssertionCreated>, Self::Error>;
+	fn get_assertion_events(&self) -> Result<Vec<AssertionEvent>, Self::Error>;
 }
 
 #[derive(Debug)]
@@ -145,6 +145,10 @@ pub enum ParentchainEventProcessingError {
 	ScheduledEnclaveRemovedFailure,
 	OpaqueTaskPostedFailure,
 	AssertionCreatedFailure,
+	AssertionUpdatedFailure,
+	AssertionDisabledFailure,
+	AssertionEnabledFailure,
+	AssertionRemovedFailure,
 }
 
 impl core::fmt::Display for ParentchainEventProcessingError {
@@ -168,6 +172,14 @@ impl core::fmt::Display for ParentchainEventProcessingError {
 				"Parentchain Event Processing Error: OpaqueTaskPostedFailure",
 			ParentchainEventProcessingError::AssertionCreatedFailure =>
 				"Parentchain Event Processing Error: AssertionCreatedFailure",
+			ParentchainEventProcessingError::AssertionUpdatedFailure =>
+				"Parentchain Event Processing Error: AssertionUpdatedFailure",
+			ParentchainEventProcessingError::AssertionDisabledFailure =>
+				"Parentchain Event Processing Error: AssertionDisabledFailure",
+			ParentchainEventProcessingError::AssertionEnabledFailure =>
+				"Parentchain Event Processing Error: AssertionEnabledFailure",
+			ParentchainEventProcessingError::AssertionRemovedFailure =>
+				"Parentchain Event Processing Error: AssertionRemovedFailure",
 		};
 		write!(f, "{}", message)
 	}
@@ -39,7 +39,7 @@ use crate::rpc_watch_extractor::RpcWatchExtractor;
 use codec::{Encode, Error as CodecError};
 use itc_tls_websocket_server::error::WebSocketError;
 use itp_rpc::RpcResponse;
-use itp_types::{TrustedOperationStatus, H256};
+use itp_types::{RequestProgressEvent, TrustedOperationStatus, H256};
 use serde_json::error::Error as SerdeJsonError;
 use sp_runtime::traits;
 use std::{boxed::Box, fmt::Debug, vec::Vec};
@@ -98,7 +98,7 @@ pub type ForceWait = bool;
 /// Registry for RPC connections (i.e. connections that are kept alive to send updates).
 pub trait RpcConnectionRegistry: Send + Sync {
 	type Hash: RpcHash;
-	type Connection: Copy + Debug;
+	type Connection: Copy + Debug + PartialEq;
 
 	fn store(
 		&self,
@@ -111,6 +111,26 @@ pub trait RpcConnectionRegistry: Send + Sync {
 	fn withdraw(&self, hash: &Self::Hash) -> Option<(Self::Connection, RpcResponse, ForceWait)>;
 
 	fn is_force_wait(&self, hash: &Self::Hash) -> bool;
+
+	// Litentry: subscribe `connection` to the progress of the request `hash`, returns the latest
+	// progress of each item of the request so far and whether the request is finished, or `None`
+	// if the request is unknown. The connection is not kept if the request is already finished.
+	fn subscribe_progress(
+		&self,
+		hash: Self::Hash,
+		connection: Self::Connection,
+		rpc_response: RpcResponse,
+	) -> Option<(Vec<RequestProgressEvent>, bool)>;
+
+	// Litentry: record the progress of the request `hash`, returns the subscribed connections
+	// and whether the request is finished
+	fn publish_progress(
+		&self,
+		hash: &Self::Hash,
+		event: RequestProgressEvent,
+	) -> (Vec<(Self::Connection, RpcResponse)>, bool);
+
+	fn unsubscribe_progress(&self, hash: &Self::Hash, connection: Self::Connection);
 }
 
 /// Sends an RPC response back to the client.
@@ -147,6 +167,13 @@ pub trait SendRpcResponse: Send + Sync {
 	fn swap_hash(&self, old_hash: Self::Hash, new_hash: Self::Hash) -> DirectRpcResult<()>;
 
 	fn is_force_wait(&self, hash: Self::Hash) -> bool;
+
+	// Litentry: send the progress of a request to the connections subscribed to it
+	fn send_request_progress(
+		&self,
+		hash: Self::Hash,
+		event: RequestProgressEvent,
+	) -> DirectRpcResult<()>;
 }
 
 /// Determines if a given connection must be watched (i.e. kept alive),
@@ -155,6 +182,10 @@ pub trait DetermineWatch: Send + Sync {
 	type Hash: RpcHash;
 
 	fn must_be_watched(&self, rpc_response: &RpcResponse) -> DirectRpcResult<Option<Self::Hash>>;
+
+	// Litentry: the hash of the request whose progress the connection subscribes to, if any
+	fn must_be_subscribed(&self, rpc_response: &RpcResponse)
+		-> DirectRpcResult<Option<Self::Hash>>;
 }
 
 /// Convenience method to create a do_watch extractor.
@@ -49,4 +49,11 @@ where
 	fn must_be_watched(&self, _rpc_response: &RpcResponse) -> DirectRpcResult<Option<Self::Hash>> {
 		Ok(self.watch_next.clone())
 	}
+
+	fn must_be_subscribed(
+		&self,
+		_rpc_response: &RpcResponse,
+	) -> DirectRpcResult<Option<Self::Hash>> {
+		Ok(None)
+	}
 }
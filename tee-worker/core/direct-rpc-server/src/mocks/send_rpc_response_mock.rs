@@ -22,7 +22,7 @@ use std::sync::SgxRwLock as RwLock;
 use std::sync::RwLock;
 
 use crate::{DirectRpcResult, RpcHash, SendRpcResponse};
-use itp_types::TrustedOperationStatus;
+use itp_types::{RequestProgressEvent, TrustedOperationStatus};
 use std::vec::Vec;
 
 /// Send RPC response mock.
@@ -73,6 +73,14 @@ where
 		Ok(())
 	}
 
+	fn send_request_progress(
+		&self,
+		_hash: Self::Hash,
+		_event: RequestProgressEvent,
+	) -> DirectRpcResult<()> {
+		Ok(())
+	}
+
 	fn swap_hash(&self, _old_hash: Self::Hash, _new_hash: Self::Hash) -> DirectRpcResult<()> {
 		Ok(())
 	}
@@ -23,23 +23,68 @@ use std::sync::RwLock;
 
 use crate::{ForceWait, RpcConnectionRegistry, RpcHash};
 use itp_rpc::RpcResponse;
-use std::{collections::HashMap, fmt::Debug};
+use itp_types::{RequestProgress, RequestProgressEvent};
+use std::{
+	collections::{BTreeMap, HashMap, VecDeque},
+	fmt::Debug,
+	vec::Vec,
+};
 
 type HashMapLock<K, V> = RwLock<HashMap<K, V>>;
 
+/// The number of finished requests whose progress is kept, so that a client can still
+/// re-attach to a request that finished while it was disconnected.
+pub const FINISHED_PROGRESS_CAPACITY: usize = 256;
+
+/// The number of unfinished requests whose progress is kept, the oldest one is dropped first,
+/// so that requests which never finish (e.g. failing before their completion is reported)
+/// don't accumulate.
+pub const UNFINISHED_PROGRESS_CAPACITY: usize = 1024;
+
+struct RequestProgressState<Token> {
+	// latest event of each item of the request
+	events: BTreeMap<u8, RequestProgressEvent>,
+	subscribers: Vec<(Token, RpcResponse)>,
+}
+
+impl<Token> Default for RequestProgressState<Token> {
+	fn default() -> Self {
+		Self { events: BTreeMap::new(), subscribers: Vec::new() }
+	}
+}
+
+impl<Token> RequestProgressState<Token> {
+	fn is_finished(&self) -> bool {
+		let len = self.events.values().map(|e| e.len).max().unwrap_or_default().max(1);
+		self.events
+			.values()
+			.filter(|e| matches!(e.progress, RequestProgress::Completed(_)))
+			.count() >= len as usize
+	}
+}
+
+struct ProgressMap<Hash, Token> {
+	requests: HashMap<Hash, RequestProgressState<Token>>,
+	// in the order of their first progress
+	unfinished: VecDeque<Hash>,
+	// in the order of their completion
+	finished: VecDeque<Hash>,
+}
+
 pub struct ConnectionRegistry<Hash, Token>
 where
 	Hash: RpcHash,
-	Token: Copy + Send + Sync + Debug,
+	Token: Copy + Send + Sync + Debug + PartialEq,
 {
 	connection_map:
 		HashMapLock<<Self as RpcConnectionRegistry>::Hash, (Token, RpcResponse, ForceWait)>,
+	progress_map: RwLock<ProgressMap<Hash, Token>>,
 }
 
 impl<Hash, Token> ConnectionRegistry<Hash, Token>
 where
 	Hash: RpcHash,
-	Token: Copy + Send + Sync + Debug,
+	Token: Copy + Send + Sync + Debug + PartialEq,
 {
 	pub fn new() -> Self {
 		Self::default()
@@ -54,17 +99,24 @@ where
 impl<Hash, Token> Default for ConnectionRegistry<Hash, Token>
 where
 	Hash: RpcHash,
-	Token: Copy + Send + Sync + Debug,
+	Token: Copy + Send + Sync + Debug + PartialEq,
 {
 	fn default() -> Self {
-		ConnectionRegistry { connection_map: RwLock::new(HashMap::default()) }
+		ConnectionRegistry {
+			connection_map: RwLock::new(HashMap::default()),
+			progress_map: RwLock::new(ProgressMap {
+				requests: HashMap::default(),
+				unfinished: VecDeque::default(),
+				finished: VecDeque::default(),
+			}),
+		}
 	}
 }
 
 impl<Hash, Token> RpcConnectionRegistry for ConnectionRegistry<Hash, Token>
 where
 	Hash: RpcHash,
-	Token: Copy + Send + Sync + Debug,
+	Token: Copy + Send + Sync + Debug + PartialEq,
 {
 	type Hash = Hash;
 	type Connection = Token;
@@ -93,6 +145,66 @@ where
 			false
 		}
 	}
+
+	fn subscribe_progress(
+		&self,
+		hash: Self::Hash,
+		connection: Self::Connection,
+		rpc_response: RpcResponse,
+	) -> Option<(Vec<RequestProgressEvent>, bool)> {
+		log::info!("Subscribe connection {:?} to the progress of {:?}", connection, hash);
+		let mut map = self.progress_map.write().expect("Lock poisoning");
+		let state = map.requests.get_mut(&hash)?;
+		let events = state.events.values().cloned().collect();
+		let finished = state.is_finished();
+		if !finished {
+			state.subscribers.retain(|(c, _)| c != &connection);
+			state.subscribers.push((connection, rpc_response));
+		}
+		Some((events, finished))
+	}
+
+	fn publish_progress(
+		&self,
+		hash: &Self::Hash,
+		event: RequestProgressEvent,
+	) -> (Vec<(Self::Connection, RpcResponse)>, bool) {
+		let mut map = self.progress_map.write().expect("Lock poisoning");
+		if !map.requests.contains_key(hash) {
+			map.unfinished.push_back(hash.clone());
+			if map.unfinished.len() > UNFINISHED_PROGRESS_CAPACITY {
+				if let Some(evicted) = map.unfinished.pop_front() {
+					log::warn!("Drop the progress of unfinished request {:?}", evicted);
+					map.requests.remove(&evicted);
+				}
+			}
+		}
+		let state = map.requests.entry(hash.clone()).or_default();
+		let was_finished = state.is_finished();
+		state.events.insert(event.idx, event);
+		if !state.is_finished() {
+			return (state.subscribers.clone(), false)
+		}
+
+		let subscribers = core::mem::take(&mut state.subscribers);
+		if !was_finished {
+			map.unfinished.retain(|h| h != hash);
+			map.finished.push_back(hash.clone());
+			if map.finished.len() > FINISHED_PROGRESS_CAPACITY {
+				if let Some(evicted) = map.finished.pop_front() {
+					map.requests.remove(&evicted);
+				}
+			}
+		}
+		(subscribers, true)
+	}
+
+	fn unsubscribe_progress(&self, hash: &Self::Hash, connection: Self::Connection) {
+		let mut map = self.progress_map.write().expect("Lock poisoning");
+		if let Some(state) = map.requests.get_mut(hash) {
+			state.subscribers.retain(|(c, _)| c != &connection);
+		}
+	}
 }
 
 #[cfg(test)]
@@ -135,6 +247,84 @@ pub mod tests {
 		assert!(registry.is_empty());
 	}
 
+	#[test]
+	pub fn subscribing_to_progress_returns_events_so_far() {
+		let registry = TestRegistry::new();
+		let hash = "first".to_string();
+
+		assert!(registry.subscribe_progress(hash.clone(), 1, dummy_rpc_response()).is_none());
+
+		assert!(registry
+			.publish_progress(&hash, progress_event(0, RequestProgress::Queued))
+			.0
+			.is_empty());
+		let events = registry.subscribe_progress(hash.clone(), 1, dummy_rpc_response());
+		assert_eq!(events, Some((vec![progress_event(0, RequestProgress::Queued)], false)));
+
+		// re-attaching doesn't duplicate the subscription
+		registry.subscribe_progress(hash.clone(), 1, dummy_rpc_response());
+		registry.subscribe_progress(hash.clone(), 2, dummy_rpc_response());
+		let (subscribers, _) =
+			registry.publish_progress(&hash, progress_event(1, RequestProgress::Building));
+		assert_eq!(subscribers.iter().map(|s| s.0).collect::<Vec<_>>(), vec![1, 2]);
+
+		registry.unsubscribe_progress(&hash, 1);
+		let (subscribers, _) =
+			registry.publish_progress(&hash, progress_event(1, RequestProgress::Signed));
+		assert_eq!(subscribers.iter().map(|s| s.0).collect::<Vec<_>>(), vec![2]);
+	}
+
+	#[test]
+	pub fn finished_request_drops_subscriptions() {
+		let registry = TestRegistry::new();
+		let hash = "first".to_string();
+
+		registry.publish_progress(&hash, progress_event(0, RequestProgress::Queued));
+		registry.subscribe_progress(hash.clone(), 1, dummy_rpc_response());
+		registry.publish_progress(&hash, progress_event(0, RequestProgress::Completed(vec![])));
+		let (subscribers, finished) =
+			registry.publish_progress(&hash, progress_event(1, RequestProgress::Completed(vec![])));
+		assert_eq!(subscribers.len(), 1);
+		assert!(finished);
+
+		// the events are kept after the request is finished, but not the connection
+		let (events, finished) =
+			registry.subscribe_progress(hash.clone(), 2, dummy_rpc_response()).unwrap();
+		assert_eq!(events.len(), 2);
+		assert!(finished);
+		assert!(registry
+			.publish_progress(&hash, progress_event(1, RequestProgress::Completed(vec![])))
+			.0
+			.is_empty());
+	}
+
+	#[test]
+	pub fn unfinished_requests_are_capped() {
+		let registry = TestRegistry::new();
+		let hash = |i: usize| format!("request-{}", i);
+
+		registry.publish_progress(&hash(0), progress_event(0, RequestProgress::Queued));
+		registry.publish_progress(&hash(0), progress_event(0, RequestProgress::Completed(vec![])));
+		registry.publish_progress(&hash(0), progress_event(1, RequestProgress::Completed(vec![])));
+		for i in 1..=UNFINISHED_PROGRESS_CAPACITY {
+			registry.publish_progress(&hash(i), progress_event(0, RequestProgress::Queued));
+		}
+		assert!(registry.subscribe_progress(hash(1), 1, dummy_rpc_response()).is_some());
+
+		// the oldest unfinished request is dropped, but not the finished one
+		registry.publish_progress(
+			&hash(UNFINISHED_PROGRESS_CAPACITY + 1),
+			progress_event(0, RequestProgress::Queued),
+		);
+		assert!(registry.subscribe_progress(hash(1), 1, dummy_rpc_response()).is_none());
+		assert!(registry.subscribe_progress(hash(2), 1, dummy_rpc_response()).is_some());
+		assert!(registry.subscribe_progress(hash(0), 1, dummy_rpc_response()).is_some());
+	}
+
+	fn progress_event(idx: u8, progress: RequestProgress) -> RequestProgressEvent {
+		RequestProgressEvent { idx, len: 2, progress }
+	}
+
 	fn dummy_rpc_response() -> RpcResponse {
 		RpcResponse { jsonrpc: String::new(), result: Default::default(), id: Id::Number(1u32) }
 	}
@@ -19,9 +19,10 @@ use crate::{
 	response_channel::ResponseChannel, DirectRpcError, DirectRpcResult, RpcConnectionRegistry,
 	RpcHash, SendRpcResponse,
 };
-use alloc::format;
+use alloc::{format, vec};
+use codec::Encode;
 use itp_rpc::{RpcResponse, RpcReturnValue};
-use itp_types::{DirectRequestStatus, TrustedOperationStatus};
+use itp_types::{DirectRequestStatus, RequestProgressEvent, TrustedOperationStatus};
 use itp_utils::{FromHexPrefixed, ToHexPrefixed};
 use log::*;
 use std::{sync::Arc, vec::Vec};
@@ -231,6 +232,35 @@ where
 		Ok(())
 	}
 
+	fn send_request_progress(
+		&self,
+		hash: Self::Hash,
+		event: RequestProgressEvent,
+	) -> DirectRpcResult<()> {
+		debug!("Send request progress, hash: {:?}, event: {:?}", hash, event);
+
+		let top_hash = hash.maybe_h256().ok_or(DirectRpcError::HashConversionError)?;
+		let (subscribers, finished) =
+			self.connection_registry.publish_progress(&hash, event.clone());
+		let result = RpcReturnValue::new(
+			vec![event].encode(),
+			!finished,
+			DirectRequestStatus::RequestProgress(top_hash),
+		)
+		.to_hex();
+
+		for (connection_token, rpc_response) in subscribers {
+			let mut new_response = rpc_response;
+			new_response.result = result.clone();
+			if let Err(e) = self.encode_and_send_response(connection_token, &new_response) {
+				warn!("Failed to send request progress to {:?}: {:?}", connection_token, e);
+				self.connection_registry.unsubscribe_progress(&hash, connection_token);
+			}
+		}
+
+		Ok(())
+	}
+
 	fn swap_hash(&self, old_hash: Self::Hash, new_hash: Self::Hash) -> DirectRpcResult<()> {
 		debug!("swap hash, old: {:?}, new: {:?}", old_hash, new_hash);
 
@@ -70,6 +70,26 @@ where
 			_ => Ok(None),
 		}
 	}
+
+	fn must_be_subscribed(
+		&self,
+		rpc_response: &RpcResponse,
+	) -> DirectRpcResult<Option<Self::Hash>> {
+		let rpc_return_value = RpcReturnValue::from_hex(&rpc_response.result)
+			.map_err(|e| DirectRpcError::Other(format!("{:?}", e).into()))?;
+
+		if !rpc_return_value.do_watch {
+			return Ok(None)
+		}
+
+		match rpc_return_value.status {
+			DirectRequestStatus::RequestProgress(request_hash) =>
+				Self::Hash::decode::<_>(&mut request_hash.as_ref())
+					.map(Some)
+					.map_err(DirectRpcError::EncodingError),
+			_ => Ok(None),
+		}
+	}
 }
 
 #[cfg(test)]
@@ -127,4 +147,18 @@ pub mod tests {
 
 		assert_eq!(Some(hash), do_watch);
 	}
+
+	#[test]
+	fn rpc_response_with_request_progress_must_be_subscribed() {
+		let hash = H256::random();
+		let watch_extractor = RpcWatchExtractor::<H256>::new();
+		let rpc_return_value = RpcReturnValueBuilder::new()
+			.with_do_watch(true)
+			.with_status(DirectRequestStatus::RequestProgress(hash))
+			.build();
+		let rpc_response = RpcResponseBuilder::new().with_result(rpc_return_value).build();
+
+		assert_eq!(None, watch_extractor.must_be_watched(&rpc_response).unwrap());
+		assert_eq!(Some(hash), watch_extractor.must_be_subscribed(&rpc_response).unwrap());
+	}
 }
@@ -19,7 +19,11 @@
 use crate::sgx_reexport_prelude::*;
 
 use crate::{DetermineWatch, RpcConnectionRegistry, RpcHash};
+use codec::Encode;
 use itc_tls_websocket_server::{error::WebSocketResult, ConnectionToken, WebSocketMessageHandler};
+use itp_rpc::{RpcResponse, RpcReturnValue};
+use itp_types::DirectRequestStatus;
+use itp_utils::ToHexPrefixed;
 use jsonrpc_core::IoHandler;
 use log::*;
 use std::{string::String, sync::Arc};
@@ -48,6 +52,28 @@ where
 	) -> Self {
 		RpcWsHandler { rpc_io_handler, connection_watcher, connection_registry }
 	}
+
+	// Litentry: subscribe the connection to the progress of the request, the response carries
+	// the progress so far, so that a client can re-attach to an in-flight request.
+	fn subscribe_progress(
+		&self,
+		request_hash: Hash,
+		connection: Registry::Connection,
+		mut rpc_response: RpcResponse,
+	) -> RpcResponse {
+		let status =
+			DirectRequestStatus::RequestProgress(request_hash.maybe_h256().unwrap_or_default());
+		let return_value = match self.connection_registry.subscribe_progress(
+			request_hash,
+			connection,
+			rpc_response.clone(),
+		) {
+			Some((events, finished)) => RpcReturnValue::new(events.encode(), !finished, status),
+			None => RpcReturnValue::from_error_message("Unknown request hash"),
+		};
+		rpc_response.result = return_value.to_hex();
+		rpc_response
+	}
 }
 
 impl<Watcher, Registry, Hash> WebSocketMessageHandler for RpcWsHandler<Watcher, Registry, Hash>
@@ -69,6 +95,14 @@ where
 		if let Ok(rpc_response) =
 			serde_json::from_str(maybe_rpc_response.clone().unwrap_or_default().as_str())
 		{
+			if let Ok(Some(request_hash)) =
+				self.connection_watcher.must_be_subscribed(&rpc_response)
+			{
+				let rpc_response =
+					self.subscribe_progress(request_hash, connection_token.into(), rpc_response);
+				return Ok(serde_json::to_string(&rpc_response).ok())
+			}
+
 			if let Ok(Some(connection_hash)) =
 				self.connection_watcher.must_be_watched(&rpc_response)
 			{
@@ -56,6 +56,8 @@ pub enum Error {
 	ImportScheduledEnclave(ScheduledEnclaveError),
 	#[error("AssertionCreated handling error: {0:?}")]
 	AssertionCreatedHandling(String),
+	#[error("AssertionRemoved handling error: {0:?}")]
+	AssertionRemovedHandling(String),
 }
 
 impl From<ParentchainEventProcessingError> for Error {
@@ -4,7 +4,7 @@ use itp_test::mock::stf_mock::TrustedCallSignedMock;
 use itp_types::{
 	parentchain::{
 		events::{
-			ActivateIdentityRequested, AssertionCreated, DeactivateIdentityRequested,
+			ActivateIdentityRequested, AssertionEvent, DeactivateIdentityRequested,
 			LinkIdentityRequested, OpaqueTaskPosted, ScheduledEnclaveRemoved, ScheduledEnclaveSet,
 			VCRequested,
 		},
@@ -67,7 +67,7 @@ impl FilterEvents for MockEvents {
 		Ok(Vec::new())
 	}
 
-	fn get_assertion_created_events(&self) -> Result<Vec<AssertionCreated>, Self::Error> {
+	fn get_assertion_events(&self) -> Result<Vec<AssertionEvent>, Self::Error> {
 		Ok(Vec::new())
 	}
 }
@@ -138,7 +138,9 @@ where
 						}
 					},
 
-					DirectRequestStatus::Ok | DirectRequestStatus::Error => {
+					DirectRequestStatus::Ok
+					| DirectRequestStatus::Error
+					| DirectRequestStatus::RequestProgress(_) => {
 						log::warn!(
 							"Got unexpected direct request status: {:?}",
 							rpc_return_value.status
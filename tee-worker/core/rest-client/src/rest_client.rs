@@ -18,7 +18,10 @@
 #[cfg(all(not(feature = "std"), feature = "sgx"))]
 use crate::sgx_reexport_prelude::*;
 
-pub use http_req::{request::Method, response::Headers};
+pub use http_req::{
+	request::Method,
+	response::{Headers, Response},
+};
 pub use url::{form_urlencoded, Url};
 
 use crate::{
@@ -62,6 +65,11 @@ where
 		self.body_wash_fn = func;
 	}
 
+	/// Base url the requests are sent to
+	pub fn base_url(&self) -> &Url {
+		&self.baseurl
+	}
+
 	/// Response headers captured from previous request
 	pub fn response_headers(&mut self) -> &Headers {
 		&self.response_headers
@@ -91,7 +91,7 @@ use its_sidechain::{
 	slots::FailSlotOnDemand,
 };
 use lazy_static::lazy_static;
-use lc_data_providers::DataProviderConfig;
+use lc_data_providers::config_repository::DataProviderConfigRepository;
 use lc_evm_dynamic_assertions::{repository::EvmAssertionRepository, sealing::io::AssertionsSeal};
 use lc_scheduled_enclave::ScheduledEnclaveSeal as EnclaveScheduledEnclaveSeal;
 use litentry_primitives::BroadcastedRequest;
@@ -506,5 +506,5 @@ pub static GLOBAL_SIDECHAIN_FAIL_SLOT_ON_DEMAND_COMPONENT: ComponentContainer<
 	Option<FailSlotOnDemand>,
 > = ComponentContainer::new("sidechain_fail_slot_on_demand");
 
-pub static GLOBAL_DATA_PROVIDER_CONFIG: ComponentContainer<DataProviderConfig> =
+pub static GLOBAL_DATA_PROVIDER_CONFIG: ComponentContainer<DataProviderConfigRepository> =
 	ComponentContainer::new("data_provider_config");
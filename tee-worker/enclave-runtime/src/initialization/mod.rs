@@ -67,8 +67,9 @@ use itp_attestation_handler::{AttestationHandler, IntelAttestationHandler};
 use itp_component_container::{ComponentGetter, ComponentInitializer};
 use itp_primitives_cache::GLOBAL_PRIMITIVES_CACHE;
 use itp_settings::files::{
-	ASSERTIONS_FILE, LITENTRY_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, STATE_SNAPSHOTS_CACHE_SIZE,
-	TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
+	ASSERTIONS_FILE, DATA_PROVIDER_CONFIG_FILE, LITENTRY_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
+	STATE_SNAPSHOTS_CACHE_SIZE, TARGET_A_PARENTCHAIN_LIGHT_CLIENT_DB_PATH,
+	TARGET_B_PARENTCHAIN_LIGHT_CLIENT_DB_PATH, VC_JOBS_PATH,
 };
 use itp_sgx_crypto::{
 	get_aes_repository, get_ed25519_repository, get_rsa3072_repository, key_repository::AccessKey,
@@ -85,11 +86,11 @@ use its_sidechain::{
 	block_composer::BlockComposer,
 	slots::{FailSlotMode, FailSlotOnDemand},
 };
-use lc_data_providers::DataProviderConfig;
+use lc_data_providers::{config_repository::DataProviderConfigRepository, DataProviderConfig};
 use lc_evm_dynamic_assertions::repository::EvmAssertionRepository;
 use lc_scheduled_enclave::{ScheduledEnclaveUpdater, GLOBAL_SCHEDULED_ENCLAVE};
 use lc_stf_task_receiver::{run_stf_task_receiver, StfTaskContext};
-use lc_vc_task_receiver::run_vc_handler_runner;
+use lc_vc_task_receiver::{run_vc_handler_runner, VcJobQueue, VcTaskConfig};
 use litentry_primitives::BroadcastedRequest;
 use log::*;
 use sgx_types::sgx_status_t;
@@ -202,11 +203,10 @@ pub(crate) fn init_enclave(
 	GLOBAL_DIRECT_RPC_BROADCASTER_COMPONENT.initialize(broadcaster);
 	DIRECT_RPC_REQUEST_SINK_COMPONENT.initialize(request_sink);
 
-	if let Ok(data_provider_config) = DataProviderConfig::new() {
-		GLOBAL_DATA_PROVIDER_CONFIG.initialize(data_provider_config.into());
-	} else {
-		return Err(Error::Other("data provider initialize error".into()))
-	}
+	let data_provider_config = DataProviderConfig::new()
+		.and_then(|config| DataProviderConfigRepository::new(config, DATA_PROVIDER_CONFIG_FILE))
+		.map_err(|e| Error::Other(format!("data provider initialize error: {:?}", e).into()))?;
+	GLOBAL_DATA_PROVIDER_CONFIG.initialize(data_provider_config.into());
 
 	let data_provider_config = GLOBAL_DATA_PROVIDER_CONFIG.get()?;
 	let getter_executor = Arc::new(EnclaveGetterExecutor::new(state_observer));
@@ -306,8 +306,15 @@ fn run_vc_issuance() -> Result<(), Error> {
 	);
 	let extrinsic_factory = get_extrinsic_factory_from_integritee_solo_or_parachain()?;
 	let node_metadata_repo = get_node_metadata_repository_from_integritee_solo_or_parachain()?;
-
-	run_vc_handler_runner(Arc::new(stf_task_context), extrinsic_factory, node_metadata_repo);
+	let job_queue = Arc::new(VcJobQueue::new(VC_JOBS_PATH).map_err(|e| Error::Other(e.into()))?);
+
+	run_vc_handler_runner(
+		Arc::new(stf_task_context),
+		extrinsic_factory,
+		node_metadata_repo,
+		job_queue,
+		VcTaskConfig::from_env(),
+	);
 
 	Ok(())
 }
@@ -25,9 +25,9 @@ use crate::{
 		get_validator_accessor_from_integritee_solo_or_parachain,
 	},
 };
-use codec::Encode;
+use codec::{Decode, Encode};
 use core::result::Result;
-use ita_sgx_runtime::{Runtime, System};
+use ita_sgx_runtime::{Runtime, Sudo, System};
 use ita_stf::{Getter, TrustedCallSigned};
 use itc_parentchain::light_client::{concurrent_access::ValidatorAccess, ExtrinsicSender};
 use itp_primitives_cache::{GetPrimitives, GLOBAL_PRIMITIVES_CACHE};
@@ -48,8 +48,10 @@ use its_sidechain::rpc_handler::{
 	direct_top_pool_api, direct_top_pool_api::decode_shard_from_base58, import_block_api,
 };
 use jsonrpc_core::{serde_json::json, IoHandler, Params, Value};
-use lc_data_providers::DataProviderConfig;
-use lc_identity_verification::web2::twitter;
+use lc_data_providers::config_repository::{
+	DataProviderConfigRepository, SignedDataProviderConfigUpdate,
+};
+use lc_identity_verification::web2::{discord, github, twitter};
 use lc_scheduled_enclave::{ScheduledEnclaveUpdater, GLOBAL_SCHEDULED_ENCLAVE};
 use litentry_macros::{if_development, if_development_or};
 use litentry_primitives::{DecryptableRequest, Identity};
@@ -57,7 +59,13 @@ use log::debug;
 use sgx_crypto_helper::rsa3072::Rsa3072PubKey;
 use sp_core::Pair;
 use sp_runtime::OpaqueExtrinsic;
-use std::{borrow::ToOwned, format, str, string::String, sync::Arc, vec::Vec};
+use std::{
+	borrow::ToOwned,
+	format, str,
+	string::{String, ToString},
+	sync::Arc,
+	vec::Vec,
+};
 
 fn compute_hex_encoded_return_error(error_msg: &str) -> String {
 	RpcReturnValue::from_error_message(error_msg).to_hex()
@@ -78,7 +86,7 @@ pub fn public_api_rpc_handler<Author, GetterExecutor, AccessShieldingKey, State>
 	getter_executor: Arc<GetterExecutor>,
 	shielding_key: Arc<AccessShieldingKey>,
 	state: Option<Arc<State>>,
-	data_provider_config: Arc<DataProviderConfig>,
+	data_provider_config: Arc<DataProviderConfigRepository>,
 ) -> IoHandler
 where
 	Author: AuthorApi<H256, H256, TrustedCallSigned, Getter> + Send + Sync + 'static,
@@ -118,6 +126,9 @@ where
 		Ok(json!(json_value.to_hex()))
 	});
 
+	let data_provider_config_shielding_key = shielding_key.clone();
+	let data_provider_config_state = state.clone();
+
 	// author_getEnclaveSignerAccount
 	let rsa_pubkey_name: &str = "author_getEnclaveSignerAccount";
 	io.add_sync_method(rsa_pubkey_name, move |_: Params| {
@@ -270,6 +281,32 @@ where
 		Ok(json!(json_value.to_hex()))
 	});
 
+	// author_subscribeRequestProgress, params: hex encoded hash of the request.
+	// The connection is subscribed by the rpc ws handler, which replies with the
+	// `RequestProgressEvent`s so far, the following ones are pushed as they happen.
+	// Only the VC requests handled by the vc-task (`request_vc`, `request_batch_vc`) report
+	// their progress, the identity requests (`link_identity` etc.) are unknown to it.
+	io.add_sync_method("author_subscribeRequestProgress", |params: Params| {
+		debug!("worker_api_direct rpc was called: author_subscribeRequestProgress");
+		let json_value = match params
+			.parse::<Vec<String>>()
+			.map_err(|e| format!("{:?}", e))
+			.and_then(|params| {
+				params.get(0).cloned().ok_or("Could not get first param".to_string())
+			})
+			.and_then(|hash_hex| H256::from_hex(&hash_hex).map_err(|e| format!("{:?}", e)))
+		{
+			Ok(hash) =>
+				RpcReturnValue::new(vec![], true, DirectRequestStatus::RequestProgress(hash))
+					.to_hex(),
+			Err(e) => {
+				let error_msg = format!("Could not subscribe to request progress due to: {}", e);
+				compute_hex_encoded_return_error(error_msg.as_str())
+			},
+		};
+		Ok(json!(json_value))
+	});
+
 	io.add_sync_method("chain_subscribeAllHeads", |_: Params| {
 		debug!("worker_api_direct rpc was called: chain_subscribeAllHeads");
 		let parsed = "world";
@@ -475,6 +512,7 @@ where
 		Ok(Value::String(format!("hello, {}", parsed)))
 	});
 
+	let twitter_data_provider_config = data_provider_config.clone();
 	io.add_sync_method("identity_getTwitterAuthorizeUrl", move |params: Params| {
 		debug!("worker_api_direct rpc was called: identity_getTwitterAuthorizeUrl");
 
@@ -492,10 +530,14 @@ where
 							"Could not parse identity"
 						))),
 				};
-				let authorize_data = twitter::get_authorize_data(
-					&data_provider_config.twitter_client_id,
-					&redirect_url,
-				);
+				let client_id = match twitter_data_provider_config.get() {
+					Ok(config) => config.twitter_client_id.clone(),
+					Err(_) =>
+						return Ok(json!(compute_hex_encoded_return_error(
+							"Could not get data provider config"
+						))),
+				};
+				let authorize_data = twitter::get_authorize_data(&client_id, &redirect_url);
 				match twitter::OAuthStore::save_data(
 					account_id,
 					authorize_data.code_verifier,
@@ -518,6 +560,132 @@ where
 		}
 	});
 
+	let discord_data_provider_config = data_provider_config.clone();
+	io.add_sync_method("identity_getDiscordAuthorizeUrl", move |params: Params| {
+		debug!("worker_api_direct rpc was called: identity_getDiscordAuthorizeUrl");
+
+		match params.parse::<(String, String)>() {
+			Ok((encoded_did, redirect_url)) => {
+				let account_id = match Identity::from_did(encoded_did.as_str()) {
+					Ok(identity) =>
+						if let Some(account_id) = identity.to_account_id() {
+							account_id
+						} else {
+							return Ok(json!(compute_hex_encoded_return_error("Invalid identity")))
+						},
+					Err(_) =>
+						return Ok(json!(compute_hex_encoded_return_error(
+							"Could not parse identity"
+						))),
+				};
+				let client_id = match discord_data_provider_config.get() {
+					Ok(config) => config.discord_client_id.clone(),
+					Err(_) =>
+						return Ok(json!(compute_hex_encoded_return_error(
+							"Could not get data provider config"
+						))),
+				};
+				let authorize_data = discord::get_authorize_data(&client_id, &redirect_url);
+				match discord::OAuthStore::save_data(
+					account_id,
+					authorize_data.code_verifier,
+					authorize_data.state,
+				) {
+					Ok(_) => {
+						let json_value = RpcReturnValue::new(
+							authorize_data.authorize_url.encode(),
+							false,
+							DirectRequestStatus::Ok,
+						);
+						Ok(json!(json_value.to_hex()))
+					},
+					Err(_) =>
+						Ok(json!(compute_hex_encoded_return_error("Could not save code verifier"))),
+				}
+			},
+
+			Err(_) => Ok(json!(compute_hex_encoded_return_error("Could not parse params"))),
+		}
+	});
+
+	let github_data_provider_config = data_provider_config.clone();
+	io.add_sync_method("identity_getGithubAuthorizeUrl", move |params: Params| {
+		debug!("worker_api_direct rpc was called: identity_getGithubAuthorizeUrl");
+
+		match params.parse::<(String, String)>() {
+			Ok((encoded_did, redirect_url)) => {
+				let account_id = match Identity::from_did(encoded_did.as_str()) {
+					Ok(identity) =>
+						if let Some(account_id) = identity.to_account_id() {
+							account_id
+						} else {
+							return Ok(json!(compute_hex_encoded_return_error("Invalid identity")))
+						},
+					Err(_) =>
+						return Ok(json!(compute_hex_encoded_return_error(
+							"Could not parse identity"
+						))),
+				};
+				let client_id = match github_data_provider_config.get() {
+					Ok(config) => config.github_client_id.clone(),
+					Err(_) =>
+						return Ok(json!(compute_hex_encoded_return_error(
+							"Could not get data provider config"
+						))),
+				};
+				let authorize_data = github::get_authorize_data(&client_id, &redirect_url);
+				match github::OAuthStore::save_state(account_id, authorize_data.state) {
+					Ok(_) => {
+						let json_value = RpcReturnValue::new(
+							authorize_data.authorize_url.encode(),
+							false,
+							DirectRequestStatus::Ok,
+						);
+						Ok(json!(json_value.to_hex()))
+					},
+					Err(_) => Ok(json!(compute_hex_encoded_return_error("Could not save state"))),
+				}
+			},
+
+			Err(_) => Ok(json!(compute_hex_encoded_return_error("Could not parse params"))),
+		}
+	});
+
+	// state_getDataProviderConfig, returns the json of the config in use, with the secrets redacted
+	let get_data_provider_config = data_provider_config.clone();
+	io.add_sync_method("state_getDataProviderConfig", move |_: Params| {
+		debug!("worker_api_direct rpc was called: state_getDataProviderConfig");
+		let json_value = match get_data_provider_config
+			.get()
+			.map_err(|e| format!("{:?}", e))
+			.and_then(|config| serde_json::to_string(&config.redacted()).map_err(|e| e.to_string()))
+		{
+			Ok(config_json) =>
+				RpcReturnValue::new(config_json.encode(), false, DirectRequestStatus::Ok).to_hex(),
+			Err(e) => {
+				let error_msg = format!("Could not get data provider config due to: {}", e);
+				compute_hex_encoded_return_error(error_msg.as_str())
+			},
+		};
+		Ok(json!(json_value))
+	});
+
+	// state_updateDataProviderConfig, params: hex encoded `RsaRequest`, whose payload is a
+	// `SignedDataProviderConfigUpdate` encrypted to the shielding key
+	io.add_sync_method("state_updateDataProviderConfig", move |params: Params| {
+		debug!("worker_api_direct rpc was called: state_updateDataProviderConfig");
+		let json_value = match update_data_provider_config_inner(
+			data_provider_config_shielding_key.as_ref(),
+			data_provider_config_state.as_deref(),
+			data_provider_config.as_ref(),
+			params,
+		) {
+			Ok(()) => RpcReturnValue::new(vec![], false, DirectRequestStatus::Ok).to_hex(),
+			Err(error) => compute_hex_encoded_return_error(error.as_str()),
+		};
+		Ok(json!(json_value))
+	});
+
 	let rpc_methods_string = get_all_rpc_methods_string(&io);
 	io.add_sync_method("rpc_methods", move |_: Params| {
 		debug!("worker_api_direct rpc was called: rpc_methods");
@@ -548,6 +716,55 @@ fn execute_getter_inner<GE: ExecuteGetter>(
 	Ok(getter_result)
 }
 
+fn update_data_provider_config_inner<AccessShieldingKey, State>(
+	shielding_key: &AccessShieldingKey,
+	state: Option<&State>,
+	data_provider_config: &DataProviderConfigRepository,
+	params: Params,
+) -> Result<(), String>
+where
+	AccessShieldingKey: AccessKey,
+	AccessShieldingKey::KeyType: ShieldingCryptoDecrypt,
+	State: HandleState,
+	State::StateT: SgxExternalitiesTrait,
+{
+	let hex_encoded_params = params.parse::<Vec<String>>().map_err(|e| format!("{:?}", e))?;
+
+	let param = &hex_encoded_params.get(0).ok_or("Could not get first param")?;
+	let request = RsaRequest::from_hex(param).map_err(|e| format!("{:?}", e))?;
+	let shard = request.shard();
+
+	let payload = shielding_key
+		.retrieve_key()
+		.map_err(|e| format!("{:?}", e))?
+		.decrypt(request.payload())
+		.map_err(|_| "Could not decrypt request")?;
+	let signed_update = SignedDataProviderConfigUpdate::decode(&mut payload.as_slice())
+		.map_err(|e| format!("Could not decode config update: {:?}", e))?;
+
+	let mrenclave = GLOBAL_SCHEDULED_ENCLAVE
+		.get_current_mrenclave()
+		.map_err(|e| format!("{:?}", e))?;
+	if !signed_update.verify(&mrenclave, &shard) {
+		return Err("Invalid signature".into())
+	}
+
+	// only the root account of the shard is allowed to update the config
+	let (mut state, _) = state
+		.ok_or("State is not available")?
+		.load_cloned(&shard)
+		.map_err(|e| format!("Could not load shard state: {:?}", e))?;
+	let root = state.execute_with(Sudo::key);
+	match (signed_update.signer.to_account_id(), root) {
+		(Some(signer), Some(root)) if signer == root => {},
+		_ => return Err("Signer is not the root account of the shard".into()),
+	}
+
+	data_provider_config
+		.update(signed_update.nonce, signed_update.update)
+		.map_err(|e| format!("Could not update data provider config: {:?}", e))
+}
+
 fn forward_dcap_quote_inner(params: Params) -> Result<OpaqueExtrinsic, String> {
 	let hex_encoded_params = params.parse::<Vec<String>>().map_err(|e| format!("{:?}", e))?;
 
@@ -33,7 +33,7 @@ use itp_test::mock::handle_state_mock::HandleStateMock;
 use itp_top_pool_author::mocks::AuthorApiMock;
 use itp_types::{DirectRequestStatus, RsaRequest, ShardIdentifier};
 use itp_utils::{FromHexPrefixed, ToHexPrefixed};
-use lc_data_providers::DataProviderConfig;
+use lc_data_providers::{config_repository::DataProviderConfigRepository, DataProviderConfig};
 use litentry_primitives::{Address32, Identity};
 use std::{string::ToString, sync::Arc, vec::Vec};
 
@@ -51,8 +51,11 @@ pub fn get_state_request_works() {
 	let getter_executor =
 		Arc::new(GetterExecutor::<_, GetStateMock<TestState>, Getter>::new(state_observer));
 	let top_pool_author = Arc::new(AuthorApiMock::default());
-	let data_provider_config =
-		DataProviderConfig::new().expect("Failed to create DataProviderConfig");
+	let data_provider_config = DataProviderConfigRepository::new(
+		DataProviderConfig::new().expect("Failed to create DataProviderConfig"),
+		temp_dir.path().join("data_provider_config").to_str().unwrap(),
+	)
+	.expect("Failed to create DataProviderConfigRepository");
 
 	let io_handler = public_api_rpc_handler(
 		top_pool_author,
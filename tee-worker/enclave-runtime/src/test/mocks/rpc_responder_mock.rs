@@ -16,7 +16,7 @@
 */
 
 use itc_direct_rpc_server::{DirectRpcResult, RpcHash, SendRpcResponse};
-use itp_types::TrustedOperationStatus;
+use itp_types::{RequestProgressEvent, TrustedOperationStatus};
 use std::{marker::PhantomData, vec::Vec};
 
 pub struct RpcResponderMock<Hash> {
@@ -74,6 +74,14 @@ where
 		Ok(())
 	}
 
+	fn send_request_progress(
+		&self,
+		_hash: Self::Hash,
+		_event: RequestProgressEvent,
+	) -> DirectRpcResult<()> {
+		Ok(())
+	}
+
 	fn swap_hash(&self, _old_hash: Self::Hash, _new_hash: Self::Hash) -> DirectRpcResult<()> {
 		Ok(())
 	}
@@ -166,6 +166,7 @@ pub extern "C" fn test_main_entrance() -> size_t {
 
 		// evm assertions test
 		lc_evm_dynamic_assertions::repository::sgx_tests::restores_state_from_seal,
+		lc_evm_dynamic_assertions::repository::sgx_tests::removes_assertion_from_seal,
 
 		// these unit test (?) need an ipfs node running..
 		// ipfs::test_creates_ipfs_content_struct_works,
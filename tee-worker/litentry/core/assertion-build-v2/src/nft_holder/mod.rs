@@ -46,9 +46,12 @@ pub fn build(
 	let result = has_nft(nft_type.clone(), addresses, data_provider_config).map_err(|e| {
 		Error::RequestVCFailed(
 			Assertion::NftHolder(nft_type.clone()),
-			ErrorDetail::DataProviderError(ErrorString::truncate_from(
-				format!("{e:?}").as_bytes().to_vec(),
-			)),
+			match e {
+				ErrorDetail::DataProviderUnavailable(_) => e,
+				_ => ErrorDetail::DataProviderError(ErrorString::truncate_from(
+					format!("{e:?}").as_bytes().to_vec(),
+				)),
+			},
 		)
 	});
 
@@ -49,9 +49,12 @@ pub fn build(
 		is_user(platform_user_type.clone(), addresses, data_provider_config).map_err(|e| {
 			Error::RequestVCFailed(
 				Assertion::PlatformUser(platform_user_type.clone()),
-				ErrorDetail::DataProviderError(ErrorString::truncate_from(
-					format!("{e:?}").as_bytes().to_vec(),
-				)),
+				match e {
+					ErrorDetail::DataProviderUnavailable(_) => e,
+					_ => ErrorDetail::DataProviderError(ErrorString::truncate_from(
+						format!("{e:?}").as_bytes().to_vec(),
+					)),
+				},
 			)
 		});
 
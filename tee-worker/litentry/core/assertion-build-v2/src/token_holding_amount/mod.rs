@@ -47,9 +47,12 @@ pub fn build(
 		get_token_balance(token_type.clone(), addresses, data_provider_config).map_err(|e| {
 			Error::RequestVCFailed(
 				Assertion::TokenHoldingAmount(token_type.clone()),
-				ErrorDetail::DataProviderError(ErrorString::truncate_from(
-					format!("{e:?}").as_bytes().to_vec(),
-				)),
+				match e {
+					ErrorDetail::DataProviderUnavailable(_) => e,
+					_ => ErrorDetail::DataProviderError(ErrorString::truncate_from(
+						format!("{e:?}").as_bytes().to_vec(),
+					)),
+				},
 			)
 		});
 
@@ -32,7 +32,7 @@ use itc_rest_client::{
 	rest_client::RestClient,
 	RestPath, RestPost,
 };
-use lc_data_providers::{build_client_with_cert, DataProviderConfig};
+use lc_data_providers::{build_client_with_cert, http_error_detail, DataProviderConfig};
 use serde::{Deserialize, Serialize};
 
 const VC_A14_SUBJECT_DESCRIPTION: &str =
@@ -86,14 +86,7 @@ impl A14Client {
 	pub fn send_request(&mut self, data: &A14Data) -> Result<A14Response> {
 		self.client
 			.post_capture::<String, A14Data, A14Response>(String::default(), data)
-			.map_err(|e| {
-				Error::RequestVCFailed(
-					Assertion::A14,
-					ErrorDetail::DataProviderError(ErrorString::truncate_from(
-						format!("{e:?}").as_bytes().to_vec(),
-					)),
-				)
-			})
+			.map_err(|e| Error::RequestVCFailed(Assertion::A14, http_error_detail(e)))
 	}
 }
 
@@ -23,7 +23,7 @@ extern crate sgx_tstd as std;
 use http::header::CONNECTION;
 use http_req::response::Headers;
 use itc_rest_client::{error::Error as RestClientError, RestGet, RestPath};
-use lc_data_providers::{build_client_with_cert, DataProviderConfig};
+use lc_data_providers::{build_client_with_cert, http_error_detail, DataProviderConfig};
 use serde::{Deserialize, Serialize};
 
 #[cfg(all(not(feature = "std"), feature = "sgx"))]
@@ -70,14 +70,7 @@ pub fn build(
 	let value = client
 		.get_with::<String, EarlyBirdResponse>("".to_string(), query.as_slice())
 		.map(|data| data.has_joined)
-		.map_err(|e| {
-			Error::RequestVCFailed(
-				Assertion::A20,
-				ErrorDetail::DataProviderError(ErrorString::truncate_from(
-					format!("{e:?}").as_bytes().to_vec(),
-				)),
-			)
-		})?;
+		.map_err(|e| Error::RequestVCFailed(Assertion::A20, http_error_detail(e)))?;
 	match Credential::new(&req.who, &req.shard) {
 		Ok(mut credential_unsigned) => {
 			credential_unsigned.add_subject_info(VC_A20_SUBJECT_DESCRIPTION, VC_A20_SUBJECT_TYPE);
@@ -36,14 +36,9 @@ pub fn build(
 
 	let mut client = GeniidataClient::new(data_provider_config)
 		.map_err(|e| Error::RequestVCFailed(Assertion::BRC20AmountHolder, e))?;
-	let response = client.create_brc20_amount_holder_sum(addresses).map_err(|e| {
-		Error::RequestVCFailed(
-			Assertion::BRC20AmountHolder,
-			ErrorDetail::DataProviderError(ErrorString::truncate_from(
-				format!("{e:?}").as_bytes().to_vec(),
-			)),
-		)
-	})?;
+	let response = client
+		.create_brc20_amount_holder_sum(addresses)
+		.map_err(|e| Error::RequestVCFailed(Assertion::BRC20AmountHolder, e.into_error_detail()))?;
 
 	let mut credential_unsigned = Credential::new(&req.who, &req.shard).map_err(|e| {
 		error!("Generate unsigned credential failed {:?}", e);
@@ -42,6 +42,15 @@ abstract contract DynamicAssertion {
         bool
     );
 
+    // validity period of credentials which never expire
+    uint64 internal constant NEVER_EXPIRES = type(uint64).max;
+
+    // validity period of the issued credential in seconds, 0 means the default validity applies,
+    // NEVER_EXPIRES that the credential doesn't expire
+    function validityPeriod() public view virtual returns (uint64) {
+        return 0;
+    }
+
     function encode_params(string memory url, string memory jsonPointer)
     internal
     pure
@@ -0,0 +1,98 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+pragma solidity ^0.8.8;
+
+import {HttpHeader} from "../DynamicAssertion.sol";
+
+// Single value http calls, each call performs a request.
+// Reverts if the request fails or there is no value of expected type under `jsonPointer`.
+library Http {
+    function GetI64(
+        string memory url,
+        string memory jsonPointer,
+        HttpHeader[] memory headers
+    ) internal returns (int64) {
+        bytes memory value = callPrecompile(0x03E8, abi.encode(url, jsonPointer, headers));
+        return abi.decode(value, (int64));
+    }
+
+    function GetBool(
+        string memory url,
+        string memory jsonPointer,
+        HttpHeader[] memory headers
+    ) internal returns (bool) {
+        bytes memory value = callPrecompile(0x03E9, abi.encode(url, jsonPointer, headers));
+        return abi.decode(value, (bool));
+    }
+
+    function GetString(
+        string memory url,
+        string memory jsonPointer,
+        HttpHeader[] memory headers
+    ) internal returns (string memory) {
+        bytes memory value = callPrecompile(0x03EA, abi.encode(url, jsonPointer, headers));
+        return abi.decode(value, (string));
+    }
+
+    function PostI64(
+        string memory url,
+        string memory jsonPointer,
+        string memory payload,
+        HttpHeader[] memory headers
+    ) internal returns (int64) {
+        bytes memory value = callPrecompile(
+            0x03EB,
+            abi.encode(url, jsonPointer, payload, headers)
+        );
+        return abi.decode(value, (int64));
+    }
+
+    function PostBool(
+        string memory url,
+        string memory jsonPointer,
+        string memory payload,
+        HttpHeader[] memory headers
+    ) internal returns (bool) {
+        bytes memory value = callPrecompile(
+            0x03EC,
+            abi.encode(url, jsonPointer, payload, headers)
+        );
+        return abi.decode(value, (bool));
+    }
+
+    function PostString(
+        string memory url,
+        string memory jsonPointer,
+        string memory payload,
+        HttpHeader[] memory headers
+    ) internal returns (string memory) {
+        bytes memory value = callPrecompile(
+            0x03ED,
+            abi.encode(url, jsonPointer, payload, headers)
+        );
+        return abi.decode(value, (string));
+    }
+
+    function callPrecompile(uint160 precompile, bytes memory params)
+    private
+    returns (bytes memory)
+    {
+        (bool success, bytes memory value) = address(precompile).call(params);
+        require(success, "http precompile call failed");
+        return value;
+    }
+}
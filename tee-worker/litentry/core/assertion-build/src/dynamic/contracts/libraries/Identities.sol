@@ -0,0 +1,69 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+pragma solidity ^0.8.8;
+
+// Converts identity values to the address strings expected by data providers.
+// Reverts if the value is not a valid key/address of the expected kind.
+library Identities {
+    // `prefix` is the ss58 network prefix, e.g. 0 for polkadot, 2 for kusama, 31 for litentry
+    function toSS58Address(bytes memory pubkey, uint16 prefix)
+    internal
+    returns (string memory)
+    {
+        bytes memory value = callPrecompile(0x041C, abi.encode(pubkey, prefix));
+        return abi.decode(value, (string));
+    }
+
+    // `pubkey` is the 33 bytes compressed pubkey of a bitcoin identity
+    function toBitcoinP2trAddress(bytes memory pubkey)
+    internal
+    returns (string memory)
+    {
+        bytes memory value = callPrecompile(0x041D, abi.encode(pubkey));
+        return abi.decode(value, (string));
+    }
+
+    function toBitcoinP2wpkhAddress(bytes memory pubkey)
+    internal
+    returns (string memory)
+    {
+        bytes memory value = callPrecompile(0x041E, abi.encode(pubkey));
+        return abi.decode(value, (string));
+    }
+
+    // EIP-55 checksummed address string
+    function toChecksumAddress(bytes memory evmAddress)
+    internal
+    returns (string memory)
+    {
+        require(evmAddress.length == 20, "invalid evm address length");
+        bytes memory value = callPrecompile(
+            0x041F,
+            abi.encode(address(bytes20(evmAddress)))
+        );
+        return abi.decode(value, (string));
+    }
+
+    function callPrecompile(uint160 precompile, bytes memory params)
+    private
+    returns (bytes memory)
+    {
+        (bool success, bytes memory value) = address(precompile).call(params);
+        require(success, "identity precompile call failed");
+        return value;
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+pragma solidity ^0.8.8;
+
+import {HttpHeader} from "../DynamicAssertion.sol";
+
+// "Fetch once, query many" json api. `Get`/`Post` perform the request and return a handle
+// to the response, which is valid until the end of the assertion execution. Values are
+// read with json pointers, e.g. "/data/0/name".
+//
+// Example, iterating over an array:
+//   uint256 response = Json.Get(url, headers);
+//   int64 len = Json.GetArrayLen(response, "/data");
+//   for (int64 i = 0; i < len; i++) {
+//       string memory name = Json.GetString(
+//           response,
+//           string(abi.encodePacked("/data/", Strings.toString(uint64(i)), "/name"))
+//       );
+//   }
+library Json {
+    function Get(string memory url, HttpHeader[] memory headers)
+    internal
+    returns (uint256)
+    {
+        bytes memory value = callPrecompile(0x03FC, abi.encode(url, headers));
+        return abi.decode(value, (uint256));
+    }
+
+    function Post(
+        string memory url,
+        string memory payload,
+        HttpHeader[] memory headers
+    ) internal returns (uint256) {
+        bytes memory value = callPrecompile(0x03FD, abi.encode(url, payload, headers));
+        return abi.decode(value, (uint256));
+    }
+
+    function GetI64(uint256 response, string memory jsonPointer)
+    internal
+    returns (int64)
+    {
+        bytes memory value = callPrecompile(0x03FE, abi.encode(response, jsonPointer));
+        return abi.decode(value, (int64));
+    }
+
+    function GetBool(uint256 response, string memory jsonPointer)
+    internal
+    returns (bool)
+    {
+        bytes memory value = callPrecompile(0x03FF, abi.encode(response, jsonPointer));
+        return abi.decode(value, (bool));
+    }
+
+    function GetString(uint256 response, string memory jsonPointer)
+    internal
+    returns (string memory)
+    {
+        bytes memory value = callPrecompile(0x0400, abi.encode(response, jsonPointer));
+        return abi.decode(value, (string));
+    }
+
+    function GetArrayLen(uint256 response, string memory jsonPointer)
+    internal
+    returns (int64)
+    {
+        bytes memory value = callPrecompile(0x0401, abi.encode(response, jsonPointer));
+        return abi.decode(value, (int64));
+    }
+
+    function callPrecompile(uint160 precompile, bytes memory params)
+    private
+    returns (bytes memory)
+    {
+        (bool success, bytes memory value) = address(precompile).call(params);
+        require(success, "json precompile call failed");
+        return value;
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+pragma solidity ^0.8.8;
+
+library Utils {
+    function keccak256Hash(bytes memory data) internal returns (bytes32) {
+        bytes memory value = callPrecompile(0x0420, abi.encode(data));
+        return abi.decode(value, (bytes32));
+    }
+
+    function blake2_256Hash(bytes memory data) internal returns (bytes32) {
+        bytes memory value = callPrecompile(0x0421, abi.encode(data));
+        return abi.decode(value, (bytes32));
+    }
+
+    function sha256Hash(bytes memory data) internal returns (bytes32) {
+        bytes memory value = callPrecompile(0x0422, abi.encode(data));
+        return abi.decode(value, (bytes32));
+    }
+
+    // Parses a decimal string like "1234.5678" into an integer scaled by 10^decimals,
+    // extra fractional digits are truncated.
+    function parseDecimal(string memory decimal, uint8 decimals)
+    internal
+    returns (uint256)
+    {
+        bytes memory value = callPrecompile(0x0423, abi.encode(decimal, decimals));
+        return abi.decode(value, (uint256));
+    }
+
+    function callPrecompile(uint160 precompile, bytes memory params)
+    private
+    returns (bytes memory)
+    {
+        (bool success, bytes memory value) = address(precompile).call(params);
+        require(success, "utils precompile call failed");
+        return value;
+    }
+}
@@ -15,10 +15,12 @@
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{dynamic::repository::SmartContractByteCode, *};
+use core::time::Duration;
 use itp_types::Assertion;
-use lc_credentials::{assertion_logic::AssertionLogic, Credential};
+use lc_credentials::{assertion_logic::AssertionLogic, validity, Credential};
+use lc_data_providers::DataProviderConfig;
 use lc_dynamic_assertion::{AssertionExecutor, AssertionLogicRepository};
-use lc_evm_dynamic_assertions::EvmAssertionExecutor;
+use lc_evm_dynamic_assertions::{EvmAssertionExecutor, ExecutionLimits};
 use lc_stf_task_sender::AssertionBuildRequest;
 use log::error;
 use primitive_types::H160;
@@ -31,12 +33,16 @@ pub fn build<
 	req: &AssertionBuildRequest,
 	smart_contract_id: SC::Id,
 	repository: Arc<SC>,
+	data_provider_config: &DataProviderConfig,
 ) -> Result<Credential> {
-	let executor = EvmAssertionExecutor { assertion_repository: repository };
+	let executor = EvmAssertionExecutor::new(repository).with_limits(ExecutionLimits {
+		gas_limit: data_provider_config.evm_assertion_gas_limit,
+		timeout: Duration::from_millis(data_provider_config.evm_assertion_timeout),
+	});
 	let result = executor.execute(smart_contract_id, &req.identities).map_err(|e| {
 		Error::RequestVCFailed(
 			Assertion::Dynamic(smart_contract_id),
-			ErrorDetail::StfError(ErrorString::truncate_from(e.into())),
+			ErrorDetail::StfError(ErrorString::truncate_from(e.to_string().into())),
 		)
 	})?;
 	match Credential::new(&req.who, &req.shard) {
@@ -59,6 +65,23 @@ pub fn build<
 				result.schema_url,
 				result.meet,
 			);
+			// the validity period declared by the assertion takes precedence over the configured one
+			let validity_period = match result.validity_period {
+				Some(validity_period) => Some(validity_period)
+					.filter(|validity_period| *validity_period != validity::NEVER_EXPIRES),
+				None => validity::get_validity_period(
+					&Assertion::Dynamic(smart_contract_id),
+					data_provider_config,
+				),
+			};
+			if let Some(validity_period) = validity_period {
+				credential_unsigned.set_expiration(validity_period).map_err(|e| {
+					Error::RequestVCFailed(
+						Assertion::Dynamic(smart_contract_id),
+						e.into_error_detail(),
+					)
+				})?;
+			}
 			Ok(credential_unsigned)
 		},
 		Err(e) => {
@@ -75,6 +98,8 @@ pub fn build<
 pub mod tests {
 	use crate::dynamic::{build, repository::InMemorySmartContractRepo};
 	use itp_types::Assertion;
+	use lc_data_providers::DataProviderConfig;
+	use lc_dynamic_assertion::AssertionLogicRepository;
 	use lc_mock_server::run;
 	use lc_stf_task_sender::AssertionBuildRequest;
 	use litentry_primitives::{Identity, IdentityString};
@@ -112,7 +137,9 @@ pub mod tests {
 		let repository = InMemorySmartContractRepo::new();
 
 		// when
-		let credential = build(&request, hash(1), repository.into()).unwrap();
+		let credential =
+			build(&request, hash(1), repository.into(), &DataProviderConfig::new().unwrap())
+				.unwrap();
 
 		// then
 		assert!(credential.credential_subject.values[0]);
@@ -142,7 +169,9 @@ pub mod tests {
 		let repository = InMemorySmartContractRepo::new();
 
 		// when
-		let credential = build(&request, hash(0), repository.into()).unwrap();
+		let credential =
+			build(&request, hash(0), repository.into(), &DataProviderConfig::new().unwrap())
+				.unwrap();
 
 		// then
 		assert!(credential.credential_subject.values[0]);
@@ -174,7 +203,9 @@ pub mod tests {
 		let repository = InMemorySmartContractRepo::new();
 
 		// when
-		let credential = build(&request, hash(2), repository.into()).unwrap();
+		let credential =
+			build(&request, hash(2), repository.into(), &DataProviderConfig::new().unwrap())
+				.unwrap();
 
 		println!("Credential is: {:?}", credential);
 
@@ -205,12 +236,46 @@ pub mod tests {
 		let repository = InMemorySmartContractRepo::new();
 
 		// when
-		let credential = build(&request, hash(0), repository.into()).unwrap();
+		let credential =
+			build(&request, hash(0), repository.into(), &DataProviderConfig::new().unwrap())
+				.unwrap();
 
 		// then
 		assert!(!credential.credential_subject.values[0]);
 	}
 
+	#[test]
+	pub fn test_removed_assertion_fails() {
+		let _ = env_logger::builder().is_test(true).try_init();
+		// given
+		let twitter_identity = Identity::Twitter(IdentityString::new(vec![]));
+		let substrate_identity = Identity::Substrate(AccountId32::new([0; 32]).into());
+
+		let request = AssertionBuildRequest {
+			shard: Default::default(),
+			signer: AccountId32::new([0; 32]),
+			who: Identity::Twitter(IdentityString::new(vec![])),
+			assertion: Assertion::Dynamic(hash(0)),
+			identities: vec![(twitter_identity, vec![]), (substrate_identity, vec![])],
+			top_hash: Default::default(),
+			parachain_block_number: Default::default(),
+			sidechain_block_number: Default::default(),
+			maybe_key: None,
+			req_ext_hash: Default::default(),
+			should_create_id_graph: Default::default(),
+		};
+
+		let repository = InMemorySmartContractRepo::new();
+		repository.remove(&hash(0)).unwrap();
+
+		// when
+		let result =
+			build(&request, hash(0), repository.into(), &DataProviderConfig::new().unwrap());
+
+		// then
+		assert!(result.is_err());
+	}
+
 	fn hash(a: u64) -> H160 {
 		H160::from_low_u64_be(a)
 	}
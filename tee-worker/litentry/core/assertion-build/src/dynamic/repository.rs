@@ -91,6 +91,11 @@ impl AssertionLogicRepository for InMemorySmartContractRepo {
 		self.map.lock().unwrap().insert(id, item);
 		Ok(())
 	}
+
+	fn remove(&self, id: &Self::Id) -> Result<(), String> {
+		self.map.lock().unwrap().remove(id);
+		Ok(())
+	}
 }
 
 fn hash(a: u64) -> H160 {
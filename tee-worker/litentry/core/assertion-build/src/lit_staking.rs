@@ -27,7 +27,7 @@ use itc_rest_client::{
 use itp_stf_primitives::types::AccountId;
 use itp_utils::hex_display::AsBytesRef;
 use lc_credentials::litentry_profile::lit_staking::UpdateLITStakingAmountCredential;
-use lc_data_providers::build_client_with_cert;
+use lc_data_providers::{build_client_with_cert, http_error_detail};
 use litentry_primitives::ParentchainBalance;
 use pallet_parachain_staking::types::Delegator;
 use serde::{Deserialize, Serialize};
@@ -90,14 +90,7 @@ impl LitentryStakingClient {
 	pub fn send_request(&mut self, data: &JsonRPCRequest) -> Result<JsonRPCResponse> {
 		self.client
 			.post_capture::<String, JsonRPCRequest, JsonRPCResponse>(String::default(), data)
-			.map_err(|e| {
-				Error::RequestVCFailed(
-					Assertion::LITStaking,
-					ErrorDetail::DataProviderError(ErrorString::truncate_from(
-						format!("{e:?}").as_bytes().to_vec(),
-					)),
-				)
-			})
+			.map_err(|e| Error::RequestVCFailed(Assertion::LITStaking, http_error_detail(e)))
 	}
 }
 
@@ -95,9 +95,7 @@ pub fn build(
 		get_holding_balance(token_type.clone(), addresses, data_provider_config).map_err(|e| {
 			Error::RequestVCFailed(
 				Assertion::EVMAmountHolding(token_type.clone()),
-				ErrorDetail::DataProviderError(ErrorString::truncate_from(
-					format!("{e:?}").as_bytes().to_vec(),
-				)),
+				e.into_error_detail(),
 			)
 		});
 
@@ -84,6 +84,15 @@ pub fn build(
 	}
 
 	if !has_nft && !errors.is_empty() {
+		// retried later if the provider was unavailable for some of the addresses
+		if let Some(e) =
+			errors.iter().find(|e| matches!(e, DataProviderError::ProviderUnavailable(_)))
+		{
+			return Err(Error::RequestVCFailed(
+				Assertion::WeirdoGhostGangHolder,
+				e.clone().into_error_detail(),
+			))
+		}
 		return Err(Error::RequestVCFailed(
 			Assertion::WeirdoGhostGangHolder,
 			ErrorDetail::DataProviderError(ErrorString::truncate_from(
@@ -26,7 +26,7 @@ use crate::*;
 use http::header::{AUTHORIZATION, CONNECTION};
 use http_req::response::Headers;
 use itc_rest_client::{error::Error as RestClientError, RestGet, RestPath};
-use lc_data_providers::{build_client_with_cert, DataProviderConfig};
+use lc_data_providers::{build_client_with_cert, http_error_detail, DataProviderConfig};
 use serde::{Deserialize, Serialize};
 use std::string::ToString;
 
@@ -58,12 +58,7 @@ fn fetch_data_from_notion(
 	let mut client = build_client_with_cert(oneblock_notion_url.as_str(), headers);
 
 	client.get::<String, OneBlockResponse>(String::default()).map_err(|e| {
-		Error::RequestVCFailed(
-			Assertion::OneBlock(course_type.clone()),
-			ErrorDetail::DataProviderError(ErrorString::truncate_from(
-				format!("{e:?}").as_bytes().to_vec(),
-			)),
-		)
+		Error::RequestVCFailed(Assertion::OneBlock(course_type.clone()), http_error_detail(e))
 	})
 }
 
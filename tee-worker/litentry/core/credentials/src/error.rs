@@ -37,6 +37,12 @@ pub enum Error {
 	InvalidProof,
 	#[error("Credential Is Too Long")]
 	CredentialIsTooLong,
+	#[error("Invalid Expiration Date")]
+	InvalidExpirationDate,
+	#[error("Credential Expired")]
+	CredentialExpired,
+	#[error("Invalid Schema: {0}")]
+	InvalidSchema(String),
 	#[error("Parse Error: {0}")]
 	ParseError(String),
 	#[error("Unsupported Assertion")]
@@ -37,14 +37,15 @@ compile_error!("feature \"std\" and feature \"sgx\" cannot be enabled at the sam
 
 use codec::{Decode, Encode};
 use itp_stf_primitives::types::ShardIdentifier;
-use itp_time_utils::{from_iso8601, now_as_iso8601};
+use itp_time_utils::{from_iso8601, now_as_iso8601, now_as_secs};
 use itp_types::{AccountId, BlockNumber as SidechainBlockNumber};
 use itp_utils::stringify::account_id_to_string;
+use lc_vc_verifier::CheckResult;
 use litentry_primitives::{Identity, ParentchainBlockNumber, Web3Network};
 use log::*;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
-use sp_core::{hashing::blake2_256, hexdisplay::HexDisplay};
+use sp_core::{hashing::blake2_256, hexdisplay::HexDisplay, H256};
 use std::{
 	fmt::Debug,
 	string::{String, ToString},
@@ -59,13 +60,15 @@ extern crate core;
 extern crate rand_sgx as rand;
 
 #[cfg(all(not(feature = "std"), feature = "sgx"))]
-use crate::sgx_reexport_prelude::chrono::{offset::Utc as TzUtc, DateTime, NaiveDateTime};
+use crate::sgx_reexport_prelude::chrono::{
+	offset::Utc as TzUtc, DateTime, Duration as ChronoDuration, NaiveDateTime,
+};
 
 #[cfg(all(not(feature = "std"), feature = "sgx"))]
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "std")]
-use chrono::offset::Utc as TzUtc;
+use chrono::{offset::Utc as TzUtc, Duration as ChronoDuration};
 
 use rand::Rng;
 use rust_base58::ToBase58;
@@ -77,6 +80,7 @@ pub mod assertion_logic;
 pub mod litentry_profile;
 pub mod oneblock;
 pub mod schema;
+pub mod validity;
 use assertion_logic::{AssertionLogic, Op};
 
 pub mod brc20;
@@ -88,6 +92,8 @@ pub mod vip3;
 pub const LITENTRY_ISSUER_NAME: &str = "Litentry TEE Worker";
 pub const PROOF_PURPOSE: &str = "assertionMethod";
 pub const MAX_CREDENTIAL_SIZE: usize = 2048;
+pub const CREDENTIAL_STATUS_TYPE: &str = "VCRegistry";
+pub const CREDENTIAL_STATUS_PURPOSE: &str = "revocation";
 
 /// Ed25519 Signature 2018, W3C, 23 July 2021, https://w3c-ccg.github.io/lds-ed25519-2018
 /// May be registered in Linked Data Cryptographic Suite Registry, W3C, 29 December 2020
@@ -204,6 +210,30 @@ impl Proof {
 	}
 }
 
+/// Revocation status of the credential, it refers to the entry of the credential
+/// in `VCManagement::VCRegistry` on the parachain, the entry is removed once the credential
+/// is revoked
+#[derive(Serialize, Deserialize, Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialStatus {
+	/// The registry entry id, which is the credential id
+	pub id: String,
+	#[serde(rename = "type")]
+	pub status_type: String,
+	/// Purpose of the status, generally it is expected as a fixed value 'revocation'
+	pub status_purpose: String,
+}
+
+impl CredentialStatus {
+	pub fn new(id: &str) -> Self {
+		CredentialStatus {
+			id: id.to_string(),
+			status_type: CREDENTIAL_STATUS_TYPE.to_string(),
+			status_purpose: CREDENTIAL_STATUS_PURPOSE.to_string(),
+		}
+	}
+}
+
 #[derive(Serialize, Deserialize, Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
 #[serde(rename_all = "camelCase")]
 pub struct Credential {
@@ -220,6 +250,9 @@ pub struct Credential {
 	/// The TEE enclave who issued the credential
 	pub issuer: Issuer,
 	pub issuance_date: String,
+	/// (Optional) The ISO-8601 datetime after which the credential is no longer valid
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub expiration_date: Option<String>,
 	/// The parachain and sidechain block number on which the state is read and calculated
 	pub parachain_block_number: ParentchainBlockNumber,
 	pub sidechain_block_number: SidechainBlockNumber,
@@ -228,6 +261,9 @@ pub struct Credential {
 	pub proof: Option<Proof>,
 	/// The JSON Schema information the credential follows
 	pub credential_schema: CredentialSchema,
+	/// Where verifiers can check if the credential has been revoked
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub credential_status: Option<CredentialStatus>,
 }
 
 impl Credential {
@@ -270,6 +306,17 @@ impl Credential {
 		let vc_id = blake2_256(ext_hash.as_slice());
 		self.id = "0x".to_string();
 		self.id.push_str(&(format!("{}", HexDisplay::from(&vc_id.to_vec()))));
+		self.credential_status = Some(CredentialStatus::new(&self.id));
+	}
+
+	/// The index of the credential in the parachain VC registry, decoded from the id
+	pub fn vc_index(&self) -> Result<H256, Error> {
+		let id =
+			hex::decode(self.id.trim_start_matches("0x")).map_err(|_| Error::InvalidCredential)?;
+		if id.len() != 32 {
+			return Err(Error::InvalidCredential)
+		}
+		Ok(H256::from_slice(&id))
 	}
 
 	pub fn to_json(&self) -> Result<String, Error> {
@@ -320,12 +367,47 @@ impl Credential {
 			return Err(Error::InvalidProof)
 		}
 
+		vc.validate_schema()?;
+
+		if vc.is_expired()? {
+			return Err(Error::CredentialExpired)
+		}
+
 		Ok(())
 	}
 
+	/// Sets the expiration date to `validity_period` seconds after the issuance date
+	pub fn set_expiration(&mut self, validity_period: u64) -> Result<(), Error> {
+		let issuance_date =
+			from_iso8601(&self.issuance_date).ok_or(Error::EmptyIssuanceTimestamp)?;
+		let expiration_date =
+			ChronoDuration::from_std(core::time::Duration::from_secs(validity_period))
+				.ok()
+				.and_then(|validity_period| issuance_date.checked_add_signed(validity_period))
+				.ok_or(Error::InvalidExpirationDate)?;
+		self.expiration_date = Some(expiration_date.to_rfc3339());
+		Ok(())
+	}
+
+	pub fn is_expired(&self) -> Result<bool, Error> {
+		match self.expiration_date {
+			Some(ref expiration_date) => {
+				let expiration_date =
+					from_iso8601(expiration_date).ok_or(Error::InvalidExpirationDate)?;
+				Ok(expiration_date.timestamp() <= now_as_secs() as i64)
+			},
+			None => Ok(false),
+		}
+	}
+
+	/// Checks the signed credential against the schema offline verifiers check it against
 	pub fn validate_schema(&self) -> Result<(), Error> {
 		//ToDo: fetch schema from Parentchain and check its status
-		Ok(())
+		match lc_vc_verifier::validate_schema(&self.to_json()?) {
+			CheckResult::Passed => Ok(()),
+			CheckResult::Failed(reason) | CheckResult::Skipped(reason) =>
+				Err(Error::InvalidSchema(reason)),
+		}
 	}
 
 	// Including assertion 4/7/10/11
@@ -556,6 +638,9 @@ pub fn format_assertion_to_date() -> String {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use lc_data_providers::DataProviderConfig;
+	use litentry_primitives::Assertion;
+	use sp_core::Pair;
 
 	#[test]
 	fn eval_simple_success() {
@@ -571,6 +656,94 @@ mod tests {
 		assert_eq!(id, "did:litentry:substrate:0x0000000000000000000000000000000000000000000000000000000000000000");
 	}
 
+	#[test]
+	fn credential_status_works() {
+		let identity = AccountId::from([0; 32]).into();
+		let shard = ShardIdentifier::default();
+
+		let vc = Credential::new(&identity, &shard).unwrap();
+		let status = vc.credential_status.clone().unwrap();
+		assert_eq!(status.id, vc.id);
+		assert_eq!(status.status_type, CREDENTIAL_STATUS_TYPE);
+		assert_eq!(status.status_purpose, CREDENTIAL_STATUS_PURPOSE);
+		assert_eq!(format!("{:?}", vc.vc_index().unwrap()), vc.id);
+		assert!(vc.to_json().unwrap().contains("\"credentialStatus\""));
+	}
+
+	#[test]
+	fn expiration_works() {
+		let identity = AccountId::from([0; 32]).into();
+		let shard = ShardIdentifier::default();
+
+		let mut vc = Credential::new(&identity, &shard).unwrap();
+		vc.issuer.set_id(&AccountId::from([0; 32]));
+		vc.add_proof(&vec![0u8; 64], &AccountId::from([0; 32]));
+		assert!(!vc.is_expired().unwrap());
+
+		vc.set_expiration(30 * 24 * 60 * 60).unwrap();
+		assert!(!vc.is_expired().unwrap());
+		assert!(vc.validate().is_ok());
+
+		vc.issuance_date = "2023-01-01T00:00:00+00:00".to_string();
+		vc.set_expiration(24 * 60 * 60).unwrap();
+		assert_eq!(vc.expiration_date, Some("2023-01-02T00:00:00+00:00".to_string()));
+		assert!(vc.is_expired().unwrap());
+		assert!(matches!(vc.validate(), Err(Error::CredentialExpired)));
+	}
+
+	#[test]
+	fn issued_credential_can_be_verified() {
+		let pair = sp_core::ed25519::Pair::from_seed(&[1; 32]);
+		let enclave_account = AccountId::from(pair.public().0);
+		let identity = AccountId::from([0; 32]).into();
+		let shard = ShardIdentifier::default();
+
+		// issued the way the worker issues it
+		let mut vc = Credential::new(&identity, &shard).unwrap();
+		vc.add_assertion_a1(true);
+		vc.set_expiration(30 * 24 * 60 * 60).unwrap();
+		vc.issuer.set_id(&enclave_account);
+		let sig = pair.sign(vc.to_json().unwrap().as_bytes());
+		vc.add_proof(&sig.0.to_vec(), &enclave_account);
+		assert!(vc.validate().is_ok());
+
+		let enclaves = vec![lc_vc_verifier::RegisteredEnclave {
+			mrenclave: vc.issuer.mrenclave.clone(),
+			vc_pubkey: account_id_to_string(&enclave_account),
+		}];
+		let report =
+			lc_vc_verifier::verify(&vc.to_json().unwrap(), &enclaves, now_as_secs() as i64)
+				.unwrap();
+		assert!(report.is_valid(), "{:?}", report);
+
+		// a credential without proof doesn't pass the schema check
+		vc.proof = None;
+		assert!(matches!(vc.validate_schema(), Err(Error::InvalidSchema(_))));
+	}
+
+	#[test]
+	fn validity_period_is_configurable() {
+		let mut config = DataProviderConfig::new().unwrap();
+		config.set_holding_validity_period(100);
+		assert_eq!(validity::get_validity_period(&Assertion::BnbDomainHolding, &config), Some(100));
+		assert_eq!(
+			validity::get_validity_period(&Assertion::A13(AccountId::from([0; 32])), &config),
+			None
+		);
+
+		config.set_vc_validity_period("A13".to_string(), 200).unwrap();
+		config
+			.set_vc_validity_period("BnbDomainHolding".to_string(), validity::NEVER_EXPIRES)
+			.unwrap();
+		// unknown variants are rejected
+		assert!(config.set_vc_validity_period("A13(..)".to_string(), 200).is_err());
+		assert_eq!(
+			validity::get_validity_period(&Assertion::A13(AccountId::from([0; 32])), &config),
+			Some(200)
+		);
+		assert_eq!(validity::get_validity_period(&Assertion::BnbDomainHolding, &config), None);
+	}
+
 	#[test]
 	fn update_holder_works() {
 		let who = AccountId::from([0; 32]);
@@ -0,0 +1,127 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use lc_data_providers::DataProviderConfig;
+use litentry_primitives::{AchainableParams, Assertion};
+
+/// Validity period of the credentials which never expire, it is `type(uint64).max` in the
+/// `validityPeriod()` of dynamic assertions
+pub const NEVER_EXPIRES: u64 = u64::MAX;
+
+enum ValidityClass {
+	/// Assertions about amounts, balances or holdings, which can change at any time
+	Holding,
+	/// Assertions about accounts and their activity, which change slowly
+	Activity,
+	/// Dynamic assertions which don't declare a validity period
+	Dynamic,
+	/// Assertions about past events, which don't change
+	Permanent,
+}
+
+/// Returns the validity period in seconds of the credential issued for the given assertion,
+/// `None` if the credential doesn't expire.
+/// The period configured for the assertion variant takes precedence over the one of its class.
+pub fn get_validity_period(assertion: &Assertion, config: &DataProviderConfig) -> Option<u64> {
+	let variant = assertion.variant_name();
+	let period = match config.vc_validity_periods.iter().find(|(name, _)| name == variant) {
+		Some((_, period)) => *period,
+		None => match validity_class(assertion) {
+			ValidityClass::Holding => config.holding_validity_period,
+			ValidityClass::Activity => config.activity_validity_period,
+			ValidityClass::Dynamic => config.dynamic_validity_period,
+			ValidityClass::Permanent => NEVER_EXPIRES,
+		},
+	};
+	(period != NEVER_EXPIRES).then_some(period)
+}
+
+fn validity_class(assertion: &Assertion) -> ValidityClass {
+	match assertion {
+		Assertion::A1 => ValidityClass::Activity,
+
+		Assertion::A2(_) => ValidityClass::Activity,
+
+		Assertion::A3(_, _, _) => ValidityClass::Activity,
+
+		Assertion::A4(_) => ValidityClass::Holding,
+
+		Assertion::A6 => ValidityClass::Activity,
+
+		Assertion::A7(_) => ValidityClass::Holding,
+
+		Assertion::A8(_) => ValidityClass::Activity,
+
+		Assertion::A10(_) => ValidityClass::Holding,
+
+		Assertion::A11(_) => ValidityClass::Holding,
+
+		// badge of a past event
+		Assertion::A13(_) => ValidityClass::Permanent,
+
+		Assertion::A14 => ValidityClass::Activity,
+
+		Assertion::Achainable(params) => match params {
+			AchainableParams::AmountHolding(_)
+			| AchainableParams::AmountToken(_)
+			| AchainableParams::Amount(_)
+			| AchainableParams::Amounts(_)
+			| AchainableParams::BetweenPercents(_)
+			| AchainableParams::Token(_) => ValidityClass::Holding,
+
+			// account creation date doesn't change
+			AchainableParams::ClassOfYear(_) => ValidityClass::Permanent,
+
+			AchainableParams::Basic(_)
+			| AchainableParams::Mirror(_)
+			| AchainableParams::Date(_)
+			| AchainableParams::DateInterval(_)
+			| AchainableParams::DatePercent(_) => ValidityClass::Activity,
+		},
+
+		// early bird campaign is over
+		Assertion::A20 => ValidityClass::Permanent,
+
+		// course completion is a past event
+		Assertion::OneBlock(_) => ValidityClass::Permanent,
+
+		Assertion::GenericDiscordRole(_) => ValidityClass::Activity,
+
+		Assertion::BnbDomainHolding => ValidityClass::Holding,
+
+		Assertion::BnbDigitDomainClub(_) => ValidityClass::Holding,
+
+		Assertion::VIP3MembershipCard(_) => ValidityClass::Holding,
+
+		Assertion::WeirdoGhostGangHolder => ValidityClass::Holding,
+
+		Assertion::LITStaking => ValidityClass::Holding,
+
+		Assertion::EVMAmountHolding(_) => ValidityClass::Holding,
+
+		Assertion::BRC20AmountHolder => ValidityClass::Holding,
+
+		Assertion::CryptoSummary => ValidityClass::Holding,
+
+		Assertion::PlatformUser(_) => ValidityClass::Activity,
+
+		Assertion::NftHolder(_) => ValidityClass::Holding,
+
+		Assertion::TokenHoldingAmount(_) => ValidityClass::Holding,
+
+		Assertion::Dynamic(_) => ValidityClass::Dynamic,
+	}
+}
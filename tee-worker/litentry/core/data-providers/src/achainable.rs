@@ -18,7 +18,9 @@
 use crate::sgx_reexport_prelude::*;
 
 use crate::{
-	build_client_with_cert, ConvertParameterString, DataProviderConfig, Error, HttpError,
+	build_client_with_cert, cache,
+	throttle::{self, ProviderLimits},
+	ConvertParameterString, DataProviderConfig, Error, HttpError, RetryableRestPost,
 	LIT_TOKEN_ADDRESS, USDC_TOKEN_ADDRESS, USDT_TOKEN_ADDRESS, WETH_TOKEN_ADDRESS,
 };
 use http::header::{AUTHORIZATION, CONNECTION};
@@ -26,7 +28,7 @@ use http_req::response::Headers;
 use itc_rest_client::{
 	http_client::{HttpClient, SendWithCertificateVerification},
 	rest_client::RestClient,
-	RestPath, RestPost,
+	RestPath,
 };
 use litentry_primitives::{AchainableParams, VCMPError, Web3Network};
 use log::debug;
@@ -51,6 +53,8 @@ impl AchainableClient {
 		);
 		let client =
 			build_client_with_cert(data_provider_config.achainable_url.clone().as_str(), headers);
+		cache::set_cache_ttl(client.base_url(), data_provider_config.achainable_api_cache_ttl);
+		throttle::set_limits(client.base_url(), ProviderLimits::new(0, data_provider_config));
 
 		AchainableClient { client }
 	}
@@ -94,7 +98,9 @@ impl AchainablePost for AchainableClient {
 	) -> Result<serde_json::Value, Error> {
 		let response = self
 			.client
-			.post_capture::<SystemLabelReqPath, ReqBody, serde_json::Value>(params, body);
+			.post_capture_retry::<SystemLabelReqPath, ReqBody, serde_json::Value>(
+				params, body, None,
+			);
 		debug!("ReqBody response: {:?}", response);
 		response.map_err(|e| Error::AchainableError(format!("Achainable response error: {}", e)))
 	}
@@ -115,7 +121,7 @@ impl AchainableResultParser for AchainableClient {
 	}
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemLabelReqPath {
 	path: String,
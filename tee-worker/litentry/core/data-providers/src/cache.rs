@@ -0,0 +1,291 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! In-enclave cache of data provider responses.
+//!
+//! VC requests over the same identities (e.g. a `request_batch_vc` of several token
+//! assertions) tend to issue identical provider requests. Successful responses are kept
+//! for a per-provider TTL, so that they are only fetched once within that period.
+//! The cache is bounded both in the number of responses and in their total size.
+
+use itc_rest_client::Query;
+use itp_time_utils::now_as_millis;
+use lazy_static::lazy_static;
+use log::{debug, warn};
+use lru::LruCache;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+#[cfg(feature = "sgx")]
+use std::sync::SgxRwLock as RwLock;
+use std::{
+	collections::BTreeMap,
+	format,
+	num::NonZeroUsize,
+	string::{String, ToString},
+	vec::Vec,
+};
+use url::Url;
+
+/// The maximum number of responses kept in the cache, the least recently used
+/// response is evicted first.
+pub const RESPONSE_CACHE_CAPACITY: usize = 1024;
+
+/// The maximum total size in bytes of the cached responses, including their keys,
+/// the least recently used responses are evicted first.
+pub const RESPONSE_CACHE_MAX_BYTES: usize = 16 * 1024 * 1024;
+
+/// Responses with a larger body are not cached.
+pub const RESPONSE_CACHE_MAX_ENTRY_BYTES: usize = 1024 * 1024;
+
+struct CacheEntry {
+	body: String,
+	expires_at: u64,
+}
+
+fn entry_size(key: &str, entry: &CacheEntry) -> usize {
+	key.len().saturating_add(entry.body.len())
+}
+
+/// Hits and misses of the cache for a provider.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+	pub hits: u64,
+	pub misses: u64,
+}
+
+struct ResponseCache {
+	entries: LruCache<String, CacheEntry>,
+	// total size of the entries
+	bytes: usize,
+	max_bytes: usize,
+	// TTL in milliseconds, keyed by the provider base url
+	ttls: BTreeMap<String, u64>,
+	// keyed by the provider host
+	stats: BTreeMap<String, CacheStats>,
+}
+
+impl ResponseCache {
+	fn new(capacity: usize, max_bytes: usize) -> Self {
+		Self {
+			entries: LruCache::new(NonZeroUsize::new(capacity).unwrap()),
+			bytes: 0,
+			max_bytes,
+			ttls: BTreeMap::new(),
+			stats: BTreeMap::new(),
+		}
+	}
+
+	fn insert(&mut self, key: String, entry: CacheEntry) {
+		self.bytes = self.bytes.saturating_add(entry_size(&key, &entry));
+		// either the replaced entry of the key or the evicted one
+		if let Some((key, entry)) = self.entries.push(key, entry) {
+			self.bytes = self.bytes.saturating_sub(entry_size(&key, &entry));
+		}
+		while self.bytes > self.max_bytes {
+			match self.entries.pop_lru() {
+				Some((key, entry)) =>
+					self.bytes = self.bytes.saturating_sub(entry_size(&key, &entry)),
+				None => break,
+			}
+		}
+	}
+
+	fn remove(&mut self, key: &str) {
+		if let Some(entry) = self.entries.pop(key) {
+			self.bytes = self.bytes.saturating_sub(entry_size(key, &entry));
+		}
+	}
+
+	fn clear(&mut self) {
+		self.entries.clear();
+		self.bytes = 0;
+	}
+}
+
+lazy_static! {
+	static ref CACHE: RwLock<ResponseCache> =
+		RwLock::new(ResponseCache::new(RESPONSE_CACHE_CAPACITY, RESPONSE_CACHE_MAX_BYTES));
+}
+
+fn provider_of(base_url: &Url) -> String {
+	base_url.host_str().unwrap_or_default().to_string()
+}
+
+/// Set the TTL in milliseconds of the responses from the provider at `base_url`,
+/// 0 disables the caching.
+pub fn set_cache_ttl(base_url: &Url, ttl: u64) {
+	match CACHE.write() {
+		Ok(mut cache) => {
+			cache.ttls.insert(base_url.to_string(), ttl);
+		},
+		Err(_) => warn!("Failed to set response cache ttl: lock poisoning"),
+	}
+}
+
+/// The TTL in milliseconds of the responses from the provider at `base_url`.
+pub fn cache_ttl(base_url: &Url) -> u64 {
+	CACHE
+		.read()
+		.ok()
+		.and_then(|cache| cache.ttls.get(base_url.as_str()).copied())
+		.unwrap_or_default()
+}
+
+/// Build the key of a request, the query parameters are sorted so that the key
+/// doesn't depend on their order.
+pub fn cache_key(path: &str, query: Option<&Query<'_>>, body: Option<&str>) -> String {
+	let mut query: Vec<(&str, &str)> = query.map(|q| q.to_vec()).unwrap_or_default();
+	query.sort_unstable();
+	let query: Vec<String> = query.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+
+	format!("{}?{}#{}", path, query.join("&"), body.unwrap_or_default())
+}
+
+/// Get the cached response of the request, the hit or miss is counted for the provider.
+pub fn get(base_url: &Url, key: &str) -> Option<String> {
+	let mut cache = match CACHE.write() {
+		Ok(cache) => cache,
+		Err(_) => {
+			warn!("Failed to read response cache: lock poisoning");
+			return None
+		},
+	};
+	let key = format!("{}{}", base_url, key);
+	let now = now_as_millis();
+
+	let body = match cache.entries.get(&key) {
+		Some(entry) if entry.expires_at > now => Some(entry.body.clone()),
+		Some(_) => {
+			cache.remove(&key);
+			None
+		},
+		None => None,
+	};
+
+	let stats = cache.stats.entry(provider_of(base_url)).or_default();
+	if body.is_some() {
+		stats.hits = stats.hits.saturating_add(1);
+	} else {
+		stats.misses = stats.misses.saturating_add(1);
+	}
+	body
+}
+
+/// Cache the response of the request for `ttl` milliseconds,
+/// bodies larger than `RESPONSE_CACHE_MAX_ENTRY_BYTES` are not cached.
+pub fn put(base_url: &Url, key: &str, body: String, ttl: u64) {
+	match CACHE.write() {
+		Ok(mut cache) => {
+			let key = format!("{}{}", base_url, key);
+			if body.len() > RESPONSE_CACHE_MAX_ENTRY_BYTES {
+				debug!("Skip caching response of {} bytes for {}", body.len(), key);
+				// the stale response must not outlive the new one
+				cache.remove(&key);
+				return
+			}
+			let expires_at = now_as_millis().saturating_add(ttl);
+			cache.insert(key, CacheEntry { body, expires_at });
+		},
+		Err(_) => warn!("Failed to write response cache: lock poisoning"),
+	}
+}
+
+/// Take the hits and misses counted since the last call, per provider.
+pub fn take_cache_stats() -> Vec<(String, CacheStats)> {
+	match CACHE.write() {
+		Ok(mut cache) => core::mem::take(&mut cache.stats).into_iter().collect(),
+		Err(_) => Vec::new(),
+	}
+}
+
+/// Drop all the cached responses.
+pub fn clear_cache() {
+	if let Ok(mut cache) = CACHE.write() {
+		cache.clear();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cache_key_does_not_depend_on_query_order() {
+		let q1 = [("a", "1"), ("b", "2")];
+		let q2 = [("b", "2"), ("a", "1")];
+		assert_eq!(cache_key("path", Some(&q1), None), cache_key("path", Some(&q2), None));
+		assert_ne!(cache_key("path", Some(&q1), None), cache_key("path", None, None));
+		assert_ne!(cache_key("path", None, Some("{}")), cache_key("path", None, None));
+	}
+
+	#[test]
+	fn cache_works() {
+		let base_url = Url::parse("https://cache-works.test/").unwrap();
+		let key = cache_key("path", None, None);
+		set_cache_ttl(&base_url, 60_000);
+		assert_eq!(cache_ttl(&base_url), 60_000);
+
+		assert_eq!(get(&base_url, &key), None);
+		put(&base_url, &key, "{}".to_string(), cache_ttl(&base_url));
+		assert_eq!(get(&base_url, &key), Some("{}".to_string()));
+
+		// expired
+		put(&base_url, &key, "{}".to_string(), 0);
+		assert_eq!(get(&base_url, &key), None);
+
+		let stats = take_cache_stats();
+		let stats = stats.iter().find(|(provider, _)| provider == "cache-works.test").unwrap();
+		assert_eq!(stats.1, CacheStats { hits: 1, misses: 2 });
+	}
+
+	#[test]
+	fn large_response_is_not_cached() {
+		let base_url = Url::parse("https://large-response.test/").unwrap();
+		let key = cache_key("path", None, None);
+
+		put(&base_url, &key, "{}".to_string(), 60_000);
+		assert_eq!(get(&base_url, &key), Some("{}".to_string()));
+
+		// the stale response is dropped as well
+		put(&base_url, &key, "a".repeat(RESPONSE_CACHE_MAX_ENTRY_BYTES + 1), 60_000);
+		assert_eq!(get(&base_url, &key), None);
+	}
+
+	#[test]
+	fn cache_size_is_bounded() {
+		let mut cache = ResponseCache::new(10, 25);
+		let entry = |body: &str| CacheEntry { body: body.to_string(), expires_at: u64::MAX };
+
+		cache.insert("a".to_string(), entry("0123456789"));
+		cache.insert("b".to_string(), entry("0123456789"));
+		assert_eq!(cache.bytes, 22);
+
+		// replacing an entry accounts the new size only
+		cache.insert("b".to_string(), entry("01234"));
+		assert_eq!(cache.bytes, 17);
+
+		// the least recently used entries are evicted to fit
+		cache.insert("c".to_string(), entry("0123456789"));
+		assert_eq!(cache.bytes, 17);
+		assert!(!cache.entries.contains("a"));
+		assert!(cache.entries.contains("b"));
+
+		cache.remove("b");
+		assert_eq!(cache.bytes, 11);
+		cache.clear();
+		assert_eq!(cache.bytes, 0);
+	}
+}
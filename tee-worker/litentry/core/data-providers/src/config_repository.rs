@@ -0,0 +1,293 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime updates of the `DataProviderConfig` credentials.
+//!
+//! The API keys and client credentials can be rotated without restarting the worker, by an
+//! update signed by the sidechain root account. Updates are sealed to disk and applied on
+//! top of the config built from the env when the enclave starts.
+
+use crate::{DataProviderConfig, Error};
+use codec::{Decode, Encode};
+use litentry_primitives::{Identity, LitentryMultiSignature, MrEnclave, ShardIdentifier};
+use log::info;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+#[cfg(feature = "sgx")]
+use std::sync::SgxRwLock as RwLock;
+use std::{
+	format,
+	string::{String, ToString},
+	sync::Arc,
+	vec::Vec,
+};
+
+const REDACTED: &str = "<redacted>";
+
+macro_rules! config_update {
+	($($field:ident),* $(,)?) => {
+		/// The credentials of `DataProviderConfig` that can be updated at runtime,
+		/// `None` keeps the current value.
+		#[derive(Encode, Decode, Clone, Default, PartialEq, Eq)]
+		pub struct DataProviderConfigUpdate {
+			$(pub $field: Option<String>,)*
+		}
+
+		impl DataProviderConfigUpdate {
+			/// Merge `other` into `self`, the values of `other` take precedence.
+			pub fn merge(&mut self, other: DataProviderConfigUpdate) {
+				$(if other.$field.is_some() {
+					self.$field = other.$field;
+				})*
+			}
+
+			/// The names of the updated fields, without their values.
+			pub fn fields(&self) -> Vec<&'static str> {
+				let mut fields = Vec::new();
+				$(if self.$field.is_some() {
+					fields.push(stringify!($field));
+				})*
+				fields
+			}
+		}
+
+		impl DataProviderConfig {
+			// the setters are not used on purpose, they log the values
+			pub fn apply(&mut self, update: &DataProviderConfigUpdate) {
+				$(if let Some(v) = &update.$field {
+					self.$field = v.clone();
+				})*
+			}
+		}
+	};
+}
+
+config_update!(
+	twitter_auth_token_v2,
+	twitter_client_id,
+	twitter_client_secret,
+	discord_auth_token,
+	discord_client_id,
+	discord_client_secret,
+	achainable_auth_key,
+	oneblock_notion_key,
+	nodereal_api_key,
+	geniidata_api_key,
+	moralis_api_key,
+	github_client_id,
+	github_client_secret,
+);
+
+impl DataProviderConfig {
+	/// A copy of the config with the secrets redacted, to be returned to clients.
+	pub fn redacted(&self) -> Self {
+		fn redact(v: &mut String) {
+			if !v.is_empty() {
+				*v = REDACTED.to_string();
+			}
+		}
+
+		let mut config = self.clone();
+		redact(&mut config.twitter_auth_token_v2);
+		redact(&mut config.twitter_client_secret);
+		redact(&mut config.discord_auth_token);
+		redact(&mut config.discord_client_secret);
+		redact(&mut config.achainable_auth_key);
+		redact(&mut config.oneblock_notion_key);
+		redact(&mut config.nodereal_api_key);
+		redact(&mut config.geniidata_api_key);
+		redact(&mut config.moralis_api_key);
+		redact(&mut config.github_client_secret);
+		config
+	}
+}
+
+/// An update signed by the sidechain root account of `shard`.
+#[derive(Encode, Decode, Clone)]
+pub struct SignedDataProviderConfigUpdate {
+	/// Must be greater than the nonce of the last applied update, so that an older update
+	/// can't be replayed.
+	pub nonce: u64,
+	pub update: DataProviderConfigUpdate,
+	pub signer: Identity,
+	pub signature: LitentryMultiSignature,
+}
+
+impl SignedDataProviderConfigUpdate {
+	pub fn signature_payload(
+		nonce: u64,
+		update: &DataProviderConfigUpdate,
+		mrenclave: &MrEnclave,
+		shard: &ShardIdentifier,
+	) -> Vec<u8> {
+		(b"litentry/data-provider-config", nonce, update, mrenclave, shard).encode()
+	}
+
+	pub fn verify(&self, mrenclave: &MrEnclave, shard: &ShardIdentifier) -> bool {
+		let payload = Self::signature_payload(self.nonce, &self.update, mrenclave, shard);
+		self.signature.verify(&payload, &self.signer)
+	}
+}
+
+/// The updates applied so far, merged, this is what gets sealed.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq)]
+pub struct DataProviderConfigOverrides {
+	pub nonce: u64,
+	pub update: DataProviderConfigUpdate,
+}
+
+/// Holds the `DataProviderConfig` in use. Consumers take a snapshot with `get` for each
+/// request, so that an update is seen by the following requests.
+pub struct DataProviderConfigRepository {
+	config: RwLock<Arc<DataProviderConfig>>,
+	overrides: RwLock<DataProviderConfigOverrides>,
+	seal_path: String,
+}
+
+impl DataProviderConfigRepository {
+	/// Create the repository, the overrides sealed at `seal_path` are applied to `config`.
+	pub fn new(mut config: DataProviderConfig, seal_path: &str) -> Result<Self, Error> {
+		let overrides = io::unseal_overrides(seal_path)?;
+		if overrides.nonce > 0 {
+			info!(
+				"Applying sealed data provider config overrides, nonce: {}, fields: {:?}",
+				overrides.nonce,
+				overrides.update.fields()
+			);
+			config.apply(&overrides.update);
+		}
+
+		Ok(Self {
+			config: RwLock::new(Arc::new(config)),
+			overrides: RwLock::new(overrides),
+			seal_path: seal_path.to_string(),
+		})
+	}
+
+	pub fn get(&self) -> Result<Arc<DataProviderConfig>, Error> {
+		self.config.read().map(|config| config.clone()).map_err(|_| lock_poisoning())
+	}
+
+	/// Seal and apply the update, `nonce` must be greater than the one of the last update.
+	pub fn update(&self, nonce: u64, update: DataProviderConfigUpdate) -> Result<(), Error> {
+		let mut overrides = self.overrides.write().map_err(|_| lock_poisoning())?;
+		if nonce <= overrides.nonce {
+			return Err(Error::ConfigError(format!(
+				"invalid nonce {}, expected greater than {}",
+				nonce, overrides.nonce
+			)))
+		}
+
+		let mut new_overrides = overrides.clone();
+		new_overrides.nonce = nonce;
+		new_overrides.update.merge(update.clone());
+		io::seal_overrides(&self.seal_path, &new_overrides)?;
+		*overrides = new_overrides;
+
+		let mut config = self.config.write().map_err(|_| lock_poisoning())?;
+		let mut new_config = DataProviderConfig::clone(&config);
+		new_config.apply(&update);
+		*config = Arc::new(new_config);
+
+		info!("Data provider config updated, nonce: {}, fields: {:?}", nonce, update.fields());
+		Ok(())
+	}
+
+	pub fn nonce(&self) -> Result<u64, Error> {
+		self.overrides
+			.read()
+			.map(|overrides| overrides.nonce)
+			.map_err(|_| lock_poisoning())
+	}
+}
+
+fn lock_poisoning() -> Error {
+	Error::ConfigError("lock poisoning".to_string())
+}
+
+#[cfg(feature = "std")]
+mod io {
+	use super::DataProviderConfigOverrides;
+	use crate::Error;
+
+	pub fn seal_overrides(
+		_path: &str,
+		_overrides: &DataProviderConfigOverrides,
+	) -> Result<(), Error> {
+		Ok(())
+	}
+
+	pub fn unseal_overrides(_path: &str) -> Result<DataProviderConfigOverrides, Error> {
+		Ok(Default::default())
+	}
+}
+
+#[cfg(feature = "sgx")]
+mod io {
+	use super::DataProviderConfigOverrides;
+	use crate::Error;
+	use codec::{Decode, Encode};
+	use itp_sgx_io::{seal, unseal};
+	use log::info;
+	use std::{format, sgxfs::SgxFile};
+
+	pub fn seal_overrides(
+		path: &str,
+		overrides: &DataProviderConfigOverrides,
+	) -> Result<(), Error> {
+		overrides
+			.using_encoded(|bytes| seal(bytes, path))
+			.map_err(|e| Error::ConfigError(format!("failed to seal config: {:?}", e)))
+	}
+
+	pub fn unseal_overrides(path: &str) -> Result<DataProviderConfigOverrides, Error> {
+		if SgxFile::open(path).is_err() {
+			info!("Data provider config seal file not found, using the default config {}", path);
+			return Ok(Default::default())
+		}
+
+		let bytes = unseal(path)
+			.map_err(|e| Error::ConfigError(format!("failed to unseal config: {:?}", e)))?;
+		DataProviderConfigOverrides::decode(&mut bytes.as_slice())
+			.map_err(|e| Error::ConfigError(format!("failed to decode config: {:?}", e)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn update_works() {
+		let repository =
+			DataProviderConfigRepository::new(DataProviderConfig::new().unwrap(), "").unwrap();
+		let update = DataProviderConfigUpdate {
+			moralis_api_key: Some("new-key".to_string()),
+			..Default::default()
+		};
+
+		let before = repository.get().unwrap();
+		repository.update(1, update.clone()).unwrap();
+		let after = repository.get().unwrap();
+		assert_eq!(after.moralis_api_key, "new-key");
+		assert_eq!(after.nodereal_api_key, before.nodereal_api_key);
+		assert_eq!(after.redacted().moralis_api_key, REDACTED);
+		assert_eq!(repository.nonce().unwrap(), 1);
+
+		// replayed
+		assert!(matches!(repository.update(1, update), Err(Error::ConfigError(_))));
+	}
+}
@@ -20,6 +20,7 @@ use crate::sgx_reexport_prelude::*;
 use crate::{
 	build_client_with_cert, vec_to_string, DataProviderConfig, Error, HttpError, UserInfo,
 };
+use base64::engine::{general_purpose::STANDARD as BASE64_STANDARD, Engine};
 use http::header::{AUTHORIZATION, CONNECTION};
 use http_req::response::Headers;
 use itc_rest_client::{
@@ -29,7 +30,13 @@ use itc_rest_client::{
 };
 use log::*;
 use serde::{Deserialize, Serialize};
-use std::{format, string::String, vec, vec::Vec};
+use std::{
+	collections::HashMap,
+	format,
+	string::{String, ToString},
+	vec,
+	vec::Vec,
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct DiscordMessage {
@@ -52,6 +59,28 @@ pub struct DiscordUser {
 	pub discriminator: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DiscordUserAccessTokenData {
+	pub client_id: String,
+	pub code: String,
+	pub code_verifier: String,
+	pub redirect_uri: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DiscordUserAccessToken {
+	pub access_token: String,
+	pub token_type: String,
+	pub expires_in: u32,
+	pub scope: String,
+}
+
+impl RestPath<String> for DiscordUserAccessToken {
+	fn get_path(path: String) -> Result<String, HttpError> {
+		Ok(path)
+	}
+}
+
 impl RestPath<String> for DiscordUser {
 	fn get_path(path: String) -> Result<String, HttpError> {
 		Ok(path)
@@ -89,6 +118,19 @@ impl DiscordOfficialClient {
 		DiscordOfficialClient { client }
 	}
 
+	/// Build a client with a custom `Authorization` header, e.g. for the OAuth2 flow
+	pub fn with_authorization(url: &str, authorization: &str) -> Self {
+		let mut headers = Headers::new();
+		headers.insert(CONNECTION.as_str(), "close");
+		headers.insert(AUTHORIZATION.as_str(), authorization);
+		let client = build_client_with_cert(url, headers);
+		DiscordOfficialClient { client }
+	}
+
+	pub fn oauth2_authorization(client_id: &str, client_secret: &str) -> String {
+		format!("Basic {}", BASE64_STANDARD.encode(format!("{}:{}", client_id, client_secret)))
+	}
+
 	pub fn query_message(
 		&mut self,
 		channel_id: Vec<u8>,
@@ -114,6 +156,26 @@ impl DiscordOfficialClient {
 			.get_with::<String, DiscordUser>(path, query.as_slice())
 			.map_err(|e| Error::RequestError(format!("{:?}", e)))
 	}
+
+	pub fn request_user_access_token(
+		&mut self,
+		data: DiscordUserAccessTokenData,
+	) -> Result<DiscordUserAccessToken, Error> {
+		debug!("discord create access token");
+
+		let path = String::from("/api/oauth2/token");
+
+		let mut body = HashMap::new();
+		body.insert("client_id".to_string(), data.client_id);
+		body.insert("code".to_string(), data.code);
+		body.insert("code_verifier".to_string(), data.code_verifier);
+		body.insert("redirect_uri".to_string(), data.redirect_uri);
+		body.insert("grant_type".to_string(), "authorization_code".to_string());
+
+		self.client
+			.post_form_urlencoded_capture::<String, DiscordUserAccessToken>(path, body)
+			.map_err(|e| Error::RequestError(format!("{:?}", e)))
+	}
 }
 
 #[cfg(test)]
@@ -149,4 +211,42 @@ mod tests {
 		assert_eq!(message.content, "Hello, litentry.");
 		assert_eq!(message.channel_id, channel_id)
 	}
+
+	#[test]
+	fn request_user_access_token_work() {
+		let data_provider_config = init();
+
+		let data = DiscordUserAccessTokenData {
+			client_id: data_provider_config.discord_client_id.clone(),
+			code: "code".to_string(),
+			code_verifier: "code_verifier".to_string(),
+			redirect_uri: "redirect_uri".to_string(),
+		};
+		let authorization = DiscordOfficialClient::oauth2_authorization(
+			&data_provider_config.discord_client_id,
+			&data_provider_config.discord_client_secret,
+		);
+		let mut client = DiscordOfficialClient::with_authorization(
+			&data_provider_config.discord_official_url,
+			&authorization,
+		);
+		let result = client.request_user_access_token(data);
+		assert!(result.is_ok(), "error: {:?}", result);
+	}
+
+	#[test]
+	fn get_user_info_with_access_token_work() {
+		let data_provider_config = init();
+
+		let mut client = DiscordOfficialClient::with_authorization(
+			&data_provider_config.discord_official_url,
+			"Bearer discord_access_token",
+		);
+		let result = client.get_user_info("@me".to_string());
+		assert!(result.is_ok(), "error: {:?}", result);
+
+		let user = result.unwrap();
+		assert_eq!(user.username, "mock_discord_user");
+		assert_eq!(user.discriminator, "0");
+	}
 }
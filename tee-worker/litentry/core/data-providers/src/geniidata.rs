@@ -20,14 +20,18 @@ use crate::sgx_reexport_prelude::*;
 #[cfg(all(not(feature = "std"), feature = "sgx"))]
 extern crate sgx_tstd as std;
 
-use crate::{build_client_with_cert, DataProviderConfig, Error as DataProviderError};
+use crate::{
+	build_client_with_cert, cache,
+	throttle::{self, ProviderLimits},
+	DataProviderConfig, Error as DataProviderError, RetryableRestGet,
+};
 use http::header::{ACCEPT, CONNECTION};
 use http_req::response::Headers;
 use itc_rest_client::{
 	error::Error as RestClientError,
 	http_client::{HttpClient, SendWithCertificateVerification},
 	rest_client::RestClient,
-	RestGet, RestPath,
+	RestPath,
 };
 use litentry_primitives::ErrorDetail;
 use serde::{Deserialize, Serialize};
@@ -84,6 +88,8 @@ impl GeniidataClient {
 		headers.insert("api-key", data_provider_config.geniidata_api_key.as_str());
 
 		let client = build_client_with_cert(data_provider_config.geniidata_url.as_str(), headers);
+		cache::set_cache_ttl(client.base_url(), data_provider_config.geniidata_api_cache_ttl);
+		throttle::set_limits(client.base_url(), ProviderLimits::new(0, data_provider_config));
 
 		Ok(GeniidataClient { client })
 	}
@@ -98,7 +104,7 @@ impl GeniidataClient {
 				vec![("limit", GENIIDATA_QUERY_LIMIT), ("offset", "0"), ("address", &address)];
 			let response = self
 				.client
-				.get_with::<String, GeniidataResponse>("".to_string(), query.as_slice())
+				.get_with_retry::<String, GeniidataResponse>("".to_string(), query.as_slice(), None)
 				.map_err(|e| {
 					DataProviderError::GeniiDataError(format!("GeniiData response error: {}", e))
 				})?;
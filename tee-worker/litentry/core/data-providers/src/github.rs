@@ -0,0 +1,167 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+use crate::sgx_reexport_prelude::*;
+
+use crate::{build_client_with_cert, Error, HttpError};
+use http::header::{ACCEPT, AUTHORIZATION, CONNECTION, USER_AGENT};
+use http_req::response::Headers;
+use itc_rest_client::{
+	http_client::{HttpClient, SendWithCertificateVerification},
+	rest_client::RestClient,
+	RestGet, RestPath,
+};
+use log::*;
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::HashMap,
+	format,
+	string::{String, ToString},
+	vec,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GithubUserAccessTokenData {
+	pub client_id: String,
+	pub client_secret: String,
+	pub code: String,
+	pub redirect_uri: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GithubUserAccessToken {
+	pub access_token: String,
+	pub token_type: String,
+	pub scope: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GithubUser {
+	pub id: u64,
+	pub login: String,
+}
+
+impl RestPath<String> for GithubUserAccessToken {
+	fn get_path(path: String) -> Result<String, HttpError> {
+		Ok(path)
+	}
+}
+
+impl RestPath<String> for GithubUser {
+	fn get_path(path: String) -> Result<String, HttpError> {
+		Ok(path)
+	}
+}
+
+pub struct GithubClient {
+	client: RestClient<HttpClient<SendWithCertificateVerification>>,
+}
+
+/// OAuth2 web application flow: https://docs.github.com/en/apps/oauth-apps/building-oauth-apps/authorizing-oauth-apps
+impl GithubClient {
+	/// `url` is either the github official url (for the code exchange)
+	/// or the github api url (for user queries), the latter requires a user `token`
+	pub fn new(url: &str, token: Option<&str>) -> Self {
+		let mut headers = Headers::new();
+		headers.insert(CONNECTION.as_str(), "close");
+		headers.insert(ACCEPT.as_str(), "application/json");
+		// github api rejects requests without user agent
+		headers.insert(USER_AGENT.as_str(), "litentry");
+		if let Some(token) = token {
+			headers.insert(AUTHORIZATION.as_str(), format!("Bearer {}", token).as_str());
+		}
+		let client = build_client_with_cert(url, headers);
+
+		GithubClient { client }
+	}
+
+	pub fn request_user_access_token(
+		&mut self,
+		data: GithubUserAccessTokenData,
+	) -> Result<GithubUserAccessToken, Error> {
+		debug!("Github create access token");
+
+		let path = String::from("/login/oauth/access_token");
+
+		let mut body = HashMap::new();
+		body.insert("client_id".to_string(), data.client_id);
+		body.insert("client_secret".to_string(), data.client_secret);
+		body.insert("code".to_string(), data.code);
+		body.insert("redirect_uri".to_string(), data.redirect_uri);
+
+		self.client
+			.post_form_urlencoded_capture::<String, GithubUserAccessToken>(path, body)
+			.map_err(|e| Error::RequestError(format!("{:?}", e)))
+	}
+
+	/// Get the authenticated user, requires the client to be built with a user token
+	pub fn get_user_info(&mut self) -> Result<GithubUser, Error> {
+		debug!("Github query authenticated user");
+
+		let path = String::from("/user");
+		let query = vec![];
+		self.client
+			.get_with::<String, GithubUser>(path, query.as_slice())
+			.map_err(|e| Error::RequestError(format!("{:?}", e)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::DataProviderConfig;
+	use lc_mock_server::run;
+
+	fn init() -> DataProviderConfig {
+		let _ = env_logger::builder().is_test(true).try_init();
+		let url = run(0).unwrap();
+		let mut data_provider_config = DataProviderConfig::new().unwrap();
+		data_provider_config.set_github_official_url(url.clone()).unwrap();
+		data_provider_config.set_github_api_url(url).unwrap();
+		data_provider_config
+	}
+
+	#[test]
+	fn request_user_access_token_work() {
+		let data_provider_config = init();
+
+		let data = GithubUserAccessTokenData {
+			client_id: data_provider_config.github_client_id.clone(),
+			client_secret: data_provider_config.github_client_secret.clone(),
+			code: "code".to_string(),
+			redirect_uri: "redirect_uri".to_string(),
+		};
+		let mut client = GithubClient::new(&data_provider_config.github_official_url, None);
+		let result = client.request_user_access_token(data);
+		assert!(result.is_ok(), "error: {:?}", result);
+		assert_eq!(result.unwrap().token_type, "bearer");
+	}
+
+	#[test]
+	fn get_user_info_work() {
+		let data_provider_config = init();
+
+		let mut client =
+			GithubClient::new(&data_provider_config.github_api_url, Some("github_access_token"));
+		let result = client.get_user_info();
+		assert!(result.is_ok(), "error: {:?}", result);
+
+		let user = result.unwrap();
+		assert_eq!(user.id, 1);
+		assert_eq!(user.login, "mock_github_user");
+	}
+}
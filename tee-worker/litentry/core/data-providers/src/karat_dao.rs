@@ -21,8 +21,9 @@ use crate::sgx_reexport_prelude::*;
 extern crate sgx_tstd as std;
 
 use crate::{
-	build_client_with_cert, DataProviderConfig, Error, HttpError, ReqPath, RetryOption,
-	RetryableRestGet,
+	build_client_with_cert,
+	throttle::{self, ProviderLimits},
+	DataProviderConfig, Error, HttpError, ReqPath, RetryOption, RetryableRestGet,
 };
 use http::header::CONNECTION;
 use http_req::response::Headers;
@@ -62,6 +63,7 @@ impl KaratDaoClient {
 		let mut headers = Headers::new();
 		headers.insert(CONNECTION.as_str(), "close");
 		let client = build_client_with_cert(api_url.as_str(), headers);
+		throttle::set_limits(client.base_url(), ProviderLimits::new(0, data_provider_config));
 
 		KaratDaoClient { retry_option, client }
 	}
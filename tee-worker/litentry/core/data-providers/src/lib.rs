@@ -35,7 +35,7 @@ pub mod sgx_reexport_prelude {
 use crate::sgx_reexport_prelude::*;
 
 use codec::{Decode, Encode};
-use core::time::Duration;
+use core::{marker::PhantomData, time::Duration};
 use http_req::response::Headers;
 use itc_rest_client::{
 	error::Error as HttpError,
@@ -44,8 +44,9 @@ use itc_rest_client::{
 	Query, RestGet, RestPath, RestPost,
 };
 use log::debug;
-use serde::{Deserialize, Serialize};
-use std::{thread, vec};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::value::RawValue;
+use std::{boxed::Box, thread, vec};
 
 use litentry_primitives::{
 	AchainableParams, Assertion, ErrorDetail, ErrorString, IntoErrorDetail, ParameterString,
@@ -64,17 +65,22 @@ compile_error!("feature \"std\" and feature \"sgx\" cannot be enabled at the sam
 pub mod achainable;
 pub mod achainable_names;
 pub mod blockchain_info;
+pub mod cache;
+pub mod config_repository;
 pub mod discord_litentry;
 pub mod discord_official;
 pub mod geniidata;
+pub mod github;
 pub mod karat_dao;
 pub mod moralis;
 pub mod nodereal;
 pub mod nodereal_jsonrpc;
+pub mod throttle;
 pub mod twitter_official;
 pub mod vip3;
 
 const TIMEOUT: Duration = Duration::from_secs(3u64);
+const DAY_SECS: u64 = 24 * 60 * 60;
 
 pub const WBTC_TOKEN_ADDRESS: &str = "0x2260fac5e5542a773aa44fbcfedf7c193bc2c599";
 pub const WETH_TOKEN_ADDRESS: &str = "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
@@ -172,8 +178,11 @@ pub struct DataProviderConfig {
 	pub discord_official_url: String,
 	pub litentry_discord_microservice_url: String,
 	pub discord_auth_token: String,
+	pub discord_client_id: String,
+	pub discord_client_secret: String,
 	pub achainable_url: String,
 	pub achainable_auth_key: String,
+	pub achainable_api_cache_ttl: u64,
 	pub credential_endpoint: String,
 	pub oneblock_notion_key: String,
 	pub oneblock_notion_url: String,
@@ -182,6 +191,8 @@ pub struct DataProviderConfig {
 	pub nodereal_api_key: String,
 	pub nodereal_api_retry_delay: u64,
 	pub nodereal_api_retry_times: u16,
+	pub nodereal_api_cache_ttl: u64,
+	pub nodereal_api_rate_limit: u32,
 	pub nodereal_api_url: String,
 	pub nodereal_api_chain_network_url: String,
 	pub contest_legend_discord_role_id: String,
@@ -190,6 +201,7 @@ pub struct DataProviderConfig {
 	pub vip3_url: String,
 	pub geniidata_url: String,
 	pub geniidata_api_key: String,
+	pub geniidata_api_cache_ttl: u64,
 	pub litentry_archive_url: String,
 	pub karat_dao_api_retry_delay: u64,
 	pub karat_dao_api_retry_times: u16,
@@ -198,10 +210,29 @@ pub struct DataProviderConfig {
 	pub moralis_solana_api_url: String,
 	pub moralis_api_retry_delay: u64,
 	pub moralis_api_retry_times: u16,
+	pub moralis_api_cache_ttl: u64,
+	pub moralis_api_rate_limit: u32,
 	pub moralis_api_key: String,
 	pub blockchain_info_api_retry_delay: u64,
 	pub blockchain_info_api_retry_times: u16,
 	pub blockchain_info_api_url: String,
+	pub github_official_url: String,
+	pub github_api_url: String,
+	pub github_client_id: String,
+	pub github_client_secret: String,
+	pub data_provider_failure_threshold: u16,
+	pub data_provider_circuit_open_duration: u64,
+	pub evm_assertion_gas_limit: u64,
+	pub evm_assertion_timeout: u64,
+	/// Validity period in seconds of the credentials about amounts, balances or holdings
+	pub holding_validity_period: u64,
+	/// Validity period in seconds of the credentials about accounts and their activity
+	pub activity_validity_period: u64,
+	/// Validity period in seconds of the credentials of dynamic assertions not declaring one
+	pub dynamic_validity_period: u64,
+	/// Validity periods in seconds overriding the above for single assertion variants,
+	/// by variant name, e.g. `A4` or `Achainable`
+	pub vc_validity_periods: Vec<(String, u64)>,
 }
 
 impl DataProviderConfig {
@@ -218,8 +249,11 @@ impl DataProviderConfig {
 			litentry_discord_microservice_url: "https://tee-microservice.litentry.io:9528"
 				.to_string(),
 			discord_auth_token: "".to_string(),
+			discord_client_id: "".to_string(),
+			discord_client_secret: "".to_string(),
 			achainable_url: "https://label-production.graph.tdf-labs.io/".to_string(),
 			achainable_auth_key: "".to_string(),
+			achainable_api_cache_ttl: 60_000,
 			credential_endpoint: "wss://rpc.rococo-parachain.litentry.io".to_string(),
 			oneblock_notion_key: "".to_string(),
 			oneblock_notion_url: "https://api.notion.com/".to_string(),
@@ -228,6 +262,8 @@ impl DataProviderConfig {
 			nodereal_api_key: "".to_string(),
 			nodereal_api_retry_delay: 5000,
 			nodereal_api_retry_times: 2,
+			nodereal_api_cache_ttl: 60_000,
+			nodereal_api_rate_limit: 20,
 			nodereal_api_url: "https://open-platform.nodereal.io/".to_string(),
 			nodereal_api_chain_network_url: "https://{chain}-mainnet.nodereal.io/".to_string(),
 			contest_legend_discord_role_id: "1172576273063739462".to_string(),
@@ -236,6 +272,7 @@ impl DataProviderConfig {
 			vip3_url: "https://dappapi.vip3.io/".to_string(),
 			geniidata_url: "https://api.geniidata.com/".to_string(),
 			geniidata_api_key: "".to_string(),
+			geniidata_api_cache_ttl: 60_000,
 			litentry_archive_url: "https://archive-test.litentry.io".to_string(),
 			karat_dao_api_retry_delay: 5000,
 			karat_dao_api_retry_times: 2,
@@ -243,11 +280,25 @@ impl DataProviderConfig {
 			moralis_api_key: "".to_string(),
 			moralis_api_retry_delay: 5000,
 			moralis_api_retry_times: 2,
+			moralis_api_cache_ttl: 60_000,
+			moralis_api_rate_limit: 20,
 			moralis_api_url: "https://deep-index.moralis.io/api/v2.2/".to_string(),
 			moralis_solana_api_url: "https://solana-gateway.moralis.io/".to_string(),
 			blockchain_info_api_retry_delay: 5000,
 			blockchain_info_api_retry_times: 2,
 			blockchain_info_api_url: "https://blockchain.info/".to_string(),
+			github_official_url: "https://github.com".to_string(),
+			github_api_url: "https://api.github.com".to_string(),
+			github_client_id: "".to_string(),
+			github_client_secret: "".to_string(),
+			data_provider_failure_threshold: 5,
+			data_provider_circuit_open_duration: 30_000,
+			evm_assertion_gas_limit: 30_000_000,
+			evm_assertion_timeout: 30_000,
+			holding_validity_period: 30 * DAY_SECS,
+			activity_validity_period: 180 * DAY_SECS,
+			dynamic_validity_period: 30 * DAY_SECS,
+			vc_validity_periods: vec![],
 		};
 
 		// we allow to override following config properties for non prod dev
@@ -265,6 +316,9 @@ impl DataProviderConfig {
 			if let Ok(v) = env::var("ACHAINABLE_URL") {
 				config.set_achainable_url(v)?;
 			}
+			if let Ok(v) = env::var("ACHAINABLE_API_CACHE_TTL") {
+				config.set_achainable_api_cache_ttl(v.parse::<u64>().unwrap());
+			}
 			if let Ok(v) = env::var("CREDENTIAL_ENDPOINT") {
 				config.set_credential_endpoint(v);
 			}
@@ -286,6 +340,12 @@ impl DataProviderConfig {
 			if let Ok(v) = env::var("NODEREAL_API_RETRY_TIMES") {
 				config.set_nodereal_api_retry_times(v.parse::<u16>().unwrap());
 			}
+			if let Ok(v) = env::var("NODEREAL_API_CACHE_TTL") {
+				config.set_nodereal_api_cache_ttl(v.parse::<u64>().unwrap());
+			}
+			if let Ok(v) = env::var("NODEREAL_API_RATE_LIMIT") {
+				config.set_nodereal_api_rate_limit(v.parse::<u32>().unwrap());
+			}
 			if let Ok(v) = env::var("NODEREAL_API_CHAIN_NETWORK_URL") {
 				config.set_nodereal_api_chain_network_url(v)?;
 			}
@@ -304,6 +364,9 @@ impl DataProviderConfig {
 			if let Ok(v) = env::var("GENIIDATA_URL") {
 				config.set_geniidata_url(v)?;
 			}
+			if let Ok(v) = env::var("GENIIDATA_API_CACHE_TTL") {
+				config.set_geniidata_api_cache_ttl(v.parse::<u64>().unwrap());
+			}
 			if let Ok(v) = env::var("LITENTRY_ARCHIVE_URL") {
 				config.set_litentry_archive_url(v)?;
 			}
@@ -328,6 +391,12 @@ impl DataProviderConfig {
 			if let Ok(v) = env::var("MORALIS_API_RETRY_TIMES") {
 				config.set_moralis_api_retry_times(v.parse::<u16>().unwrap());
 			}
+			if let Ok(v) = env::var("MORALIS_API_CACHE_TTL") {
+				config.set_moralis_api_cache_ttl(v.parse::<u64>().unwrap());
+			}
+			if let Ok(v) = env::var("MORALIS_API_RATE_LIMIT") {
+				config.set_moralis_api_rate_limit(v.parse::<u32>().unwrap());
+			}
 			if let Ok(v) = env::var("BLOCKCHAIN_INFO_API_URL") {
 				config.set_blockchain_info_api_url(v)?;
 			}
@@ -337,6 +406,42 @@ impl DataProviderConfig {
 			if let Ok(v) = env::var("BLOCKCHAIN_INFO_API_RETRY_TIMES") {
 				config.set_blockchain_info_api_retry_times(v.parse::<u16>().unwrap());
 			}
+			if let Ok(v) = env::var("GITHUB_OFFICIAL_URL") {
+				config.set_github_official_url(v)?;
+			}
+			if let Ok(v) = env::var("GITHUB_API_URL") {
+				config.set_github_api_url(v)?;
+			}
+			if let Ok(v) = env::var("DATA_PROVIDER_FAILURE_THRESHOLD") {
+				config.set_data_provider_failure_threshold(v.parse::<u16>().unwrap());
+			}
+			if let Ok(v) = env::var("DATA_PROVIDER_CIRCUIT_OPEN_DURATION") {
+				config.set_data_provider_circuit_open_duration(v.parse::<u64>().unwrap());
+			}
+			if let Ok(v) = env::var("EVM_ASSERTION_GAS_LIMIT") {
+				config.set_evm_assertion_gas_limit(v.parse::<u64>().unwrap());
+			}
+			if let Ok(v) = env::var("EVM_ASSERTION_TIMEOUT") {
+				config.set_evm_assertion_timeout(v.parse::<u64>().unwrap());
+			}
+			if let Ok(v) = env::var("HOLDING_VALIDITY_PERIOD") {
+				config.set_holding_validity_period(v.parse::<u64>().unwrap());
+			}
+			if let Ok(v) = env::var("ACTIVITY_VALIDITY_PERIOD") {
+				config.set_activity_validity_period(v.parse::<u64>().unwrap());
+			}
+			if let Ok(v) = env::var("DYNAMIC_VALIDITY_PERIOD") {
+				config.set_dynamic_validity_period(v.parse::<u64>().unwrap());
+			}
+			// comma separated `<variant>=<seconds>` pairs, e.g. `A4=86400,A13=86400`
+			if let Ok(v) = env::var("VC_VALIDITY_PERIODS") {
+				for (variant, period) in v.split(',').filter_map(|p| p.split_once('=')) {
+					config.set_vc_validity_period(
+						variant.trim().to_string(),
+						period.trim().parse::<u64>().unwrap(),
+					)?;
+				}
+			}
 		};
 		// set secrets from env variables
 		if let Ok(v) = env::var("TWITTER_AUTH_TOKEN_V2") {
@@ -351,6 +456,12 @@ impl DataProviderConfig {
 		if let Ok(v) = env::var("DISCORD_AUTH_TOKEN") {
 			config.set_discord_auth_token(v);
 		}
+		if let Ok(v) = env::var("DISCORD_CLIENT_ID") {
+			config.set_discord_client_id(v);
+		}
+		if let Ok(v) = env::var("DISCORD_CLIENT_SECRET") {
+			config.set_discord_client_secret(v);
+		}
 		if let Ok(v) = env::var("ACHAINABLE_AUTH_KEY") {
 			config.set_achainable_auth_key(v);
 		}
@@ -366,6 +477,12 @@ impl DataProviderConfig {
 		if let Ok(v) = env::var("MORALIS_API_KEY") {
 			config.set_moralis_api_key(v);
 		}
+		if let Ok(v) = env::var("GITHUB_CLIENT_ID") {
+			config.set_github_client_id(v);
+		}
+		if let Ok(v) = env::var("GITHUB_CLIENT_SECRET") {
+			config.set_github_client_secret(v);
+		}
 		Ok(config)
 	}
 	pub fn set_twitter_official_url(&mut self, v: String) -> Result<(), Error> {
@@ -402,6 +519,14 @@ impl DataProviderConfig {
 		debug!("set_discord_auth_token: {:?}", v);
 		self.discord_auth_token = v;
 	}
+	pub fn set_discord_client_id(&mut self, v: String) {
+		debug!("set_discord_client_id: {:?}", v);
+		self.discord_client_id = v;
+	}
+	pub fn set_discord_client_secret(&mut self, v: String) {
+		debug!("set_discord_client_secret: {:?}", v);
+		self.discord_client_secret = v;
+	}
 	pub fn set_achainable_url(&mut self, v: String) -> Result<(), Error> {
 		check_url(&v)?;
 		debug!("set_achainable_url: {:?}", v);
@@ -412,6 +537,10 @@ impl DataProviderConfig {
 		debug!("set_achainable_auth_key: {:?}", v);
 		self.achainable_auth_key = v;
 	}
+	pub fn set_achainable_api_cache_ttl(&mut self, v: u64) {
+		debug!("set_achainable_api_cache_ttl: {:?}", v);
+		self.achainable_api_cache_ttl = v;
+	}
 	pub fn set_credential_endpoint(&mut self, v: String) {
 		debug!("set_credential_endpoint: {:?}", v);
 		self.credential_endpoint = v;
@@ -446,6 +575,14 @@ impl DataProviderConfig {
 		debug!("set_nodereal_api_retry_times: {:?}", v);
 		self.nodereal_api_retry_times = v;
 	}
+	pub fn set_nodereal_api_cache_ttl(&mut self, v: u64) {
+		debug!("set_nodereal_api_cache_ttl: {:?}", v);
+		self.nodereal_api_cache_ttl = v;
+	}
+	pub fn set_nodereal_api_rate_limit(&mut self, v: u32) {
+		debug!("set_nodereal_api_rate_limit: {:?}", v);
+		self.nodereal_api_rate_limit = v;
+	}
 	pub fn set_nodereal_api_url(&mut self, v: String) -> Result<(), Error> {
 		check_url(&v)?;
 		debug!("set_nodereal_api_url: {:?}", v);
@@ -486,6 +623,10 @@ impl DataProviderConfig {
 		debug!("set_geniidata_api_key: {:?}", v);
 		self.geniidata_api_key = v;
 	}
+	pub fn set_geniidata_api_cache_ttl(&mut self, v: u64) {
+		debug!("set_geniidata_api_cache_ttl: {:?}", v);
+		self.geniidata_api_cache_ttl = v;
+	}
 	pub fn set_litentry_archive_url(&mut self, v: String) -> Result<(), Error> {
 		check_url(&v)?;
 		debug!("set_litentry_archive_url: {:?}", v);
@@ -518,6 +659,14 @@ impl DataProviderConfig {
 		debug!("set_moralis_api_retry_times: {:?}", v);
 		self.moralis_api_retry_times = v;
 	}
+	pub fn set_moralis_api_cache_ttl(&mut self, v: u64) {
+		debug!("set_moralis_api_cache_ttl: {:?}", v);
+		self.moralis_api_cache_ttl = v;
+	}
+	pub fn set_moralis_api_rate_limit(&mut self, v: u32) {
+		debug!("set_moralis_api_rate_limit: {:?}", v);
+		self.moralis_api_rate_limit = v;
+	}
 	pub fn set_moralis_api_url(&mut self, v: String) -> Result<(), Error> {
 		check_url(&v)?;
 		debug!("set_moralis_api_url: {:?}", v);
@@ -544,6 +693,65 @@ impl DataProviderConfig {
 		self.blockchain_info_api_url = v;
 		Ok(())
 	}
+	pub fn set_github_official_url(&mut self, v: String) -> Result<(), Error> {
+		check_url(&v)?;
+		debug!("set_github_official_url: {:?}", v);
+		self.github_official_url = v;
+		Ok(())
+	}
+	pub fn set_github_api_url(&mut self, v: String) -> Result<(), Error> {
+		check_url(&v)?;
+		debug!("set_github_api_url: {:?}", v);
+		self.github_api_url = v;
+		Ok(())
+	}
+	pub fn set_github_client_id(&mut self, v: String) {
+		debug!("set_github_client_id: {:?}", v);
+		self.github_client_id = v;
+	}
+	pub fn set_github_client_secret(&mut self, v: String) {
+		debug!("set_github_client_secret: {:?}", v);
+		self.github_client_secret = v;
+	}
+	pub fn set_data_provider_failure_threshold(&mut self, v: u16) {
+		debug!("set_data_provider_failure_threshold: {:?}", v);
+		self.data_provider_failure_threshold = v;
+	}
+	pub fn set_data_provider_circuit_open_duration(&mut self, v: u64) {
+		debug!("set_data_provider_circuit_open_duration: {:?}", v);
+		self.data_provider_circuit_open_duration = v;
+	}
+	pub fn set_evm_assertion_gas_limit(&mut self, v: u64) {
+		debug!("set_evm_assertion_gas_limit: {:?}", v);
+		self.evm_assertion_gas_limit = v;
+	}
+	pub fn set_evm_assertion_timeout(&mut self, v: u64) {
+		debug!("set_evm_assertion_timeout: {:?}", v);
+		self.evm_assertion_timeout = v;
+	}
+	pub fn set_holding_validity_period(&mut self, v: u64) {
+		debug!("set_holding_validity_period: {:?}", v);
+		self.holding_validity_period = v;
+	}
+	pub fn set_activity_validity_period(&mut self, v: u64) {
+		debug!("set_activity_validity_period: {:?}", v);
+		self.activity_validity_period = v;
+	}
+	pub fn set_dynamic_validity_period(&mut self, v: u64) {
+		debug!("set_dynamic_validity_period: {:?}", v);
+		self.dynamic_validity_period = v;
+	}
+	pub fn set_vc_validity_period(&mut self, variant: String, v: u64) -> Result<(), Error> {
+		if !Assertion::VARIANT_NAMES.contains(&variant.as_str()) {
+			return Err(Error::ConfigError(format!("unknown assertion variant: {}", variant)))
+		}
+		debug!("set_vc_validity_period: {:?} {:?}", variant, v);
+		match self.vc_validity_periods.iter_mut().find(|(name, _)| *name == variant) {
+			Some((_, period)) => *period = v,
+			None => self.vc_validity_periods.push((variant, v)),
+		}
+		Ok(())
+	}
 }
 
 fn check_url(v: &String) -> Result<(), Error> {
@@ -572,12 +780,34 @@ pub enum Error {
 
 	#[error("Retryable error: {0}")]
 	RetryableError(String),
+
+	#[error("Provider unavailable: {0}")]
+	ProviderUnavailable(String),
+
+	#[error("Config error: {0}")]
+	ConfigError(String),
 }
 
 impl IntoErrorDetail for Error {
 	fn into_error_detail(self) -> ErrorDetail {
+		match self {
+			Error::ProviderUnavailable(reason) =>
+				ErrorDetail::DataProviderUnavailable(ErrorString::truncate_from(reason.into())),
+			_ => ErrorDetail::DataProviderError(ErrorString::truncate_from(
+				format!("{self:?}").as_bytes().to_vec(),
+			)),
+		}
+	}
+}
+
+/// The error detail of a failed request sent by a raw rest client, a failing provider, e.g.
+/// 5xx or timeout, is reported as unavailable so that the request can be retried later.
+pub fn http_error_detail(err: HttpError) -> ErrorDetail {
+	if throttle::is_provider_failure(&err) {
+		Error::ProviderUnavailable(format!("{err:?}")).into_error_detail()
+	} else {
 		ErrorDetail::DataProviderError(ErrorString::truncate_from(
-			format!("{self:?}").as_bytes().to_vec(),
+			format!("{err:?}").as_bytes().to_vec(),
 		))
 	}
 }
@@ -652,6 +882,7 @@ where
 		let mut retries = 0;
 		let base_delay = Duration::from_millis(retry_option.retry_delay.unwrap_or_default());
 		let maximum_retries = retry_option.retry_times.unwrap_or_default();
+		let base_url = self.base_url().clone();
 
 		loop {
 			if retries > 0 {
@@ -659,17 +890,35 @@ where
 			}
 
 			if retries > maximum_retries {
-				return Err(Error::RetryableError(format!(
+				// still rate-limited, the request can be retried later
+				return Err(Error::ProviderUnavailable(format!(
 					"Fail to call rest api within {} retries",
 					maximum_retries
 				)))
 			}
 
-			match action(self) {
+			let wait = throttle::acquire(&base_url)?;
+			if !wait.is_zero() {
+				debug!("Rate limit of {} reached, wait for {:?}", base_url, wait);
+				thread::sleep(wait);
+			}
+
+			let response = action(self);
+			throttle::record(
+				&base_url,
+				!matches!(&response, Err(err) if throttle::is_provider_failure(err)),
+			);
+
+			match response {
 				Ok(response) => return Ok(response),
 				Err(err) => {
-					let req_err: Error =
-						Error::RequestError(format!("call rest api error: {}", err));
+					// a failing provider, e.g. 5xx or timeout, is reported as unavailable
+					// so that the request can be retried later
+					let req_err: Error = if throttle::is_provider_failure(&err) {
+						Error::ProviderUnavailable(format!("call rest api error: {}", err))
+					} else {
+						Error::RequestError(format!("call rest api error: {}", err))
+					};
 					match err {
 						HttpError::HttpError(code, _) =>
 							if code == 429 {
@@ -688,11 +937,81 @@ where
 	}
 }
 
+/// The raw body of a response, deserialized only after going through the response cache.
+struct RawResponse<R>(Box<RawValue>, PhantomData<R>);
+
+impl<'de, R> Deserialize<'de> for RawResponse<R> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		Box::<RawValue>::deserialize(deserializer).map(|raw| RawResponse(raw, PhantomData))
+	}
+}
+
+impl<U, R: RestPath<U>> RestPath<U> for RawResponse<R> {
+	fn get_path(params: U) -> Result<String, HttpError> {
+		R::get_path(params)
+	}
+}
+
+trait RetryableCachedRestClient<T> {
+	fn retry_cached<A, R>(
+		&mut self,
+		key: Option<String>,
+		action: A,
+		retry_option: RetryOption,
+	) -> Result<R, Error>
+	where
+		A: Fn(&mut RestHttpClient<T>) -> Result<Box<RawValue>, HttpError>,
+		R: serde::de::DeserializeOwned;
+}
+
+impl<T> RetryableCachedRestClient<T> for RestHttpClient<T>
+where
+	T: Send,
+{
+	// the response is served from the cache if the provider has a cache ttl set
+	fn retry_cached<A, R>(
+		&mut self,
+		key: Option<String>,
+		action: A,
+		retry_option: RetryOption,
+	) -> Result<R, Error>
+	where
+		A: Fn(&mut RestHttpClient<T>) -> Result<Box<RawValue>, HttpError>,
+		R: serde::de::DeserializeOwned,
+	{
+		let base_url = self.base_url().clone();
+		let ttl = cache::cache_ttl(&base_url);
+		let key = key.filter(|_| ttl > 0);
+
+		let body = match key.as_ref().and_then(|key| cache::get(&base_url, key)) {
+			Some(body) => body,
+			None => {
+				let body = self.retry(&action, retry_option)?.get().to_string();
+				if let Some(key) = key {
+					cache::put(&base_url, &key, body.clone(), ttl);
+				}
+				body
+			},
+		};
+
+		match serde_json::from_str(body.as_str()) {
+			Ok(response) => Ok(response),
+			Err(err) => Err(Error::RequestError(format!(
+				"call rest api error: {}",
+				HttpError::DeserializeParseError(err, body)
+			))),
+		}
+	}
+}
+
 pub trait RetryableRestGet {
 	// set retry_option to None for fail fast
 	fn get_retry<U, R>(&mut self, params: U, retry_option: Option<RetryOption>) -> Result<R, Error>
 	where
-		U: Copy,
+		U: Clone,
 		R: serde::de::DeserializeOwned + RestPath<U>;
 
 	// set retry_option to None for fail fast
@@ -703,7 +1022,7 @@ pub trait RetryableRestGet {
 		retry_option: Option<RetryOption>,
 	) -> Result<R, Error>
 	where
-		U: Copy,
+		U: Clone,
 		R: serde::de::DeserializeOwned + RestPath<U>;
 }
 
@@ -713,10 +1032,15 @@ where
 {
 	fn get_retry<U, R>(&mut self, params: U, retry_option: Option<RetryOption>) -> Result<R, Error>
 	where
-		U: Copy,
+		U: Clone,
 		R: serde::de::DeserializeOwned + RestPath<U>,
 	{
-		self.retry(|c| c.get(params), retry_option.unwrap_or(DEFAULT_RETRY_OPTION))
+		let key = R::get_path(params.clone()).ok().map(|path| cache::cache_key(&path, None, None));
+		self.retry_cached(
+			key,
+			|c| c.get::<U, RawResponse<R>>(params.clone()).map(|r| r.0),
+			retry_option.unwrap_or(DEFAULT_RETRY_OPTION),
+		)
 	}
 
 	fn get_with_retry<U, R>(
@@ -726,10 +1050,17 @@ where
 		retry_option: Option<RetryOption>,
 	) -> Result<R, Error>
 	where
-		U: Copy,
+		U: Clone,
 		R: serde::de::DeserializeOwned + RestPath<U>,
 	{
-		self.retry(|c| c.get_with(params, query), retry_option.unwrap_or(DEFAULT_RETRY_OPTION))
+		let key = R::get_path(params.clone())
+			.ok()
+			.map(|path| cache::cache_key(&path, Some(query), None));
+		self.retry_cached(
+			key,
+			|c| c.get_with::<U, RawResponse<R>>(params.clone(), query).map(|r| r.0),
+			retry_option.unwrap_or(DEFAULT_RETRY_OPTION),
+		)
 	}
 }
 
@@ -742,7 +1073,7 @@ pub trait RetryableRestPost {
 		retry_option: Option<RetryOption>,
 	) -> Result<K, Error>
 	where
-		U: Copy,
+		U: Clone,
 		D: serde::Serialize + RestPath<U>,
 		K: serde::de::DeserializeOwned;
 }
@@ -758,11 +1089,20 @@ where
 		retry_option: Option<RetryOption>,
 	) -> Result<K, Error>
 	where
-		U: Copy,
+		U: Clone,
 		D: serde::Serialize + RestPath<U>,
 		K: serde::de::DeserializeOwned,
 	{
-		self.retry(|c| c.post_capture(params, data), retry_option.unwrap_or(DEFAULT_RETRY_OPTION))
+		// serializing through `Value` sorts the object keys of the body
+		let key = D::get_path(params.clone()).ok().and_then(|path| {
+			let body = serde_json::to_value(data).ok()?.to_string();
+			Some(cache::cache_key(&path, None, Some(&body)))
+		});
+		self.retry_cached(
+			key,
+			|c| c.post_capture::<U, D, Box<RawValue>>(params.clone(), data),
+			retry_option.unwrap_or(DEFAULT_RETRY_OPTION),
+		)
 	}
 }
 
@@ -799,6 +1139,22 @@ pub fn convert_balance_hex_json_value_to_u128(value: serde_json::Value) -> Resul
 mod tests {
 	use super::*;
 
+	#[test]
+	fn failing_provider_is_unavailable() {
+		assert!(matches!(
+			http_error_detail(HttpError::HttpError(503, "".to_string())),
+			ErrorDetail::DataProviderUnavailable(_)
+		));
+		assert!(matches!(
+			http_error_detail(HttpError::TimeoutError),
+			ErrorDetail::DataProviderUnavailable(_)
+		));
+		assert!(matches!(
+			http_error_detail(HttpError::HttpError(404, "".to_string())),
+			ErrorDetail::DataProviderError(_)
+		));
+	}
+
 	#[test]
 	fn should_return_correct_value_when_param_is_valid() {
 		assert_eq!(
@@ -18,8 +18,9 @@
 use crate::sgx_reexport_prelude::*;
 
 use crate::{
-	build_client_with_cert, DataProviderConfig, Error, HttpError, ReqPath, RetryOption,
-	RetryableRestGet,
+	build_client_with_cert, cache,
+	throttle::{self, ProviderLimits},
+	DataProviderConfig, Error, HttpError, ReqPath, RetryOption, RetryableRestGet,
 };
 use http::header::CONNECTION;
 use http_req::response::Headers;
@@ -70,6 +71,12 @@ impl MoralisClient {
 		headers.insert("X-API-Key", api_key.as_str());
 		let client = build_client_with_cert(api_url.as_str(), headers.clone());
 		let solana_client = build_client_with_cert(solana_api_url.as_str(), headers.clone());
+		cache::set_cache_ttl(client.base_url(), data_provider_config.moralis_api_cache_ttl);
+		cache::set_cache_ttl(solana_client.base_url(), data_provider_config.moralis_api_cache_ttl);
+		let limits =
+			ProviderLimits::new(data_provider_config.moralis_api_rate_limit, data_provider_config);
+		throttle::set_limits(client.base_url(), limits);
+		throttle::set_limits(solana_client.base_url(), limits);
 
 		MoralisClient { retry_option, client, solana_client }
 	}
@@ -18,8 +18,9 @@
 use crate::sgx_reexport_prelude::*;
 
 use crate::{
-	build_client_with_cert, convert_balance_hex_json_value_to_u128, DataProviderConfig, Error,
-	HttpError, ReqPath, RetryOption, RetryableRestPost,
+	build_client_with_cert, cache, convert_balance_hex_json_value_to_u128,
+	throttle::{self, ProviderLimits},
+	DataProviderConfig, Error, HttpError, ReqPath, RetryOption, RetryableRestPost,
 };
 use http::header::CONNECTION;
 use http_req::response::Headers;
@@ -128,6 +129,11 @@ impl NoderealJsonrpcClient {
 		let mut headers = Headers::new();
 		headers.insert(CONNECTION.as_str(), "close");
 		let client = build_client_with_cert(base_url.as_str(), headers);
+		cache::set_cache_ttl(client.base_url(), data_provider_config.nodereal_api_cache_ttl);
+		throttle::set_limits(
+			client.base_url(),
+			ProviderLimits::new(data_provider_config.nodereal_api_rate_limit, data_provider_config),
+		);
 
 		NoderealJsonrpcClient { path, retry_option, client }
 	}
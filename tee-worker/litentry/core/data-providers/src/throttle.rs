@@ -0,0 +1,270 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Per-provider rate limiting and circuit breaking, shared by all the in-flight requests.
+//!
+//! Each provider has a token bucket refilled at `rate_limit` tokens per second, a request
+//! that finds the bucket empty reserves the next token and waits for it.
+//!
+//! The circuit of a provider opens after `failure_threshold` consecutive failures, requests
+//! are then rejected with `Error::ProviderUnavailable` until `open_duration` has passed.
+//! After that a single probing request is let through (half-open): the circuit is closed
+//! again if it succeeds, or re-opened if it fails.
+
+use crate::{DataProviderConfig, Error, HttpError};
+use core::time::Duration;
+use itp_time_utils::now_as_millis;
+use lazy_static::lazy_static;
+use log::warn;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+#[cfg(feature = "sgx")]
+use std::sync::SgxRwLock as RwLock;
+use std::{
+	collections::BTreeMap,
+	format,
+	string::{String, ToString},
+};
+use url::Url;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderLimits {
+	/// Requests per second, 0 disables the rate limiting
+	pub rate_limit: u32,
+	/// Consecutive failures opening the circuit, 0 disables the circuit breaker
+	pub failure_threshold: u16,
+	/// Milliseconds the circuit stays open before a probing request is let through
+	pub open_duration: u64,
+}
+
+impl ProviderLimits {
+	pub fn new(rate_limit: u32, data_provider_config: &DataProviderConfig) -> Self {
+		Self {
+			rate_limit,
+			failure_threshold: data_provider_config.data_provider_failure_threshold,
+			open_duration: data_provider_config.data_provider_circuit_open_duration,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitState {
+	Closed,
+	Open { until: u64 },
+	HalfOpen { probing: bool },
+}
+
+struct ProviderState {
+	limits: ProviderLimits,
+	tokens: f64,
+	refilled_at: u64,
+	circuit: CircuitState,
+	failures: u16,
+}
+
+impl ProviderState {
+	fn new(limits: ProviderLimits, now: u64) -> Self {
+		Self {
+			limits,
+			tokens: limits.rate_limit as f64,
+			refilled_at: now,
+			circuit: CircuitState::Closed,
+			failures: 0,
+		}
+	}
+
+	// returns how long the request has to wait for its token
+	fn take_token(&mut self, now: u64) -> Duration {
+		let rate = self.limits.rate_limit as f64;
+		if rate == 0.0 {
+			return Duration::ZERO
+		}
+
+		let elapsed = now.saturating_sub(self.refilled_at) as f64;
+		self.tokens = (self.tokens + elapsed * rate / 1000.0).min(rate);
+		self.refilled_at = now;
+
+		self.tokens -= 1.0;
+		if self.tokens >= 0.0 {
+			Duration::ZERO
+		} else {
+			Duration::from_millis((-self.tokens * 1000.0 / rate) as u64)
+		}
+	}
+}
+
+lazy_static! {
+	static ref PROVIDERS: RwLock<BTreeMap<String, ProviderState>> = RwLock::new(BTreeMap::new());
+}
+
+/// Set the limits of the provider at `base_url`, the state of the provider is kept if the
+/// limits don't change.
+pub fn set_limits(base_url: &Url, limits: ProviderLimits) {
+	let mut providers = match PROVIDERS.write() {
+		Ok(providers) => providers,
+		Err(_) => {
+			warn!("Failed to set data provider limits: lock poisoning");
+			return
+		},
+	};
+
+	match providers.get(base_url.as_str()) {
+		Some(state) if state.limits == limits => {},
+		_ => {
+			providers.insert(base_url.to_string(), ProviderState::new(limits, now_as_millis()));
+		},
+	}
+}
+
+/// Acquire the permission to send a request to the provider at `base_url`.
+///
+/// Returns how long the request has to wait to respect the rate limit, or
+/// `Error::ProviderUnavailable` if the circuit of the provider is open.
+pub fn acquire(base_url: &Url) -> Result<Duration, Error> {
+	let mut providers = match PROVIDERS.write() {
+		Ok(providers) => providers,
+		Err(_) => return Ok(Duration::ZERO),
+	};
+	let state = match providers.get_mut(base_url.as_str()) {
+		Some(state) => state,
+		None => return Ok(Duration::ZERO),
+	};
+	let now = now_as_millis();
+
+	match state.circuit {
+		CircuitState::Closed => {},
+		CircuitState::Open { until } if now < until =>
+			return Err(unavailable(base_url, "circuit is open")),
+		CircuitState::Open { .. } | CircuitState::HalfOpen { probing: false } => {
+			state.circuit = CircuitState::HalfOpen { probing: true };
+		},
+		CircuitState::HalfOpen { probing: true } =>
+			return Err(unavailable(base_url, "circuit is half-open")),
+	}
+
+	Ok(state.take_token(now))
+}
+
+/// Record the outcome of a request to the provider at `base_url`.
+pub fn record(base_url: &Url, success: bool) {
+	let mut providers = match PROVIDERS.write() {
+		Ok(providers) => providers,
+		Err(_) => return,
+	};
+	let state = match providers.get_mut(base_url.as_str()) {
+		Some(state) => state,
+		None => return,
+	};
+
+	if success {
+		state.failures = 0;
+		state.circuit = CircuitState::Closed;
+		return
+	}
+
+	state.failures = state.failures.saturating_add(1);
+	let threshold = state.limits.failure_threshold;
+	let should_open = match state.circuit {
+		CircuitState::HalfOpen { .. } => true,
+		_ => threshold > 0 && state.failures >= threshold,
+	};
+	if should_open {
+		warn!(
+			"Opening the circuit of data provider {} after {} failures",
+			base_url, state.failures
+		);
+		state.circuit = CircuitState::Open {
+			until: now_as_millis().saturating_add(state.limits.open_duration),
+		};
+	}
+}
+
+/// Whether the error means the provider is failing, as opposed to e.g. rejecting an
+/// invalid request, which shouldn't open the circuit.
+pub fn is_provider_failure(err: &HttpError) -> bool {
+	match err {
+		HttpError::HttpError(code, _) => *code == 429 || *code >= 500,
+		HttpError::RequestError
+		| HttpError::HttpReqError(_)
+		| HttpError::IoError(_)
+		| HttpError::TimeoutError => true,
+		_ => false,
+	}
+}
+
+fn unavailable(base_url: &Url, reason: &str) -> Error {
+	Error::ProviderUnavailable(format!(
+		"{} is temporarily unavailable, {}",
+		base_url.host_str().unwrap_or_default(),
+		reason
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn limits(rate_limit: u32, failure_threshold: u16, open_duration: u64) -> ProviderLimits {
+		ProviderLimits { rate_limit, failure_threshold, open_duration }
+	}
+
+	#[test]
+	fn rate_limit_works() {
+		let mut state = ProviderState::new(limits(2, 0, 0), 0);
+		assert_eq!(state.take_token(0), Duration::ZERO);
+		assert_eq!(state.take_token(0), Duration::ZERO);
+		assert_eq!(state.take_token(0), Duration::from_millis(500));
+		assert_eq!(state.take_token(0), Duration::from_millis(1000));
+		// refilled
+		assert_eq!(state.take_token(2000), Duration::ZERO);
+
+		let mut state = ProviderState::new(limits(0, 0, 0), 0);
+		for _ in 0..10 {
+			assert_eq!(state.take_token(0), Duration::ZERO);
+		}
+	}
+
+	#[test]
+	fn circuit_breaker_works() {
+		let base_url = Url::parse("https://circuit-breaker-works.test/").unwrap();
+		set_limits(&base_url, limits(0, 2, 0));
+
+		assert!(acquire(&base_url).is_ok());
+		record(&base_url, false);
+		assert!(acquire(&base_url).is_ok());
+		record(&base_url, false);
+
+		// the open duration has passed, a single probe is let through
+		assert!(acquire(&base_url).is_ok());
+		assert!(matches!(acquire(&base_url), Err(Error::ProviderUnavailable(_))));
+		record(&base_url, false);
+
+		assert!(acquire(&base_url).is_ok());
+		record(&base_url, true);
+		assert!(acquire(&base_url).is_ok());
+		assert!(acquire(&base_url).is_ok());
+	}
+
+	#[test]
+	fn open_circuit_rejects_requests() {
+		let base_url = Url::parse("https://open-circuit-rejects-requests.test/").unwrap();
+		set_limits(&base_url, limits(0, 1, 60_000));
+
+		assert!(acquire(&base_url).is_ok());
+		record(&base_url, false);
+		assert!(matches!(acquire(&base_url), Err(Error::ProviderUnavailable(_))));
+	}
+}
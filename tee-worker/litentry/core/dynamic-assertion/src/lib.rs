@@ -33,6 +33,8 @@ pub trait AssertionLogicRepository {
 	#[allow(clippy::type_complexity)]
 	fn get(&self, id: &Self::Id) -> Result<Option<Self::Item>, String>;
 	fn save(&self, id: Self::Id, item: Self::Item) -> Result<(), String>;
+	/// Removes the item, removing a non-existing item is not an error
+	fn remove(&self, id: &Self::Id) -> Result<(), String>;
 }
 
 pub struct AssertionResult {
@@ -41,12 +43,17 @@ pub struct AssertionResult {
 	pub assertions: Vec<String>,
 	pub schema_url: String,
 	pub meet: bool,
+	/// Validity period of the issued credential in seconds, declared by the assertion itself,
+	/// `u64::MAX` if the credential never expires
+	pub validity_period: Option<u64>,
 }
 
 pub trait AssertionExecutor<I> {
+	type Error;
+
 	fn execute(
 		&self,
 		assertion_id: I,
 		identities: &[IdentityNetworkTuple],
-	) -> Result<AssertionResult, String>;
+	) -> Result<AssertionResult, Self::Error>;
 }
@@ -0,0 +1,175 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+extern crate sgx_tstd as std;
+
+use core::time::Duration;
+use ethabi::ethereum_types::{H160, H256, U256};
+use evm::{
+	backend::{Backend, Basic},
+	executor::stack::{StackState, StackSubstateMetadata},
+	ExitError, Transfer,
+};
+use itp_time_utils::remaining_time;
+use std::vec::Vec;
+
+// reading the clock is expensive inside the enclave, so it's only read every this many opcodes
+const DEADLINE_CHECK_INTERVAL: u32 = 1024;
+
+// Stack state enforcing the execution deadline while the contract is running, so that pure
+// compute is bounded by the time limit too and not only by the gas limit.
+// The executor records the cost of each opcode through `metadata_mut`, once the deadline is
+// exceeded the gasometer of the current frame is failed, which aborts it and all of its callers.
+pub struct DeadlineStackState<S> {
+	inner: S,
+	// execution deadline as duration since unix epoch
+	deadline: Duration,
+	steps: u32,
+	timed_out: bool,
+}
+
+impl<S> DeadlineStackState<S> {
+	pub fn new(inner: S, deadline: Duration) -> Self {
+		Self { inner, deadline, steps: 0, timed_out: false }
+	}
+}
+
+impl<S: Backend> Backend for DeadlineStackState<S> {
+	fn gas_price(&self) -> U256 {
+		self.inner.gas_price()
+	}
+	fn origin(&self) -> H160 {
+		self.inner.origin()
+	}
+	fn block_hash(&self, number: U256) -> H256 {
+		self.inner.block_hash(number)
+	}
+	fn block_number(&self) -> U256 {
+		self.inner.block_number()
+	}
+	fn block_coinbase(&self) -> H160 {
+		self.inner.block_coinbase()
+	}
+	fn block_timestamp(&self) -> U256 {
+		self.inner.block_timestamp()
+	}
+	fn block_difficulty(&self) -> U256 {
+		self.inner.block_difficulty()
+	}
+	fn block_randomness(&self) -> Option<H256> {
+		self.inner.block_randomness()
+	}
+	fn block_gas_limit(&self) -> U256 {
+		self.inner.block_gas_limit()
+	}
+	fn block_base_fee_per_gas(&self) -> U256 {
+		self.inner.block_base_fee_per_gas()
+	}
+	fn chain_id(&self) -> U256 {
+		self.inner.chain_id()
+	}
+	fn exists(&self, address: H160) -> bool {
+		self.inner.exists(address)
+	}
+	fn basic(&self, address: H160) -> Basic {
+		self.inner.basic(address)
+	}
+	fn code(&self, address: H160) -> Vec<u8> {
+		self.inner.code(address)
+	}
+	fn storage(&self, address: H160, index: H256) -> H256 {
+		self.inner.storage(address, index)
+	}
+	fn original_storage(&self, address: H160, index: H256) -> Option<H256> {
+		self.inner.original_storage(address, index)
+	}
+}
+
+impl<'config, S: StackState<'config>> StackState<'config> for DeadlineStackState<S> {
+	fn metadata(&self) -> &StackSubstateMetadata<'config> {
+		self.inner.metadata()
+	}
+
+	fn metadata_mut(&mut self) -> &mut StackSubstateMetadata<'config> {
+		if !self.timed_out {
+			self.steps += 1;
+			if self.steps >= DEADLINE_CHECK_INTERVAL {
+				self.steps = 0;
+				self.timed_out = remaining_time(self.deadline).is_none();
+			}
+		}
+		let metadata = self.inner.metadata_mut();
+		if self.timed_out {
+			let _ = metadata.gasometer_mut().fail();
+		}
+		metadata
+	}
+
+	fn enter(&mut self, gas_limit: u64, is_static: bool) {
+		self.inner.enter(gas_limit, is_static)
+	}
+	fn exit_commit(&mut self) -> Result<(), ExitError> {
+		self.inner.exit_commit()
+	}
+	fn exit_revert(&mut self) -> Result<(), ExitError> {
+		self.inner.exit_revert()
+	}
+	fn exit_discard(&mut self) -> Result<(), ExitError> {
+		self.inner.exit_discard()
+	}
+
+	fn is_empty(&self, address: H160) -> bool {
+		self.inner.is_empty(address)
+	}
+	fn deleted(&self, address: H160) -> bool {
+		self.inner.deleted(address)
+	}
+	fn is_cold(&self, address: H160) -> bool {
+		self.inner.is_cold(address)
+	}
+	fn is_storage_cold(&self, address: H160, key: H256) -> bool {
+		self.inner.is_storage_cold(address, key)
+	}
+
+	fn inc_nonce(&mut self, address: H160) -> Result<(), ExitError> {
+		self.inner.inc_nonce(address)
+	}
+	fn set_storage(&mut self, address: H160, key: H256, value: H256) {
+		self.inner.set_storage(address, key, value)
+	}
+	fn reset_storage(&mut self, address: H160) {
+		self.inner.reset_storage(address)
+	}
+	fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) {
+		self.inner.log(address, topics, data)
+	}
+	fn set_deleted(&mut self, address: H160) {
+		self.inner.set_deleted(address)
+	}
+	fn set_code(&mut self, address: H160, code: Vec<u8>) {
+		self.inner.set_code(address, code)
+	}
+	fn transfer(&mut self, transfer: Transfer) -> Result<(), ExitError> {
+		self.inner.transfer(transfer)
+	}
+	fn reset_balance(&mut self, address: H160) {
+		self.inner.reset_balance(address)
+	}
+	fn touch(&mut self, address: H160) {
+		self.inner.touch(address)
+	}
+}
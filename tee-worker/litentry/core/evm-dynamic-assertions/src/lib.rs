@@ -27,34 +27,43 @@ extern crate sgx_tstd as std;
 #[cfg(all(not(feature = "std"), feature = "sgx"))]
 pub mod sgx_reexport_prelude {
 	pub use http_sgx as http;
+	pub use rust_base58_sgx as rust_base58;
+	pub use thiserror_sgx as thiserror;
 }
 
 #[cfg(all(not(feature = "std"), feature = "sgx"))]
 use crate::sgx_reexport_prelude::*;
 
-use crate::precompiles::Precompiles;
+use crate::{deadline::DeadlineStackState, precompiles::Precompiles};
+use core::time::Duration;
 use ethabi::{
 	decode, encode,
 	ethereum_types::{H160, U256},
-	ParamType, Token,
+	short_signature, ParamType, Token,
 };
 use evm::{
 	backend::{MemoryBackend, MemoryVicinity},
-	executor::stack::{MemoryStackState, StackExecutor, StackSubstateMetadata},
-	Config,
+	executor::stack::{
+		MemoryStackState, PrecompileSet, StackExecutor, StackState, StackSubstateMetadata,
+	},
+	Config, ExitError, ExitReason,
 };
+use itc_rest_client::rest_client::Url;
+use itp_time_utils::{duration_now, remaining_time};
 use lc_dynamic_assertion::{
 	AssertionExecutor, AssertionLogicRepository, AssertionResult, Identity, IdentityNetworkTuple,
 	Web3Network,
 };
 use std::{
 	collections::BTreeMap,
+	format,
 	string::{String, ToString},
 	sync::Arc,
 	vec,
 	vec::Vec,
 };
 
+mod deadline;
 mod precompiles;
 pub mod repository;
 pub mod sealing;
@@ -63,69 +72,268 @@ pub mod sealing;
 pub mod mock;
 
 pub use itp_settings::files::ASSERTIONS_FILE;
+pub use precompiles::trace::HttpCallTrace;
 
 pub type AssertionId = H160;
 pub type SmartContractByteCode = Vec<u8>;
 pub type AssertionRepositoryItem = (SmartContractByteCode, Vec<String>);
 
+// Gas budget shared by contract deployment and the `execute` call,
+// it must be large enough to cover several http precompile calls.
+pub const DEFAULT_GAS_LIMIT: u64 = 30_000_000;
+pub const DEFAULT_EXECUTION_TIMEOUT: Duration = Duration::from_secs(30);
+
+// selector of solidity's `Error(string)`, used to encode revert reasons
+const REVERT_REASON_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+// optional function of assertion contracts, signature:
+// function validityPeriod() returns (uint64)
+const VALIDITY_PERIOD_FUNCTION: &str = "validityPeriod";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("Could not access assertion repository: {0}")]
+	RepositoryError(String),
+	#[error("Assertion not found, it may have been disabled or removed")]
+	AssertionNotFound,
+	#[error("Could not prepare evm execution input")]
+	InvalidInput,
+	#[error("Assertion contract deployment failed: {0}")]
+	DeployFailed(String),
+	#[error("Assertion contract reverted: {0}")]
+	Reverted(String),
+	#[error("Assertion execution ran out of gas, gas limit: {0}")]
+	OutOfGas(u64),
+	#[error("Assertion execution exceeded the time limit of {0:?}")]
+	Timeout(Duration),
+	#[error("Assertion execution failed: {0}")]
+	ExecutionFailed(String),
+	#[error("Could not decode evm assertion execution result")]
+	DecodeError,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExecutionLimits {
+	// gas available for contract deployment and execution
+	pub gas_limit: u64,
+	// wall-clock time limit, enforced while the contract runs and before each precompile call
+	pub timeout: Duration,
+}
+
+impl Default for ExecutionLimits {
+	fn default() -> Self {
+		Self { gas_limit: DEFAULT_GAS_LIMIT, timeout: DEFAULT_EXECUTION_TIMEOUT }
+	}
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ExecutionTrace {
+	// gas used by contract deployment and execution
+	pub gas_used: u64,
+	// http calls made by precompiles, in call order
+	pub http_calls: Vec<HttpCallTrace>,
+}
+
 pub struct EvmAssertionExecutor<A: AssertionLogicRepository> {
 	pub assertion_repository: Arc<A>,
+	pub limits: ExecutionLimits,
+	// redirects precompile http calls, only meant to run assertions against a mock server
+	pub http_base_url: Option<Url>,
+}
+
+impl<A: AssertionLogicRepository> EvmAssertionExecutor<A> {
+	pub fn new(assertion_repository: Arc<A>) -> Self {
+		Self { assertion_repository, limits: ExecutionLimits::default(), http_base_url: None }
+	}
+
+	pub fn with_limits(mut self, limits: ExecutionLimits) -> Self {
+		self.limits = limits;
+		self
+	}
+
+	pub fn with_http_base_url(mut self, http_base_url: Url) -> Self {
+		self.http_base_url = Some(http_base_url);
+		self
+	}
 }
 
 impl<A: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem>>
 	AssertionExecutor<AssertionId> for EvmAssertionExecutor<A>
 {
+	type Error = Error;
+
 	fn execute(
 		&self,
 		assertion_id: A::Id,
 		identities: &[IdentityNetworkTuple],
-	) -> Result<AssertionResult, String> {
-		let (smart_contract_byte_code, secrets) = self
+	) -> Result<AssertionResult, Self::Error> {
+		self.execute_with_trace(assertion_id, identities).0
+	}
+}
+
+impl<A: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem>>
+	EvmAssertionExecutor<A>
+{
+	// Same as `execute`, but also returns the gas used and the http calls made by precompiles,
+	// the trace is returned even if the execution failed.
+	pub fn execute_with_trace(
+		&self,
+		assertion_id: A::Id,
+		identities: &[IdentityNetworkTuple],
+	) -> (Result<AssertionResult, Error>, ExecutionTrace) {
+		let (smart_contract_byte_code, secrets) = match self
 			.assertion_repository
 			.get(&assertion_id)
-			.map_err(|_| "Could not access assertion repository")?
-			.ok_or("Assertion not found")?;
-		let input = prepare_execute_call_input(identities, secrets)
-			.map_err(|_| "Could not prepare evm execution input")?;
+			.map_err(Error::RepositoryError)
+			.and_then(|item| item.ok_or(Error::AssertionNotFound))
+		{
+			Ok(item) => item,
+			Err(e) => return (Err(e), ExecutionTrace::default()),
+		};
+		let input = match prepare_execute_call_input(identities, secrets) {
+			Ok(input) => input,
+			Err(_) => return (Err(Error::InvalidInput), ExecutionTrace::default()),
+		};
+
+		let gas_limit = self.limits.gas_limit;
+		let deadline = duration_now() + self.limits.timeout;
 
 		// prepare EVM runtime
 		let config = prepare_config();
 		let vicinity = prepare_memory();
 		let state = BTreeMap::new();
 		let mut backend = MemoryBackend::new(&vicinity, state);
-		let metadata = StackSubstateMetadata::new(u64::MAX, &config);
-		let state = MemoryStackState::new(metadata, &mut backend);
-		let precompiles = Precompiles {};
+		let metadata = StackSubstateMetadata::new(gas_limit, &config);
+		let state =
+			DeadlineStackState::new(MemoryStackState::new(metadata, &mut backend), deadline);
+		let precompiles = Precompiles::new(deadline, self.http_base_url.clone());
 		let mut executor = StackExecutor::new_with_precompiles(state, &config, &precompiles);
 
-		// caller, just an unused account
-		let caller = hash(5); //0x05
-
-		// deploy assertion smart contract
-		let address = executor.create_address(evm::CreateScheme::Legacy { caller });
-		let _create_result = executor.transact_create(
-			caller,
-			U256::zero(),
+		let result = deploy_and_call(
+			&mut executor,
 			smart_contract_byte_code,
-			u64::MAX,
-			Vec::new(),
-		);
+			input,
+			gas_limit,
+			deadline,
+			self.limits.timeout,
+		)
+		.and_then(|(output, validity_period)| {
+			let (description, assertion_type, assertions, schema_url, meet) =
+				decode_result(&output).map_err(|_| Error::DecodeError)?;
+			Ok(AssertionResult {
+				description,
+				assertion_type,
+				assertions,
+				schema_url,
+				meet,
+				validity_period,
+			})
+		});
+		let gas_used = executor.used_gas();
+		drop(executor);
+
+		(result, ExecutionTrace { gas_used, http_calls: precompiles.http_calls.into_inner() })
+	}
+}
 
-		// call assertion smart contract
-		let call_result =
-			executor.transact_call(caller, address, U256::zero(), input, u64::MAX, Vec::new());
+// Deploys the assertion contract and calls its `execute` function, returns the call output
+// and the validity period declared by the contract.
+fn deploy_and_call<'config, S: StackState<'config>, P: PrecompileSet>(
+	executor: &mut StackExecutor<'config, '_, S, P>,
+	smart_contract_byte_code: SmartContractByteCode,
+	input: Vec<u8>,
+	gas_limit: u64,
+	deadline: Duration,
+	timeout: Duration,
+) -> Result<(Vec<u8>, Option<u64>), Error> {
+	// caller, just an unused account
+	let caller = hash(5); //0x05
+
+	// deploy assertion smart contract
+	let address = executor.create_address(evm::CreateScheme::Legacy { caller });
+	let create_result = executor.transact_create(
+		caller,
+		U256::zero(),
+		smart_contract_byte_code,
+		gas_limit,
+		Vec::new(),
+	);
+	ensure_within_deadline(deadline, timeout)?;
+	match create_result {
+		(ExitReason::Succeed(_), _) => {},
+		(ExitReason::Error(ExitError::OutOfGas), _) => return Err(Error::OutOfGas(gas_limit)),
+		(ExitReason::Revert(_), data) =>
+			return Err(Error::DeployFailed(decode_revert_reason(&data))),
+		(reason, _) => return Err(Error::DeployFailed(format!("{:?}", reason))),
+	}
 
-		let (description, assertion_type, assertions, schema_url, meet) =
-			decode_result(&call_result.1)
-				.map_err(|_| "Could not decode evm assertion execution result")?;
+	// call assertion smart contract, gas used by the deployment is deducted from the budget
+	let remaining_gas = gas_limit.saturating_sub(executor.used_gas());
+	let call_result =
+		executor.transact_call(caller, address, U256::zero(), input, remaining_gas, Vec::new());
+	ensure_within_deadline(deadline, timeout)?;
+	let output = match call_result {
+		(ExitReason::Succeed(_), output) => output,
+		(ExitReason::Error(ExitError::OutOfGas), _) => return Err(Error::OutOfGas(gas_limit)),
+		(ExitReason::Revert(_), data) => return Err(Error::Reverted(decode_revert_reason(&data))),
+		(reason, _) => return Err(Error::ExecutionFailed(format!("{:?}", reason))),
+	};
+
+	// `validityPeriod()` is optional, contracts without it fall back to the default validity
+	let remaining_gas = gas_limit.saturating_sub(executor.used_gas());
+	let validity_period = match executor.transact_call(
+		caller,
+		address,
+		U256::zero(),
+		short_signature(VALIDITY_PERIOD_FUNCTION, &[]).to_vec(),
+		remaining_gas,
+		Vec::new(),
+	) {
+		(ExitReason::Succeed(_), output) => decode_validity_period(&output),
+		_ => None,
+	};
+
+	Ok((output, validity_period))
+}
+
+// A validity period of 0 means the contract doesn't declare one, `type(uint64).max` that the
+// credential never expires.
+fn decode_validity_period(data: &[u8]) -> Option<u64> {
+	decode(&[ParamType::Uint(64)], data)
+		.ok()
+		.and_then(|decoded| decoded.get(0).and_then(|t| t.clone().into_uint()))
+		.filter(|v| !v.is_zero() && *v <= U256::from(u64::MAX))
+		.map(|v| v.as_u64())
+}
 
-		Ok(AssertionResult { description, assertion_type, assertions, schema_url, meet })
+fn ensure_within_deadline(deadline: Duration, timeout: Duration) -> Result<(), Error> {
+	match remaining_time(deadline) {
+		Some(_) => Ok(()),
+		None => Err(Error::Timeout(timeout)),
 	}
 }
 
+// Decodes revert data produced by `revert("reason")` or `require(cond, "reason")`,
+// falls back to hex for custom errors.
+pub fn decode_revert_reason(data: &[u8]) -> String {
+	if data.is_empty() {
+		return "no revert reason".to_string()
+	}
+	if data.len() > 4 && data[0..4] == REVERT_REASON_SELECTOR {
+		if let Ok(decoded) = decode(&[ParamType::String], &data[4..]) {
+			if let Some(reason) = decoded.get(0).and_then(|t| t.clone().into_string()) {
+				return reason
+			}
+		}
+	}
+	format!("0x{}", hex::encode(data))
+}
+
 fn prepare_config() -> Config {
 	let mut config = Config::frontier();
 	config.has_bitwise_shifting = true;
+	// allow contracts to read dynamically sized precompile outputs and revert with a reason
+	config.has_return_data = true;
+	config.has_revert = true;
 	config.err_on_call_with_more_gas = false;
 	config
 }
@@ -245,6 +453,86 @@ mod tests {
 	use super::*;
 	use litentry_primitives::{Address32, Identity};
 
+	// init code reverting without a reason: PUSH1 0, PUSH1 0, REVERT
+	const REVERTING_INIT_CODE: &str = "60006000fd";
+	// init code deploying a contract which reverts on any call without a reason
+	const REVERTING_CONTRACT: &str = "6005600c60003960056000f360006000fd";
+	// init code deploying a contract which loops forever on any call: JUMPDEST, PUSH1 0, JUMP
+	const LOOPING_CONTRACT: &str = "6004600c60003960046000f35b600056";
+
+	struct TestRepository(SmartContractByteCode);
+
+	impl AssertionLogicRepository for TestRepository {
+		type Id = AssertionId;
+		type Item = AssertionRepositoryItem;
+
+		fn get(&self, _id: &Self::Id) -> Result<Option<Self::Item>, String> {
+			Ok(Some((self.0.clone(), vec![])))
+		}
+
+		fn save(&self, _id: Self::Id, _item: Self::Item) -> Result<(), String> {
+			Ok(())
+		}
+
+		fn remove(&self, _id: &Self::Id) -> Result<(), String> {
+			Ok(())
+		}
+	}
+
+	fn execute(byte_code: &str, limits: ExecutionLimits) -> Result<AssertionResult, Error> {
+		let repository = Arc::new(TestRepository(hex::decode(byte_code).unwrap()));
+		EvmAssertionExecutor::new(repository).with_limits(limits).execute(hash(1), &[])
+	}
+
+	#[test]
+	pub fn should_fail_deployment() {
+		let result = execute(REVERTING_INIT_CODE, ExecutionLimits::default());
+		assert!(matches!(result, Err(Error::DeployFailed(reason)) if reason == "no revert reason"));
+	}
+
+	#[test]
+	pub fn should_report_revert() {
+		let result = execute(REVERTING_CONTRACT, ExecutionLimits::default());
+		assert!(matches!(result, Err(Error::Reverted(reason)) if reason == "no revert reason"));
+	}
+
+	#[test]
+	pub fn should_run_out_of_gas() {
+		let limits = ExecutionLimits { gas_limit: 100_000, ..Default::default() };
+		let result = execute(LOOPING_CONTRACT, limits);
+		assert!(matches!(result, Err(Error::OutOfGas(100_000))));
+	}
+
+	#[test]
+	pub fn should_time_out_in_pure_compute() {
+		// the gas limit is far beyond what can be used within the time limit
+		let limits =
+			ExecutionLimits { gas_limit: 1_000_000_000_000, timeout: Duration::from_millis(100) };
+		let result = execute(LOOPING_CONTRACT, limits);
+		assert!(matches!(result, Err(Error::Timeout(_))));
+	}
+
+	#[test]
+	pub fn should_decode_revert_reason() {
+		let mut data = REVERT_REASON_SELECTOR.to_vec();
+		data.extend(encode(&[Token::String("not allowed".to_string())]));
+
+		assert_eq!(decode_revert_reason(&data), "not allowed");
+		assert_eq!(decode_revert_reason(&[]), "no revert reason");
+		assert_eq!(decode_revert_reason(&[1, 2, 3, 4]), "0x01020304");
+	}
+
+	#[test]
+	pub fn should_decode_validity_period() {
+		assert_eq!(decode_validity_period(&encode(&[Token::Uint(86_400.into())])), Some(86_400));
+		assert_eq!(decode_validity_period(&encode(&[Token::Uint(0.into())])), None);
+		assert_eq!(
+			decode_validity_period(&encode(&[Token::Uint(u64::MAX.into())])),
+			Some(u64::MAX)
+		);
+		assert_eq!(decode_validity_period(&[]), None);
+	}
+
 	#[test]
 	pub fn should_tokenize_identity_with_networks() {
 		// given
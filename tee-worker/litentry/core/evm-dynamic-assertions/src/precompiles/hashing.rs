@@ -0,0 +1,85 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::precompiles::{
+	macros::{decode_input, success},
+	PrecompileResult,
+};
+use ethabi::{ParamType, Token};
+use sp_core::hashing::{blake2_256, keccak_256, sha2_256};
+use std::vec::Vec;
+
+// input: bytes, output: bytes32
+pub fn keccak256_hash(input: Vec<u8>) -> PrecompileResult {
+	hash_with(input, keccak_256)
+}
+
+// input: bytes, output: bytes32
+pub fn blake2_256_hash(input: Vec<u8>) -> PrecompileResult {
+	hash_with(input, blake2_256)
+}
+
+// input: bytes, output: bytes32
+pub fn sha256_hash(input: Vec<u8>) -> PrecompileResult {
+	hash_with(input, sha2_256)
+}
+
+fn hash_with(input: Vec<u8>, hash_fn: fn(&[u8]) -> [u8; 32]) -> PrecompileResult {
+	let decoded = decode_input(&[ParamType::Bytes], &input)?;
+	// safe to unwrap
+	let bytes = decoded.get(0).unwrap().clone().into_bytes().unwrap();
+
+	success(ethabi::encode(&[Token::FixedBytes(hash_fn(&bytes).to_vec())]))
+}
+
+#[cfg(test)]
+pub mod test {
+	use crate::precompiles::hashing::{blake2_256_hash, keccak256_hash, sha256_hash};
+	use ethabi::Token;
+
+	#[test]
+	pub fn test_hashing() {
+		// given
+		let input = ethabi::encode(&[Token::Bytes(b"litentry".to_vec())]);
+
+		// then
+		assert_eq!(
+			keccak256_hash(input.clone()).unwrap().output,
+			ethabi::encode(&[Token::FixedBytes(
+				sp_core::hashing::keccak_256(b"litentry").to_vec()
+			)])
+		);
+		assert_eq!(
+			blake2_256_hash(input.clone()).unwrap().output,
+			ethabi::encode(&[Token::FixedBytes(
+				sp_core::hashing::blake2_256(b"litentry").to_vec()
+			)])
+		);
+		assert_eq!(
+			sha256_hash(input).unwrap().output,
+			ethabi::encode(&[Token::FixedBytes(sp_core::hashing::sha2_256(b"litentry").to_vec())])
+		);
+	}
+
+	#[test]
+	pub fn test_sha256_of_empty_bytes() {
+		let input = ethabi::encode(&[Token::Bytes(vec![])]);
+		assert_eq!(
+			hex::encode(sha256_hash(input).unwrap().output),
+			"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+		);
+	}
+}
@@ -0,0 +1,197 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(not(feature = "std"), feature = "sgx"))]
+use crate::sgx_reexport_prelude::*;
+
+use crate::precompiles::{
+	macros::{decode_input, prepare_custom_failure, success},
+	PrecompileResult,
+};
+use ethabi::{ethereum_types::U256, ParamType, Token};
+use litentry_primitives::{is_valid_bitcoin_pubkey, p2tr_address, p2wpkh_address};
+use rust_base58::ToBase58;
+use sp_core::hashing::{blake2_512, keccak_256};
+use std::{format, string::String, vec, vec::Vec};
+
+// input: (bytes pubkey, uint16 prefix), output: ss58 address string
+pub fn to_ss58_address(input: Vec<u8>) -> PrecompileResult {
+	let decoded = decode_input(&[ParamType::Bytes, ParamType::Uint(16)], &input)?;
+	// safe to unwrap
+	let pubkey = decoded.get(0).unwrap().clone().into_bytes().unwrap();
+	let prefix = decoded.get(1).unwrap().clone().into_uint().unwrap();
+
+	if pubkey.len() != 32 {
+		return Err(prepare_custom_failure(format!(
+			"Invalid substrate pubkey length: {}",
+			pubkey.len()
+		)))
+	}
+
+	if prefix > U256::from(16_383u32) {
+		return Err(prepare_custom_failure(format!("Invalid ss58 prefix: {}", prefix)))
+	}
+	let prefix = prefix.low_u32() as u16;
+
+	// https://docs.substrate.io/reference/address-formats/
+	let mut bytes = match prefix {
+		0..=63 => vec![prefix as u8],
+		_ => {
+			let first = ((prefix & 0b0000_0000_1111_1100) as u8) >> 2;
+			let second = ((prefix >> 8) as u8) | ((prefix & 0b0000_0000_0000_0011) as u8) << 6;
+			vec![first | 0b01000000, second]
+		},
+	};
+	bytes.extend(&pubkey);
+	let checksum = blake2_512(&[b"SS58PRE".as_slice(), bytes.as_slice()].concat());
+	bytes.extend(&checksum[0..2]);
+
+	success(ethabi::encode(&[Token::String(bytes.to_base58())]))
+}
+
+// input: bytes (33 bytes compressed pubkey), output: p2tr address string
+pub fn to_bitcoin_p2tr_address(input: Vec<u8>) -> PrecompileResult {
+	to_bitcoin_address(input, p2tr_address)
+}
+
+// input: bytes (33 bytes compressed pubkey), output: p2wpkh address string
+pub fn to_bitcoin_p2wpkh_address(input: Vec<u8>) -> PrecompileResult {
+	to_bitcoin_address(input, p2wpkh_address)
+}
+
+fn to_bitcoin_address(input: Vec<u8>, address_fn: fn(&str) -> String) -> PrecompileResult {
+	let decoded = decode_input(&[ParamType::Bytes], &input)?;
+	// safe to unwrap
+	let pubkey = hex::encode(decoded.get(0).unwrap().clone().into_bytes().unwrap());
+
+	if !is_valid_bitcoin_pubkey(&pubkey) {
+		return Err(prepare_custom_failure(format!("Invalid bitcoin pubkey: {}", pubkey)))
+	}
+	let address = address_fn(&pubkey);
+	if address.is_empty() {
+		return Err(prepare_custom_failure(format!(
+			"Could not derive bitcoin address from pubkey: {}",
+			pubkey
+		)))
+	}
+
+	success(ethabi::encode(&[Token::String(address)]))
+}
+
+// input: address, output: EIP-55 checksummed address string
+pub fn to_checksum_address(input: Vec<u8>) -> PrecompileResult {
+	let decoded = decode_input(&[ParamType::Address], &input)?;
+	// safe to unwrap
+	let address = hex::encode(decoded.get(0).unwrap().clone().into_address().unwrap());
+
+	// https://eips.ethereum.org/EIPS/eip-55
+	let hash = hex::encode(keccak_256(address.as_bytes()));
+	let checksummed: String = address
+		.chars()
+		.zip(hash.chars())
+		.map(|(c, h)| match h.to_digit(16) {
+			Some(n) if n >= 8 => c.to_ascii_uppercase(),
+			_ => c,
+		})
+		.collect();
+
+	success(ethabi::encode(&[Token::String(format!("0x{}", checksummed))]))
+}
+
+#[cfg(test)]
+pub mod test {
+	use crate::precompiles::identity::{
+		to_bitcoin_p2tr_address, to_bitcoin_p2wpkh_address, to_checksum_address, to_ss58_address,
+	};
+	use ethabi::{ethereum_types::H160, Token};
+
+	#[test]
+	pub fn test_to_ss58_address() {
+		// given
+		let pubkey =
+			hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+				.unwrap();
+
+		// then
+		assert_eq!(
+			decode_string(to_ss58_address(encode_ss58_input(&pubkey, 42)).unwrap().output),
+			"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+		);
+		assert_eq!(
+			decode_string(to_ss58_address(encode_ss58_input(&pubkey, 0)).unwrap().output),
+			"15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+		);
+	}
+
+	#[test]
+	pub fn to_ss58_address_fails_for_invalid_pubkey() {
+		assert!(to_ss58_address(encode_ss58_input(&[1u8; 20], 42)).is_err());
+		assert!(to_ss58_address(encode_ss58_input(&[1u8; 32], 16_384)).is_err());
+	}
+
+	#[test]
+	pub fn test_to_bitcoin_addresses() {
+		// given
+		let pubkey =
+			hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+				.unwrap();
+		let input = ethabi::encode(&[Token::Bytes(pubkey.clone())]);
+
+		// when
+		let p2tr = decode_string(to_bitcoin_p2tr_address(input.clone()).unwrap().output);
+		let p2wpkh = decode_string(to_bitcoin_p2wpkh_address(input).unwrap().output);
+
+		// then
+		let pubkey = hex::encode(pubkey);
+		assert_eq!(p2tr, litentry_primitives::p2tr_address(&pubkey));
+		assert_eq!(p2wpkh, litentry_primitives::p2wpkh_address(&pubkey));
+		assert!(p2tr.starts_with("bc1p"));
+		// BIP-173 test vector
+		assert_eq!(p2wpkh, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+	}
+
+	#[test]
+	pub fn to_bitcoin_address_fails_for_invalid_pubkey() {
+		let input = ethabi::encode(&[Token::Bytes([1u8; 33].to_vec())]);
+		assert!(to_bitcoin_p2tr_address(input.clone()).is_err());
+		assert!(to_bitcoin_p2wpkh_address(input).is_err());
+	}
+
+	#[test]
+	pub fn test_to_checksum_address() {
+		// given
+		let address: H160 =
+			H160::from_slice(&hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap());
+		let input = ethabi::encode(&[Token::Address(address)]);
+
+		// when
+		let result = to_checksum_address(input).unwrap();
+
+		// then
+		assert_eq!(decode_string(result.output), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+	}
+
+	fn encode_ss58_input(pubkey: &[u8], prefix: u16) -> Vec<u8> {
+		ethabi::encode(&[Token::Bytes(pubkey.to_vec()), Token::Uint(prefix.into())])
+	}
+
+	fn decode_string(output: Vec<u8>) -> String {
+		ethabi::decode(&[ethabi::ParamType::String], &output).unwrap()[0]
+			.clone()
+			.into_string()
+			.unwrap()
+	}
+}
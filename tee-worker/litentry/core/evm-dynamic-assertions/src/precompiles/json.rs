@@ -0,0 +1,275 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+// "Fetch once, query many" json api: the response of an http call is kept for the duration
+// of the assertion execution and referenced by a handle, so that several fields can be read
+// and arrays iterated without refetching.
+
+use crate::{
+	json_get_precompile_fn,
+	precompiles::{
+		macros::{
+			decode_http_headers, decode_input, http_headers_param_type, json_pointer_value,
+			prepare_custom_failure, request_json, success, unexpected_type_failure,
+		},
+		PrecompileResult,
+	},
+};
+use core::cell::RefCell;
+use ethabi::{ethereum_types::U256, ParamType, Token};
+use evm::executor::stack::PrecompileFailure;
+use itc_rest_client::{http_client::SendHttpRequest, rest_client::Method};
+use std::{format, vec::Vec};
+
+#[derive(Default)]
+pub struct JsonResponses(RefCell<Vec<serde_json::Value>>);
+
+impl JsonResponses {
+	// Stores the response and returns its handle
+	pub fn store(&self, value: serde_json::Value) -> U256 {
+		let mut responses = self.0.borrow_mut();
+		responses.push(value);
+		U256::from(responses.len() - 1)
+	}
+
+	pub fn with<R>(
+		&self,
+		handle: U256,
+		f: impl FnOnce(&serde_json::Value) -> Result<R, PrecompileFailure>,
+	) -> Result<R, PrecompileFailure> {
+		let responses = self.0.borrow();
+		let value = (handle < U256::from(responses.len()))
+			.then(|| &responses[handle.as_usize()])
+			.ok_or_else(|| {
+			prepare_custom_failure(format!("Invalid json response handle: {}", handle))
+		})?;
+		f(value)
+	}
+}
+
+// input: (url, headers), output: handle
+pub fn http_get_json<T: SendHttpRequest>(
+	input: Vec<u8>,
+	client: T,
+	responses: &JsonResponses,
+) -> PrecompileResult {
+	let decoded = decode_input(&[ParamType::String, http_headers_param_type()], &input)?;
+	// safe to unwrap
+	let url = decoded.get(0).unwrap().clone().into_string().unwrap();
+	let http_headers = decode_http_headers(decoded.get(1).unwrap());
+
+	let value = request_json(client, url, Method::GET, None, http_headers)?;
+	success(ethabi::encode(&[Token::Uint(responses.store(value))]))
+}
+
+// input: (url, payload, headers), output: handle
+pub fn http_post_json<T: SendHttpRequest>(
+	input: Vec<u8>,
+	client: T,
+	responses: &JsonResponses,
+) -> PrecompileResult {
+	let decoded =
+		decode_input(&[ParamType::String, ParamType::String, http_headers_param_type()], &input)?;
+	// safe to unwrap
+	let url = decoded.get(0).unwrap().clone().into_string().unwrap();
+	let payload = decoded.get(1).unwrap().clone().into_string().unwrap();
+	let http_headers = decode_http_headers(decoded.get(2).unwrap());
+
+	let value = request_json(client, url, Method::POST, Some(payload), http_headers)?;
+	success(ethabi::encode(&[Token::Uint(responses.store(value))]))
+}
+
+json_get_precompile_fn!(json_get_bool, Bool, as_bool);
+json_get_precompile_fn!(json_get_i64, Uint, as_i64);
+json_get_precompile_fn!(json_get_string, String, as_str);
+
+// input: (handle, pointer), output: length of the array under pointer
+pub fn json_get_array_len(input: Vec<u8>, responses: &JsonResponses) -> PrecompileResult {
+	let decoded = decode_input(&[ParamType::Uint(256), ParamType::String], &input)?;
+	// safe to unwrap
+	let handle = decoded.get(0).unwrap().clone().into_uint().unwrap();
+	let pointer = decoded.get(1).unwrap().clone().into_string().unwrap();
+
+	responses.with(handle, |value| match json_pointer_value(value, &pointer)?.as_array() {
+		Some(array) => success(ethabi::encode(&[Token::Uint(U256::from(array.len()))])),
+		None => Err(unexpected_type_failure(&pointer)),
+	})
+}
+
+#[cfg(test)]
+pub mod test {
+	use crate::precompiles::{
+		json::{
+			http_get_json, http_post_json, json_get_array_len, json_get_bool, json_get_i64,
+			json_get_string, JsonResponses,
+		},
+		mocks::MockedHttpClient,
+		PrecompileResult,
+	};
+	use ethabi::{ethereum_types::U256, Token};
+	use evm::{executor::stack::PrecompileFailure, ExitError};
+
+	#[test]
+	pub fn queries_many_values_from_one_response() {
+		// given
+		let responses = JsonResponses::default();
+		let handle = fetch(&responses);
+
+		// when
+		let bool_result = json_get_bool(prepare_query(handle, "/bool"), &responses).unwrap();
+		let i64_result = json_get_i64(prepare_query(handle, "/i64"), &responses).unwrap();
+		let string_result = json_get_string(prepare_query(handle, "/string"), &responses).unwrap();
+
+		// then
+		assert_eq!(ethabi::encode(&[Token::Bool(true)]), bool_result.output);
+		assert_eq!(ethabi::encode(&[Token::Uint(U256::from(10))]), i64_result.output);
+		assert_eq!(ethabi::encode(&[Token::String("string".to_string())]), string_result.output);
+	}
+
+	#[test]
+	pub fn iterates_over_array() {
+		// given
+		let responses = JsonResponses::default();
+		let handle = fetch(&responses);
+
+		// when
+		let len = json_get_array_len(prepare_query(handle, "/array"), &responses).unwrap();
+		let len = ethabi::decode(&[ethabi::ParamType::Uint(256)], &len.output).unwrap()[0]
+			.clone()
+			.into_uint()
+			.unwrap()
+			.as_usize();
+		let names: Vec<String> = (0..len)
+			.map(|i| {
+				let result = json_get_string(
+					prepare_query(handle, &format!("/array/{}/name", i)),
+					&responses,
+				)
+				.unwrap();
+				ethabi::decode(&[ethabi::ParamType::String], &result.output).unwrap()[0]
+					.clone()
+					.into_string()
+					.unwrap()
+			})
+			.collect();
+
+		// then
+		assert_eq!(names, vec!["first", "second", "third"]);
+	}
+
+	#[test]
+	pub fn posts_payload_and_returns_handle() {
+		// given
+		let responses = JsonResponses::default();
+		let payload = r#"{"jsonrpc":"2.0","method":"eth_blockNumber","params":[],"id":1}"#;
+		let input = ethabi::encode(&[
+			Token::String("https://www.litentry.com/".to_string()),
+			Token::String(payload.to_string()),
+			Token::Array(vec![]),
+		]);
+
+		// when
+		let result = http_post_json(input, MockedHttpClient::default(), &responses).unwrap();
+		let handle = ethabi::decode(&[ethabi::ParamType::Uint(256)], &result.output).unwrap()[0]
+			.clone()
+			.into_uint()
+			.unwrap();
+		let body = json_get_string(prepare_query(handle, "/request_body"), &responses).unwrap();
+
+		// then
+		assert_eq!(handle, U256::zero());
+		assert_eq!(ethabi::encode(&[Token::String(payload.to_string())]), body.output);
+	}
+
+	#[test]
+	pub fn returns_error_for_invalid_handle() {
+		// given
+		let responses = JsonResponses::default();
+		fetch(&responses);
+
+		// when
+		let result = json_get_bool(prepare_query(U256::from(1), "/bool"), &responses);
+
+		// then
+		assert_exit_status_reason(&result, "Invalid json response handle: 1");
+	}
+
+	#[test]
+	pub fn returns_error_for_non_array_value() {
+		// given
+		let responses = JsonResponses::default();
+		let handle = fetch(&responses);
+
+		// when
+		let result = json_get_array_len(prepare_query(handle, "/string"), &responses);
+
+		// then
+		assert_exit_status_reason(
+			&result,
+			"There is no value or it might be of different type, pointer: $\"/string\"",
+		);
+	}
+
+	#[test]
+	pub fn returns_error_for_http_error() {
+		// given
+		let responses = JsonResponses::default();
+		let input = ethabi::encode(&[
+			Token::String("https://www.litentry.com/".to_string()),
+			Token::Array(vec![]),
+		]);
+
+		// when
+		let result = http_get_json(input, MockedHttpClient::http_error(), &responses);
+
+		// then
+		assert_exit_status_reason(
+			&result,
+			"Error while performing http call: HttpError(404, \"Not found\")",
+		);
+	}
+
+	fn fetch(responses: &JsonResponses) -> U256 {
+		let input = ethabi::encode(&[
+			Token::String("https://www.litentry.com/".to_string()),
+			Token::Array(vec![]),
+		]);
+		let result = http_get_json(input, MockedHttpClient::default(), responses).unwrap();
+		ethabi::decode(&[ethabi::ParamType::Uint(256)], &result.output).unwrap()[0]
+			.clone()
+			.into_uint()
+			.unwrap()
+	}
+
+	fn prepare_query(handle: U256, pointer: &str) -> Vec<u8> {
+		ethabi::encode(&[Token::Uint(handle), Token::String(pointer.to_string())])
+	}
+
+	fn assert_exit_status_reason(result: &PrecompileResult, expected_reason: &str) {
+		match result {
+			Err(e) => match e {
+				PrecompileFailure::Error { exit_status } => match exit_status {
+					ExitError::Other(reason) => {
+						assert_eq!(reason.to_string(), expected_reason)
+					},
+					_ => panic!("Different exit status"),
+				},
+				_ => panic!("Different failure"),
+			},
+			_ => panic!("Expected err"),
+		}
+	}
+}
@@ -14,13 +14,100 @@
 // You should have received a copy of the GNU General Public License
 // along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
 
+use ethabi::{ParamType, Token};
 use evm::{executor::stack::PrecompileFailure, ExitError};
-use std::{borrow::Cow, string::String};
+use itc_rest_client::{
+	http_client::SendHttpRequest,
+	rest_client::{Method, Url},
+};
+use std::{borrow::Cow, format, string::String, vec, vec::Vec};
 
 pub fn prepare_custom_failure(reason: String) -> PrecompileFailure {
 	PrecompileFailure::Error { exit_status: ExitError::Other(Cow::Owned(reason)) }
 }
 
+// abi type of solidity's `HttpHeader[]`
+pub fn http_headers_param_type() -> ParamType {
+	ParamType::Array(ParamType::Tuple(vec![ParamType::String, ParamType::String]).into())
+}
+
+pub fn decode_input(types: &[ParamType], input: &[u8]) -> Result<Vec<Token>, PrecompileFailure> {
+	ethabi::decode(types, input).map_err(|e| {
+		prepare_custom_failure(format!("Could not decode bytes {:?}, reason: {:?}", input, e))
+	})
+}
+
+// `token` must be of `http_headers_param_type()`, which is guaranteed by a successful decoding
+pub fn decode_http_headers(token: &Token) -> Vec<(String, String)> {
+	// safe to unwrap
+	token
+		.clone()
+		.into_array()
+		.unwrap()
+		.into_iter()
+		.map(|v| {
+			let header = v.into_tuple().unwrap();
+			let name = header.get(0).unwrap().clone().into_string().unwrap();
+			let value = header.get(1).unwrap().clone().into_string().unwrap();
+			(name, value)
+		})
+		.collect()
+}
+
+// Performs the http request and parses the response body as json.
+pub fn request_json<T: SendHttpRequest>(
+	client: T,
+	url: String,
+	method: Method,
+	maybe_body: Option<String>,
+	headers: Vec<(String, String)>,
+) -> Result<serde_json::Value, PrecompileFailure> {
+	let url = Url::parse(&url).map_err(|e| {
+		prepare_custom_failure(format!("Could not parse url {:?}, reason: {:?}", url, e))
+	})?;
+	let resp = client.send_request_raw(url, method, maybe_body, headers).map_err(|e| {
+		prepare_custom_failure(format!("Error while performing http call: {:?}", e))
+	})?;
+	serde_json::from_slice(&resp.1).map_err(|e| {
+		prepare_custom_failure(format!("Could not parse json {:?}, reason: {:?}", resp.1, e))
+	})
+}
+
+pub fn json_pointer_value<'a>(
+	value: &'a serde_json::Value,
+	pointer: &str,
+) -> Result<&'a serde_json::Value, PrecompileFailure> {
+	value.pointer(pointer).ok_or_else(|| {
+		prepare_custom_failure(format!("No value under given pointer: :{:?}", pointer))
+	})
+}
+
+pub fn unexpected_type_failure(pointer: &str) -> PrecompileFailure {
+	prepare_custom_failure(format!(
+		"There is no value or it might be of different type, pointer: ${:?}",
+		pointer
+	))
+}
+
+pub fn success(output: Vec<u8>) -> crate::precompiles::PrecompileResult {
+	Ok(evm::executor::stack::PrecompileOutput { exit_status: evm::ExitSucceed::Returned, output })
+}
+
+// Encodes the value under `pointer` as `Token::$token` using `serde_json::Value::$parse_fn_name`
+#[macro_export]
+macro_rules! encode_json_value {
+	($value:expr, $pointer:expr, $token:ident, $parse_fn_name:ident) => {{
+		let result = $crate::precompiles::macros::json_pointer_value($value, $pointer)?;
+		match result.$parse_fn_name() {
+			Some(v) =>
+				$crate::precompiles::macros::success(ethabi::encode(&[ethabi::Token::$token(
+					v.into(),
+				)])),
+			None => Err($crate::precompiles::macros::unexpected_type_failure($pointer)),
+		}
+	}};
+}
+
 #[macro_export]
 macro_rules! http_get_precompile_fn {
 	($name:ident, $token:ident, $parse_fn_name:ident) => {
@@ -28,106 +115,84 @@ macro_rules! http_get_precompile_fn {
 			input: Vec<u8>,
 			client: T,
 		) -> $crate::precompiles::PrecompileResult {
-			let decoded = ethabi::decode(
+			let decoded = $crate::precompiles::macros::decode_input(
 				&[
 					ethabi::ParamType::String,
 					ethabi::ParamType::String,
-					ethabi::ParamType::Array(
-						ethabi::ParamType::Tuple(vec![
-							ethabi::ParamType::String,
-							ethabi::ParamType::String,
-						])
-						.into(),
-					),
+					$crate::precompiles::macros::http_headers_param_type(),
 				],
 				&input,
-			)
-			.map_err(|e| {
-				$crate::precompiles::macros::prepare_custom_failure(std::format!(
-					"Could not decode bytes {:?}, reason: {:?}",
-					input, e
-				))
-			})?;
+			)?;
 			// safe to unwrap
 			let url = decoded.get(0).unwrap().clone().into_string().unwrap();
-			let url = itc_rest_client::rest_client::Url::parse(&url).map_err(|e| {
-				$crate::precompiles::macros::prepare_custom_failure(std::format!(
-					"Could not parse url {:?}, reason: {:?}",
-					url, e
-				))
-			})?;
+			let pointer = decoded.get(1).unwrap().clone().into_string().unwrap();
+			let http_headers =
+				$crate::precompiles::macros::decode_http_headers(decoded.get(2).unwrap());
 
+			let value = $crate::precompiles::macros::request_json(
+				client,
+				url,
+				itc_rest_client::rest_client::Method::GET,
+				None,
+				http_headers,
+			)?;
+			$crate::encode_json_value!(&value, &pointer, $token, $parse_fn_name)
+		}
+	};
+}
+
+#[macro_export]
+macro_rules! http_post_precompile_fn {
+	($name:ident, $token:ident, $parse_fn_name:ident) => {
+		pub fn $name<T: SendHttpRequest>(
+			input: Vec<u8>,
+			client: T,
+		) -> $crate::precompiles::PrecompileResult {
+			let decoded = $crate::precompiles::macros::decode_input(
+				&[
+					ethabi::ParamType::String,
+					ethabi::ParamType::String,
+					ethabi::ParamType::String,
+					$crate::precompiles::macros::http_headers_param_type(),
+				],
+				&input,
+			)?;
+			// safe to unwrap
+			let url = decoded.get(0).unwrap().clone().into_string().unwrap();
+			let pointer = decoded.get(1).unwrap().clone().into_string().unwrap();
+			let payload = decoded.get(2).unwrap().clone().into_string().unwrap();
+			let http_headers =
+				$crate::precompiles::macros::decode_http_headers(decoded.get(3).unwrap());
+
+			let value = $crate::precompiles::macros::request_json(
+				client,
+				url,
+				itc_rest_client::rest_client::Method::POST,
+				Some(payload),
+				http_headers,
+			)?;
+			$crate::encode_json_value!(&value, &pointer, $token, $parse_fn_name)
+		}
+	};
+}
+
+#[macro_export]
+macro_rules! json_get_precompile_fn {
+	($name:ident, $token:ident, $parse_fn_name:ident) => {
+		pub fn $name(
+			input: Vec<u8>,
+			responses: &$crate::precompiles::json::JsonResponses,
+		) -> $crate::precompiles::PrecompileResult {
+			let decoded = $crate::precompiles::macros::decode_input(
+				&[ethabi::ParamType::Uint(256), ethabi::ParamType::String],
+				&input,
+			)?;
 			// safe to unwrap
+			let handle = decoded.get(0).unwrap().clone().into_uint().unwrap();
 			let pointer = decoded.get(1).unwrap().clone().into_string().unwrap();
-			let http_headers: Vec<(String, String)> = decoded
-				.get(2)
-				.unwrap()
-				.clone()
-				.into_array()
-				.unwrap()
-				.iter()
-				.map(|v| {
-					let name = v
-						.clone()
-						.into_tuple()
-						.unwrap()
-						.get(0)
-						.unwrap()
-						.clone()
-						.into_string()
-						.unwrap();
-					let value = v
-						.clone()
-						.into_tuple()
-						.unwrap()
-						.get(1)
-						.unwrap()
-						.clone()
-						.into_string()
-						.unwrap();
-
-					(name, value)
-				})
-				.collect();
-			let resp = client
-				.send_request_raw(
-					url,
-					itc_rest_client::rest_client::Method::GET,
-					None,
-					http_headers,
-				)
-				.map_err(|e| {
-					$crate::precompiles::macros::prepare_custom_failure(std::format!(
-						"Error while performing http call: {:?}",
-						e
-					))
-				})?;
-			let value: serde_json::Value = serde_json::from_slice(&resp.1).map_err(|e| {
-				$crate::precompiles::macros::prepare_custom_failure(std::format!(
-					"Could not parse json {:?}, reason: {:?}",
-					resp.1, e
-				))
-			})?;
-			let result = match value.pointer(&pointer) {
-				Some(v) => v,
-				None =>
-					return Err($crate::precompiles::macros::prepare_custom_failure(std::format!(
-						"No value under given pointer: :{:?}",
-						pointer
-					))),
-			};
-
-			let encoded = match result.$parse_fn_name() {
-				Some(v) => ethabi::encode(&[ethabi::Token::$token(v.into())]),
-				None =>
-					return Err($crate::precompiles::macros::prepare_custom_failure(std::format!(
-						"There is no value or it might be of different type, pointer: ${:?}",
-						pointer
-					))),
-			};
-			Ok(evm::executor::stack::PrecompileOutput {
-				exit_status: evm::ExitSucceed::Returned,
-				output: encoded,
+
+			responses.with(handle, |value| {
+				$crate::encode_json_value!(value, &pointer, $token, $parse_fn_name)
 			})
 		}
 	};
@@ -65,11 +65,14 @@ impl SendHttpRequest for MockedHttpClient {
 	fn send_request_raw(
 		&self,
 		_url: Url,
-		_method: Method,
-		_maybe_body: Option<String>,
+		method: Method,
+		maybe_body: Option<String>,
 		_headers: Vec<(String, String)>,
 	) -> Result<(Response, EncodedBody), Error> {
 		if !self.http_err {
+			if matches!(method, Method::POST) && maybe_body.is_none() {
+				return Err(Error::HttpError(400, "Missing body".to_string()))
+			}
 			const HEAD: &[u8; 102] = b"HTTP/1.1 200 OK\r\n\
                          Date: Sat, 11 Jan 2003 02:44:04 GMT\r\n\
                          Content-Type: text/html\r\n\
@@ -83,7 +86,14 @@ impl SendHttpRequest for MockedHttpClient {
 						"bool": true,
 						"i64": 10,
 						"string": "string",
-						"not_bool": 10
+						"not_bool": 10,
+						"array": [
+							{ "name": "first", "amount": 1 },
+							{ "name": "second", "amount": 2 },
+							{ "name": "third", "amount": 3 }
+						],
+						// posted body is echoed back
+						"request_body": maybe_body.unwrap_or_default()
 					}))
 					.unwrap()
 					.as_bytes()
@@ -21,63 +21,172 @@ extern crate sgx_tstd as std;
 use crate::sgx_reexport_prelude::*;
 
 use crate::precompiles::{
+	hashing::{blake2_256_hash, keccak256_hash, sha256_hash},
 	http_get::{http_get_bool, http_get_i64, http_get_string},
+	http_post::{http_post_bool, http_post_i64, http_post_string},
+	identity::{
+		to_bitcoin_p2tr_address, to_bitcoin_p2wpkh_address, to_checksum_address, to_ss58_address,
+	},
+	json::{
+		http_get_json, http_post_json, json_get_array_len, json_get_bool, json_get_i64,
+		json_get_string, JsonResponses,
+	},
+	macros::prepare_custom_failure,
+	parse_decimal::parse_decimal,
 	to_hex::to_hex,
+	trace::{HttpCallTraces, TracingHttpClient},
 };
+use core::time::Duration;
 use ethabi::ethereum_types::H160;
 use evm::executor::stack::{
 	IsPrecompileResult, PrecompileFailure, PrecompileHandle, PrecompileOutput, PrecompileSet,
 };
-use itc_rest_client::http_client::HttpClient;
-use std::result::Result as StdResult;
+use itc_rest_client::{http_client::HttpClient, rest_client::Url};
+use itp_time_utils::remaining_time;
+use std::{result::Result as StdResult, string::ToString};
 
+mod hashing;
 mod http_get;
+mod http_post;
+mod identity;
+mod json;
 mod macros;
+mod parse_decimal;
 mod to_hex;
+pub mod trace;
 
 #[cfg(test)]
 mod mocks;
 
 pub type PrecompileResult = StdResult<PrecompileOutput, PrecompileFailure>;
 
-pub struct Precompiles();
+// http calls are charged heavily, they are slow and consume data provider quota
+pub const HTTP_CALL_GAS_COST: u64 = 1_000_000;
+pub const JSON_QUERY_GAS_COST: u64 = 1_000;
+pub const TO_HEX_GAS_COST: u64 = 1_000;
+// bitcoin p2tr derivation involves an elliptic curve tweak
+pub const ADDRESS_CONVERSION_GAS_COST: u64 = 5_000;
+pub const HASH_GAS_COST: u64 = 1_000;
+pub const PARSE_DECIMAL_GAS_COST: u64 = 1_000;
+
+const HTTP_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct Precompiles {
+	// execution deadline as duration since unix epoch
+	pub deadline: Duration,
+	// responses fetched by `http_get_json`/`http_post_json` during this execution
+	pub json_responses: JsonResponses,
+	// if set, http calls are sent to this base url instead, e.g. a local mock server
+	pub http_base_url: Option<Url>,
+	pub http_calls: HttpCallTraces,
+}
+
+impl Precompiles {
+	pub fn new(deadline: Duration, http_base_url: Option<Url>) -> Self {
+		Self {
+			deadline,
+			json_responses: JsonResponses::default(),
+			http_base_url,
+			http_calls: HttpCallTraces::default(),
+		}
+	}
+}
 
 impl PrecompileSet for Precompiles {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		let gas_cost = precompile_gas_cost(handle.code_address())?;
+		if let Err(e) = handle.record_cost(gas_cost) {
+			return Some(Err(PrecompileFailure::Error { exit_status: e }))
+		}
+		let remaining = match remaining_time(self.deadline) {
+			Some(remaining) => remaining,
+			None =>
+				return Some(Err(prepare_custom_failure("Execution deadline exceeded".to_string()))),
+		};
+
 		let mut headers = itc_rest_client::rest_client::Headers::new();
 		headers.insert(http::header::CONNECTION.as_str(), "close");
-		let client = HttpClient::new(
-			itc_rest_client::http_client::DefaultSend {},
-			true,
-			Some(core::time::Duration::from_secs(5)),
-			Some(headers),
-			None,
-		);
+		let client = TracingHttpClient {
+			inner: HttpClient::new(
+				itc_rest_client::http_client::DefaultSend {},
+				true,
+				Some(remaining.min(HTTP_TIMEOUT)),
+				Some(headers),
+				None,
+			),
+			precompile: handle.code_address(),
+			base_url: self.http_base_url.as_ref(),
+			traces: &self.http_calls,
+		};
 
 		match handle.code_address() {
 			a if a == hash(1000) => Some(http_get_i64(handle.input().to_vec(), client)),
 			a if a == hash(1001) => Some(http_get_bool(handle.input().to_vec(), client)),
 			a if a == hash(1002) => Some(http_get_string(handle.input().to_vec(), client)),
+			a if a == hash(1003) => Some(http_post_i64(handle.input().to_vec(), client)),
+			a if a == hash(1004) => Some(http_post_bool(handle.input().to_vec(), client)),
+			a if a == hash(1005) => Some(http_post_string(handle.input().to_vec(), client)),
+			a if a == hash(1020) =>
+				Some(http_get_json(handle.input().to_vec(), client, &self.json_responses)),
+			a if a == hash(1021) =>
+				Some(http_post_json(handle.input().to_vec(), client, &self.json_responses)),
+			a if a == hash(1022) =>
+				Some(json_get_i64(handle.input().to_vec(), &self.json_responses)),
+			a if a == hash(1023) =>
+				Some(json_get_bool(handle.input().to_vec(), &self.json_responses)),
+			a if a == hash(1024) =>
+				Some(json_get_string(handle.input().to_vec(), &self.json_responses)),
+			a if a == hash(1025) =>
+				Some(json_get_array_len(handle.input().to_vec(), &self.json_responses)),
 			a if a == hash(1051) => Some(to_hex(handle.input().to_vec())),
+			a if a == hash(1052) => Some(to_ss58_address(handle.input().to_vec())),
+			a if a == hash(1053) => Some(to_bitcoin_p2tr_address(handle.input().to_vec())),
+			a if a == hash(1054) => Some(to_bitcoin_p2wpkh_address(handle.input().to_vec())),
+			a if a == hash(1055) => Some(to_checksum_address(handle.input().to_vec())),
+			a if a == hash(1056) => Some(keccak256_hash(handle.input().to_vec())),
+			a if a == hash(1057) => Some(blake2_256_hash(handle.input().to_vec())),
+			a if a == hash(1058) => Some(sha256_hash(handle.input().to_vec())),
+			a if a == hash(1059) => Some(parse_decimal(handle.input().to_vec())),
 			_ => None,
 		}
 	}
 
 	fn is_precompile(&self, address: H160, _remaining_gas: u64) -> IsPrecompileResult {
-		match address {
-			a if a == hash(1000) =>
-				IsPrecompileResult::Answer { is_precompile: true, extra_cost: 0 },
-			a if a == hash(1001) =>
-				IsPrecompileResult::Answer { is_precompile: true, extra_cost: 0 },
-			a if a == hash(1002) =>
-				IsPrecompileResult::Answer { is_precompile: true, extra_cost: 0 },
-			a if a == hash(1051) =>
-				IsPrecompileResult::Answer { is_precompile: true, extra_cost: 0 },
-			_ => IsPrecompileResult::Answer { is_precompile: false, extra_cost: 0 },
+		IsPrecompileResult::Answer {
+			is_precompile: precompile_gas_cost(address).is_some(),
+			extra_cost: 0,
 		}
 	}
 }
 
+// Returns the gas charged for calling the precompile at `address`, `None` if there is no such precompile.
+fn precompile_gas_cost(address: H160) -> Option<u64> {
+	match address {
+		a if a == hash(1000) => Some(HTTP_CALL_GAS_COST),
+		a if a == hash(1001) => Some(HTTP_CALL_GAS_COST),
+		a if a == hash(1002) => Some(HTTP_CALL_GAS_COST),
+		a if a == hash(1003) => Some(HTTP_CALL_GAS_COST),
+		a if a == hash(1004) => Some(HTTP_CALL_GAS_COST),
+		a if a == hash(1005) => Some(HTTP_CALL_GAS_COST),
+		a if a == hash(1020) => Some(HTTP_CALL_GAS_COST),
+		a if a == hash(1021) => Some(HTTP_CALL_GAS_COST),
+		a if a == hash(1022) => Some(JSON_QUERY_GAS_COST),
+		a if a == hash(1023) => Some(JSON_QUERY_GAS_COST),
+		a if a == hash(1024) => Some(JSON_QUERY_GAS_COST),
+		a if a == hash(1025) => Some(JSON_QUERY_GAS_COST),
+		a if a == hash(1051) => Some(TO_HEX_GAS_COST),
+		a if a == hash(1052) => Some(ADDRESS_CONVERSION_GAS_COST),
+		a if a == hash(1053) => Some(ADDRESS_CONVERSION_GAS_COST),
+		a if a == hash(1054) => Some(ADDRESS_CONVERSION_GAS_COST),
+		a if a == hash(1055) => Some(ADDRESS_CONVERSION_GAS_COST),
+		a if a == hash(1056) => Some(HASH_GAS_COST),
+		a if a == hash(1057) => Some(HASH_GAS_COST),
+		a if a == hash(1058) => Some(HASH_GAS_COST),
+		a if a == hash(1059) => Some(PARSE_DECIMAL_GAS_COST),
+		_ => None,
+	}
+}
+
 fn hash(a: u64) -> H160 {
 	H160::from_low_u64_be(a)
 }
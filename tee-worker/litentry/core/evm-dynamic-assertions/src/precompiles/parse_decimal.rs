@@ -0,0 +1,95 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::precompiles::{
+	macros::{decode_input, prepare_custom_failure, success},
+	PrecompileResult,
+};
+use ethabi::{ethereum_types::U256, ParamType, Token};
+use std::{format, string::String, vec::Vec};
+
+// Parses a decimal string like "1234.5678" into an integer scaled by `10^decimals`,
+// e.g. token balances returned by data providers. Extra fractional digits are truncated.
+//
+// input: (string value, uint8 decimals), output: uint256
+pub fn parse_decimal(input: Vec<u8>) -> PrecompileResult {
+	let decoded = decode_input(&[ParamType::String, ParamType::Uint(8)], &input)?;
+	// safe to unwrap
+	let value = decoded.get(0).unwrap().clone().into_string().unwrap();
+	let decimals = decoded.get(1).unwrap().clone().into_uint().unwrap();
+	if decimals > U256::from(u8::MAX) {
+		return Err(prepare_custom_failure(format!("Invalid decimals: {}", decimals)))
+	}
+	let decimals = decimals.low_u32() as usize;
+
+	let invalid = || prepare_custom_failure(format!("Invalid decimal string: {:?}", value));
+	let (integer, fraction) = match value.split_once('.') {
+		Some((integer, fraction)) => (integer, fraction),
+		None => (value.as_str(), ""),
+	};
+	if integer.is_empty()
+		|| !integer.chars().all(|c| c.is_ascii_digit())
+		|| !fraction.chars().all(|c| c.is_ascii_digit())
+	{
+		return Err(invalid())
+	}
+
+	let mut digits = String::from(integer);
+	digits.extend(fraction.chars().chain(core::iter::repeat('0')).take(decimals));
+	let result = U256::from_dec_str(&digits).map_err(|_| {
+		prepare_custom_failure(format!("Decimal string {:?} overflows uint256", value))
+	})?;
+
+	success(ethabi::encode(&[Token::Uint(result)]))
+}
+
+#[cfg(test)]
+pub mod test {
+	use crate::precompiles::parse_decimal::parse_decimal;
+	use ethabi::{ethereum_types::U256, Token};
+
+	#[test]
+	pub fn test_parse_decimal() {
+		assert_eq!(parse("123", 0), Some(U256::from(123)));
+		assert_eq!(parse("123", 2), Some(U256::from(12300)));
+		assert_eq!(parse("1.5", 18), Some(U256::from(1_500_000_000_000_000_000u128)));
+		assert_eq!(parse("0.000123", 6), Some(U256::from(123)));
+		// extra fractional digits are truncated
+		assert_eq!(parse("1.23456", 2), Some(U256::from(123)));
+		assert_eq!(parse("007.", 1), Some(U256::from(70)));
+	}
+
+	#[test]
+	pub fn parse_decimal_fails_for_invalid_input() {
+		assert_eq!(parse("", 2), None);
+		assert_eq!(parse(".5", 2), None);
+		assert_eq!(parse("-1", 2), None);
+		assert_eq!(parse("1.2.3", 2), None);
+		assert_eq!(parse("1e18", 2), None);
+		assert_eq!(parse(&"9".repeat(78), 0), None);
+	}
+
+	fn parse(value: &str, decimals: u8) -> Option<U256> {
+		let input =
+			ethabi::encode(&[Token::String(value.to_string()), Token::Uint(decimals.into())]);
+		parse_decimal(input).ok().map(|result| {
+			ethabi::decode(&[ethabi::ParamType::Uint(256)], &result.output).unwrap()[0]
+				.clone()
+				.into_uint()
+				.unwrap()
+		})
+	}
+}
@@ -0,0 +1,183 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+// Records the http calls made by precompiles, so that an assertion execution can be inspected
+// when developing assertion contracts.
+
+use core::cell::RefCell;
+use ethabi::ethereum_types::H160;
+use itc_rest_client::{
+	error::Error,
+	http_client::{EncodedBody, SendHttpRequest},
+	rest_client::{Method, Response, Url},
+	Query, RestPath,
+};
+use std::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+
+#[derive(Clone, Debug)]
+pub struct HttpCallTrace {
+	// address of the precompile that performed the call
+	pub precompile: H160,
+	pub method: String,
+	// url the request was sent to, after applying the base url override
+	pub url: String,
+	pub body: Option<String>,
+	// http status code on success, error description otherwise
+	pub result: Result<u16, String>,
+}
+
+#[derive(Default)]
+pub struct HttpCallTraces(RefCell<Vec<HttpCallTrace>>);
+
+impl HttpCallTraces {
+	pub fn record(&self, trace: HttpCallTrace) {
+		self.0.borrow_mut().push(trace);
+	}
+
+	pub fn into_inner(self) -> Vec<HttpCallTrace> {
+		self.0.into_inner()
+	}
+}
+
+// Wraps the http client used by a precompile call, redirects requests to `base_url` if set.
+pub struct TracingHttpClient<'a, T: SendHttpRequest> {
+	pub inner: T,
+	pub precompile: H160,
+	pub base_url: Option<&'a Url>,
+	pub traces: &'a HttpCallTraces,
+}
+
+impl<'a, T: SendHttpRequest> SendHttpRequest for TracingHttpClient<'a, T> {
+	fn send_request<U, R>(
+		&self,
+		base_url: Url,
+		method: Method,
+		params: U,
+		query: Option<&Query<'_>>,
+		maybe_body: Option<String>,
+	) -> Result<(Response, EncodedBody), Error>
+	where
+		R: RestPath<U>,
+	{
+		// not used by precompiles, which only send raw requests
+		self.inner.send_request::<U, R>(base_url, method, params, query, maybe_body)
+	}
+
+	fn send_request_raw(
+		&self,
+		url: Url,
+		method: Method,
+		maybe_body: Option<String>,
+		headers: Vec<(String, String)>,
+	) -> Result<(Response, EncodedBody), Error> {
+		let url = match self.base_url {
+			Some(base_url) => rebase_url(url, base_url)?,
+			None => url,
+		};
+		let method_name = format!("{:?}", method);
+		let response =
+			self.inner.send_request_raw(url.clone(), method, maybe_body.clone(), headers);
+		self.traces.record(HttpCallTrace {
+			precompile: self.precompile,
+			method: method_name,
+			url: url.to_string(),
+			body: maybe_body,
+			result: match &response {
+				Ok((response, _)) => Ok(response.status_code().into()),
+				Err(e) => Err(format!("{:?}", e)),
+			},
+		});
+		response
+	}
+}
+
+// Replaces scheme, host and port of `url` with the ones of `base_url`, path and query are kept.
+fn rebase_url(mut url: Url, base_url: &Url) -> Result<Url, Error> {
+	url.set_scheme(base_url.scheme()).map_err(|_| Error::UrlError)?;
+	url.set_host(base_url.host_str()).map_err(|_| Error::UrlError)?;
+	url.set_port(base_url.port()).map_err(|_| Error::UrlError)?;
+	Ok(url)
+}
+
+#[cfg(test)]
+pub mod test {
+	use super::*;
+	use crate::precompiles::mocks::MockedHttpClient;
+
+	#[test]
+	pub fn records_http_calls() {
+		// given
+		let traces = HttpCallTraces::default();
+		let client = TracingHttpClient {
+			inner: MockedHttpClient::default(),
+			precompile: H160::from_low_u64_be(1021),
+			base_url: None,
+			traces: &traces,
+		};
+
+		// when
+		let _ = client.send_request_raw(
+			Url::parse("https://api.example.com/balance?account=alice").unwrap(),
+			Method::POST,
+			Some("{}".to_string()),
+			Vec::new(),
+		);
+		let _ = client.send_request_raw(
+			Url::parse("https://api.example.com/balance").unwrap(),
+			Method::POST,
+			None,
+			Vec::new(),
+		);
+
+		// then
+		let traces = traces.into_inner();
+		assert_eq!(traces.len(), 2);
+		assert_eq!(traces[0].precompile, H160::from_low_u64_be(1021));
+		assert_eq!(traces[0].url, "https://api.example.com/balance?account=alice");
+		assert_eq!(traces[0].body, Some("{}".to_string()));
+		assert_eq!(traces[0].result, Ok(200));
+		assert!(traces[1].result.is_err());
+	}
+
+	#[test]
+	pub fn redirects_http_calls_to_base_url() {
+		// given
+		let traces = HttpCallTraces::default();
+		let base_url = Url::parse("http://localhost:19527").unwrap();
+		let client = TracingHttpClient {
+			inner: MockedHttpClient::default(),
+			precompile: H160::from_low_u64_be(1000),
+			base_url: Some(&base_url),
+			traces: &traces,
+		};
+
+		// when
+		let _ = client.send_request_raw(
+			Url::parse("https://api.example.com/v1/balance?account=alice").unwrap(),
+			Method::GET,
+			None,
+			Vec::new(),
+		);
+
+		// then
+		let traces = traces.into_inner();
+		assert_eq!(traces[0].url, "http://localhost:19527/v1/balance?account=alice");
+	}
+}
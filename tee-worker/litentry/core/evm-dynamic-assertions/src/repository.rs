@@ -22,7 +22,7 @@ extern crate sgx_tstd as std;
 
 use crate::{
 	sealing::io::{seal_state, unseal_state},
-	AssertionId, SmartContractByteCode,
+	AssertionId, AssertionRepositoryItem, SmartContractByteCode,
 };
 use lc_dynamic_assertion::AssertionLogicRepository;
 use std::{
@@ -57,6 +57,20 @@ impl EvmAssertionRepository {
 
 		Ok(EvmAssertionRepository { state: state.into(), path: path.to_string() })
 	}
+
+	fn seal(&self) -> Result<(), String> {
+		// prepare data for encoding
+		let unsealed_state: Vec<(AssertionId, AssertionRepositoryItem)> = self
+			.state
+			.lock()
+			.map_err(|e| format!("Could not acquire lock on inner state: {:?}", e))?
+			.iter()
+			.map(|(key, val)| (*key, val.clone()))
+			.collect();
+
+		seal_state(&self.path, unsealed_state)
+			.map_err(|e| format!("Could not seal assertions state: {:?}", e))
+	}
 }
 
 impl AssertionLogicRepository for EvmAssertionRepository {
@@ -77,22 +91,36 @@ impl AssertionLogicRepository for EvmAssertionRepository {
 			.lock()
 			.map_err(|e| format!("Could not acquire lock on inner state: {:?}", e))?
 			.insert(id, item);
-		// prepare data for encoding
-		let unsealed_state: Vec<(AssertionId, Self::Item)> = self
-			.state
-			.lock()
-			.map_err(|e| format!("Could not acquire lock on inner state: {:?}", e))?
-			.iter()
-			.map(|(key, val)| (*key, val.clone()))
-			.collect();
 
-		if let Err(e) = seal_state(&self.path, unsealed_state) {
+		if let Err(e) = self.seal() {
 			//clean up memory state
 			self.state
 				.lock()
 				.map_err(|e| format!("Could not acquire lock on inner state: {:?}", e))?
 				.remove(&id);
-			return Err(format!("Could not seal assertions state: {:?}", e))
+			return Err(e)
+		}
+		Ok(())
+	}
+
+	fn remove(&self, id: &Self::Id) -> Result<(), String> {
+		let item = match self
+			.state
+			.lock()
+			.map_err(|e| format!("Could not acquire lock on inner state: {:?}", e))?
+			.remove(id)
+		{
+			Some(item) => item,
+			None => return Ok(()),
+		};
+
+		if let Err(e) = self.seal() {
+			//restore memory state
+			self.state
+				.lock()
+				.map_err(|e| format!("Could not acquire lock on inner state: {:?}", e))?
+				.insert(*id, item);
+			return Err(e)
 		}
 		Ok(())
 	}
@@ -126,4 +154,24 @@ pub mod sgx_tests {
 		assert_eq!(assertion.0, byte_code);
 		assert_eq!(assertion.1, secrets);
 	}
+	pub fn removes_assertion_from_seal() {
+		let seal_file_name = "test_sealed_assertion.bin";
+		let temp_dir = TempDir::with_prefix("evm_assertion_seal_remove_tests").unwrap();
+		let seal_path = temp_dir.path().join(seal_file_name);
+
+		let assertion_id = H160::default();
+		seal_state(
+			seal_path.to_str().unwrap(),
+			vec![(assertion_id.clone(), ([1; 67].to_vec(), vec!["secret_1".to_string()]))],
+		)
+		.unwrap();
+
+		let repository = EvmAssertionRepository::new(seal_path.to_str().unwrap()).unwrap();
+		repository.remove(&assertion_id).unwrap();
+		assert!(repository.get(&assertion_id).unwrap().is_none());
+
+		// the removal is persisted
+		let repository = EvmAssertionRepository::new(seal_path.to_str().unwrap()).unwrap();
+		assert!(repository.get(&assertion_id).unwrap().is_none());
+	}
 }
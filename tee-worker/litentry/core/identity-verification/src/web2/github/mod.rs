@@ -0,0 +1,55 @@
+mod oauth_store;
+pub use oauth_store::*;
+
+use crate::web2::twitter::helpers;
+use std::{format, string::String};
+
+#[derive(Debug)]
+pub struct AuthorizeData {
+	pub authorize_url: String,
+	pub state: String,
+}
+
+const BASE_URL: &str = "https://github.com/login/oauth/authorize";
+const SCOPES: &str = "read:user";
+
+pub fn get_authorize_data(client_id: &str, redirect_uri: &str) -> AuthorizeData {
+	let state = helpers::get_state_verifier();
+
+	let authorize_url = format!(
+		"{}?client_id={}&redirect_uri={}&scope={}&state={}&allow_signup=false",
+		BASE_URL, client_id, redirect_uri, SCOPES, state
+	);
+
+	AuthorizeData { authorize_url, state }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use url::Url;
+
+	#[test]
+	fn test_get_authorize_data() {
+		let client_id = "client_id";
+		let redirect_uri = "http://localhost:8080";
+		let authorize_data = get_authorize_data(client_id, redirect_uri);
+
+		let authorize_url = Url::parse(&authorize_data.authorize_url).unwrap();
+		assert_eq!(authorize_url.query_pairs().count(), 5);
+		assert_eq!(
+			authorize_url.query_pairs().find(|(key, _)| key == "client_id").unwrap().1,
+			client_id
+		);
+		assert_eq!(
+			authorize_url.query_pairs().find(|(key, _)| key == "redirect_uri").unwrap().1,
+			redirect_uri
+		);
+		assert_eq!(authorize_url.query_pairs().find(|(key, _)| key == "scope").unwrap().1, SCOPES);
+		assert_eq!(
+			authorize_url.query_pairs().find(|(key, _)| key == "state").unwrap().1,
+			authorize_data.state
+		);
+		assert_eq!(authorize_data.state.len(), 32);
+	}
+}
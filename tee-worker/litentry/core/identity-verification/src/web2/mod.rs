@@ -20,19 +20,22 @@ extern crate sgx_tstd as std;
 #[cfg(all(feature = "std", feature = "sgx"))]
 compile_error!("feature \"std\" and feature \"sgx\" cannot be enabled at the same time");
 
+pub mod discord;
+pub mod github;
 pub mod twitter;
 
 use crate::{ensure, Error, Result};
 use itp_sgx_crypto::ShieldingCryptoDecrypt;
 use itp_utils::stringify::account_id_to_string;
 use lc_data_providers::{
-	discord_official::{DiscordMessage, DiscordOfficialClient},
+	discord_official::{DiscordMessage, DiscordOfficialClient, DiscordUserAccessTokenData},
+	github::{GithubClient, GithubUserAccessTokenData},
 	twitter_official::{Tweet, TwitterOfficialClient, TwitterUserAccessTokenData},
 	vec_to_string, DataProviderConfig, UserInfo,
 };
 use litentry_primitives::{
-	DiscordValidationData, ErrorDetail, ErrorString, Identity, IntoErrorDetail,
-	TwitterValidationData, Web2ValidationData,
+	DiscordValidationData, ErrorDetail, ErrorString, GithubValidationData, Identity,
+	IntoErrorDetail, TwitterValidationData, Web2ValidationData,
 };
 use log::*;
 use std::{string::ToString, vec::Vec};
@@ -152,39 +155,172 @@ pub fn verify(
 				Ok(user.username)
 			},
 		},
-		Web2ValidationData::Discord(DiscordValidationData {
-			ref channel_id,
-			ref message_id,
-			..
-		}) => {
-			let mut client = DiscordOfficialClient::new(config);
-			let message: DiscordMessage = client
-				.query_message(channel_id.to_vec(), message_id.to_vec())
-				.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+		Web2ValidationData::Discord(data) => match data {
+			DiscordValidationData::PublicMessage { ref channel_id, ref message_id, .. } => {
+				let mut client = DiscordOfficialClient::new(config);
+				let message: DiscordMessage = client
+					.query_message(channel_id.to_vec(), message_id.to_vec())
+					.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+
+				let user = client
+					.get_user_info(message.author.id.clone())
+					.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+
+				let mut username = message.author.username.clone();
+				// if discord user's username is upgraded complete, the discriminator value from api will be "0".
+				if user.discriminator != "0" {
+					username.push_str(&'#'.to_string());
+					username.push_str(&user.discriminator);
+				}
+				let payload = payload_from_discord(&message)?;
+				ensure!(
+					payload.as_slice() == raw_msg,
+					Error::LinkIdentityFailed(ErrorDetail::UnexpectedMessage)
+				);
+
+				Ok(username)
+			},
+			DiscordValidationData::OAuth2 { code, state, redirect_uri } => {
+				let redirect_uri = vec_to_string(redirect_uri.to_vec())
+					.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+				let code = vec_to_string(code.to_vec())
+					.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+				let state = vec_to_string(state.to_vec())
+					.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+				let Some(account_id) = who.to_account_id() else {
+					return Err(Error::LinkIdentityFailed(ErrorDetail::ParseError));
+				};
+				let (code_verifier, state_verifier) =
+					match discord::OAuthStore::get_data(&account_id) {
+						Ok(data) => data.ok_or_else(|| {
+							Error::LinkIdentityFailed(ErrorDetail::StfError(
+								ErrorString::truncate_from(
+									std::format!(
+										"no oauth data found for {}",
+										account_id_to_string(&account_id)
+									)
+									.as_bytes()
+									.to_vec(),
+								),
+							))
+						})?,
+						Err(e) =>
+							return Err(Error::LinkIdentityFailed(ErrorDetail::StfError(
+								ErrorString::truncate_from(
+									std::format!("failed to get oauth data: {}", e)
+										.as_bytes()
+										.to_vec(),
+								),
+							))),
+					};
 
-			let user = client
-				.get_user_info(message.author.id.clone())
+				ensure!(
+					state == state_verifier,
+					Error::LinkIdentityFailed(ErrorDetail::StfError(ErrorString::truncate_from(
+						"stored state mismatch".as_bytes().to_vec()
+					)))
+				);
+
+				let authorization = DiscordOfficialClient::oauth2_authorization(
+					&config.discord_client_id,
+					&config.discord_client_secret,
+				);
+				let mut oauth2_client = DiscordOfficialClient::with_authorization(
+					&config.discord_official_url,
+					&authorization,
+				);
+				let data = DiscordUserAccessTokenData {
+					client_id: config.discord_client_id.clone(),
+					code,
+					code_verifier,
+					redirect_uri,
+				};
+				let user_token = oauth2_client.request_user_access_token(data).map_err(|e| {
+					Error::LinkIdentityFailed(ErrorDetail::StfError(ErrorString::truncate_from(
+						e.to_string().as_bytes().to_vec(),
+					)))
+				})?;
+
+				let user_authorization = std::format!("Bearer {}", user_token.access_token);
+				let mut user_client = DiscordOfficialClient::with_authorization(
+					&config.discord_official_url,
+					&user_authorization,
+				);
+				let user = user_client
+					.get_user_info("@me".to_string())
+					.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+
+				let mut username = user.username;
+				// if discord user's username is upgraded complete, the discriminator value from api will be "0".
+				if user.discriminator != "0" {
+					username.push_str(&'#'.to_string());
+					username.push_str(&user.discriminator);
+				}
+
+				Ok(username)
+			},
+		},
+		Web2ValidationData::Github(GithubValidationData { code, state, redirect_uri }) => {
+			let redirect_uri = vec_to_string(redirect_uri.to_vec())
+				.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+			let code = vec_to_string(code.to_vec())
 				.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+			let state = vec_to_string(state.to_vec())
+				.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+			let Some(account_id) = who.to_account_id() else {
+				return Err(Error::LinkIdentityFailed(ErrorDetail::ParseError));
+			};
+			let state_verifier = github::OAuthStore::get_state(&account_id)
+				.map_err(|e| {
+					Error::LinkIdentityFailed(ErrorDetail::StfError(ErrorString::truncate_from(
+						std::format!("failed to get oauth data: {}", e).as_bytes().to_vec(),
+					)))
+				})?
+				.ok_or_else(|| {
+					Error::LinkIdentityFailed(ErrorDetail::StfError(ErrorString::truncate_from(
+						std::format!(
+							"no oauth data found for {}",
+							account_id_to_string(&account_id)
+						)
+						.as_bytes()
+						.to_vec(),
+					)))
+				})?;
 
-			let mut username = message.author.username.clone();
-			// if discord user's username is upgraded complete, the discriminator value from api will be "0".
-			if user.discriminator != "0" {
-				username.push_str(&'#'.to_string());
-				username.push_str(&user.discriminator);
-			}
-			let payload = payload_from_discord(&message)?;
 			ensure!(
-				payload.as_slice() == raw_msg,
-				Error::LinkIdentityFailed(ErrorDetail::UnexpectedMessage)
+				state == state_verifier,
+				Error::LinkIdentityFailed(ErrorDetail::StfError(ErrorString::truncate_from(
+					"stored state mismatch".as_bytes().to_vec()
+				)))
 			);
 
-			Ok(username)
+			let data = GithubUserAccessTokenData {
+				client_id: config.github_client_id.clone(),
+				client_secret: config.github_client_secret.clone(),
+				code,
+				redirect_uri,
+			};
+			let mut oauth2_client = GithubClient::new(&config.github_official_url, None);
+			let user_token = oauth2_client.request_user_access_token(data).map_err(|e| {
+				Error::LinkIdentityFailed(ErrorDetail::StfError(ErrorString::truncate_from(
+					e.to_string().as_bytes().to_vec(),
+				)))
+			})?;
+
+			let mut user_client =
+				GithubClient::new(&config.github_api_url, Some(&user_token.access_token));
+			let user = user_client
+				.get_user_info()
+				.map_err(|e| Error::LinkIdentityFailed(e.into_error_detail()))?;
+
+			Ok(user.login)
 		},
 	}?;
 
 	// compare the username:
 	// - twitter's username is case insensitive
 	// - discord's username is case sensitive
+	// - github's username is case insensitive
 	match identity {
 		Identity::Twitter(address) => {
 			let handle = std::str::from_utf8(address.inner_ref())
@@ -199,6 +335,14 @@ pub fn verify(
 				.map_err(|_| Error::LinkIdentityFailed(ErrorDetail::ParseError))?;
 			ensure!(username.eq(handle), Error::LinkIdentityFailed(ErrorDetail::WrongWeb2Handle));
 		},
+		Identity::Github(address) => {
+			let handle = std::str::from_utf8(address.inner_ref())
+				.map_err(|_| Error::LinkIdentityFailed(ErrorDetail::ParseError))?;
+			ensure!(
+				username.eq_ignore_ascii_case(handle),
+				Error::LinkIdentityFailed(ErrorDetail::WrongWeb2Handle)
+			);
+		},
 		_ => return Err(Error::LinkIdentityFailed(ErrorDetail::InvalidIdentity)),
 	}
 
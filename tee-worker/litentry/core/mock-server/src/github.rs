@@ -0,0 +1,56 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+#![allow(opaque_hidden_inferred_bound)]
+
+use lc_data_providers::github::{GithubUser, GithubUserAccessToken};
+use std::collections::HashMap;
+use warp::{http::Response, Filter};
+
+pub(crate) fn request_user_access_token(
+) -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
+	warp::post()
+		.and(warp::path!("login" / "oauth" / "access_token"))
+		.and(warp::body::form())
+		.map(|p: HashMap<String, String>| {
+			if p.get("code").map(|c| c.is_empty()).unwrap_or(true) {
+				return Response::builder().status(400).body(String::from("Error query"))
+			}
+			let body = GithubUserAccessToken {
+				access_token: "github_access_token".to_string(),
+				token_type: "bearer".to_string(),
+				scope: "read:user".to_string(),
+			};
+
+			Response::builder()
+				.header("Content-Type", "application/json")
+				.body(serde_json::to_string(&body).unwrap())
+		})
+}
+
+pub(crate) fn get_user_info(
+) -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
+	warp::get()
+		.and(warp::path!("user"))
+		.and(warp::header::<String>("authorization"))
+		.map(|authorization: String| {
+			if authorization != "Bearer github_access_token" {
+				return Response::builder().status(401).body(String::from("Bad credentials"))
+			}
+			let body = GithubUser { id: 1, login: "mock_github_user".to_string() };
+
+			Response::builder().body(serde_json::to_string(&body).unwrap())
+		})
+}
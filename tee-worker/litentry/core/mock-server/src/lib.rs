@@ -26,6 +26,7 @@ pub mod blockchain_info;
 pub mod discord_litentry;
 pub mod discord_official;
 pub mod geniidata;
+pub mod github;
 pub mod karat_dao;
 pub mod litentry_archive;
 pub mod moralis;
@@ -64,6 +65,8 @@ pub fn run(port: u16) -> Result<String, RecvError> {
 					.or(twitter_official::query_user_by_id())
 					.or(twitter_official::request_user_access_token())
 					.or(discord_official::query_message())
+					.or(discord_official::get_user_info())
+					.or(discord_official::request_user_access_token())
 					.or(discord_litentry::check_id_hubber())
 					.or(discord_litentry::check_join())
 					.or(discord_litentry::has_role())
@@ -79,6 +82,8 @@ pub fn run(port: u16) -> Result<String, RecvError> {
 					.or(nodereal::query())
 					.or(geniidata::query())
 					.or(oneblock::query())
+					.or(github::request_user_access_token())
+					.or(github::get_user_info())
 					.boxed(),
 			)
 			.bind_with_graceful_shutdown(([127, 0, 0, 1], port), shutdown_signal());
@@ -24,15 +24,15 @@ use itp_sgx_externalities::SgxExternalitiesTrait;
 use itp_stf_executor::traits::StfEnclaveSigning;
 use itp_stf_state_handler::handle_state::HandleState;
 use itp_top_pool_author::traits::AuthorApi;
-use itp_types::ShardIdentifier;
-use lc_credentials::credential_schema;
+use itp_types::{RequestProgress, ShardIdentifier};
+use lc_credentials::{credential_schema, validity};
 use lc_data_providers::DataProviderConfig;
 use lc_dynamic_assertion::AssertionLogicRepository;
 use lc_evm_dynamic_assertions::AssertionRepositoryItem;
 use lc_stf_task_sender::AssertionBuildRequest;
 use litentry_primitives::{
-	AmountHoldingTimeType, Assertion, ErrorDetail, ErrorString, Identity, ParameterString,
-	VCMPError,
+	AmountHoldingTimeType, Assertion, ErrorDetail, ErrorString, Identity, IntoErrorDetail,
+	ParameterString, VCMPError,
 };
 use log::*;
 use sp_core::H160;
@@ -66,12 +66,13 @@ where
 	AR: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem>,
 {
 	type Error = VCMPError;
-	type Result = Vec<u8>; // vc_byte_array
+	type Result = (H256, Vec<u8>); // (vc_index, vc_byte_array)
 
 	fn on_process(&self) -> Result<Self::Result, Self::Error> {
 		// create the initial credential
 		// TODO: maybe we can further simplify this
-		create_credential_str(&self.req, &self.context)
+		// the progress is only reported for the requests of the vc-task
+		create_credential_str(&self.req, &self.context, &|_| {})
 	}
 
 	fn on_success(
@@ -82,12 +83,13 @@ where
 		debug!("Assertion build OK");
 		// we shouldn't have the maximum text length limit in normal RSA3072 encryption, as the payload
 		// using enclave's shielding key is encrypted in chunks
-		let vc_payload = result;
+		let (vc_index, vc_payload) = result;
 		if let Ok(enclave_signer) = self.context.enclave_signer.get_enclave_account() {
 			let c = TrustedCall::request_vc_callback(
 				enclave_signer.into(),
 				self.req.who.clone(),
 				self.req.assertion.clone(),
+				vc_index,
 				vc_payload,
 				self.req.maybe_key,
 				self.req.should_create_id_graph,
@@ -142,11 +144,18 @@ pub fn create_credential_str<
 >(
 	req: &AssertionBuildRequest,
 	context: &Arc<StfTaskContext<ShieldingKeyRepository, A, S, H, O, AR>>,
-) -> Result<Vec<u8>, VCMPError>
+	on_progress: &dyn Fn(RequestProgress),
+) -> Result<(H256, Vec<u8>), VCMPError>
 where
 	ShieldingKeyRepository: AccessKey,
 	<ShieldingKeyRepository as AccessKey>::KeyType: ShieldingCryptoEncrypt + 'static,
 {
+	let data_provider_config = context
+		.data_provider_config
+		.get()
+		.map_err(|e| VCMPError::RequestVCFailed(req.assertion.clone(), e.into_error_detail()))?;
+
+	on_progress(RequestProgress::FetchingData);
 	let mut credential = match req.assertion.clone() {
 		Assertion::A1 => {
 			#[cfg(test)]
@@ -156,93 +165,64 @@ where
 			lc_assertion_build::a1::build(req)
 		},
 		Assertion::A2(guild_id) =>
-			lc_assertion_build::a2::build(req, guild_id, &context.data_provider_config),
+			lc_assertion_build::a2::build(req, guild_id, &data_provider_config),
 
-		Assertion::A3(guild_id, channel_id, role_id) => lc_assertion_build::a3::build(
-			req,
-			guild_id,
-			channel_id,
-			role_id,
-			&context.data_provider_config,
-		),
+		Assertion::A3(guild_id, channel_id, role_id) =>
+			lc_assertion_build::a3::build(req, guild_id, channel_id, role_id, &data_provider_config),
 
-		Assertion::A4(min_balance) => build_holding_time(
-			req,
-			AmountHoldingTimeType::LIT,
-			min_balance,
-			&context.data_provider_config,
-		),
+		Assertion::A4(min_balance) =>
+			build_holding_time(req, AmountHoldingTimeType::LIT, min_balance, &data_provider_config),
 
-		Assertion::A6 => lc_assertion_build::a6::build(req, &context.data_provider_config),
+		Assertion::A6 => lc_assertion_build::a6::build(req, &data_provider_config),
 
-		Assertion::A7(min_balance) => build_holding_time(
-			req,
-			AmountHoldingTimeType::DOT,
-			min_balance,
-			&context.data_provider_config,
-		),
+		Assertion::A7(min_balance) =>
+			build_holding_time(req, AmountHoldingTimeType::DOT, min_balance, &data_provider_config),
 
 		// no need to pass `networks` again because it's the same as the `get_supported_web3networks`
-		Assertion::A8(_networks) =>
-			lc_assertion_build::a8::build(req, &context.data_provider_config),
+		Assertion::A8(_networks) => lc_assertion_build::a8::build(req, &data_provider_config),
 
-		Assertion::A10(min_balance) => build_holding_time(
-			req,
-			AmountHoldingTimeType::WBTC,
-			min_balance,
-			&context.data_provider_config,
-		),
+		Assertion::A10(min_balance) =>
+			build_holding_time(req, AmountHoldingTimeType::WBTC, min_balance, &data_provider_config),
 
-		Assertion::A11(min_balance) => build_holding_time(
-			req,
-			AmountHoldingTimeType::ETH,
-			min_balance,
-			&context.data_provider_config,
-		),
+		Assertion::A11(min_balance) =>
+			build_holding_time(req, AmountHoldingTimeType::ETH, min_balance, &data_provider_config),
 
 		Assertion::A13(owner) =>
 			lc_assertion_build::a13::build(req, context.ocall_api.clone(), &owner),
 
-		Assertion::A14 => lc_assertion_build::a14::build(req, &context.data_provider_config),
+		Assertion::A14 => lc_assertion_build::a14::build(req, &data_provider_config),
 
 		Assertion::Achainable(param) =>
-			lc_assertion_build::achainable::build(req, param, &context.data_provider_config),
+			lc_assertion_build::achainable::build(req, param, &data_provider_config),
 
-		Assertion::A20 => lc_assertion_build::a20::build(req, &context.data_provider_config),
+		Assertion::A20 => lc_assertion_build::a20::build(req, &data_provider_config),
 
-		Assertion::OneBlock(course_type) => lc_assertion_build::oneblock::course::build(
-			req,
-			course_type,
-			&context.data_provider_config,
-		),
+		Assertion::OneBlock(course_type) =>
+			lc_assertion_build::oneblock::course::build(req, course_type, &data_provider_config),
 
 		Assertion::GenericDiscordRole(role_type) =>
-			lc_assertion_build::generic_discord_role::build(
-				req,
-				role_type,
-				&context.data_provider_config,
-			),
+			lc_assertion_build::generic_discord_role::build(req, role_type, &data_provider_config),
 
 		Assertion::BnbDomainHolding =>
 			lc_assertion_build::nodereal::bnb_domain::bnb_domain_holding_amount::build(
 				req,
-				&context.data_provider_config,
+				&data_provider_config,
 			),
 
 		Assertion::BnbDigitDomainClub(digit_domain_type) =>
 			lc_assertion_build::nodereal::bnb_domain::bnb_digit_domain_club_amount::build(
 				req,
 				digit_domain_type,
-				&context.data_provider_config,
+				&data_provider_config,
 			),
 
 		Assertion::VIP3MembershipCard(level) =>
-			lc_assertion_build::vip3::card::build(req, level, &context.data_provider_config),
+			lc_assertion_build::vip3::card::build(req, level, &data_provider_config),
 
 		Assertion::WeirdoGhostGangHolder =>
 			lc_assertion_build::nodereal::nft_holder::weirdo_ghost_gang_holder::build(
 				req,
-				&context.data_provider_config,
+				&data_provider_config,
 			),
 
 		Assertion::LITStaking => lc_assertion_build::lit_staking::build(req),
@@ -251,39 +231,41 @@ where
 			lc_assertion_build::nodereal::amount_holding::evm_amount_holding::build(
 				req,
 				token_type,
-				&context.data_provider_config,
+				&data_provider_config,
 			),
 
 		Assertion::BRC20AmountHolder =>
-			lc_assertion_build::brc20::amount_holder::build(req, &context.data_provider_config),
+			lc_assertion_build::brc20::amount_holder::build(req, &data_provider_config),
 
 		Assertion::CryptoSummary =>
-			lc_assertion_build::nodereal::crypto_summary::build(req, &context.data_provider_config),
+			lc_assertion_build::nodereal::crypto_summary::build(req, &data_provider_config),
 
 		Assertion::TokenHoldingAmount(token_type) =>
 			lc_assertion_build_v2::token_holding_amount::build(
 				req,
 				token_type,
-				&context.data_provider_config,
+				&data_provider_config,
 			),
 
 		Assertion::PlatformUser(platform_user_type) => lc_assertion_build_v2::platform_user::build(
 			req,
 			platform_user_type,
-			&context.data_provider_config,
+			&data_provider_config,
 		),
 
 		Assertion::NftHolder(nft_type) =>
-			lc_assertion_build_v2::nft_holder::build(req, nft_type, &context.data_provider_config),
+			lc_assertion_build_v2::nft_holder::build(req, nft_type, &data_provider_config),
 
 		Assertion::Dynamic(smart_contract_id) => lc_assertion_build::dynamic::build(
 			req,
 			smart_contract_id,
 			context.assertion_repository.clone(),
+			&data_provider_config,
 		),
 	}?;
 
 	// post-process the credential
+	on_progress(RequestProgress::Building);
 	let signer = context.enclave_signer.as_ref();
 	let enclave_account = signer.get_enclave_account().map_err(|e| {
 		VCMPError::RequestVCFailed(
@@ -295,8 +277,7 @@ where
 	credential.parachain_block_number = req.parachain_block_number;
 	credential.sidechain_block_number = req.sidechain_block_number;
 
-	credential.credential_subject.endpoint =
-		context.data_provider_config.credential_endpoint.to_string();
+	credential.credential_subject.endpoint = data_provider_config.credential_endpoint.to_string();
 
 	credential.credential_subject.assertion_text = format!("{:?}", req.assertion);
 
@@ -304,6 +285,20 @@ where
 		credential.credential_schema.id = schema;
 	}
 
+	// the expiration of dynamic assertions is set by their builder, as they may declare it
+	if !matches!(req.assertion, Assertion::Dynamic(_)) {
+		if let Some(validity_period) =
+			validity::get_validity_period(&req.assertion, &data_provider_config)
+		{
+			credential.set_expiration(validity_period).map_err(|e| {
+				VCMPError::RequestVCFailed(
+					req.assertion.clone(),
+					ErrorDetail::StfError(ErrorString::truncate_from(format!("{e:?}").into())),
+				)
+			})?;
+		}
+	}
+
 	credential.issuer.id = Identity::Substrate(enclave_account.into()).to_did().map_err(|e| {
 		VCMPError::RequestVCFailed(
 			req.assertion.clone(),
@@ -322,6 +317,7 @@ where
 		)
 	})?;
 	debug!("Credential Payload signature: {:?}", sig);
+	on_progress(RequestProgress::Signed);
 
 	credential.add_proof(&sig, &enclave_account);
 	credential.validate().map_err(|e| {
@@ -335,5 +331,11 @@ where
 		.to_json()
 		.map_err(|_| VCMPError::RequestVCFailed(req.assertion.clone(), ErrorDetail::ParseError))?;
 	debug!("Credential: {}, length: {}", credential_str, credential_str.len());
-	Ok(credential_str.as_bytes().to_vec())
+	let vc_index = credential.vc_index().map_err(|e| {
+		VCMPError::RequestVCFailed(
+			req.assertion.clone(),
+			ErrorDetail::StfError(ErrorString::truncate_from(format!("{e:?}").into())),
+		)
+	})?;
+	Ok((vc_index, credential_str.as_bytes().to_vec()))
 }
@@ -28,7 +28,7 @@ use itp_types::{ShardIdentifier, H256};
 use lc_dynamic_assertion::AssertionLogicRepository;
 use lc_evm_dynamic_assertions::AssertionRepositoryItem;
 use lc_stf_task_sender::Web2IdentityVerificationRequest;
-use litentry_primitives::IMPError;
+use litentry_primitives::{IMPError, IntoErrorDetail};
 use log::*;
 use sp_core::H160;
 use std::sync::{mpsc::Sender, Arc};
@@ -63,7 +63,12 @@ where
 	type Result = ();
 
 	fn on_process(&self) -> Result<Self::Result, Self::Error> {
-		lc_identity_verification::verify(&self.req, &self.context.data_provider_config)
+		let data_provider_config = self
+			.context
+			.data_provider_config
+			.get()
+			.map_err(|e| IMPError::LinkIdentityFailed(e.into_error_detail()))?;
+		lc_identity_verification::verify(&self.req, &data_provider_config)
 	}
 
 	fn on_success(
@@ -51,7 +51,7 @@ use itp_stf_primitives::types::TrustedOperation;
 use itp_stf_state_handler::handle_state::HandleState;
 use itp_top_pool_author::traits::AuthorApi;
 use itp_types::{RsaRequest, ShardIdentifier, H256};
-use lc_data_providers::DataProviderConfig;
+use lc_data_providers::config_repository::DataProviderConfigRepository;
 use lc_dynamic_assertion::AssertionLogicRepository;
 use lc_evm_dynamic_assertions::AssertionRepositoryItem;
 use lc_stf_task_sender::{init_stf_task_sender_storage, RequestType};
@@ -101,7 +101,7 @@ pub struct StfTaskContext<
 	pub enclave_signer: Arc<S>,
 	pub state_handler: Arc<H>,
 	pub ocall_api: Arc<O>,
-	pub data_provider_config: Arc<DataProviderConfig>,
+	pub data_provider_config: Arc<DataProviderConfigRepository>,
 	pub assertion_repository: Arc<AR>,
 }
 
@@ -124,7 +124,7 @@ where
 		enclave_signer: Arc<S>,
 		state_handler: Arc<H>,
 		ocall_api: Arc<O>,
-		data_provider_config: Arc<DataProviderConfig>,
+		data_provider_config: Arc<DataProviderConfigRepository>,
 		assertion_repository: Arc<AR>,
 	) -> Self {
 		Self {
@@ -10,6 +10,7 @@ use itp_test::mock::{
 	shielding_crypto_mock::ShieldingCryptoMock,
 };
 use itp_top_pool_author::mocks::AuthorApiMock;
+use lc_data_providers::{config_repository::DataProviderConfigRepository, DataProviderConfig};
 use lc_evm_dynamic_assertions::repository::EvmAssertionRepository;
 use lc_stf_task_sender::{SendStfRequest, StfRequestSender};
 use litentry_primitives::Assertion;
@@ -22,7 +23,8 @@ fn test_threadpool_behaviour() {
 	let stf_enclave_signer_mock = StfEnclaveSignerMock::default();
 	let handle_state_mock = HandleStateMock::default();
 	let onchain_mock = OnchainMock::default();
-	let data_provider_conifg = DataProviderConfig::new().unwrap();
+	let data_provider_conifg =
+		DataProviderConfigRepository::new(DataProviderConfig::new().unwrap(), "").unwrap();
 	let assertion_repository = EvmAssertionRepository::new(Default::default()).unwrap();
 	let context = StfTaskContext::new(
 		Arc::new(shielding_key_repository_mock),
@@ -0,0 +1,95 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+use core::time::Duration;
+use log::warn;
+use std::env;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VcTaskConfig {
+	/// Number of VC request items processed concurrently
+	pub pool_size: usize,
+	/// Times the building of a VC failing with a transient data provider error is retried
+	pub max_retries: u16,
+	/// Milliseconds before the first retry, doubled for each following one.
+	/// The total backoff should outlast the data provider circuit open duration.
+	pub retry_delay: u64,
+}
+
+impl Default for VcTaskConfig {
+	fn default() -> Self {
+		Self { pool_size: 960, max_retries: 3, retry_delay: 5000 }
+	}
+}
+
+impl VcTaskConfig {
+	/// The default config, overridden by the `VC_TASK_POOL_SIZE`, `VC_TASK_MAX_RETRIES`
+	/// and `VC_TASK_RETRY_DELAY` env variables.
+	pub fn from_env() -> Self {
+		let mut config = Self::default();
+		if let Ok(v) = env::var("VC_TASK_POOL_SIZE") {
+			match v.parse::<usize>() {
+				Ok(pool_size) if pool_size > 0 => config.pool_size = pool_size,
+				_ => warn!("Invalid VC_TASK_POOL_SIZE: {}, using {}", v, config.pool_size),
+			}
+		}
+		if let Ok(v) = env::var("VC_TASK_MAX_RETRIES") {
+			match v.parse::<u16>() {
+				Ok(max_retries) => config.max_retries = max_retries,
+				_ => warn!("Invalid VC_TASK_MAX_RETRIES: {}, using {}", v, config.max_retries),
+			}
+		}
+		if let Ok(v) = env::var("VC_TASK_RETRY_DELAY") {
+			match v.parse::<u64>() {
+				Ok(retry_delay) => config.retry_delay = retry_delay,
+				_ => warn!("Invalid VC_TASK_RETRY_DELAY: {}, using {}", v, config.retry_delay),
+			}
+		}
+		config
+	}
+
+	/// The delay before the retry following the `attempt`-th failure, starting at 0.
+	pub fn retry_delay(&self, attempt: u16) -> Duration {
+		Duration::from_millis(self.retry_delay.saturating_mul(1 << attempt.min(16)))
+	}
+
+	/// The time spent waiting over all the retries.
+	pub fn total_backoff(&self) -> Duration {
+		(0..self.max_retries).map(|attempt| self.retry_delay(attempt)).sum()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use lc_data_providers::DataProviderConfig;
+
+	#[test]
+	fn retry_delay_is_doubled() {
+		let config = VcTaskConfig { pool_size: 1, max_retries: 3, retry_delay: 100 };
+		assert_eq!(config.retry_delay(0), Duration::from_millis(100));
+		assert_eq!(config.retry_delay(2), Duration::from_millis(400));
+		assert_eq!(config.total_backoff(), Duration::from_millis(700));
+	}
+
+	#[test]
+	fn default_backoff_outlasts_circuit_open_duration() {
+		let circuit_open_duration = Duration::from_millis(
+			DataProviderConfig::new().unwrap().data_provider_circuit_open_duration,
+		);
+		assert!(VcTaskConfig::default().total_backoff() > circuit_open_duration);
+	}
+}
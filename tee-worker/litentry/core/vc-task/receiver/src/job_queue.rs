@@ -0,0 +1,450 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Sealed queue of the VC requests handled by the vc-task receiver.
+//!
+//! Each request is sealed to its own file when it's received and updated as its items are
+//! processed, so that the requests in flight when the worker stops are processed again on the
+//! next enclave init. The response of each processed item is kept with its job: the items of a
+//! recovered request whose VC was already issued are not processed again, their response is
+//! re-published to the clients re-attaching to the request by its hash instead.
+//!
+//! The response of an item is recorded as submitted before its VC is sent to the parentchain.
+//! A submitted item isn't processed again on recovery, as the VC may have been issued already.
+//!
+//! Finished requests are kept with their responses, up to `FINISHED_JOBS_CAPACITY`, so that
+//! the responses can be re-published when the same request is submitted again.
+
+use codec::{Decode, Encode};
+use itp_types::H256;
+use litentry_primitives::AesRequest;
+use log::*;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+#[cfg(feature = "sgx")]
+use std::sync::SgxMutex as Mutex;
+use std::{
+	collections::{HashMap, VecDeque},
+	format,
+	string::{String, ToString},
+	sync::Arc,
+	vec::Vec,
+};
+
+/// The number of finished requests kept, to re-publish their responses when submitted again.
+pub const FINISHED_JOBS_CAPACITY: usize = 1024;
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct VcJob {
+	/// The request hash, which is also the hash of the rpc connection
+	pub hash: H256,
+	pub request: AesRequest,
+	/// The position of the job in the queue, to recover the jobs in the order they were pushed
+	pub seq: u64,
+	/// The encoded `RequestVcResultOrError` of the processed items, with their index
+	pub processed: Vec<(u8, Vec<u8>)>,
+	/// The encoded `RequestVcResultOrError` of the items whose VC is being submitted to the
+	/// parentchain, with their index
+	pub submitted: Vec<(u8, Vec<u8>)>,
+	pub finished: bool,
+}
+
+impl VcJob {
+	pub fn new(hash: H256, request: AesRequest, seq: u64) -> Self {
+		Self { hash, request, seq, processed: Vec::new(), submitted: Vec::new(), finished: false }
+	}
+
+	pub fn processed_item(&self, idx: u8) -> Option<&Vec<u8>> {
+		self.processed.iter().find(|(i, _)| *i == idx).map(|(_, response)| response)
+	}
+
+	pub fn submitted_item(&self, idx: u8) -> Option<&Vec<u8>> {
+		self.submitted.iter().find(|(i, _)| *i == idx).map(|(_, response)| response)
+	}
+}
+
+/// The result of pushing a request to the queue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PushOutcome {
+	/// The request is new, its job is to be processed
+	New(VcJob),
+	/// A request with the same hash is being processed
+	Pending,
+	/// A request with the same hash was finished, with the job holding its responses
+	Finished(VcJob),
+}
+
+#[derive(Default)]
+struct VcJobs {
+	pending: HashMap<H256, Arc<Mutex<VcJob>>>,
+	finished: VecDeque<H256>,
+	next_seq: u64,
+}
+
+pub struct VcJobQueue {
+	// only guards the index of the jobs, each job is locked and sealed on its own
+	jobs: Mutex<VcJobs>,
+	seal_dir: String,
+}
+
+impl VcJobQueue {
+	/// Create the queue, the jobs sealed in `seal_dir` are recovered.
+	pub fn new(seal_dir: &str) -> Result<Self, String> {
+		let mut recovered = io::unseal_jobs(seal_dir)?;
+		recovered.sort_by_key(|job| job.seq);
+
+		let mut jobs = VcJobs {
+			next_seq: recovered.last().map(|job| job.seq + 1).unwrap_or_default(),
+			..Default::default()
+		};
+		for job in recovered {
+			if job.finished {
+				jobs.finished.push_back(job.hash);
+			} else {
+				jobs.pending.insert(job.hash, Arc::new(Mutex::new(job)));
+			}
+		}
+		Self::prune_finished(seal_dir, &mut jobs);
+		if !jobs.pending.is_empty() {
+			info!("Recovered {} pending vc jobs", jobs.pending.len());
+		}
+
+		Ok(Self { jobs: Mutex::new(jobs), seal_dir: seal_dir.to_string() })
+	}
+
+	/// The jobs not finished yet, in the order they were pushed.
+	pub fn pending(&self) -> Result<Vec<VcJob>, String> {
+		let pending: Vec<_> = self
+			.jobs
+			.lock()
+			.map_err(|_| lock_poisoning())?
+			.pending
+			.values()
+			.cloned()
+			.collect();
+		let mut jobs = pending
+			.iter()
+			.map(|job| job.lock().map(|job| job.clone()).map_err(|_| lock_poisoning()))
+			.collect::<Result<Vec<_>, _>>()?;
+		jobs.sort_by_key(|job| job.seq);
+		Ok(jobs)
+	}
+
+	/// Push the job of a new request, unless a request with the same hash is pending or was
+	/// finished already.
+	pub fn push(&self, hash: H256, request: AesRequest) -> Result<PushOutcome, String> {
+		let job = {
+			let mut jobs = self.jobs.lock().map_err(|_| lock_poisoning())?;
+			if jobs.pending.contains_key(&hash) {
+				return Ok(PushOutcome::Pending)
+			}
+			if jobs.finished.contains(&hash) {
+				drop(jobs);
+				return io::unseal_job(&self.seal_dir, &hash).map(PushOutcome::Finished)
+			}
+
+			let job = VcJob::new(hash, request, jobs.next_seq);
+			jobs.next_seq += 1;
+			jobs.pending.insert(hash, Arc::new(Mutex::new(job.clone())));
+			job
+		};
+
+		if let Err(e) = io::seal_job(&self.seal_dir, &job) {
+			self.jobs.lock().map_err(|_| lock_poisoning())?.pending.remove(&hash);
+			return Err(e)
+		}
+		Ok(PushOutcome::New(job))
+	}
+
+	/// Record the response of the `idx`-th item of the job before its VC is submitted to the
+	/// parentchain, so that the item isn't processed again on recovery.
+	pub fn submit_item(&self, hash: &H256, idx: u8, response: Vec<u8>) -> Result<(), String> {
+		let job = self.pending_job(hash)?;
+		let mut job = job.lock().map_err(|_| lock_poisoning())?;
+		if job.submitted_item(idx).is_none() {
+			job.submitted.push((idx, response));
+		}
+		io::seal_job(&self.seal_dir, &job)
+	}
+
+	/// Record the response of the `idx`-th of the `len` items of the job, the job is finished
+	/// once all of its items are processed.
+	pub fn complete_item(
+		&self,
+		hash: &H256,
+		idx: u8,
+		len: u8,
+		response: Vec<u8>,
+	) -> Result<(), String> {
+		let job = self.pending_job(hash)?;
+		let finished = {
+			let mut job = job.lock().map_err(|_| lock_poisoning())?;
+			if job.processed_item(idx).is_none() {
+				job.processed.push((idx, response));
+			}
+			job.submitted.retain(|(i, _)| *i != idx);
+			job.finished = job.processed.len() >= len as usize;
+			io::seal_job(&self.seal_dir, &job)?;
+			job.finished
+		};
+		if finished {
+			self.move_to_finished(hash)?;
+		}
+		Ok(())
+	}
+
+	/// Finish the job without processing its items, e.g. when the request is invalid, with the
+	/// error `response` sent to the client.
+	pub fn finish(&self, hash: &H256, response: Vec<u8>) -> Result<(), String> {
+		let job = self.pending_job(hash)?;
+		{
+			let mut job = job.lock().map_err(|_| lock_poisoning())?;
+			job.processed = vec![(0, response)];
+			job.submitted.clear();
+			job.finished = true;
+			io::seal_job(&self.seal_dir, &job)?;
+		}
+		self.move_to_finished(hash)
+	}
+
+	fn pending_job(&self, hash: &H256) -> Result<Arc<Mutex<VcJob>>, String> {
+		self.jobs
+			.lock()
+			.map_err(|_| lock_poisoning())?
+			.pending
+			.get(hash)
+			.cloned()
+			.ok_or_else(|| "Job not found".to_string())
+	}
+
+	fn move_to_finished(&self, hash: &H256) -> Result<(), String> {
+		let mut jobs = self.jobs.lock().map_err(|_| lock_poisoning())?;
+		if jobs.pending.remove(hash).is_some() {
+			jobs.finished.push_back(*hash);
+			Self::prune_finished(&self.seal_dir, &mut jobs);
+		}
+		Ok(())
+	}
+
+	fn prune_finished(seal_dir: &str, jobs: &mut VcJobs) {
+		while jobs.finished.len() > FINISHED_JOBS_CAPACITY {
+			if let Some(hash) = jobs.finished.pop_front() {
+				if let Err(e) = io::remove_job(seal_dir, &hash) {
+					warn!("Failed to remove finished vc job {:?}: {}", hash, e);
+				}
+			}
+		}
+	}
+}
+
+fn lock_poisoning() -> String {
+	"lock poisoning".to_string()
+}
+
+fn job_file_name(hash: &H256) -> String {
+	format!("{:x}.bin", hash)
+}
+
+#[cfg(feature = "std")]
+mod io {
+	use super::{job_file_name, VcJob};
+	use codec::{Decode, Encode};
+	use itp_types::H256;
+	use std::{format, fs, path::Path, string::String, vec::Vec};
+
+	pub fn seal_job(dir: &str, job: &VcJob) -> Result<(), String> {
+		fs::create_dir_all(dir).map_err(|e| format!("Failed to create vc jobs dir: {:?}", e))?;
+		fs::write(Path::new(dir).join(job_file_name(&job.hash)), job.encode())
+			.map_err(|e| format!("Failed to seal vc job: {:?}", e))
+	}
+
+	pub fn unseal_job(dir: &str, hash: &H256) -> Result<VcJob, String> {
+		let bytes = fs::read(Path::new(dir).join(job_file_name(hash)))
+			.map_err(|e| format!("Failed to unseal vc job: {:?}", e))?;
+		VcJob::decode(&mut bytes.as_slice())
+			.map_err(|e| format!("Failed to decode vc job: {:?}", e))
+	}
+
+	pub fn unseal_jobs(dir: &str) -> Result<Vec<VcJob>, String> {
+		fs::create_dir_all(dir).map_err(|e| format!("Failed to create vc jobs dir: {:?}", e))?;
+		let entries =
+			fs::read_dir(dir).map_err(|e| format!("Failed to read vc jobs dir: {:?}", e))?;
+		Ok(entries
+			.filter_map(|entry| entry.ok())
+			.filter_map(|entry| fs::read(entry.path()).ok())
+			.filter_map(|bytes| VcJob::decode(&mut bytes.as_slice()).ok())
+			.collect())
+	}
+
+	pub fn remove_job(dir: &str, hash: &H256) -> Result<(), String> {
+		fs::remove_file(Path::new(dir).join(job_file_name(hash)))
+			.map_err(|e| format!("Failed to remove vc job: {:?}", e))
+	}
+}
+
+#[cfg(feature = "sgx")]
+mod io {
+	use super::{job_file_name, VcJob};
+	use codec::{Decode, Encode};
+	use itp_sgx_io::{seal, unseal};
+	use itp_types::H256;
+	use log::warn;
+	use std::{format, fs, path::Path, string::String, vec::Vec};
+
+	pub fn seal_job(dir: &str, job: &VcJob) -> Result<(), String> {
+		fs::create_dir_all(dir).map_err(|e| format!("Failed to create vc jobs dir: {:?}", e))?;
+		job.using_encoded(|bytes| seal(bytes, Path::new(dir).join(job_file_name(&job.hash))))
+			.map_err(|e| format!("Failed to seal vc job: {:?}", e))
+	}
+
+	pub fn unseal_job(dir: &str, hash: &H256) -> Result<VcJob, String> {
+		let bytes = unseal(Path::new(dir).join(job_file_name(hash)))
+			.map_err(|e| format!("Failed to unseal vc job: {:?}", e))?;
+		VcJob::decode(&mut bytes.as_slice())
+			.map_err(|e| format!("Failed to decode vc job: {:?}", e))
+	}
+
+	pub fn unseal_jobs(dir: &str) -> Result<Vec<VcJob>, String> {
+		fs::create_dir_all(dir).map_err(|e| format!("Failed to create vc jobs dir: {:?}", e))?;
+		let entries = Path::new(dir)
+			.read_dir()
+			.map_err(|e| format!("Failed to read vc jobs dir: {:?}", e))?;
+		Ok(entries
+			.filter_map(|entry| entry.ok())
+			.filter_map(|entry| match unseal(entry.path()) {
+				Ok(bytes) => VcJob::decode(&mut bytes.as_slice()).ok(),
+				Err(e) => {
+					warn!("Failed to unseal vc job {:?}: {:?}", entry.path(), e);
+					None
+				},
+			})
+			.collect())
+	}
+
+	pub fn remove_job(dir: &str, hash: &H256) -> Result<(), String> {
+		fs::remove_file(Path::new(dir).join(job_file_name(hash)))
+			.map_err(|e| format!("Failed to remove vc job: {:?}", e))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use itp_sgx_temp_dir::TempDir;
+
+	fn new_queue(dir: &TempDir) -> VcJobQueue {
+		VcJobQueue::new(dir.path().join("vc_jobs").to_str().unwrap()).unwrap()
+	}
+
+	#[test]
+	fn job_is_finished_once_all_items_are_processed() {
+		let dir = TempDir::new().unwrap();
+		let queue = new_queue(&dir);
+		let hash = H256::repeat_byte(1);
+
+		assert!(matches!(queue.push(hash, AesRequest::default()).unwrap(), PushOutcome::New(_)));
+		// the same request is rejected
+		assert_eq!(queue.push(hash, AesRequest::default()).unwrap(), PushOutcome::Pending);
+
+		queue.complete_item(&hash, 1, 2, vec![1]).unwrap();
+		let pending = queue.pending().unwrap();
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].processed_item(1), Some(&vec![1]));
+		assert_eq!(pending[0].processed_item(0), None);
+
+		queue.complete_item(&hash, 0, 2, vec![0]).unwrap();
+		assert!(queue.pending().unwrap().is_empty());
+		// a finished request gets its responses back
+		match queue.push(hash, AesRequest::default()).unwrap() {
+			PushOutcome::Finished(job) => {
+				assert_eq!(job.processed_item(0), Some(&vec![0]));
+				assert_eq!(job.processed_item(1), Some(&vec![1]));
+			},
+			outcome => panic!("unexpected outcome: {:?}", outcome),
+		}
+	}
+
+	#[test]
+	fn jobs_are_recovered() {
+		let dir = TempDir::new().unwrap();
+		let queue = new_queue(&dir);
+		let (first, second, finished) =
+			(H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+
+		queue.push(first, AesRequest::default()).unwrap();
+		queue.push(second, AesRequest::default()).unwrap();
+		queue.push(finished, AesRequest::default()).unwrap();
+		queue.complete_item(&first, 0, 3, vec![0]).unwrap();
+		queue.submit_item(&first, 1, vec![1]).unwrap();
+		queue.finish(&finished, vec![2]).unwrap();
+		drop(queue);
+
+		let queue = new_queue(&dir);
+		let pending = queue.pending().unwrap();
+		assert_eq!(pending.iter().map(|job| job.hash).collect::<Vec<_>>(), vec![first, second]);
+		assert_eq!(pending[0].processed_item(0), Some(&vec![0]));
+		assert_eq!(pending[0].submitted_item(1), Some(&vec![1]));
+		assert_eq!(pending[0].processed_item(1), None);
+		assert!(pending[1].processed.is_empty());
+
+		// the order is kept for the jobs pushed after the recovery
+		let third = H256::repeat_byte(4);
+		queue.push(third, AesRequest::default()).unwrap();
+		assert_eq!(queue.pending().unwrap()[2].hash, third);
+
+		match queue.push(finished, AesRequest::default()).unwrap() {
+			PushOutcome::Finished(job) => assert_eq!(job.processed_item(0), Some(&vec![2])),
+			outcome => panic!("unexpected outcome: {:?}", outcome),
+		}
+	}
+
+	#[test]
+	fn completing_a_submitted_item_clears_the_marker() {
+		let dir = TempDir::new().unwrap();
+		let queue = new_queue(&dir);
+		let hash = H256::repeat_byte(1);
+
+		queue.push(hash, AesRequest::default()).unwrap();
+		queue.submit_item(&hash, 0, vec![0]).unwrap();
+		queue.complete_item(&hash, 0, 2, vec![0]).unwrap();
+
+		let pending = queue.pending().unwrap();
+		assert_eq!(pending[0].submitted_item(0), None);
+		assert_eq!(pending[0].processed_item(0), Some(&vec![0]));
+	}
+
+	#[test]
+	fn finished_jobs_are_pruned() {
+		let dir = TempDir::new().unwrap();
+		let queue = new_queue(&dir);
+
+		for i in 0..=FINISHED_JOBS_CAPACITY as u64 {
+			let hash = H256::from_low_u64_be(i);
+			queue.push(hash, AesRequest::default()).unwrap();
+			queue.finish(&hash, vec![]).unwrap();
+		}
+
+		// the oldest finished request is forgotten
+		assert!(matches!(
+			queue.push(H256::from_low_u64_be(0), AesRequest::default()).unwrap(),
+			PushOutcome::New(_)
+		));
+		assert!(matches!(
+			queue.push(H256::from_low_u64_be(1), AesRequest::default()).unwrap(),
+			PushOutcome::Finished(_)
+		));
+	}
+}
@@ -15,6 +15,12 @@ compile_error!("feature \"std\" and feature \"sgx\" cannot be enabled at the sam
 #[cfg(all(not(feature = "std"), feature = "sgx"))]
 pub use crate::sgx_reexport_prelude::*;
 
+mod config;
+mod job_queue;
+
+pub use config::VcTaskConfig;
+pub use job_queue::{PushOutcome, VcJob, VcJobQueue};
+
 #[cfg(feature = "std")]
 use std::sync::Mutex;
 #[cfg(feature = "sgx")]
@@ -22,7 +28,7 @@ use std::sync::SgxMutex as Mutex;
 
 use codec::{Decode, Encode};
 use frame_support::{ensure, sp_runtime::traits::One};
-use futures::executor::ThreadPoolBuilder;
+use futures::executor::{ThreadPool, ThreadPoolBuilder};
 use ita_sgx_runtime::{pallet_imt::get_eligible_identities, BlockNumber, Hash, Runtime};
 #[cfg(feature = "development")]
 use ita_stf::helpers::ensure_alice;
@@ -47,7 +53,7 @@ use itp_storage::{storage_map_key, storage_value_key, StorageHasher};
 use itp_top_pool_author::traits::AuthorApi;
 use itp_types::{
 	parentchain::ParentchainId, AccountId, BlockNumber as SidechainBlockNumber, OpaqueCall,
-	ShardIdentifier, H256,
+	RequestProgress, RequestProgressEvent, ShardIdentifier, H256,
 };
 use lc_dynamic_assertion::AssertionLogicRepository;
 use lc_evm_dynamic_assertions::AssertionRepositoryItem;
@@ -55,7 +61,9 @@ use lc_stf_task_receiver::{handler::assertion::create_credential_str, StfTaskCon
 use lc_stf_task_sender::AssertionBuildRequest;
 use lc_vc_task_sender::init_vc_task_sender_storage;
 use litentry_macros::if_development_or;
-use litentry_primitives::{Assertion, DecryptableRequest, Identity, ParentchainBlockNumber};
+use litentry_primitives::{
+	Assertion, DecryptableRequest, ErrorDetail, Identity, ParentchainBlockNumber, VCMPError,
+};
 use log::*;
 use pallet_identity_management_tee::{identity_context::sort_id_graph, IdentityContext};
 use sp_core::{blake2_256, H160};
@@ -65,11 +73,11 @@ use std::{
 	format,
 	string::{String, ToString},
 	sync::{
-		mpsc::{channel, Sender},
+		mpsc::{channel, RecvTimeoutError, Sender},
 		Arc,
 	},
 	thread,
-	time::Instant,
+	time::{Duration, Instant},
 	vec::Vec,
 };
 
@@ -77,6 +85,8 @@ pub fn run_vc_handler_runner<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 	context: Arc<StfTaskContext<ShieldingKeyRepository, A, S, H, O, AR>>,
 	extrinsic_factory: Arc<Z>,
 	node_metadata_repo: Arc<N>,
+	job_queue: Arc<VcJobQueue>,
+	config: VcTaskConfig,
 ) where
 	ShieldingKeyRepository: AccessKey + Send + Sync + 'static,
 	<ShieldingKeyRepository as AccessKey>::KeyType:
@@ -92,8 +102,20 @@ pub fn run_vc_handler_runner<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 	AR: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem> + Send + Sync + 'static,
 {
 	let vc_task_receiver = init_vc_task_sender_storage();
-	let n_workers = 960;
-	let pool = ThreadPoolBuilder::new().pool_size(n_workers).create().unwrap();
+	info!("Running vc handler with config: {:?}", config);
+	if let Ok(data_provider_config) = context.data_provider_config.get() {
+		let circuit_open_duration =
+			Duration::from_millis(data_provider_config.data_provider_circuit_open_duration);
+		if config.total_backoff() <= circuit_open_duration {
+			warn!(
+				"VC task retries give up after {:?}, within the data provider circuit open duration {:?}",
+				config.total_backoff(),
+				circuit_open_duration
+			);
+		}
+	}
+	let pool = ThreadPoolBuilder::new().pool_size(config.pool_size).create().unwrap();
+	let retry_scheduler = run_retry_scheduler(pool.clone());
 
 	let (tc_sender, tc_receiver) = channel::<(ShardIdentifier, TrustedCall)>();
 
@@ -112,22 +134,20 @@ pub fn run_vc_handler_runner<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 	// use local registry to manage request reponse status
 	let req_registry = RequestRegistry::new();
 
-	while let Ok(mut req) = vc_task_receiver.recv() {
-		let request = &mut req.request;
-		let connection_hash = request.using_encoded(|x| H256::from(blake2_256(x)));
+	let handle_job = |job: VcJob| {
+		let connection_hash = job.hash;
+		let mut request = job.request.clone();
+		let fail = |e: String| {
+			let response =
+				send_vc_response(connection_hash, context.clone(), Err(e), 0u8, 0u8, false);
+			if let Err(e) = job_queue.finish(&connection_hash, response) {
+				warn!("Failed to finish vc job {:?}: {}", connection_hash, e);
+			}
+		};
+
 		let enclave_shielding_key = match context.shielding_key.retrieve_key() {
 			Ok(value) => value,
-			Err(e) => {
-				send_vc_response(
-					connection_hash,
-					context.clone(),
-					Err(format!("Failed to retrieve shielding key: {:?}", e)),
-					0u8,
-					0u8,
-					false,
-				);
-				continue
-			},
+			Err(e) => return fail(format!("Failed to retrieve shielding key: {:?}", e)),
 		};
 		let tcs = match request
 			.decrypt(Box::new(enclave_shielding_key))
@@ -138,48 +158,35 @@ pub fn run_vc_handler_runner<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 			.and_then(|top| top.to_call().cloned())
 		{
 			Some(tcs) => tcs,
-			None => {
-				send_vc_response(
-					connection_hash,
-					context.clone(),
-					Err("Failed to decode request payload".to_string()),
-					0u8,
-					0u8,
-					false,
-				);
-				continue
-			},
+			None => return fail("Failed to decode request payload".to_string()),
 		};
 		let mrenclave = match context.ocall_api.get_mrenclave_of_self() {
 			Ok(m) => m.m,
-			Err(_) => {
-				send_vc_response(
-					connection_hash,
-					context.clone(),
-					Err("Failed to get mrenclave".to_string()),
-					0u8,
-					0u8,
-					false,
-				);
-				continue
-			},
+			Err(_) => return fail("Failed to get mrenclave".to_string()),
 		};
 		if !tcs.verify_signature(&mrenclave, &request.shard) {
-			send_vc_response(
-				connection_hash,
-				context.clone(),
-				Err("Failed to verify sig".to_string()),
-				0u8,
-				0u8,
-				false,
-			);
-			continue
+			return fail("Failed to verify sig".to_string())
 		}
 
 		// Until now, preparation work is done. If any error happens, error message would have been returned already.
 
 		if let TrustedCall::request_vc(..) = tcs.call {
+			if let Some(response) = job.processed_item(0) {
+				// processed before the restart, re-publish its response
+				republish_vc_response(connection_hash, &context, response.clone(), 0u8, 1);
+				return
+			}
+			if let Some(response) = job.submitted_item(0) {
+				// submitted to the parentchain before the restart, don't issue the VC again
+				republish_vc_response(connection_hash, &context, response.clone(), 0u8, 1);
+				complete_vc_job(&job_queue, connection_hash, 0u8, 1, response.clone());
+				return
+			}
 			req_registry.add_new_item(connection_hash, 1u8);
+			context.author_api.send_request_progress(
+				connection_hash,
+				RequestProgressEvent { idx: 0u8, len: 1, progress: RequestProgress::Queued },
+			);
 
 			let shard_pool = request.shard;
 			let context_pool = context.clone();
@@ -187,34 +194,70 @@ pub fn run_vc_handler_runner<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 			let node_metadata_repo_pool = node_metadata_repo.clone();
 			let tc_sender_pool = tc_sender.clone();
 			let req_registry_pool = req_registry.clone();
-			pool.spawn_ok(async move {
-				let response = process_single_request(
-					shard_pool,
-					context_pool.clone(),
-					extrinsic_factory_pool,
-					node_metadata_repo_pool,
-					tc_sender_pool,
-					tcs.call.clone(),
-				);
+			let job_queue_pool = job_queue.clone();
+			let mut attempt = 0;
+			spawn_retryable(
+				&pool,
+				&retry_scheduler,
+				Box::new(move || {
+					let on_progress = |progress| {
+						context_pool.author_api.send_request_progress(
+							connection_hash,
+							RequestProgressEvent { idx: 0u8, len: 1, progress },
+						)
+					};
+					let on_submit = |payload: &[u8]| {
+						submit_vc_job(&job_queue_pool, connection_hash, 0u8, 1, payload)
+					};
+					let result = process_single_request(
+						shard_pool,
+						context_pool.clone(),
+						extrinsic_factory_pool.clone(),
+						node_metadata_repo_pool.clone(),
+						tc_sender_pool.clone(),
+						tcs.call.clone(),
+						&on_progress,
+						&on_submit,
+					);
+					let response = match result {
+						Err(e) => match retry_delay(&config, attempt, &e) {
+							Some(delay) => {
+								warn!("{}, retrying in {:?}", e.message(), delay);
+								attempt += 1;
+								on_progress(RequestProgress::Queued);
+								return Some(delay)
+							},
+							None => Err(e.message()),
+						},
+						Ok(response) => Ok(response),
+					};
 
-				// Totally fine to `unwrap` here. Because new item was just added above.
-				match req_registry_pool.update_item(connection_hash) {
-					Ok(do_watch) => {
-						send_vc_response(connection_hash, context_pool, response, 0u8, 1, do_watch);
-					},
-					Err(e) => {
-						error!("1 couldn't find connection_hash: {:?}", e);
-						send_vc_response(
+					// Totally fine to `unwrap` here. Because new item was just added above.
+					let response = match req_registry_pool.update_item(connection_hash) {
+						Ok(do_watch) => send_vc_response(
 							connection_hash,
-							context_pool,
-							Err(format!("1 couldn't find connection_hash: {:?}", e)),
+							context_pool.clone(),
+							response,
 							0u8,
 							1,
-							false,
-						);
-					},
-				}
-			});
+							do_watch,
+						),
+						Err(e) => {
+							error!("1 couldn't find connection_hash: {:?}", e);
+							send_vc_response(
+								connection_hash,
+								context_pool.clone(),
+								Err(format!("1 couldn't find connection_hash: {:?}", e)),
+								0u8,
+								1,
+								false,
+							)
+						},
+					};
+					complete_vc_job(&job_queue_pool, connection_hash, 0u8, 1, response);
+					None
+				}),
+			);
 		} else if let TrustedCall::request_batch_vc(
 			signer,
 			who,
@@ -238,6 +281,42 @@ pub fn run_vc_handler_runner<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 			let assertion_len = unique_assertions.len() as u8;
 			req_registry.add_new_item(connection_hash, assertion_len);
 			for (idx, assertion) in unique_assertions.iter().enumerate() {
+				if let Some(response) = job.processed_item(idx as u8) {
+					// processed before the restart, re-publish its response
+					republish_vc_response(
+						connection_hash,
+						&context,
+						response.clone(),
+						idx as u8,
+						assertion_len,
+					);
+					if let Err(e) = req_registry.update_item(connection_hash) {
+						error!("couldn't find connection_hash: {:?}", e);
+					}
+					continue
+				}
+				if let Some(response) = job.submitted_item(idx as u8) {
+					// submitted to the parentchain before the restart, don't issue the VC again
+					republish_vc_response(
+						connection_hash,
+						&context,
+						response.clone(),
+						idx as u8,
+						assertion_len,
+					);
+					if let Err(e) = req_registry.update_item(connection_hash) {
+						error!("couldn't find connection_hash: {:?}", e);
+					}
+					complete_vc_job(
+						&job_queue,
+						connection_hash,
+						idx as u8,
+						assertion_len,
+						response.clone(),
+					);
+					continue
+				}
+
 				if let Some(assertion) = assertion {
 					let new_call = TrustedCall::request_vc(
 						signer.clone(),
@@ -253,55 +332,106 @@ pub fn run_vc_handler_runner<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 					let node_metadata_repo_pool = node_metadata_repo.clone();
 					let tc_sender_pool = tc_sender.clone();
 					let req_registry_pool = req_registry.clone();
+					let job_queue_pool = job_queue.clone();
 
-					pool.spawn_ok(async move {
-						let response = process_single_request(
-							shard_pool,
-							context_pool.clone(),
-							extrinsic_factory_pool,
-							node_metadata_repo_pool,
-							tc_sender_pool,
-							new_call,
-						);
-
-						// Totally fine to `unwrap` here. Because new item was just added above.
-						match req_registry_pool.update_item(connection_hash) {
-							Ok(do_watch) => {
-								send_vc_response(
+					context.author_api.send_request_progress(
+						connection_hash,
+						RequestProgressEvent {
+							idx: idx as u8,
+							len: assertion_len,
+							progress: RequestProgress::Queued,
+						},
+					);
+					let mut attempt = 0;
+					spawn_retryable(
+						&pool,
+						&retry_scheduler,
+						Box::new(move || {
+							let on_progress = |progress| {
+								context_pool.author_api.send_request_progress(
+									connection_hash,
+									RequestProgressEvent {
+										idx: idx as u8,
+										len: assertion_len,
+										progress,
+									},
+								)
+							};
+							let on_submit = |payload: &[u8]| {
+								submit_vc_job(
+									&job_queue_pool,
 									connection_hash,
-									context_pool,
-									response,
 									idx as u8,
 									assertion_len,
-									do_watch,
-								);
-							},
-							Err(e) => {
-								error!("2 couldn't find connection_hash: {:?}", e);
-								send_vc_response(
+									payload,
+								)
+							};
+							let result = process_single_request(
+								shard_pool,
+								context_pool.clone(),
+								extrinsic_factory_pool.clone(),
+								node_metadata_repo_pool.clone(),
+								tc_sender_pool.clone(),
+								new_call.clone(),
+								&on_progress,
+								&on_submit,
+							);
+							let response = match result {
+								Err(e) => match retry_delay(&config, attempt, &e) {
+									Some(delay) => {
+										warn!("{}, retrying in {:?}", e.message(), delay);
+										attempt += 1;
+										on_progress(RequestProgress::Queued);
+										return Some(delay)
+									},
+									None => Err(e.message()),
+								},
+								Ok(response) => Ok(response),
+							};
+
+							// Totally fine to `unwrap` here. Because new item was just added above.
+							let response = match req_registry_pool.update_item(connection_hash) {
+								Ok(do_watch) => send_vc_response(
 									connection_hash,
-									context_pool,
-									Err(format!("2 couldn't find connection_hash: {:?}", e)),
+									context_pool.clone(),
+									response,
 									idx as u8,
 									assertion_len,
-									false,
-								);
-							},
-						}
-					});
-				} else {
-					// Totally fine to `unwrap` here. Because new item was just added above.
-					match req_registry.update_item(connection_hash) {
-						Ok(do_watch) => {
-							send_vc_response(
+									do_watch,
+								),
+								Err(e) => {
+									error!("2 couldn't find connection_hash: {:?}", e);
+									send_vc_response(
+										connection_hash,
+										context_pool.clone(),
+										Err(format!("2 couldn't find connection_hash: {:?}", e)),
+										idx as u8,
+										assertion_len,
+										false,
+									)
+								},
+							};
+							complete_vc_job(
+								&job_queue_pool,
 								connection_hash,
-								context.clone(),
-								Err("Duplicate assertion request".to_string()),
 								idx as u8,
 								assertion_len,
-								do_watch,
+								response,
 							);
-						},
+							None
+						}),
+					);
+				} else {
+					// Totally fine to `unwrap` here. Because new item was just added above.
+					let response = match req_registry.update_item(connection_hash) {
+						Ok(do_watch) => send_vc_response(
+							connection_hash,
+							context.clone(),
+							Err("Duplicate assertion request".to_string()),
+							idx as u8,
+							assertion_len,
+							do_watch,
+						),
 						Err(e) => {
 							error!("3 couldn't find connection_hash: {:?}", e);
 							send_vc_response(
@@ -311,20 +441,62 @@ pub fn run_vc_handler_runner<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 								idx as u8,
 								assertion_len,
 								false,
-							);
+							)
 						},
-					}
+					};
+					complete_vc_job(
+						&job_queue,
+						connection_hash,
+						idx as u8,
+						assertion_len,
+						response,
+					);
 				}
 			}
 		} else {
-			send_vc_response(
-				connection_hash,
-				context.clone(),
-				Err("Wrong trusted call. Expect request_batch_vc ".to_string()),
-				0u8,
-				0u8,
-				false,
-			);
+			fail("Wrong trusted call. Expect request_batch_vc ".to_string());
+		}
+	};
+
+	// resume the requests in flight when the worker stopped
+	match job_queue.pending() {
+		Ok(jobs) =>
+			for job in jobs {
+				info!("Resuming vc job {:?}", job.hash);
+				handle_job(job);
+			},
+		Err(e) => error!("Failed to get pending vc jobs: {}", e),
+	}
+
+	while let Ok(req) = vc_task_receiver.recv() {
+		let connection_hash = req.request.using_encoded(|x| H256::from(blake2_256(x)));
+		match job_queue.push(connection_hash, req.request) {
+			Ok(PushOutcome::New(job)) => handle_job(job),
+			Ok(PushOutcome::Pending) => {
+				// don't go through `send_vc_response`, the progress of the pending request
+				// with the same hash must not be affected
+				let vc_res = RequestVcResultOrError {
+					payload: "Duplicate request".as_bytes().to_vec(),
+					is_error: true,
+					idx: 0u8,
+					len: 0u8,
+				};
+				context.author_api.send_rpc_response(connection_hash, vc_res.encode(), false);
+			},
+			Ok(PushOutcome::Finished(job)) => {
+				info!("Re-publishing the responses of finished vc job {:?}", connection_hash);
+				republish_finished_vc_job(&context, job);
+			},
+			Err(e) => {
+				send_vc_response(
+					connection_hash,
+					context.clone(),
+					Err(format!("Failed to queue request: {}", e)),
+					0u8,
+					0u8,
+					false,
+				);
+			},
 		}
 	}
 	warn!("vc_task_receiver loop terminated");
@@ -379,7 +551,8 @@ fn send_vc_response<ShieldingKeyRepository, A, S, H, O, AR>(
 	idx: u8,
 	len: u8,
 	do_watch: bool,
-) where
+) -> Vec<u8>
+where
 	ShieldingKeyRepository: AccessKey + core::marker::Send + core::marker::Sync + 'static,
 	<ShieldingKeyRepository as AccessKey>::KeyType:
 		ShieldingCryptoEncrypt + ShieldingCryptoDecrypt + 'static,
@@ -397,6 +570,10 @@ fn send_vc_response<ShieldingKeyRepository, A, S, H, O, AR>(
 	};
 
 	context.author_api.send_rpc_response(hash, vc_res.encode(), do_watch);
+	context.author_api.send_request_progress(
+		hash,
+		RequestProgressEvent { idx, len, progress: RequestProgress::Completed(vc_res.encode()) },
+	);
 
 	if response.is_err() {
 		if let Err(e) = context.ocall_api.update_metric(EnclaveMetric::FailedVCIssuance) {
@@ -405,8 +582,180 @@ fn send_vc_response<ShieldingKeyRepository, A, S, H, O, AR>(
 	} else if let Err(e) = context.ocall_api.update_metric(EnclaveMetric::SuccessfullVCIssuance) {
 		warn!("Failed to update metric for VC Issuance: {:?}", e);
 	}
+
+	for (provider, stats) in lc_data_providers::cache::take_cache_stats() {
+		for metric in [
+			EnclaveMetric::DataProviderCacheHit(provider.clone(), stats.hits),
+			EnclaveMetric::DataProviderCacheMiss(provider, stats.misses),
+		] {
+			if let Err(e) = context.ocall_api.update_metric(metric) {
+				warn!("Failed to update metric for data provider cache: {:?}", e);
+			}
+		}
+	}
+
+	vc_res.encode()
 }
 
+// Publish the response of an item processed before the restart, to the clients re-attaching
+// to the request. The metrics were updated when it was processed.
+fn republish_vc_response<ShieldingKeyRepository, A, S, H, O, AR>(
+	hash: H256,
+	context: &StfTaskContext<ShieldingKeyRepository, A, S, H, O, AR>,
+	response: Vec<u8>,
+	idx: u8,
+	len: u8,
+) where
+	ShieldingKeyRepository: AccessKey,
+	<ShieldingKeyRepository as AccessKey>::KeyType: ShieldingCryptoEncrypt + 'static,
+	A: AuthorApi<Hash, Hash, TrustedCallSigned, Getter>,
+	S: StfEnclaveSigning<TrustedCallSigned>,
+	H: HandleState,
+	O: EnclaveOnChainOCallApi,
+	AR: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem>,
+{
+	context.author_api.send_request_progress(
+		hash,
+		RequestProgressEvent { idx, len, progress: RequestProgress::Completed(response) },
+	);
+}
+
+// Send the responses of a finished request to the client submitting the same request again,
+// in the order of its items.
+fn republish_finished_vc_job<ShieldingKeyRepository, A, S, H, O, AR>(
+	context: &StfTaskContext<ShieldingKeyRepository, A, S, H, O, AR>,
+	mut job: VcJob,
+) where
+	ShieldingKeyRepository: AccessKey,
+	<ShieldingKeyRepository as AccessKey>::KeyType: ShieldingCryptoEncrypt + 'static,
+	A: AuthorApi<Hash, Hash, TrustedCallSigned, Getter>,
+	S: StfEnclaveSigning<TrustedCallSigned>,
+	H: HandleState,
+	O: EnclaveOnChainOCallApi,
+	AR: AssertionLogicRepository<Id = H160, Item = AssertionRepositoryItem>,
+{
+	job.processed.sort_by_key(|(idx, _)| *idx);
+	let len = job.processed.len();
+	for (i, (_, response)) in job.processed.into_iter().enumerate() {
+		context.author_api.send_rpc_response(job.hash, response, i + 1 < len);
+	}
+}
+
+// Record the response of an item before its VC is submitted to the parentchain.
+fn submit_vc_job(
+	job_queue: &VcJobQueue,
+	hash: H256,
+	idx: u8,
+	len: u8,
+	payload: &[u8],
+) -> Result<(), String> {
+	let vc_res = RequestVcResultOrError { payload: payload.to_vec(), is_error: false, idx, len };
+	job_queue.submit_item(&hash, idx, vc_res.encode())
+}
+
+fn complete_vc_job(job_queue: &VcJobQueue, hash: H256, idx: u8, len: u8, response: Vec<u8>) {
+	if let Err(e) = job_queue.complete_item(&hash, idx, len, response) {
+		warn!("Failed to complete item {} of vc job {:?}: {}", idx, hash, e);
+	}
+}
+
+// Whether building the VC failed because of a data provider being temporarily unavailable,
+// in which case it's retried.
+fn is_transient_error(e: &VCMPError) -> bool {
+	matches!(e, VCMPError::RequestVCFailed(_, ErrorDetail::DataProviderUnavailable(_)))
+}
+
+// The error of processing an item of a VC request.
+enum ItemError {
+	// building the VC failed with a transient error, the item can be processed again later
+	Transient(VCMPError),
+	Failed(String),
+}
+
+impl ItemError {
+	fn message(&self) -> String {
+		match self {
+			ItemError::Transient(e) => format!("Failed to build assertion due to: {:?}", e),
+			ItemError::Failed(e) => e.clone(),
+		}
+	}
+}
+
+impl From<String> for ItemError {
+	fn from(e: String) -> Self {
+		ItemError::Failed(e)
+	}
+}
+
+impl From<&str> for ItemError {
+	fn from(e: &str) -> Self {
+		ItemError::Failed(e.to_string())
+	}
+}
+
+// The delay before processing the item again after its `attempt`-th failure, `None` if it's
+// not to be retried.
+fn retry_delay(config: &VcTaskConfig, attempt: u16, e: &ItemError) -> Option<Duration> {
+	match e {
+		ItemError::Transient(_) if attempt < config.max_retries =>
+			Some(config.retry_delay(attempt)),
+		_ => None,
+	}
+}
+
+// A task run on the pool, which returns the delay after which it's to be run again, if any.
+type RetryableTask = Box<dyn FnMut() -> Option<Duration> + Send>;
+
+// Run the task on the pool. When it's to be retried, it's handed to the retry scheduler with
+// the time it can run again, so that the pool threads don't sleep while a data provider is
+// unavailable.
+fn spawn_retryable(
+	pool: &ThreadPool,
+	retry_scheduler: &Sender<(Instant, RetryableTask)>,
+	mut task: RetryableTask,
+) {
+	let retry_scheduler = retry_scheduler.clone();
+	pool.spawn_ok(async move {
+		if let Some(delay) = task() {
+			if retry_scheduler.send((Instant::now() + delay, task)).is_err() {
+				error!("Failed to re-queue the task, the retry scheduler is gone");
+			}
+		}
+	});
+}
+
+// Spawn the thread which keeps the tasks to retry until their time has come, and spawns them
+// on the pool again.
+fn run_retry_scheduler(pool: ThreadPool) -> Sender<(Instant, RetryableTask)> {
+	let (sender, receiver) = channel::<(Instant, RetryableTask)>();
+	let scheduler = sender.clone();
+	thread::spawn(move || {
+		let mut scheduled: Vec<(Instant, RetryableTask)> = Vec::new();
+		loop {
+			let now = Instant::now();
+			let (due, not_due): (Vec<_>, Vec<_>) =
+				scheduled.into_iter().partition(|(not_before, _)| *not_before <= now);
+			scheduled = not_due;
+			for (_, task) in due {
+				spawn_retryable(&pool, &scheduler, task);
+			}
+
+			let received = match scheduled.iter().map(|(not_before, _)| *not_before).min() {
+				Some(next) =>
+					receiver.recv_timeout(if next > now { next - now } else { Duration::ZERO }),
+				None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+			};
+			match received {
+				Ok(task) => scheduled.push(task),
+				Err(RecvTimeoutError::Timeout) => {},
+				Err(RecvTimeoutError::Disconnected) => break,
+			}
+		}
+	});
+	sender
+}
+
+#[allow(clippy::too_many_arguments)]
 fn process_single_request<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 	shard: H256,
 	context: Arc<StfTaskContext<ShieldingKeyRepository, A, S, H, O, AR>>,
@@ -414,7 +763,9 @@ fn process_single_request<ShieldingKeyRepository, A, S, H, O, Z, N, AR>(
 	node_metadata_repo: Arc<N>,
 	tc_sender: Sender<(ShardIdentifier, TrustedCall)>,
 	call: TrustedCall,
-) -> Result<Vec<u8>, String>
+	on_progress: &dyn Fn(RequestProgress),
+	on_submit: &dyn Fn(&[u8]) -> Result<(), String>,
+) -> Result<Vec<u8>, ItemError>
 where
 	ShieldingKeyRepository: AccessKey + core::marker::Send + core::marker::Sync,
 	<ShieldingKeyRepository as AccessKey>::KeyType:
@@ -548,8 +899,14 @@ where
 			req_ext_hash,
 		};
 
-		let credential_str = create_credential_str(&req, &context)
-			.map_err(|e| format!("Failed to build assertion due to: {:?}", e))?;
+		let (vc_index, credential_str) = create_credential_str(&req, &context, on_progress)
+			.map_err(|e| {
+				if is_transient_error(&e) {
+					ItemError::Transient(e)
+				} else {
+					ItemError::Failed(format!("Failed to build assertion due to: {:?}", e))
+				}
+			})?;
 
 		let call_index = node_metadata_repo
 			.get_from_metadata(|m| m.vc_issued_call_indexes())
@@ -561,6 +918,7 @@ where
 			call_index,
 			who.clone(),
 			assertion.clone(),
+			vc_index,
 			id_graph_hash,
 			req_ext_hash,
 		));
@@ -588,10 +946,15 @@ where
 			.create_extrinsics(&[call], None)
 			.map_err(|e| format!("Failed to construct extrinsic for parentchain: {:?}", e))?;
 
+		// recorded first, so that the VC isn't issued again if the worker stops meanwhile
+		let response = res.encode();
+		on_submit(&response).map_err(|e| format!("Failed to record the submission: {}", e))?;
+
 		context
 			.ocall_api
 			.send_to_parentchain(xt, &ParentchainId::Litentry, false)
 			.map_err(|e| format!("Unable to send extrinsic to parentchain: {:?}", e))?;
+		on_progress(RequestProgress::SubmittedToParentchain);
 
 		if let Err(e) = context.ocall_api.update_metric(EnclaveMetric::VCBuildTime(
 			format!("{:?}", assertion),
@@ -600,9 +963,100 @@ where
 			warn!("Failed to update metric for vc build time: {:?}", e);
 		}
 
-		Ok(res.encode())
+		Ok(response)
 	} else {
 		// Would never come here.
-		Err("Expect request_vc trusted call".to_string())
+		Err("Expect request_vc trusted call".into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use lc_data_providers::Error as DataProviderError;
+	use litentry_primitives::{ErrorString, IntoErrorDetail};
+
+	fn config() -> VcTaskConfig {
+		VcTaskConfig { pool_size: 1, max_retries: 2, retry_delay: 0 }
+	}
+
+	fn failure(detail: ErrorDetail) -> VCMPError {
+		VCMPError::RequestVCFailed(Assertion::A1, detail)
+	}
+
+	fn unavailable() -> VCMPError {
+		failure(DataProviderError::ProviderUnavailable("down".to_string()).into_error_detail())
+	}
+
+	#[test]
+	fn provider_unavailable_is_transient() {
+		assert!(is_transient_error(&unavailable()));
+
+		let reason = format!("{:?}", DataProviderError::ProviderUnavailable("down".to_string()));
+		assert!(!is_transient_error(&failure(ErrorDetail::DataProviderError(
+			ErrorString::truncate_from(reason.into_bytes())
+		))));
+		assert!(!is_transient_error(&failure(ErrorDetail::NoEligibleIdentity)));
+	}
+
+	#[test]
+	fn retries_are_bounded() {
+		let config = config();
+		let e = ItemError::Transient(unavailable());
+		assert!(retry_delay(&config, 0, &e).is_some());
+		assert!(retry_delay(&config, 1, &e).is_some());
+		assert!(retry_delay(&config, 2, &e).is_none());
+		assert!(retry_delay(&config, 0, &ItemError::Failed("failed".to_string())).is_none());
+	}
+
+	#[test]
+	fn task_is_requeued_until_done() {
+		let pool = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+		let retry_scheduler = run_retry_scheduler(pool.clone());
+		let (sender, receiver) = channel();
+		let mut attempts = 0;
+		spawn_retryable(
+			&pool,
+			&retry_scheduler,
+			Box::new(move || {
+				attempts += 1;
+				if attempts < 3 {
+					return Some(Duration::from_millis(10))
+				}
+				sender.send(attempts).unwrap();
+				None
+			}),
+		);
+		assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(3));
+	}
+
+	#[test]
+	fn waiting_task_does_not_block_pool() {
+		let pool = ThreadPoolBuilder::new().pool_size(1).create().unwrap();
+		let retry_scheduler = run_retry_scheduler(pool.clone());
+		let (sender, receiver) = channel();
+		let waiting_sender = sender.clone();
+		let mut attempts = 0;
+		spawn_retryable(
+			&pool,
+			&retry_scheduler,
+			Box::new(move || {
+				attempts += 1;
+				if attempts < 2 {
+					return Some(Duration::from_secs(60))
+				}
+				waiting_sender.send("waiting").unwrap();
+				None
+			}),
+		);
+		spawn_retryable(
+			&pool,
+			&retry_scheduler,
+			Box::new(move || {
+				sender.send("other").unwrap();
+				None
+			}),
+		);
+		assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok("other"));
 	}
 }
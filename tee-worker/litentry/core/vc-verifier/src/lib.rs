@@ -0,0 +1,424 @@
+// Copyright 2020-2024 Trust Computing GmbH.
+// This file is part of Litentry.
+//
+// Litentry is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Litentry is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Litentry.  If not, see <https://www.gnu.org/licenses/>.
+
+// Offline verification of the credentials issued by the tee-worker.
+//
+// Everything a consumer needs is the issued credential json and a snapshot of the enclaves
+// registered in `pallet-teebag`, no connection to the parachain or the worker is required.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sp_core::{ed25519, Pair};
+
+pub const CREDENTIAL_TYPE: &str = "VerifiableCredential";
+pub const PROOF_TYPE: &str = "Ed25519Signature2020";
+
+// the proof is the only json object member added after signing
+const PROOF_MEMBER: &str = ",\"proof\":{";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+	/// The credential is not a json object
+	InvalidJson(String),
+}
+
+/// A worker enclave registered in `Teebag::EnclaveRegistry`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredEnclave {
+	/// base58 encoded mrenclave, the encoding used by the credential issuer
+	pub mrenclave: String,
+	/// hex encoded ed25519 public key the enclave signs credentials with
+	pub vc_pubkey: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckResult {
+	Passed,
+	Failed(String),
+	/// The check could not be performed because a previous check failed
+	Skipped(String),
+}
+
+impl CheckResult {
+	pub fn is_passed(&self) -> bool {
+		matches!(self, CheckResult::Passed)
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationReport {
+	/// The proof is a valid signature of the credential by `verificationMethod`
+	pub signature: CheckResult,
+	/// `verificationMethod` is the vc pubkey of a registered enclave
+	pub enclave_registered: CheckResult,
+	/// The issuer mrenclave is the one of the registered enclave
+	pub mrenclave_match: CheckResult,
+	/// The credential has the structure of the credentials issued by the worker
+	pub schema: CheckResult,
+	/// The credential is not expired
+	pub expiry: CheckResult,
+}
+
+impl VerificationReport {
+	pub fn checks(&self) -> [(&'static str, &CheckResult); 5] {
+		[
+			("signature", &self.signature),
+			("enclave registered", &self.enclave_registered),
+			("mrenclave match", &self.mrenclave_match),
+			("schema", &self.schema),
+			("expiry", &self.expiry),
+		]
+	}
+
+	pub fn is_valid(&self) -> bool {
+		self.checks().iter().all(|(_, result)| result.is_passed())
+	}
+}
+
+// The subset of the credential fields the verification relies on
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Credential {
+	#[serde(rename = "@context")]
+	context: Vec<String>,
+	id: String,
+	#[serde(rename = "type")]
+	types: Vec<String>,
+	credential_subject: CredentialSubject,
+	issuer: Issuer,
+	issuance_date: String,
+	expiration_date: Option<String>,
+	proof: Proof,
+	credential_schema: CredentialSchema,
+}
+
+#[derive(Deserialize)]
+struct CredentialSubject {
+	id: String,
+}
+
+#[derive(Deserialize)]
+struct Issuer {
+	id: String,
+	mrenclave: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Proof {
+	#[serde(rename = "type")]
+	proof_type: String,
+	proof_value: String,
+	verification_method: String,
+}
+
+#[derive(Deserialize)]
+struct CredentialSchema {
+	id: String,
+}
+
+/// Verifies the issued credential json against the registered `enclaves`,
+/// `now` is the unix timestamp in seconds the expiry is checked against.
+///
+/// The credential must be passed exactly as issued, since the signature covers its json encoding.
+pub fn verify(
+	credential: &str,
+	enclaves: &[RegisteredEnclave],
+	now: i64,
+) -> Result<VerificationReport, Error> {
+	let credential = credential.trim();
+	let value = parse_object(credential)?;
+
+	let parsed = match serde_json::from_value::<Credential>(value) {
+		Ok(parsed) => parsed,
+		Err(e) => {
+			let skipped = CheckResult::Skipped("schema check failed".to_string());
+			return Ok(VerificationReport {
+				signature: skipped.clone(),
+				enclave_registered: skipped.clone(),
+				mrenclave_match: skipped.clone(),
+				schema: CheckResult::Failed(format!("{}", e)),
+				expiry: skipped,
+			})
+		},
+	};
+
+	let signature = verify_signature(credential, &parsed.proof);
+	let enclave = find_enclave(enclaves, &parsed.proof.verification_method);
+	let enclave_registered = match enclave {
+		Some(_) => CheckResult::Passed,
+		None => CheckResult::Failed(format!(
+			"no registered enclave with vc pubkey {}",
+			parsed.proof.verification_method
+		)),
+	};
+	let mrenclave_match = match enclave {
+		Some(enclave) if enclave.mrenclave == parsed.issuer.mrenclave => CheckResult::Passed,
+		Some(enclave) => CheckResult::Failed(format!(
+			"issuer mrenclave {} differs from registered mrenclave {}",
+			parsed.issuer.mrenclave, enclave.mrenclave
+		)),
+		None => CheckResult::Skipped("issuer enclave is not registered".to_string()),
+	};
+
+	Ok(VerificationReport {
+		signature,
+		enclave_registered,
+		mrenclave_match,
+		schema: check_schema(&parsed),
+		expiry: check_expiry(&parsed, now),
+	})
+}
+
+/// Checks the credential json has the structure of the credentials issued by the worker, it's
+/// the check the worker runs before returning a signed credential.
+pub fn validate_schema(credential: &str) -> CheckResult {
+	let value = match parse_object(credential.trim()) {
+		Ok(value) => value,
+		Err(Error::InvalidJson(e)) => return CheckResult::Failed(e),
+	};
+	match serde_json::from_value::<Credential>(value) {
+		Ok(parsed) => check_schema(&parsed),
+		Err(e) => CheckResult::Failed(format!("{}", e)),
+	}
+}
+
+fn parse_object(credential: &str) -> Result<Value, Error> {
+	let value: Value =
+		serde_json::from_str(credential).map_err(|e| Error::InvalidJson(format!("{}", e)))?;
+	if !value.is_object() {
+		return Err(Error::InvalidJson("credential is not an object".to_string()))
+	}
+	Ok(value)
+}
+
+fn verify_signature(credential: &str, proof: &Proof) -> CheckResult {
+	let Some(signature) = decode_hex(&proof.proof_value)
+		.and_then(|v| <[u8; 64]>::try_from(v).ok())
+		.map(ed25519::Signature::from_raw)
+	else {
+		return CheckResult::Failed("invalid proof value".to_string())
+	};
+	let Some(public) = decode_hex(&proof.verification_method)
+		.and_then(|v| <[u8; 32]>::try_from(v).ok())
+		.map(ed25519::Public::from_raw)
+	else {
+		return CheckResult::Failed("invalid verification method".to_string())
+	};
+	let Some(payload) = unsigned_payload(credential) else {
+		return CheckResult::Failed("cannot locate proof in credential".to_string())
+	};
+
+	if ed25519::Pair::verify(&signature, payload.as_bytes(), &public) {
+		CheckResult::Passed
+	} else {
+		CheckResult::Failed("signature doesn't match credential".to_string())
+	}
+}
+
+// The worker signs the json encoding of the credential before adding the proof to it. Serde
+// keeps the field order, so removing the proof member gives back the signed payload.
+fn unsigned_payload(credential: &str) -> Option<String> {
+	// the proof comes after the credential subject, which may contain arbitrary strings
+	let start = credential.rfind(PROOF_MEMBER)?;
+	// proof values never contain a closing brace
+	let end = start + credential[start..].find('}')? + 1;
+	Some(format!("{}{}", &credential[..start], &credential[end..]))
+}
+
+fn find_enclave<'a>(
+	enclaves: &'a [RegisteredEnclave],
+	verification_method: &str,
+) -> Option<&'a RegisteredEnclave> {
+	let public = decode_hex(verification_method)?;
+	enclaves.iter().find(|e| decode_hex(&e.vc_pubkey).as_ref() == Some(&public))
+}
+
+fn check_schema(credential: &Credential) -> CheckResult {
+	if credential.context.is_empty() {
+		return CheckResult::Failed("empty @context".to_string())
+	}
+	if credential.id.is_empty() {
+		return CheckResult::Failed("empty id".to_string())
+	}
+	if !credential.types.iter().any(|t| t == CREDENTIAL_TYPE) {
+		return CheckResult::Failed(format!("type is not {}", CREDENTIAL_TYPE))
+	}
+	if credential.credential_subject.id.is_empty() {
+		return CheckResult::Failed("empty credential subject id".to_string())
+	}
+	if credential.issuer.id.is_empty() || credential.issuer.mrenclave.is_empty() {
+		return CheckResult::Failed("empty issuer".to_string())
+	}
+	if DateTime::parse_from_rfc3339(&credential.issuance_date).is_err() {
+		return CheckResult::Failed("invalid issuance date".to_string())
+	}
+	if credential.proof.proof_type != PROOF_TYPE {
+		return CheckResult::Failed(format!("proof type is not {}", PROOF_TYPE))
+	}
+	if credential.credential_schema.id.is_empty() {
+		return CheckResult::Failed("empty credential schema id".to_string())
+	}
+	CheckResult::Passed
+}
+
+fn check_expiry(credential: &Credential, now: i64) -> CheckResult {
+	match credential.expiration_date {
+		Some(ref expiration_date) => match DateTime::parse_from_rfc3339(expiration_date) {
+			Ok(date) if date.timestamp() <= now =>
+				CheckResult::Failed(format!("expired at {}", expiration_date)),
+			Ok(_) => CheckResult::Passed,
+			Err(_) => CheckResult::Failed("invalid expiration date".to_string()),
+		},
+		None => CheckResult::Passed,
+	}
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+	hex::decode(value.strip_prefix("0x").unwrap_or(value)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const MRENCLAVE: &str = "11111111111111111111111111111111";
+	// 2023-01-01T00:00:00+00:00
+	const NOW: i64 = 1672531200;
+
+	fn unsigned_credential(expiration_date: &str) -> String {
+		format!(
+			"{{\"@context\":[\"https://www.w3.org/2018/credentials/v1\"],\"id\":\"0x01\",\
+			\"type\":[\"VerifiableCredential\"],\"credentialSubject\":{{\"id\":\"did:litentry:substrate:0x00\",\
+			\"description\":\"\",\"type\":\"\",\"assertionText\":\"A1\",\"values\":[true],\"endpoint\":\"\"}},\
+			\"issuer\":{{\"id\":\"did:litentry:substrate:0x01\",\"name\":\"Litentry TEE Worker\",\"mrenclave\":\"{}\"}},\
+			\"issuanceDate\":\"2022-12-01T00:00:00+00:00\",\"expirationDate\":\"{}\",\
+			\"parachainBlockNumber\":1,\"sidechainBlockNumber\":1,\
+			\"credentialSchema\":{{\"id\":\"https://example.com/schema.json\",\"type\":\"JsonSchemaValidator2018\"}}}}",
+			MRENCLAVE, expiration_date
+		)
+	}
+
+	fn sign(credential: &str, pair: &ed25519::Pair) -> String {
+		let signature = pair.sign(credential.as_bytes());
+		let proof = format!(
+			",\"proof\":{{\"created\":\"2022-12-01T00:00:00+00:00\",\"type\":\"Ed25519Signature2020\",\
+			\"proofPurpose\":\"assertionMethod\",\"proofValue\":\"{}\",\"verificationMethod\":\"0x{}\"}}",
+			hex::encode(signature.0),
+			hex::encode(pair.public().0)
+		);
+		let at = credential.find(",\"credentialSchema\"").unwrap();
+		format!("{}{}{}", &credential[..at], proof, &credential[at..])
+	}
+
+	fn enclaves(pair: &ed25519::Pair) -> Vec<RegisteredEnclave> {
+		vec![RegisteredEnclave {
+			mrenclave: MRENCLAVE.to_string(),
+			vc_pubkey: format!("0x{}", hex::encode(pair.public().0)),
+		}]
+	}
+
+	#[test]
+	fn verify_works() {
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let credential = sign(&unsigned_credential("2023-02-01T00:00:00+00:00"), &pair);
+
+		let report = verify(&credential, &enclaves(&pair), NOW).unwrap();
+		assert!(report.is_valid(), "{:?}", report);
+	}
+
+	#[test]
+	fn verify_fails_for_tampered_credential() {
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let credential = sign(&unsigned_credential("2023-02-01T00:00:00+00:00"), &pair)
+			.replace("\"values\":[true]", "\"values\":[false]");
+
+		let report = verify(&credential, &enclaves(&pair), NOW).unwrap();
+		assert!(matches!(report.signature, CheckResult::Failed(_)));
+		assert!(report.schema.is_passed());
+	}
+
+	#[test]
+	fn verify_fails_for_unregistered_enclave() {
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let other = ed25519::Pair::from_seed(&[2; 32]);
+		let credential = sign(&unsigned_credential("2023-02-01T00:00:00+00:00"), &pair);
+
+		let report = verify(&credential, &enclaves(&other), NOW).unwrap();
+		assert!(report.signature.is_passed());
+		assert!(matches!(report.enclave_registered, CheckResult::Failed(_)));
+		assert!(matches!(report.mrenclave_match, CheckResult::Skipped(_)));
+	}
+
+	#[test]
+	fn verify_fails_for_mrenclave_mismatch() {
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let credential = sign(&unsigned_credential("2023-02-01T00:00:00+00:00"), &pair);
+		let mut enclaves = enclaves(&pair);
+		enclaves[0].mrenclave = "22222222222222222222222222222222".to_string();
+
+		let report = verify(&credential, &enclaves, NOW).unwrap();
+		assert!(report.enclave_registered.is_passed());
+		assert!(matches!(report.mrenclave_match, CheckResult::Failed(_)));
+	}
+
+	#[test]
+	fn verify_fails_for_expired_credential() {
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let credential = sign(&unsigned_credential("2022-12-31T00:00:00+00:00"), &pair);
+
+		let report = verify(&credential, &enclaves(&pair), NOW).unwrap();
+		assert!(report.signature.is_passed());
+		assert!(matches!(report.expiry, CheckResult::Failed(_)));
+		assert!(!report.is_valid());
+	}
+
+	#[test]
+	fn verify_fails_for_invalid_schema() {
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let credential = sign(&unsigned_credential("2023-02-01T00:00:00+00:00"), &pair)
+			.replace("\"issuer\"", "\"issuers\"");
+
+		let report = verify(&credential, &enclaves(&pair), NOW).unwrap();
+		assert!(matches!(report.schema, CheckResult::Failed(_)));
+		assert!(matches!(report.signature, CheckResult::Skipped(_)));
+
+		assert!(matches!(verify("[]", &[], NOW), Err(Error::InvalidJson(_))));
+	}
+
+	#[test]
+	fn validate_schema_works() {
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let credential = sign(&unsigned_credential("2023-02-01T00:00:00+00:00"), &pair);
+		assert!(validate_schema(&credential).is_passed());
+
+		// the proof is missing
+		let credential = unsigned_credential("2023-02-01T00:00:00+00:00");
+		assert!(matches!(validate_schema(&credential), CheckResult::Failed(_)));
+		assert!(matches!(validate_schema("[]"), CheckResult::Failed(_)));
+	}
+}
@@ -23,6 +23,11 @@ use std::string::{String, ToString};
 
 // Some dependency conflict of bitcoin crate with enclave building
 // when putting these functions into core-premitives/utils.
+// The address functions below panic on an invalid pubkey, check it first if it's untrusted.
+pub fn is_valid_bitcoin_pubkey(pubkey_string: &str) -> bool {
+	PublicKey::from_str(pubkey_string).is_ok()
+}
+
 pub fn p2wpkh_address(pubkey_string: &str) -> String {
 	let pubkey = PublicKey::from_str(pubkey_string).expect("pubkey");
 	let address = Address::p2wpkh(&pubkey, Network::Bitcoin);
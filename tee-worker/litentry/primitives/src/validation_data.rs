@@ -34,10 +34,25 @@ pub enum TwitterValidationData {
 
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct DiscordValidationData {
-	pub channel_id: ValidationString,
-	pub message_id: ValidationString,
-	pub guild_id: ValidationString,
+pub enum DiscordValidationData {
+	PublicMessage {
+		channel_id: ValidationString,
+		message_id: ValidationString,
+		guild_id: ValidationString,
+	},
+	OAuth2 {
+		code: ValidationString,
+		state: ValidationString,
+		redirect_uri: ValidationString,
+	},
+}
+
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GithubValidationData {
+	pub code: ValidationString,
+	pub state: ValidationString,
+	pub redirect_uri: ValidationString,
 }
 
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -55,6 +70,8 @@ pub enum Web2ValidationData {
 	Twitter(TwitterValidationData),
 	#[codec(index = 1)]
 	Discord(DiscordValidationData),
+	#[codec(index = 2)]
+	Github(GithubValidationData),
 }
 
 #[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
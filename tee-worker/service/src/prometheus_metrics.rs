@@ -97,6 +97,12 @@ lazy_static! {
 	static ref FAILED_VC_ISSUANCE_TASKS: Counter =
 		register_counter!("litentry_worker_vc_failed_issuances_tasks", "Failed VC Issuance tasks")
 			.unwrap();
+	static ref DATA_PROVIDER_CACHE_HITS: CounterVec =
+		register_counter_vec!("litentry_worker_data_provider_cache_hits", "Data provider response cache hits", &["provider"])
+			.unwrap();
+	static ref DATA_PROVIDER_CACHE_MISSES: CounterVec =
+		register_counter_vec!("litentry_worker_data_provider_cache_misses", "Data provider response cache misses", &["provider"])
+			.unwrap();
 
 }
 
@@ -243,6 +249,12 @@ impl ReceiveEnclaveMetrics for EnclaveMetricsReceiver {
 			EnclaveMetric::FailedVCIssuance => {
 				FAILED_VC_ISSUANCE_TASKS.inc();
 			},
+			EnclaveMetric::DataProviderCacheHit(provider, count) => {
+				DATA_PROVIDER_CACHE_HITS.with_label_values(&[&provider]).inc_by(count as f64);
+			},
+			EnclaveMetric::DataProviderCacheMiss(provider, count) => {
+				DATA_PROVIDER_CACHE_MISSES.with_label_values(&[&provider]).inc_by(count as f64);
+			},
 		}
 		Ok(())
 	}